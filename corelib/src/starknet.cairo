@@ -9,6 +9,7 @@ use zeroable::Zeroable;
 // StorageAccess
 mod storage_access;
 use storage_access::StorageAccess;
+use storage_access::StorageAccessInternal;
 use storage_access::StorageAddress;
 use storage_access::StorageBaseAddress;
 use storage_access::storage_base_address_const;
@@ -23,6 +24,10 @@ use syscalls::call_contract_syscall;
 use syscalls::storage_read_syscall;
 use syscalls::storage_write_syscall;
 
+// Event
+mod event;
+use event::Event;
+
 // ContractAddress
 mod contract_address;
 use contract_address::ContractAddress;
@@ -0,0 +1,10 @@
+use array::ArrayTrait;
+
+// A type that can be emitted as a Starknet event.
+// Usually derived using `#[derive(starknet::Event)]` on a struct or an enum of a contract or a
+// component module: the members of a struct marked with `#[key]` are appended to the keys of the
+// event, and the other members to its data. A variant of an enum appends its selector,
+// `starknet_keccak` of the variant name, to the keys, followed by the keys and data of its value.
+trait Event<T> {
+    fn append_keys_and_data(self: T, ref keys: Array<felt252>, ref data: Array<felt252>);
+}
@@ -61,6 +61,21 @@ trait StorageAccess<T> {
     fn write(address_domain: u32, base: StorageBaseAddress, value: T) -> SyscallResult<()>;
 }
 
+/// Access to values of type `T` laid out one after the other in the storage, as done by
+/// `#[derive(StorageAccess)]`, which also derives this trait.
+///
+/// The value is laid out over `size_internal()` consecutive storage addresses, starting `offset`
+/// addresses after `base`.
+trait StorageAccessInternal<T> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<T>;
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: T
+    ) -> SyscallResult<()>;
+    fn size_internal() -> u8;
+}
+
 impl StorageAccessFelt252 of StorageAccess<felt252> {
     #[inline(always)]
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<felt252> {
@@ -72,6 +87,25 @@ impl StorageAccessFelt252 of StorageAccess<felt252> {
     }
 }
 
+impl StorageAccessInternalFelt252 of StorageAccessInternal<felt252> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<felt252> {
+        storage_read_syscall(address_domain, storage_address_from_base_and_offset(base, offset))
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: felt252
+    ) -> SyscallResult<()> {
+        storage_write_syscall(
+            address_domain, storage_address_from_base_and_offset(base, offset), value
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessBool of StorageAccess<bool> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<bool> {
         Result::Ok(StorageAccess::<felt252>::read(address_domain, base)? != 0)
@@ -86,6 +120,33 @@ impl StorageAccessBool of StorageAccess<bool> {
     }
 }
 
+impl StorageAccessInternalBool of StorageAccessInternal<bool> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<bool> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )? != 0
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: bool
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, if value {
+                1
+            } else {
+                0
+            }
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU8 of StorageAccess<u8> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u8> {
         Result::Ok(
@@ -100,6 +161,29 @@ impl StorageAccessU8 of StorageAccess<u8> {
     }
 }
 
+impl StorageAccessInternalU8 of StorageAccessInternal<u8> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u8> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('StorageAccessU8 - non u8')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u8
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU16 of StorageAccess<u16> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u16> {
         Result::Ok(
@@ -114,6 +198,29 @@ impl StorageAccessU16 of StorageAccess<u16> {
     }
 }
 
+impl StorageAccessInternalU16 of StorageAccessInternal<u16> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u16> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('StorageAccessU16 - non u16')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u16
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU32 of StorageAccess<u32> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u32> {
         Result::Ok(
@@ -128,6 +235,29 @@ impl StorageAccessU32 of StorageAccess<u32> {
     }
 }
 
+impl StorageAccessInternalU32 of StorageAccessInternal<u32> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u32> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('StorageAccessU32 - non u32')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u32
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU64 of StorageAccess<u64> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u64> {
         Result::Ok(
@@ -142,6 +272,29 @@ impl StorageAccessU64 of StorageAccess<u64> {
     }
 }
 
+impl StorageAccessInternalU64 of StorageAccessInternal<u64> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u64> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('StorageAccessU64 - non u64')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u64
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU128 of StorageAccess<u128> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u128> {
         Result::Ok(
@@ -156,6 +309,29 @@ impl StorageAccessU128 of StorageAccess<u128> {
     }
 }
 
+impl StorageAccessInternalU128 of StorageAccessInternal<u128> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u128> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('StorageAccessU128 - non u128')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u128
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessU256 of StorageAccess<u256> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u256> {
         Result::Ok(
@@ -175,6 +351,37 @@ impl StorageAccessU256 of StorageAccess<u256> {
     }
 }
 
+impl StorageAccessInternalU256 of StorageAccessInternal<u256> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<u256> {
+        Result::Ok(
+            u256 {
+                low: StorageAccessInternal::<u128>::read_at_offset_internal(
+                    address_domain, base, offset
+                )?,
+                high: StorageAccessInternal::<u128>::read_at_offset_internal(
+                    address_domain, base, offset + 1_u8
+                )?
+            }
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: u256
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<u128>::write_at_offset_internal(
+            address_domain, base, offset, value.low
+        )?;
+        StorageAccessInternal::<u128>::write_at_offset_internal(
+            address_domain, base, offset + 1_u8, value.high
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        2_u8
+    }
+}
+
 impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<StorageAddress> {
         Result::Ok(
@@ -191,6 +398,29 @@ impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     }
 }
 
+impl StorageAccessInternalStorageAddress of StorageAccessInternal<StorageAddress> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<StorageAddress> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('Non StorageAddress')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: StorageAddress
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<ContractAddress> {
         Result::Ok(
@@ -207,6 +437,29 @@ impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
     }
 }
 
+impl StorageAccessInternalContractAddress of StorageAccessInternal<ContractAddress> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<ContractAddress> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('Non ContractAddress')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ContractAddress
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
 impl StorageAccessClassHash of StorageAccess<ClassHash> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<ClassHash> {
         Result::Ok(
@@ -218,3 +471,56 @@ impl StorageAccessClassHash of StorageAccess<ClassHash> {
         StorageAccess::<felt252>::write(address_domain, base, value.into())
     }
 }
+
+impl StorageAccessInternalClassHash of StorageAccessInternal<ClassHash> {
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<ClassHash> {
+        Result::Ok(
+            StorageAccessInternal::<felt252>::read_at_offset_internal(
+                address_domain, base, offset
+            )?.try_into().expect('Non ClassHash')
+        )
+    }
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ClassHash
+    ) -> SyscallResult<()> {
+        StorageAccessInternal::<felt252>::write_at_offset_internal(
+            address_domain, base, offset, value.into()
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessUnit of StorageAccess<()> {
+    #[inline(always)]
+    fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn write(address_domain: u32, base: StorageBaseAddress, value: ()) -> SyscallResult<()> {
+        Result::Ok(())
+    }
+}
+
+impl StorageAccessInternalUnit of StorageAccessInternal<()> {
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: u32, base: StorageBaseAddress, offset: u8, value: ()
+    ) -> SyscallResult<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        0_u8
+    }
+}
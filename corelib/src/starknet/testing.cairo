@@ -1,7 +1,28 @@
 use starknet::ContractAddress;
+use starknet::StorageAddress;
 
 extern fn set_caller_address(address: ContractAddress) implicits() nopanic;
 extern fn set_contract_address(address: ContractAddress) implicits() nopanic;
 extern fn set_sequencer_address(address: ContractAddress) implicits() nopanic;
 extern fn set_block_number(block_number: u64) implicits() nopanic;
 extern fn set_block_timestamp(block_timestamp: u64) implicits() nopanic;
+
+// Transaction info setters.
+extern fn set_version(version: felt252) implicits() nopanic;
+extern fn set_account_contract_address(address: ContractAddress) implicits() nopanic;
+extern fn set_max_fee(fee: u128) implicits() nopanic;
+extern fn set_transaction_hash(hash: felt252) implicits() nopanic;
+extern fn set_chain_id(chain_id: felt252) implicits() nopanic;
+extern fn set_nonce(nonce: felt252) implicits() nopanic;
+extern fn set_signature(signature: Span<felt252>) implicits() nopanic;
+
+// Direct access to the storage of any contract, without calling it.
+extern fn set_storage_at(
+    contract_address: ContractAddress, address: StorageAddress, value: felt252
+) implicits() nopanic;
+extern fn get_storage_at(
+    contract_address: ContractAddress, address: StorageAddress
+) -> felt252 implicits() nopanic;
+
+// Resets the storage of all the contracts and the execution info to their default values.
+extern fn reset_state() implicits() nopanic;
@@ -1,7 +1,12 @@
 use num_bigint::{BigInt, ToBigInt};
+use thiserror::Error;
 
-use crate::instructions::{Instruction, InstructionBody};
-use crate::operand::{DerefOrImmediate, Operation, Register, ResOperand};
+use crate::encoder::DecodeError;
+use crate::instructions::{
+    AddApInstruction, AssertEqInstruction, CallInstruction, Instruction, InstructionBody,
+    JnzInstruction, JumpInstruction, RetInstruction,
+};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
 
 #[cfg(test)]
 #[path = "assembler_test.rs"]
@@ -182,6 +187,109 @@ impl Instruction {
     }
 }
 
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum DisassemblyError {
+    #[error("Failed decoding the instruction at offset {offset}: {error}.")]
+    DecodeError { offset: usize, error: DecodeError },
+    #[error("The instruction at offset {offset} has no casm representation: {repr:?}.")]
+    UnsupportedInstruction { offset: usize, repr: InstructionRepr },
+}
+
+impl InstructionRepr {
+    /// Returns the instruction represented by `self`, the inverse of [Instruction::assemble].
+    /// Returns `None` for representations with no matching instruction, such as a `call` to a
+    /// double dereference target. Fields ignored by the execution of the instruction are not
+    /// validated.
+    pub fn disassemble(&self) -> Option<Instruction> {
+        let inc_ap = match self.ap_update {
+            ApUpdate::Regular | ApUpdate::Add2 => false,
+            ApUpdate::Add1 => true,
+            ApUpdate::Add => {
+                if self.opcode != Opcode::Nop || self.pc_update != PcUpdate::Regular {
+                    return None;
+                }
+                return Some(Instruction::new(
+                    InstructionBody::AddAp(AddApInstruction { operand: self.res_operand()? }),
+                    false,
+                ));
+            }
+        };
+        let body = match (&self.opcode, &self.pc_update) {
+            (Opcode::AssertEq, PcUpdate::Regular) => {
+                InstructionBody::AssertEq(AssertEqInstruction {
+                    a: CellRef { register: self.dst_register, offset: self.off0 },
+                    b: self.res_operand()?,
+                })
+            }
+            (Opcode::Call, PcUpdate::Jump | PcUpdate::JumpRel) if self.res == Res::Op1 => {
+                InstructionBody::Call(CallInstruction {
+                    target: self.op1_deref_or_immediate()?,
+                    relative: self.pc_update == PcUpdate::JumpRel,
+                })
+            }
+            (Opcode::Ret, PcUpdate::Jump) => InstructionBody::Ret(RetInstruction {}),
+            (Opcode::Nop, PcUpdate::Jump | PcUpdate::JumpRel) if self.res == Res::Op1 => {
+                InstructionBody::Jump(JumpInstruction {
+                    target: self.op1_deref_or_immediate()?,
+                    relative: self.pc_update == PcUpdate::JumpRel,
+                })
+            }
+            (Opcode::Nop, PcUpdate::Jnz) => InstructionBody::Jnz(JnzInstruction {
+                jump_offset: self.op1_deref_or_immediate()?,
+                condition: CellRef { register: self.dst_register, offset: self.off0 },
+            }),
+            _ => return None,
+        };
+        Some(Instruction::new(body, inc_ap))
+    }
+
+    /// Returns the op1 operand of the instruction, if it is not a double dereference.
+    fn op1_deref_or_immediate(&self) -> Option<DerefOrImmediate> {
+        Some(match self.op1_addr {
+            Op1Addr::Imm => DerefOrImmediate::Immediate(self.imm.clone()?.into()),
+            Op1Addr::AP => {
+                DerefOrImmediate::Deref(CellRef { register: Register::AP, offset: self.off2 })
+            }
+            Op1Addr::FP => {
+                DerefOrImmediate::Deref(CellRef { register: Register::FP, offset: self.off2 })
+            }
+            Op1Addr::Op0 => return None,
+        })
+    }
+
+    /// Returns the res operand of the instruction.
+    fn res_operand(&self) -> Option<ResOperand> {
+        let op0 = CellRef { register: self.op0_register, offset: self.off1 };
+        let op = match self.res {
+            Res::Op1 => {
+                return Some(match self.op1_addr {
+                    Op1Addr::Op0 => ResOperand::DoubleDeref(op0, self.off2),
+                    _ => self.op1_deref_or_immediate()?.into(),
+                });
+            }
+            Res::Add => Operation::Add,
+            Res::Mul => Operation::Mul,
+            Res::Unconstrained => return None,
+        };
+        Some(ResOperand::BinOp(BinOpOperand { op, a: op0, b: self.op1_deref_or_immediate()? }))
+    }
+}
+
+/// Decodes and disassembles the instructions of the given bytecode.
+pub fn disassemble(bytecode: &[BigInt]) -> Result<Vec<Instruction>, DisassemblyError> {
+    let mut instructions = vec![];
+    let mut offset = 0;
+    while offset < bytecode.len() {
+        let repr = InstructionRepr::decode(&bytecode[offset..])
+            .map_err(|error| DisassemblyError::DecodeError { offset, error })?;
+        let instruction =
+            repr.disassemble().ok_or(DisassemblyError::UnsupportedInstruction { offset, repr })?;
+        offset += instruction.body.op_size();
+        instructions.push(instruction);
+    }
+    Ok(instructions)
+}
+
 impl Register {
     fn to_op1_addr(self) -> Op1Addr {
         match self {
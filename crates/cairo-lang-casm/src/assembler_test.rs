@@ -1,10 +1,11 @@
-use num_bigint::ToBigInt;
+use num_bigint::{BigInt, ToBigInt};
 use pretty_assertions::assert_eq;
 use test_log::test;
 
-use super::InstructionRepr;
+use super::{disassemble, DisassemblyError, InstructionRepr};
 use crate::assembler::{ApUpdate, FpUpdate, Op1Addr, Opcode, PcUpdate, Res};
 use crate::casm;
+use crate::encoder::DecodeError;
 use crate::inline::CasmContext;
 use crate::operand::Register;
 
@@ -266,3 +267,59 @@ fn test_instruction_with_hint() {
         },
     );
 }
+
+#[test]
+fn test_disassemble() {
+    let casm = casm! {
+        [ap + 0] = 1, ap++;
+        [ap + 0] = 13, ap++;
+        call rel 3;
+        ret;
+        jmp rel 5 if [fp + -3] != 0;
+        [ap + 0] = [fp + -5], ap++;
+        jmp rel 8;
+        jmp abs [ap + 2], ap++;
+        [ap + 0] = [fp + -5] + [fp + -4], ap++;
+        [fp + -3] = [ap + 0] * 1, ap++;
+        [fp + 1] = [[ap + 2] + 3];
+        ap += [fp + -2];
+        ap += 5;
+        call abs [fp + -3];
+        ret;
+    };
+    let bytecode =
+        casm.instructions.iter().flat_map(|inst| inst.assemble().encode()).collect::<Vec<_>>();
+    assert_eq!(disassemble(&bytecode), Ok(casm.instructions));
+}
+
+#[test]
+fn test_disassemble_failures() {
+    // A valid encoding with no instruction representation: `call` to a double dereference.
+    let call_double_deref = InstructionRepr {
+        off0: 0,
+        off1: 1,
+        off2: 3,
+        imm: None,
+        dst_register: Register::AP,
+        op0_register: Register::AP,
+        op1_addr: Op1Addr::Op0,
+        res: Res::Op1,
+        pc_update: PcUpdate::Jump,
+        ap_update: ApUpdate::Add2,
+        fp_update: FpUpdate::ApPlus2,
+        opcode: Opcode::Call,
+    };
+    let mut bytecode = casm!(ret;).instructions[0].assemble().encode();
+    bytecode.extend(call_double_deref.encode());
+    assert_eq!(
+        disassemble(&bytecode),
+        Err(DisassemblyError::UnsupportedInstruction { offset: 1, repr: call_double_deref })
+    );
+    assert_eq!(
+        disassemble(&[BigInt::from(-1)]),
+        Err(DisassemblyError::DecodeError {
+            offset: 0,
+            error: DecodeError::InvalidEncoding(BigInt::from(-1))
+        })
+    );
+}
@@ -1,4 +1,6 @@
 use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use thiserror::Error;
 
 use crate::assembler::{ApUpdate, FpUpdate, InstructionRepr, Op1Addr, Opcode, PcUpdate, Res};
 use crate::operand::Register;
@@ -25,6 +27,18 @@ const OPCODE_CALL_BIT: i32 = 12;
 const OPCODE_RET_BIT: i32 = 13;
 const OPCODE_ASSERT_EQ_BIT: i32 = 14;
 
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum DecodeError {
+    #[error("Missing instruction encoding")]
+    MissingEncoding,
+    #[error("Invalid instruction encoding {0}")]
+    InvalidEncoding(BigInt),
+    #[error("Missing immediate value")]
+    MissingImmediate,
+    #[error("Invalid {0} flags")]
+    InvalidFlags(&'static str),
+}
+
 impl InstructionRepr {
     pub fn encode(&self) -> Vec<BigInt> {
         // Convert the offsets from possibly negative numbers in the range [-2^15, 2^15)
@@ -117,4 +131,106 @@ impl InstructionRepr {
             vec![bigint_encoding]
         }
     }
+
+    /// Decodes an instruction from the start of `data`, the inverse of [Self::encode].
+    /// The instruction takes two words of `data` if it has an immediate, and one word otherwise.
+    pub fn decode(data: &[BigInt]) -> Result<Self, DecodeError> {
+        let encoding = data.first().ok_or(DecodeError::MissingEncoding)?;
+        let encoding = encoding
+            .to_u64()
+            .filter(|encoding| encoding >> (3 * OFFSET_BITS + 15) == 0)
+            .ok_or_else(|| DecodeError::InvalidEncoding(encoding.clone()))?;
+        let decode_offset = |index: u32| -> i16 {
+            let offset_enc = (encoding >> (index * OFFSET_BITS)) & ((1 << OFFSET_BITS) - 1);
+            (offset_enc as i32 - (1 << (OFFSET_BITS - 1))) as i16
+        };
+        let flags = encoding >> (3 * OFFSET_BITS);
+        let flag = |bit: i32| flags & (1 << bit) != 0;
+
+        let op1_addr = exclusive_flags(
+            flags,
+            "op1_addr",
+            vec![(OP1_IMM_BIT, Op1Addr::Imm), (OP1_AP_BIT, Op1Addr::AP), (OP1_FP_BIT, Op1Addr::FP)],
+            Op1Addr::Op0,
+        )?;
+        let pc_update = exclusive_flags(
+            flags,
+            "pc_update",
+            vec![
+                (PC_JUMP_ABS_BIT, PcUpdate::Jump),
+                (PC_JUMP_REL_BIT, PcUpdate::JumpRel),
+                (PC_JNZ_BIT, PcUpdate::Jnz),
+            ],
+            PcUpdate::Regular,
+        )?;
+        let res = exclusive_flags(
+            flags,
+            "res",
+            vec![(RES_ADD_BIT, Res::Add), (RES_MUL_BIT, Res::Mul)],
+            if pc_update == PcUpdate::Jnz { Res::Unconstrained } else { Res::Op1 },
+        )?;
+        if pc_update == PcUpdate::Jnz && res != Res::Unconstrained {
+            return Err(DecodeError::InvalidFlags("res"));
+        }
+        let opcode = exclusive_flags(
+            flags,
+            "opcode",
+            vec![
+                (OPCODE_CALL_BIT, Opcode::Call),
+                (OPCODE_RET_BIT, Opcode::Ret),
+                (OPCODE_ASSERT_EQ_BIT, Opcode::AssertEq),
+            ],
+            Opcode::Nop,
+        )?;
+        let ap_update = exclusive_flags(
+            flags,
+            "ap_update",
+            vec![(AP_ADD_BIT, ApUpdate::Add), (AP_ADD1_BIT, ApUpdate::Add1)],
+            if opcode == Opcode::Call { ApUpdate::Add2 } else { ApUpdate::Regular },
+        )?;
+        if ap_update != ApUpdate::Add2 && opcode == Opcode::Call {
+            return Err(DecodeError::InvalidFlags("ap_update"));
+        }
+        let fp_update = match opcode {
+            Opcode::Nop | Opcode::AssertEq => FpUpdate::Regular,
+            Opcode::Call => FpUpdate::ApPlus2,
+            Opcode::Ret => FpUpdate::Dst,
+        };
+        let imm = if op1_addr == Op1Addr::Imm {
+            Some(data.get(1).ok_or(DecodeError::MissingImmediate)?.clone())
+        } else {
+            None
+        };
+
+        Ok(Self {
+            off0: decode_offset(0),
+            off1: decode_offset(1),
+            off2: decode_offset(2),
+            imm,
+            dst_register: if flag(DST_REG_BIT) { Register::FP } else { Register::AP },
+            op0_register: if flag(OP0_REG_BIT) { Register::FP } else { Register::AP },
+            op1_addr,
+            res,
+            pc_update,
+            ap_update,
+            fp_update,
+            opcode,
+        })
+    }
+}
+
+/// Returns the option matching the single set flag out of the options' flags, or `default` if none
+/// of them is set.
+fn exclusive_flags<T>(
+    flags: u64,
+    name: &'static str,
+    options: Vec<(i32, T)>,
+    default: T,
+) -> Result<T, DecodeError> {
+    let mut set_options = options.into_iter().filter(|(bit, _)| flags & (1 << bit) != 0);
+    match (set_options.next(), set_options.next()) {
+        (None, _) => Ok(default),
+        (Some((_, value)), None) => Ok(value),
+        (Some(_), Some(_)) => Err(DecodeError::InvalidFlags(name)),
+    }
 }
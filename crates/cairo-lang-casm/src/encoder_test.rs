@@ -2,6 +2,8 @@ use num_bigint::BigInt;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
+use super::DecodeError;
+use crate::assembler::InstructionRepr;
 use crate::casm;
 use crate::inline::CasmContext;
 
@@ -72,10 +74,32 @@ use crate::inline::CasmContext;
 )]
 fn test_encode(mut casm: CasmContext, encoding: u64, immediate: Option<i16>) {
     let enc = BigInt::from(encoding);
-    assert_eq!(
-        casm.instructions.remove(0).assemble().encode(),
-        if let Some(imm) = immediate { vec![enc, BigInt::from(imm)] } else { vec![enc] }
-    );
+    let expected = if let Some(imm) = immediate { vec![enc, BigInt::from(imm)] } else { vec![enc] };
+    let repr = casm.instructions.remove(0).assemble();
+    assert_eq!(repr.encode(), expected);
+    assert_eq!(InstructionRepr::decode(&expected), Ok(repr));
+}
+
+/// The encoding of the offsets `off0 = -1, off1 = -1, off2 = 1`.
+const OFFSETS: i128 = 0x8001_7fff_7fff;
+
+#[test_case(&[], DecodeError::MissingEncoding; "empty")]
+#[test_case(&[-1], DecodeError::InvalidEncoding(BigInt::from(-1)); "negative")]
+#[test_case(
+    &[1 << 63],
+    DecodeError::InvalidEncoding(BigInt::from(1_i128 << 63));
+    "too large"
+)]
+#[test_case(&[0x8780017fff7fff], DecodeError::MissingImmediate; "missing immediate")]
+#[test_case(&[0x14 << 48 | OFFSETS, 3], DecodeError::InvalidFlags("op1_addr"); "op1_addr")]
+#[test_case(&[0x184 << 48 | OFFSETS, 3], DecodeError::InvalidFlags("pc_update"); "pc_update")]
+#[test_case(&[0x60 << 48 | OFFSETS], DecodeError::InvalidFlags("res"); "res")]
+#[test_case(&[0x228 << 48 | OFFSETS], DecodeError::InvalidFlags("res"); "jnz res")]
+#[test_case(&[0x3000 << 48 | OFFSETS], DecodeError::InvalidFlags("opcode"); "opcode")]
+#[test_case(&[0x1904 << 48 | OFFSETS, 3], DecodeError::InvalidFlags("ap_update"); "call ap_update")]
+fn test_decode_failures(data: &[i128], error: DecodeError) {
+    let data = data.iter().copied().map(BigInt::from).collect::<Vec<_>>();
+    assert_eq!(InstructionRepr::decode(&data), Err(error));
 }
 
 #[test_case(
@@ -189,6 +189,45 @@ pub enum Hint {
     SetSequencerAddress {
         value: ResOperand,
     },
+    SetVersion {
+        value: ResOperand,
+    },
+    SetAccountContractAddress {
+        value: ResOperand,
+    },
+    SetMaxFee {
+        value: ResOperand,
+    },
+    SetTransactionHash {
+        value: ResOperand,
+    },
+    SetChainId {
+        value: ResOperand,
+    },
+    SetNonce {
+        value: ResOperand,
+    },
+    /// Sets the signature of the transaction to the values from start to end.
+    /// Both must be pointers.
+    SetSignature {
+        start: ResOperand,
+        end: ResOperand,
+    },
+    /// Sets the value of the storage address `address` of the contract `contract_address`.
+    SetStorageAt {
+        contract_address: ResOperand,
+        address: ResOperand,
+        value: ResOperand,
+    },
+    /// Gets the value of the storage address `address` of the contract `contract_address` into
+    /// `dst`.
+    GetStorageAt {
+        contract_address: ResOperand,
+        address: ResOperand,
+        dst: CellRef,
+    },
+    /// Resets the simulated storage of all the contracts and the execution info.
+    ResetState,
 }
 
 struct DerefOrImmediateFormatter<'a>(&'a DerefOrImmediate);
@@ -578,6 +617,63 @@ impl Display for Hint {
             Hint::SetSequencerAddress { value } => {
                 write!(f, "syscall_handler.sequencer_address = {}", ResOperandFormatter(value))
             }
+            Hint::SetVersion { value } => {
+                write!(f, "syscall_handler.tx_info.version = {}", ResOperandFormatter(value))
+            }
+            Hint::SetAccountContractAddress { value } => {
+                write!(
+                    f,
+                    "syscall_handler.tx_info.account_contract_address = {}",
+                    ResOperandFormatter(value)
+                )
+            }
+            Hint::SetMaxFee { value } => {
+                write!(f, "syscall_handler.tx_info.max_fee = {}", ResOperandFormatter(value))
+            }
+            Hint::SetTransactionHash { value } => {
+                write!(
+                    f,
+                    "syscall_handler.tx_info.transaction_hash = {}",
+                    ResOperandFormatter(value)
+                )
+            }
+            Hint::SetChainId { value } => {
+                write!(f, "syscall_handler.tx_info.chain_id = {}", ResOperandFormatter(value))
+            }
+            Hint::SetNonce { value } => {
+                write!(f, "syscall_handler.tx_info.nonce = {}", ResOperandFormatter(value))
+            }
+            Hint::SetSignature { start, end } => {
+                writedoc!(
+                    f,
+                    "
+
+                        start = {}
+                        end = {}
+                        syscall_handler.tx_info.signature = [memory[i] for i in range(start, end)]
+                    ",
+                    ResOperandFormatter(start),
+                    ResOperandFormatter(end),
+                )
+            }
+            Hint::SetStorageAt { contract_address, address, value } => {
+                write!(
+                    f,
+                    "syscall_handler.storage[{}][{}] = {}",
+                    ResOperandFormatter(contract_address),
+                    ResOperandFormatter(address),
+                    ResOperandFormatter(value)
+                )
+            }
+            Hint::GetStorageAt { contract_address, address, dst } => {
+                write!(
+                    f,
+                    "memory{dst} = syscall_handler.storage[{}].get({}, 0)",
+                    ResOperandFormatter(contract_address),
+                    ResOperandFormatter(address)
+                )
+            }
+            Hint::ResetState => write!(f, "syscall_handler.reset_state()"),
         }
     }
 }
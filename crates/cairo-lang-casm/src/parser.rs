@@ -0,0 +1,705 @@
+//! Parsing of the textual CASM format, as printed by the `Display` implementation of the
+//! instructions (e.g. `[ap + 0] = [fp + -3] + 5, ap++;`), including their `%{ hints %}`.
+
+use cairo_lang_utils::bigint::BigIntAsHex;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use num_bigint::BigInt;
+use num_traits::{Num, ToPrimitive};
+use thiserror::Error;
+
+use crate::hints::Hint;
+use crate::instructions::{
+    AddApInstruction, AssertEqInstruction, CallInstruction, Instruction, InstructionBody,
+    JnzInstruction, JumpInstruction, RetInstruction,
+};
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+
+#[cfg(test)]
+#[path = "parser_test.rs"]
+mod test;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ParseError {
+    #[error("Line {line}: Invalid instruction `{text}`.")]
+    InvalidInstruction { line: usize, text: String },
+    #[error("Line {line}: Unknown hint `{text}`.")]
+    UnknownHint { line: usize, text: String },
+    #[error("Line {line}: Unterminated hint.")]
+    UnterminatedHint { line: usize },
+    #[error("Line {line}: Missing `;` at the end of the instruction.")]
+    MissingSemicolon { line: usize },
+    #[error("Line {line}: Hints must be followed by an instruction.")]
+    DanglingHints { line: usize },
+}
+
+/// Parses CASM code in the format printed by `Display` of the instructions, where every
+/// instruction is terminated by `;` and preceded by its hints. `//` comments are ignored.
+pub fn parse_casm(code: &str) -> Result<Vec<Instruction>, ParseError> {
+    let line_of = |position: usize| code[..position].matches('\n').count() + 1;
+    let mut instructions = vec![];
+    let mut hints = vec![];
+    let mut position = 0;
+    loop {
+        let rest = &code[position..];
+        let trimmed = rest.trim_start();
+        position += rest.len() - trimmed.len();
+        if trimmed.is_empty() {
+            break;
+        }
+        let line = line_of(position);
+        if trimmed.starts_with("//") {
+            position += trimmed.find('\n').unwrap_or(trimmed.len());
+        } else if let Some(hint_code) = trimmed.strip_prefix("%{") {
+            let end = hint_code.find("%}").ok_or(ParseError::UnterminatedHint { line })?;
+            let hint_code = &hint_code[..end];
+            hints.push(
+                parse_hint(hint_code)
+                    .ok_or_else(|| ParseError::UnknownHint { line, text: hint_code.into() })?,
+            );
+            position += "%{".len() + end + "%}".len();
+        } else {
+            let end = trimmed.find(';').ok_or(ParseError::MissingSemicolon { line })?;
+            let text = &trimmed[..end];
+            let mut instruction = parse_instruction(text)
+                .ok_or_else(|| ParseError::InvalidInstruction { line, text: text.into() })?;
+            instruction.hints = std::mem::take(&mut hints);
+            instructions.push(instruction);
+            position += end + 1;
+        }
+    }
+    if !hints.is_empty() {
+        return Err(ParseError::DanglingHints { line: line_of(code.len()) });
+    }
+    Ok(instructions)
+}
+
+/// Parses a single instruction without its hints, e.g. `[ap + 0] = [fp + -3], ap++`.
+fn parse_instruction(text: &str) -> Option<Instruction> {
+    let text = text.trim();
+    let (body, inc_ap) = match text.strip_suffix("ap++") {
+        Some(body) => (body.trim_end().strip_suffix(',')?.trim_end(), true),
+        None => (text, false),
+    };
+    Some(Instruction::new(parse_instruction_body(body)?, inc_ap))
+}
+
+/// Parses the body of an instruction.
+fn parse_instruction_body(text: &str) -> Option<InstructionBody> {
+    if text == "ret" {
+        return Some(InstructionBody::Ret(RetInstruction {}));
+    }
+    if let Some(operand) = text.strip_prefix("ap +=") {
+        return Some(InstructionBody::AddAp(AddApInstruction {
+            operand: parse_whole(operand, |text| res_operand_candidates(text, ""))?,
+        }));
+    }
+    if let Some(call) = text.strip_prefix("call ") {
+        let (relative, target) = parse_jump_kind(call)?;
+        return Some(InstructionBody::Call(CallInstruction {
+            target: parse_whole(target, |text| deref_or_immediate_candidates(text, ""))?,
+            relative,
+        }));
+    }
+    if let Some(jump) = text.strip_prefix("jmp ") {
+        let (relative, jump) = parse_jump_kind(jump)?;
+        let Some((target, condition)) = jump.split_once(" if ") else {
+            return Some(InstructionBody::Jump(JumpInstruction {
+                target: parse_whole(jump, |text| deref_or_immediate_candidates(text, ""))?,
+                relative,
+            }));
+        };
+        if !relative {
+            return None;
+        }
+        let condition = condition.trim_end().strip_suffix('0')?.trim_end().strip_suffix("!=")?;
+        return Some(InstructionBody::Jnz(JnzInstruction {
+            jump_offset: parse_whole(target, |text| deref_or_immediate_candidates(text, ""))?,
+            condition: parse_whole(condition, |text| parse_cell_ref(text).into_iter().collect())?,
+        }));
+    }
+    let (a, b) = text.split_once('=')?;
+    Some(InstructionBody::AssertEq(AssertEqInstruction {
+        a: parse_whole(a, |text| parse_cell_ref(text).into_iter().collect())?,
+        b: parse_whole(b, |text| res_operand_candidates(text, ""))?,
+    }))
+}
+
+/// Parses the `rel` or `abs` keyword of a jump or a call, returning whether the jump is relative.
+fn parse_jump_kind(text: &str) -> Option<(bool, &str)> {
+    let text = text.trim_start();
+    if let Some(rest) = text.strip_prefix("rel ") {
+        Some((true, rest))
+    } else {
+        Some((false, text.strip_prefix("abs ")?))
+    }
+}
+
+/// Returns the first of the candidate parsings of the text that consumes all of it.
+fn parse_whole<'a, T>(
+    text: &'a str,
+    candidates: impl FnOnce(&'a str) -> Vec<(T, &'a str)>,
+) -> Option<T> {
+    candidates(text).into_iter().find_map(|(value, rest)| rest.trim().is_empty().then_some(value))
+}
+
+/// Parses an integer, in decimal or in hex (with a `0x` prefix), at the start of the text.
+fn parse_int(text: &str) -> Option<(BigInt, &str)> {
+    let text = text.trim_start();
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, text),
+    };
+    let (radix, digits) = match unsigned.strip_prefix("0x") {
+        Some(digits) => (16, digits),
+        None => (10, unsigned),
+    };
+    let len = digits.find(|c: char| !c.is_digit(radix)).unwrap_or(digits.len());
+    let value = BigInt::from_str_radix(&digits[..len], radix).ok()?;
+    Some((if negative { -value } else { value }, &digits[len..]))
+}
+
+/// Parses a cell reference, e.g. `[ap + -1]`, at the start of the text.
+fn parse_cell_ref(text: &str) -> Option<(CellRef, &str)> {
+    let text = text.trim_start().strip_prefix('[')?.trim_start();
+    let (register, text) = if let Some(text) = text.strip_prefix("ap") {
+        (Register::AP, text)
+    } else {
+        (Register::FP, text.strip_prefix("fp")?)
+    };
+    let text = text.trim_start();
+    let (offset, text) = if let Some(text) = text.strip_prefix('+') {
+        parse_int(text)?
+    } else if let Some(text) = text.strip_prefix('-') {
+        let (offset, text) = parse_int(text)?;
+        (-offset, text)
+    } else {
+        (BigInt::from(0), text)
+    };
+    let text = text.trim_start().strip_prefix(']')?;
+    Some((CellRef { register, offset: offset.to_i16()? }, text))
+}
+
+/// Parses a cell reference preceded by the given prefix (e.g. `memory` in hints).
+fn parse_prefixed_cell_ref<'a>(text: &'a str, prefix: &str) -> Option<(CellRef, &'a str)> {
+    parse_cell_ref(text.trim_start().strip_prefix(prefix)?)
+}
+
+/// Returns the possible parsings of a `DerefOrImmediate` at the start of the text, where
+/// dereferences are preceded by the given prefix.
+fn deref_or_immediate_candidates<'a>(
+    text: &'a str,
+    prefix: &str,
+) -> Vec<(DerefOrImmediate, &'a str)> {
+    if let Some((cell, rest)) = parse_prefixed_cell_ref(text, prefix) {
+        vec![(DerefOrImmediate::Deref(cell), rest)]
+    } else if let Some((value, rest)) = parse_int(text) {
+        vec![(DerefOrImmediate::Immediate(BigIntAsHex { value }), rest)]
+    } else {
+        vec![]
+    }
+}
+
+/// Returns the possible parsings of a `ResOperand` at the start of the text, from the shortest to
+/// the longest, where dereferences are preceded by the given prefix.
+fn res_operand_candidates<'a>(text: &'a str, prefix: &str) -> Vec<(ResOperand, &'a str)> {
+    let mut candidates = vec![];
+    if let Some((value, rest)) = parse_int(text) {
+        candidates.push((ResOperand::Immediate(BigIntAsHex { value }), rest));
+    }
+    if let Some((cell, rest)) = parse_prefixed_cell_ref(text, prefix) {
+        candidates.push((ResOperand::Deref(cell), rest));
+        let rest_start = rest.trim_start();
+        let op_and_rest = if let Some(rest) = rest_start.strip_prefix('+') {
+            Some((Operation::Add, rest))
+        } else {
+            rest_start.strip_prefix('*').map(|rest| (Operation::Mul, rest))
+        };
+        if let Some((op, rest)) = op_and_rest {
+            for (b, rest) in deref_or_immediate_candidates(rest, prefix) {
+                candidates
+                    .push((ResOperand::BinOp(BinOpOperand { op: op.clone(), a: cell, b }), rest));
+            }
+        }
+    }
+    let double_deref = text.trim_start().strip_prefix(prefix).and_then(|text| {
+        let text = text.trim_start().strip_prefix('[')?;
+        let (cell, text) = parse_prefixed_cell_ref(text, prefix)?;
+        let (offset, text) = parse_int(text.trim_start().strip_prefix('+')?)?;
+        Some((
+            ResOperand::DoubleDeref(cell, offset.to_i16()?),
+            text.trim_start().strip_prefix(']')?,
+        ))
+    });
+    candidates.extend(double_deref);
+    candidates
+}
+
+/// The values of the operands of a hint, by their names in its syntax.
+#[derive(Default)]
+struct HintOperands<'a> {
+    cells: OrderedHashMap<&'a str, CellRef>,
+    res_operands: OrderedHashMap<&'a str, ResOperand>,
+}
+impl<'a> HintOperands<'a> {
+    fn cell(&self, name: &str) -> Option<CellRef> {
+        self.cells.get(name).copied()
+    }
+    fn res(&self, name: &str) -> Option<ResOperand> {
+        self.res_operands.get(name).cloned()
+    }
+}
+
+/// Constructs a hint of a specific variant from the operands matched by its syntax.
+type HintBuilder = fn(&HintOperands<'_>) -> Option<Hint>;
+
+/// The syntax of every hint variant, as printed by `Display` of `Hint`, with the builder of the
+/// hint. Whitespaces are insignificant, `{cell:name}` is a cell reference operand (e.g.
+/// `[ap + 1]`) and `{res:name}` is a `ResOperand` operand (e.g. `memory[fp + -3] + 2`).
+const HINT_SYNTAXES: &[(&str, HintBuilder)] = &[
+    ("memory{cell:dst} = segments.add()", |ops| Some(Hint::AllocSegment { dst: ops.cell("dst")? })),
+    ("memory{cell:dst} = {res:lhs} < {res:rhs}", |ops| {
+        Some(Hint::TestLessThan {
+            lhs: ops.res("lhs")?,
+            rhs: ops.res("rhs")?,
+            dst: ops.cell("dst")?,
+        })
+    }),
+    ("memory{cell:dst} = {res:lhs} <= {res:rhs}", |ops| {
+        Some(Hint::TestLessThanOrEqual {
+            lhs: ops.res("lhs")?,
+            rhs: ops.res("rhs")?,
+            dst: ops.cell("dst")?,
+        })
+    }),
+    ("(memory{cell:quotient}, memory{cell:remainder}) = divmod({res:lhs}, {res:rhs})", |ops| {
+        Some(Hint::DivMod {
+            lhs: ops.res("lhs")?,
+            rhs: ops.res("rhs")?,
+            quotient: ops.cell("quotient")?,
+            remainder: ops.cell("remainder")?,
+        })
+    }),
+    (
+        "
+        dividend = {res:dividend_low} + {res:dividend_high} * 2**128
+        divisor = {res:divisor_low} + {res:divisor_high} * 2**128
+        quotient, remainder = divmod(dividend, divisor)
+        memory{cell:quotient0} = quotient & 0xFFFFFFFFFFFFFFFF
+        memory{cell:quotient1} = (quotient >> 64) & 0xFFFFFFFFFFFFFFFF
+        memory{cell:divisor0} = divisor & 0xFFFFFFFFFFFFFFFF
+        memory{cell:divisor1} = (divisor >> 64) & 0xFFFFFFFFFFFFFFFF
+        memory{cell:remainder_low} = remainder & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+        memory{cell:remainder_high} = remainder >> 128
+        if {res:divisor_high} == 0:
+            memory{cell:extra0} = (quotient >> 128) & 0xFFFFFFFFFFFFFFFF
+            memory{cell:extra1} = quotient >> 192
+        else:
+            memory{cell:extra0} = (divisor >> 128) & 0xFFFFFFFFFFFFFFFF
+            memory{cell:extra1} = divisor >> 192
+        ",
+        |ops| {
+            Some(Hint::Uint256DivMod {
+                dividend_low: ops.res("dividend_low")?,
+                dividend_high: ops.res("dividend_high")?,
+                divisor_low: ops.res("divisor_low")?,
+                divisor_high: ops.res("divisor_high")?,
+                quotient0: ops.cell("quotient0")?,
+                quotient1: ops.cell("quotient1")?,
+                divisor0: ops.cell("divisor0")?,
+                divisor1: ops.cell("divisor1")?,
+                extra0: ops.cell("extra0")?,
+                extra1: ops.cell("extra1")?,
+                remainder_low: ops.cell("remainder_low")?,
+                remainder_high: ops.cell("remainder_high")?,
+            })
+        },
+    ),
+    ("(memory{cell:dst}) = sqrt({res:value})", |ops| {
+        Some(Hint::SquareRoot { value: ops.res("value")?, dst: ops.cell("dst")? })
+    }),
+    (
+        "
+        (value, scalar) = ({res:value}, {res:scalar})
+        x = min(value // scalar, {res:max_x})
+        y = value - x * scalar
+        memory{cell:x} = x
+        memory{cell:y} = y
+        ",
+        |ops| {
+            Some(Hint::LinearSplit {
+                value: ops.res("value")?,
+                scalar: ops.res("scalar")?,
+                max_x: ops.res("max_x")?,
+                x: ops.cell("x")?,
+                y: ops.cell("y")?,
+            })
+        },
+    ),
+    (
+        "
+        if '__dict_manager' not in globals():
+            from starkware.cairo.common.dict import DictManager
+            __dict_manager = DictManager()
+        if '__segment_index_to_arena_index' not in globals():
+            # A map from the relocatable value segment index to the index in the
+            # arena.
+            __segment_index_to_arena_index = {}
+        # {res:segment_arena_ptr} is the address of the next SegmentArenaBuiltin.
+        # memory[{res:segment_arena_ptr} - 2] is the number of allocated segments.
+        index = memory[{res:segment_arena_ptr} - 2]
+        segment_start = __dict_manager.new_default_dict(
+            segments, 0, temp_segment=index > 0
+        )
+        # Update '__segment_index_to_arena_index'.
+        __segment_index_to_arena_index[segment_start.segment_index] = index
+        # Update 'SegmentInfo::start'.
+        # memory[{res:segment_arena_ptr} - 3] is the address of the segment arena infos
+        # segment. index * 3 is added to get the address of the new SegmentInfo.
+        memory[memory[{res:segment_arena_ptr} - 3] + index * 3] = segment_start
+        ",
+        |ops| Some(Hint::AllocFelt252Dict { segment_arena_ptr: ops.res("segment_arena_ptr")? }),
+    ),
+    (
+        "
+        dict_tracker = __dict_manager.get_tracker({res:dict_ptr})
+        dict_tracker.current_ptr += 3
+        memory{cell:value_dst} = dict_tracker.data[{res:key}]
+        ",
+        |ops| {
+            Some(Hint::Felt252DictRead {
+                dict_ptr: ops.res("dict_ptr")?,
+                key: ops.res("key")?,
+                value_dst: ops.cell("value_dst")?,
+            })
+        },
+    ),
+    (
+        "
+        dict_tracker = __dict_manager.get_tracker({res:dict_ptr})
+        memory[{res:dict_ptr} + 1] = dict_tracker.data[{res:key}]
+        dict_tracker.current_ptr += 3
+        dict_tracker.data[{res:key}] = {res:value}
+        ",
+        |ops| {
+            Some(Hint::Felt252DictWrite {
+                dict_ptr: ops.res("dict_ptr")?,
+                key: ops.res("key")?,
+                value: ops.res("value")?,
+            })
+        },
+    ),
+    (
+        "
+        memory{cell:dict_index} = __segment_index_to_arena_index[
+            {res:dict_end_ptr}.segment_index
+        ]
+        ",
+        |ops| {
+            Some(Hint::GetSegmentArenaIndex {
+                dict_end_ptr: ops.res("dict_end_ptr")?,
+                dict_index: ops.cell("dict_index")?,
+            })
+        },
+    ),
+    (
+        "
+        dict_access_size = 3
+        address = {res:dict_accesses}
+        assert {res:ptr_diff} % dict_access_size == 0, 'Accesses array size must be divisible by \
+         DictAccess.SIZE'
+        n_accesses = {res:n_accesses}
+        if '__squash_dict_max_size' in globals():
+            assert n_accesses <= __squash_dict_max_size, f'squash_dict() can only be used with \
+         n_accesses<={__squash_dict_max_size}. ' f'Got: n_accesses={n_accesses}.'
+        # A map from key to the list of indices accessing it.
+        access_indices = {}
+        for i in range(n_accesses):
+            key = memory[address + dict_access_size * i]
+            access_indices.setdefault(key, []).append(i)
+        # Descending list of keys.
+        keys = sorted(access_indices.keys(), reverse=True)
+        # Are the keys used bigger than range_check bound.
+        memory{cell:big_keys} = 1 if keys[0] >= range_check_builtin.bound else 0
+        memory{cell:first_key} = key = keys.pop()
+        ",
+        |ops| {
+            Some(Hint::InitSquashData {
+                dict_accesses: ops.res("dict_accesses")?,
+                ptr_diff: ops.res("ptr_diff")?,
+                n_accesses: ops.res("n_accesses")?,
+                big_keys: ops.cell("big_keys")?,
+                first_key: ops.cell("first_key")?,
+            })
+        },
+    ),
+    (
+        "
+        current_access_indices = sorted(access_indices[key])[::-1]
+        current_access_index = current_access_indices.pop()
+        memory[{res:range_check_ptr}] = current_access_index
+        ",
+        |ops| Some(Hint::GetCurrentAccessIndex { range_check_ptr: ops.res("range_check_ptr")? }),
+    ),
+    ("memory{cell:should_skip_loop} = 0 if current_access_indices else 1", |ops| {
+        Some(Hint::ShouldSkipSquashLoop { should_skip_loop: ops.cell("should_skip_loop")? })
+    }),
+    (
+        "
+        new_access_index = current_access_indices.pop()
+        memory{cell:index_delta_minus1} = new_access_index - current_access_index - 1
+        current_access_index = new_access_index
+        ",
+        |ops| {
+            Some(Hint::GetCurrentAccessDelta {
+                index_delta_minus1: ops.cell("index_delta_minus1")?,
+            })
+        },
+    ),
+    ("memory{cell:should_continue} = 1 if current_access_indices else 0", |ops| {
+        Some(Hint::ShouldContinueSquashLoop { should_continue: ops.cell("should_continue")? })
+    }),
+    ("assert len(current_access_indices) == 0", |_| Some(Hint::AssertCurrentAccessIndicesIsEmpty)),
+    ("assert memory{cell:n_used_accesses} == len(access_indices[key])", |ops| {
+        Some(Hint::AssertAllAccessesUsed { n_used_accesses: ops.cell("n_used_accesses")? })
+    }),
+    ("assert len(keys) == 0", |_| Some(Hint::AssertAllKeysUsed)),
+    (
+        "
+        assert len(keys) > 0, 'No keys left but remaining_accesses > 0.'
+        memory{cell:next_key} = key = keys.pop()
+        ",
+        |ops| Some(Hint::GetNextDictKey { next_key: ops.cell("next_key")? }),
+    ),
+    (
+        "
+        from starkware.cairo.common.math_utils import assert_integer
+        assert_integer({res:a})
+        assert_integer({res:b})
+        assert ({res:a} % PRIME) < ({res:b} % PRIME), f'a = {{res:a} % PRIME} is not less than b = \
+         {{res:b} % PRIME}.'
+        ",
+        |ops| Some(Hint::AssertLtAssertValidInput { a: ops.res("a")?, b: ops.res("b")? }),
+    ),
+    (
+        "
+        import itertools
+        from starkware.cairo.common.math_utils import assert_integer
+        assert_integer({res:a})
+        assert_integer({res:b})
+        a = {res:a} % PRIME
+        b = {res:b} % PRIME
+        assert a <= b, f'a = {a} is not less than or equal to b = {b}.'
+        # Find an arc less than PRIME / 3, and another less than PRIME / 2.
+        lengths_and_indices = [(a, 0), (b - a, 1), (PRIME - 1 - b, 2)]
+        lengths_and_indices.sort()
+        assert lengths_and_indices[0][0] <= PRIME // 3 and lengths_and_indices[1][0] <= PRIME // 2
+        excluded = lengths_and_indices[2][1]
+        memory[{res:range_check_ptr} + 1], memory[{res:range_check_ptr} + 0] = (
+            divmod(lengths_and_indices[0][0], 3544607988759775765608368578435044694))
+        memory[{res:range_check_ptr} + 3], memory[{res:range_check_ptr} + 2] = (
+            divmod(lengths_and_indices[1][0], 5316911983139663648412552867652567041))
+        ",
+        |ops| {
+            Some(Hint::AssertLeFindSmallArcs {
+                range_check_ptr: ops.res("range_check_ptr")?,
+                a: ops.res("a")?,
+                b: ops.res("b")?,
+            })
+        },
+    ),
+    ("memory{cell:skip_exclude_a_flag} = 1 if excluded != 0 else 0", |ops| {
+        Some(Hint::AssertLeIsFirstArcExcluded {
+            skip_exclude_a_flag: ops.cell("skip_exclude_a_flag")?,
+        })
+    }),
+    ("memory{cell:skip_exclude_b_minus_a} = 1 if excluded != 1 else 0", |ops| {
+        Some(Hint::AssertLeIsSecondArcExcluded {
+            skip_exclude_b_minus_a: ops.cell("skip_exclude_b_minus_a")?,
+        })
+    }),
+    ("assert excluded == 2", |_| Some(Hint::AssertLeAssertThirdArcExcluded)),
+    (
+        "
+        from starkware.crypto.signature.signature import ALPHA, BETA, FIELD_PRIME
+        from starkware.python.math_utils import random_ec_point
+        (memory{cell:x}, memory{cell:y}) = random_ec_point(FIELD_PRIME, ALPHA, BETA)
+        ",
+        |ops| Some(Hint::RandomEcPoint { x: ops.cell("x")?, y: ops.cell("y")? }),
+    ),
+    (
+        "
+        from starkware.crypto.signature.signature import FIELD_PRIME
+        from starkware.python.math_utils import is_quad_residue, sqrt
+        val = {res:val}
+        if is_quad_residue(val, FIELD_PRIME):
+            memory{cell:sqrt} = sqrt(val, FIELD_PRIME)
+        else:
+            memory{cell:sqrt} = sqrt(val * 3, FIELD_PRIME)
+        ",
+        |ops| Some(Hint::FieldSqrt { val: ops.res("val")?, sqrt: ops.cell("sqrt")? }),
+    ),
+    ("syscall_handler.syscall(syscall_ptr={res:system})", |ops| {
+        Some(Hint::SystemCall { system: ops.res("system")? })
+    }),
+    (
+        "
+        start = {res:start}
+        end = {res:end}
+        for i in range(start, end):
+            print(memory[i])
+        ",
+        |ops| Some(Hint::DebugPrint { start: ops.res("start")?, end: ops.res("end")? }),
+    ),
+    (
+        "
+        if '__boxed_segment' not in globals():
+            __boxed_segment = segments.add()
+        memory{cell:dst} = __boxed_segment
+        __boxed_segment += {res:size}
+        ",
+        |ops| Some(Hint::AllocConstantSize { size: ops.res("size")?, dst: ops.cell("dst")? }),
+    ),
+    ("syscall_handler.block_number = {res:value}", |ops| {
+        Some(Hint::SetBlockNumber { value: ops.res("value")? })
+    }),
+    ("syscall_handler.block_timestamp = {res:value}", |ops| {
+        Some(Hint::SetBlockTimestamp { value: ops.res("value")? })
+    }),
+    ("syscall_handler.caller_address = {res:value}", |ops| {
+        Some(Hint::SetCallerAddress { value: ops.res("value")? })
+    }),
+    ("syscall_handler.contract_address = {res:value}", |ops| {
+        Some(Hint::SetContractAddress { value: ops.res("value")? })
+    }),
+    ("syscall_handler.sequencer_address = {res:value}", |ops| {
+        Some(Hint::SetSequencerAddress { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.version = {res:value}", |ops| {
+        Some(Hint::SetVersion { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.account_contract_address = {res:value}", |ops| {
+        Some(Hint::SetAccountContractAddress { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.max_fee = {res:value}", |ops| {
+        Some(Hint::SetMaxFee { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.transaction_hash = {res:value}", |ops| {
+        Some(Hint::SetTransactionHash { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.chain_id = {res:value}", |ops| {
+        Some(Hint::SetChainId { value: ops.res("value")? })
+    }),
+    ("syscall_handler.tx_info.nonce = {res:value}", |ops| {
+        Some(Hint::SetNonce { value: ops.res("value")? })
+    }),
+    (
+        "
+        start = {res:start}
+        end = {res:end}
+        syscall_handler.tx_info.signature = [memory[i] for i in range(start, end)]
+        ",
+        |ops| Some(Hint::SetSignature { start: ops.res("start")?, end: ops.res("end")? }),
+    ),
+    ("syscall_handler.storage[{res:contract_address}][{res:address}] = {res:value}", |ops| {
+        Some(Hint::SetStorageAt {
+            contract_address: ops.res("contract_address")?,
+            address: ops.res("address")?,
+            value: ops.res("value")?,
+        })
+    }),
+    (
+        "memory{cell:dst} = syscall_handler.storage[{res:contract_address}].get({res:address}, 0)",
+        |ops| {
+            Some(Hint::GetStorageAt {
+                contract_address: ops.res("contract_address")?,
+                address: ops.res("address")?,
+                dst: ops.cell("dst")?,
+            })
+        },
+    ),
+    ("syscall_handler.reset_state()", |_| Some(Hint::ResetState)),
+];
+
+/// A part of the syntax of a hint.
+enum SyntaxPart<'a> {
+    /// Code that is identical for all the hints of the variant.
+    Literal(&'a str),
+    /// A cell reference operand with the given name.
+    Cell(&'a str),
+    /// A `ResOperand` operand with the given name.
+    Res(&'a str),
+}
+
+/// Splits the whitespace normalized syntax of a hint into its parts.
+fn syntax_parts(syntax: &str) -> Vec<SyntaxPart<'_>> {
+    let mut parts = vec![];
+    let mut rest = syntax;
+    loop {
+        let Some((start, prefix)) = ["{cell:", "{res:"]
+            .into_iter()
+            .filter_map(|prefix| Some((rest.find(prefix)?, prefix)))
+            .min() else {
+                parts.push(SyntaxPart::Literal(rest));
+                return parts;
+            };
+        let (name, after) = rest[start + prefix.len()..].split_once('}').unwrap();
+        parts.push(SyntaxPart::Literal(&rest[..start]));
+        parts.push(if prefix == "{cell:" { SyntaxPart::Cell(name) } else { SyntaxPart::Res(name) });
+        rest = after;
+    }
+}
+
+/// Parses the code of a hint, by matching it against the syntax of every hint variant.
+fn parse_hint(code: &str) -> Option<Hint> {
+    let code = normalize_whitespace(code);
+    HINT_SYNTAXES.iter().find_map(|(syntax, builder)| {
+        let syntax = normalize_whitespace(syntax);
+        let mut operands = HintOperands::default();
+        match_syntax(&syntax_parts(&syntax), &code, &mut operands).then(|| builder(&operands))?
+    })
+}
+
+/// Matches the code against the parts of the syntax of a hint, filling the matched operands.
+/// An operand appearing multiple times in the syntax must have the same value in all of them.
+fn match_syntax<'a>(parts: &[SyntaxPart<'a>], code: &str, operands: &mut HintOperands<'a>) -> bool {
+    let Some((part, parts)) = parts.split_first() else { return code.is_empty(); };
+    match part {
+        SyntaxPart::Literal(literal) => match code.strip_prefix(literal) {
+            Some(code) => match_syntax(parts, code, operands),
+            None => false,
+        },
+        SyntaxPart::Cell(name) => {
+            let Some((cell, rest)) = parse_cell_ref(code) else { return false; };
+            match operands.cells.get(*name) {
+                Some(previous) => *previous == cell && match_syntax(parts, rest, operands),
+                None => {
+                    operands.cells.insert(name, cell);
+                    match_syntax(parts, rest, operands) || {
+                        operands.cells.swap_remove(*name);
+                        false
+                    }
+                }
+            }
+        }
+        SyntaxPart::Res(name) => {
+            res_operand_candidates(code, "memory").into_iter().any(|(operand, rest)| match operands
+                .res_operands
+                .get(*name)
+            {
+                Some(previous) => *previous == operand && match_syntax(parts, rest, operands),
+                None => {
+                    operands.res_operands.insert(name, operand);
+                    match_syntax(parts, rest, operands) || {
+                        operands.res_operands.swap_remove(*name);
+                        false
+                    }
+                }
+            })
+        }
+    }
+}
+
+/// Replaces every sequence of whitespaces in the code with a single space, and trims it.
+fn normalize_whitespace(code: &str) -> String {
+    code.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,209 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use test_log::test;
+
+use super::{parse_casm, ParseError, HINT_SYNTAXES};
+use crate::hints::Hint;
+use crate::instructions::Instruction;
+use crate::operand::{BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand};
+use crate::{casm, deref};
+
+/// Formats the instructions the way `CairoProgram` is printed.
+fn format_casm(instructions: &[Instruction]) -> String {
+    instructions.iter().map(|instruction| format!("{instruction};\n")).collect()
+}
+
+#[test]
+fn test_parse_round_trip() {
+    let y = deref!([fp + 5]);
+    let instructions = casm! {
+        [fp - 5] = 1, ap++;
+        [fp - 5] = [ap + 1] + [fp - 5], ap++;
+        [fp + 5] = [ap + 1] + 2;
+        [ap + 5] = [[ap + 1] + 2];
+        [ap + 5] = [[ap - 1] + -2];
+        [ap] = [ap + 1] * [fp - 5];
+        %{ memory[ap + 5] = segments.add() %}
+        [fp - 5] = [ap + 1] * y;
+        %{ memory[ap + 0] = memory[fp - 3] < 45 %}
+        %{ memory[ap + 0] = 13 < memory[fp + 9] %}
+        [fp - 5] = [ap + 1];
+        jmp rel 123 if [ap + 17] != 0;
+        jmp rel [fp - 19] if [ap + 17] != 0;
+        %{ (memory[ap + 0], memory[ap + 1]) = divmod(memory[ap + 9], 2) %}
+        call abs 5, ap++;
+        call rel y, ap++;
+        jmp abs [ap + 2];
+        jmp rel -4, ap++;
+        ap += 3;
+        ret;
+    }
+    .instructions;
+    assert_eq!(parse_casm(&format_casm(&instructions)), Ok(instructions));
+}
+
+#[test]
+fn test_parse_all_hints() {
+    let [a, b, c, d, e, f, g, h] = [0, 1, -1, 2, -2, 3, -3, 4].map(|offset| deref!([ap + offset]));
+    let [deref, double_deref, immediate, negative, add, mul] = [
+        ResOperand::Deref(CellRef { register: Register::FP, offset: -3 }),
+        ResOperand::DoubleDeref(CellRef { register: Register::FP, offset: -4 }, 2),
+        ResOperand::Immediate(123.into()),
+        ResOperand::Immediate((-5).into()),
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Add,
+            a: CellRef { register: Register::FP, offset: -3 },
+            b: DerefOrImmediate::Immediate(1.into()),
+        }),
+        ResOperand::BinOp(BinOpOperand {
+            op: Operation::Mul,
+            a: CellRef { register: Register::AP, offset: 0 },
+            b: DerefOrImmediate::Deref(CellRef { register: Register::FP, offset: 7 }),
+        }),
+    ];
+    let hints = vec![
+        Hint::AllocSegment { dst: a },
+        Hint::TestLessThan { lhs: deref.clone(), rhs: immediate.clone(), dst: a },
+        Hint::TestLessThanOrEqual { lhs: negative.clone(), rhs: add.clone(), dst: b },
+        Hint::DivMod { lhs: mul.clone(), rhs: double_deref.clone(), quotient: a, remainder: b },
+        Hint::Uint256DivMod {
+            dividend_low: deref.clone(),
+            dividend_high: double_deref.clone(),
+            divisor_low: immediate.clone(),
+            divisor_high: add.clone(),
+            quotient0: a,
+            quotient1: b,
+            divisor0: c,
+            divisor1: d,
+            extra0: e,
+            extra1: f,
+            remainder_low: g,
+            remainder_high: h,
+        },
+        Hint::SquareRoot { value: deref.clone(), dst: c },
+        Hint::LinearSplit {
+            value: deref.clone(),
+            scalar: immediate.clone(),
+            max_x: negative.clone(),
+            x: a,
+            y: b,
+        },
+        Hint::AllocFelt252Dict { segment_arena_ptr: add.clone() },
+        Hint::Felt252DictRead { dict_ptr: deref.clone(), key: mul.clone(), value_dst: a },
+        Hint::Felt252DictWrite {
+            dict_ptr: deref.clone(),
+            key: double_deref.clone(),
+            value: immediate.clone(),
+        },
+        Hint::GetSegmentArenaIndex { dict_end_ptr: deref.clone(), dict_index: a },
+        Hint::InitSquashData {
+            dict_accesses: deref.clone(),
+            ptr_diff: add.clone(),
+            n_accesses: immediate.clone(),
+            big_keys: a,
+            first_key: b,
+        },
+        Hint::GetCurrentAccessIndex { range_check_ptr: double_deref.clone() },
+        Hint::ShouldSkipSquashLoop { should_skip_loop: a },
+        Hint::GetCurrentAccessDelta { index_delta_minus1: b },
+        Hint::ShouldContinueSquashLoop { should_continue: c },
+        Hint::AssertCurrentAccessIndicesIsEmpty,
+        Hint::AssertAllAccessesUsed { n_used_accesses: d },
+        Hint::AssertAllKeysUsed,
+        Hint::GetNextDictKey { next_key: e },
+        Hint::AssertLtAssertValidInput { a: deref.clone(), b: add.clone() },
+        Hint::AssertLeFindSmallArcs {
+            range_check_ptr: add.clone(),
+            a: deref.clone(),
+            b: mul.clone(),
+        },
+        Hint::AssertLeIsFirstArcExcluded { skip_exclude_a_flag: a },
+        Hint::AssertLeIsSecondArcExcluded { skip_exclude_b_minus_a: b },
+        Hint::AssertLeAssertThirdArcExcluded,
+        Hint::RandomEcPoint { x: a, y: b },
+        Hint::FieldSqrt { val: mul.clone(), sqrt: c },
+        Hint::SystemCall { system: deref.clone() },
+        Hint::DebugPrint { start: deref.clone(), end: add.clone() },
+        Hint::AllocConstantSize { size: immediate.clone(), dst: a },
+        Hint::SetBlockNumber { value: deref.clone() },
+        Hint::SetBlockTimestamp { value: immediate.clone() },
+        Hint::SetCallerAddress { value: add.clone() },
+        Hint::SetContractAddress { value: mul.clone() },
+        Hint::SetSequencerAddress { value: double_deref.clone() },
+        Hint::SetVersion { value: negative.clone() },
+        Hint::SetAccountContractAddress { value: deref.clone() },
+        Hint::SetMaxFee { value: immediate.clone() },
+        Hint::SetTransactionHash { value: add.clone() },
+        Hint::SetChainId { value: mul.clone() },
+        Hint::SetNonce { value: double_deref.clone() },
+        Hint::SetSignature { start: deref.clone(), end: add.clone() },
+        Hint::SetStorageAt { contract_address: deref.clone(), address: add, value: immediate },
+        Hint::GetStorageAt { contract_address: deref, address: mul, dst: a },
+        Hint::ResetState,
+    ];
+    assert_eq!(hints.len(), HINT_SYNTAXES.len(), "A hint variant is missing in the test.");
+    for hint in hints {
+        let mut instruction = casm!(ap += 0;).instructions.remove(0);
+        instruction.hints.push(hint);
+        let instructions = vec![instruction];
+        assert_eq!(parse_casm(&format_casm(&instructions)), Ok(instructions));
+    }
+}
+
+#[test]
+fn test_parse_lenient_format() {
+    assert_eq!(
+        parse_casm(indoc! {"
+            // Comments are ignored.
+            %{memory[ap+0] = segments.add()%}
+            [ap] = [fp - 3] + 0x10, ap++; // Including trailing comments.
+            jmp rel 5 if [ap-1] != 0;
+        "}),
+        Ok(casm! {
+            %{ memory[ap + 0] = segments.add() %}
+            [ap + 0] = [fp + -3] + 16, ap++;
+            jmp 5 if [ap + -1] != 0;
+        }
+        .instructions)
+    );
+}
+
+#[test_case(
+    "[ap + 0] = 1;\nret",
+    ParseError::MissingSemicolon { line: 2 };
+    "missing semicolon"
+)]
+#[test_case(
+    "ret;\n[ap + 0] == 1;",
+    ParseError::InvalidInstruction { line: 2, text: "[ap + 0] == 1".into() };
+    "invalid instruction"
+)]
+#[test_case(
+    "jmp abs 3 if [ap + 0] != 0;",
+    ParseError::InvalidInstruction { line: 1, text: "jmp abs 3 if [ap + 0] != 0".into() };
+    "absolute conditional jump"
+)]
+#[test_case(
+    "[ap + 40000] = 1;",
+    ParseError::InvalidInstruction { line: 1, text: "[ap + 40000] = 1".into() };
+    "offset out of range"
+)]
+#[test_case(
+    "%{ memory[ap + 0] = magic() %}\nret;",
+    ParseError::UnknownHint { line: 1, text: " memory[ap + 0] = magic() ".into() };
+    "unknown hint"
+)]
+#[test_case(
+    "%{ memory[ap + 0] = segments.add()\nret;",
+    ParseError::UnterminatedHint { line: 1 };
+    "unterminated hint"
+)]
+#[test_case(
+    "ret;\n%{ memory[ap + 0] = segments.add() %}\n",
+    ParseError::DanglingHints { line: 3 };
+    "dangling hints"
+)]
+fn test_parse_failures(code: &str, error: ParseError) {
+    assert_eq!(parse_casm(code), Err(error));
+}
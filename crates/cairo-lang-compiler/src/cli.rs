@@ -1,8 +1,10 @@
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
+use cairo_lang_sierra::binary_serde::program_to_bytes;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -18,6 +20,9 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Outputs the program in the binary Sierra encoding instead of as text.
+    #[arg(long, default_value_t = false)]
+    binary: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -31,9 +36,15 @@ fn main() -> anyhow::Result<()> {
         CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
     )?;
 
+    let output = if args.binary {
+        program_to_bytes(&sierra_program)
+    } else {
+        format!("{sierra_program}").into_bytes()
+    };
     match args.output {
-        Some(path) => {
-            fs::write(path, format!("{sierra_program}")).context("Failed to write output.")?
+        Some(path) => fs::write(path, output).context("Failed to write output.")?,
+        None if args.binary => {
+            std::io::stdout().write_all(&output).context("Failed to write output.")?
         }
         None => println!("{sierra_program}"),
     }
@@ -17,7 +17,7 @@ use cairo_lang_project::ProjectConfig;
 use cairo_lang_semantic::corelib::get_core_ty_by_name;
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use cairo_lang_semantic::plugin::SemanticPlugin;
-use cairo_lang_sierra_generator::db::SierraGenDatabase;
+use cairo_lang_sierra_generator::db::{init_sierra_gen_group, SierraGenDatabase};
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
 use cairo_lang_utils::Upcast;
 
@@ -41,6 +41,7 @@ impl RootDatabase {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         init_lowering_group(&mut res);
+        init_sierra_gen_group(&mut res);
         res.set_semantic_plugins(plugins);
         res
     }
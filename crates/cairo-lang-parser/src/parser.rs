@@ -1309,11 +1309,20 @@ impl<'a> Parser<'a> {
     /// Returns a GreenId of a node with kind Member or None if a struct member/enum variant can't
     /// be parsed.
     fn try_parse_member(&mut self) -> Option<MemberGreen> {
-        let name = self.try_parse_identifier()?;
+        let mut attributes = vec![];
+        while let Some(attribute) = self.try_parse_attribute() {
+            attributes.push(attribute);
+        }
+        let name = if attributes.is_empty() {
+            self.try_parse_identifier()?
+        } else {
+            self.parse_identifier()
+        };
+        let attributes = AttributeList::new_green(self.db, attributes);
         let type_clause = self.parse_type_clause(ErrorRecovery {
             should_stop: is_of_kind!(comma, rbrace, top_level),
         });
-        Some(Member::new_green(self.db, name, type_clause))
+        Some(Member::new_green(self.db, attributes, name, type_clause))
     }
 
     /// Expected pattern: `<PathSegment>(::<PathSegment>)*`
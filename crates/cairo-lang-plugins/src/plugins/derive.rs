@@ -10,11 +10,19 @@ use cairo_lang_syntax::attribute::structured::{
 use cairo_lang_syntax::node::ast::{AttributeList, MemberList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{ast, Terminal};
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use indoc::formatdoc;
-use itertools::Itertools;
+use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 
+/// The path of the derivable trait of types emitted as Starknet events, which is derived by the
+/// Starknet plugin.
+const STARKNET_EVENT_TRAIT: &str = "starknet::Event";
+
+/// The maximal number of storage addresses a type deriving `StorageAccess` is laid out over, as its
+/// offsets and size are `u8`s.
+const MAX_STORAGE_SIZE: usize = 255;
+
 #[derive(Debug, Default)]
 #[non_exhaustive]
 pub struct DerivePlugin;
@@ -26,13 +34,13 @@ impl MacroPlugin for DerivePlugin {
                 db,
                 struct_ast.name(db),
                 struct_ast.attributes(db),
-                ExtraInfo::Struct(member_names(db, struct_ast.members(db))),
+                ExtraInfo::Struct(members_info(db, struct_ast.members(db))),
             ),
             ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
                 db,
                 enum_ast.name(db),
                 enum_ast.attributes(db),
-                ExtraInfo::Enum(member_names(db, enum_ast.variants(db))),
+                ExtraInfo::Enum(members_info(db, enum_ast.variants(db))),
             ),
             ast::Item::ExternType(extern_type_ast) => generate_derive_code_for_type(
                 db,
@@ -54,14 +62,27 @@ impl AsDynMacroPlugin for DerivePlugin {
 }
 impl SemanticPlugin for DerivePlugin {}
 
+/// The name and type of a struct member or an enum variant.
+struct MemberInfo {
+    name: SmolStr,
+    ty: String,
+}
+
 enum ExtraInfo {
-    Enum(Vec<SmolStr>),
-    Struct(Vec<SmolStr>),
+    Enum(Vec<MemberInfo>),
+    Struct(Vec<MemberInfo>),
     Extern,
 }
 
-fn member_names(db: &dyn SyntaxGroup, members: MemberList) -> Vec<SmolStr> {
-    members.elements(db).into_iter().map(|member| member.name(db).text(db)).collect()
+fn members_info(db: &dyn SyntaxGroup, members: MemberList) -> Vec<MemberInfo> {
+    members
+        .elements(db)
+        .into_iter()
+        .map(|member| MemberInfo {
+            name: member.name(db).text(db),
+            ty: member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db),
+        })
+        .collect()
 }
 
 /// Adds an implementation for all requested derives for the type.
@@ -100,6 +121,9 @@ fn generate_derive_code_for_type(
                 continue;
             };
 
+            if path.as_syntax_node().get_text_without_trivia(db) == STARKNET_EVENT_TRAIT {
+                continue;
+            }
             let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] else {
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: value_stable_ptr.untyped(),
@@ -124,7 +148,20 @@ fn generate_derive_code_for_type(
                 "Serde" if !matches!(extra_info, ExtraInfo::Extern) => {
                     impls.push(get_serde_impl(&name, &extra_info))
                 }
-                "Clone" | "Destruct" | "PartialEq" | "Serde" => {
+                "StorageAccess" if !matches!(extra_info, ExtraInfo::Extern) => {
+                    if min_storage_size(&extra_info) > MAX_STORAGE_SIZE {
+                        diagnostics.push(PluginDiagnostic {
+                            stable_ptr: value_stable_ptr.untyped(),
+                            message: format!(
+                                "Derived `StorageAccess` types must fit in {MAX_STORAGE_SIZE} \
+                                 storage addresses."
+                            ),
+                        });
+                    } else {
+                        impls.push(get_storage_access_impl(&name, &extra_info))
+                    }
+                }
+                "Clone" | "Destruct" | "PartialEq" | "Serde" | "StorageAccess" => {
                     diagnostics.push(PluginDiagnostic {
                         stable_ptr: value_stable_ptr.untyped(),
                         message: "Unsupported trait for derive for extern types.".into(),
@@ -163,7 +200,7 @@ fn get_clone_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             }}
                         }}
                     }}
-                ", variants.iter().map(|variant| {
+                ", variants.iter().map(|MemberInfo { name: variant, .. }| {
                 format!("{name}::{variant}(x) => {name}::{variant}(x.clone()),")
             }).join("\n            ")}
         }
@@ -176,7 +213,7 @@ fn get_clone_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             }}
                         }}
                     }}
-                ", members.iter().map(|member| {
+                ", members.iter().map(|MemberInfo { name: member, .. }| {
                 format!("{member}: self.{member}.clone(),")
             }).join("\n            ")}
         }
@@ -195,7 +232,7 @@ fn get_destruct_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             }}
                         }}
                     }}
-                ", variants.iter().map(|variant| {
+                ", variants.iter().map(|MemberInfo { name: variant, .. }| {
                 format!("{name}::{variant}(x) => traits::Destruct::destruct(x),")
             }).join("\n            ")}
         }
@@ -206,7 +243,7 @@ fn get_destruct_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             {}
                         }}
                     }}
-                ", members.iter().map(|member| {
+                ", members.iter().map(|MemberInfo { name: member, .. }| {
                 format!("traits::Destruct::destruct(self.{member});")
             }).join("\n        ")}
         }
@@ -229,10 +266,10 @@ fn get_partial_eq_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             !(lhs == rhs)
                         }}
                     }}
-                ", variants.iter().map(|lhs_variant| {
+                ", variants.iter().map(|MemberInfo { name: lhs_variant, .. }| {
                 format!(
                     "{name}::{lhs_variant}(x) => match rhs {{\n                {}\n            }},",
-                    variants.iter().map(|rhs_variant|{
+                    variants.iter().map(|MemberInfo { name: rhs_variant, .. }|{
                         if lhs_variant == rhs_variant {
                             format!("{name}::{rhs_variant}(y) => x == y,")
                         } else {
@@ -255,7 +292,7 @@ fn get_partial_eq_impl(name: &str, extra_info: &ExtraInfo) -> String {
                             !(lhs == rhs)
                         }}
                     }}
-                ", members.iter().map(|member| {
+                ", members.iter().map(|MemberInfo { name: member, .. }| {
                 // TODO(orizi): Use `&&` when supported.
                 format!("if lhs.{member} != rhs.{member} {{ return false; }}")
             }).join("\n        ")}
@@ -283,12 +320,12 @@ fn get_serde_impl(name: &str, extra_info: &ExtraInfo) -> String {
                         }}
                     }}
                 ",
-                variants.iter().enumerate().map(|(idx, variant)| {
+                variants.iter().enumerate().map(|(idx, MemberInfo { name: variant, .. })| {
                     format!(
                         "{name}::{variant}(x) => serde::Serde::serialize(ref output, ({idx}, x)),",
                     )
                 }).join("\n            "),
-                variants.iter().enumerate().map(|(idx, variant)| {
+                variants.iter().enumerate().map(|(idx, MemberInfo { name: variant, .. })| {
                     format!(
                         "if idx == {idx} {{ {name}::{variant}(serde::Serde::deserialize(ref input)?) }}",
                     )
@@ -308,14 +345,191 @@ fn get_serde_impl(name: &str, extra_info: &ExtraInfo) -> String {
                         }}
                     }}
                 ",
-                members.iter().map(|member| format!("serde::Serde::serialize(ref output, value.{member})")).join(";\n        "),
-                members.iter().map(|member| format!("{member}: serde::Serde::deserialize(ref input)?,")).join("\n            "),
+                members.iter().map(|MemberInfo { name: member, .. }| format!("serde::Serde::serialize(ref output, value.{member})")).join(";\n        "),
+                members.iter().map(|MemberInfo { name: member, .. }| format!("{member}: serde::Serde::deserialize(ref input)?,")).join("\n            "),
             }
         }
         ExtraInfo::Extern => unreachable!(),
     }
 }
 
+/// Returns the minimal number of storage addresses a type deriving `StorageAccess` is laid out
+/// over, counting the members and variants of core types by their sizes, and the others as empty.
+fn min_storage_size(extra_info: &ExtraInfo) -> usize {
+    let member_sizes = |members: &[MemberInfo]| {
+        members.iter().map(|MemberInfo { ty, .. }| core_storage_size(ty).unwrap_or(0)).collect_vec()
+    };
+    match extra_info {
+        ExtraInfo::Struct(members) => member_sizes(members).into_iter().sum(),
+        // The index of the variant, followed by the largest variant.
+        ExtraInfo::Enum(variants) => 1 + member_sizes(variants).into_iter().max().unwrap_or(0),
+        // Extern types are not laid out by the derive.
+        ExtraInfo::Extern => 0,
+    }
+}
+
+/// Returns the number of storage addresses a value of a core type is laid out over, or None if the
+/// type is not a core type implementing `StorageAccess`.
+fn core_storage_size(ty: &str) -> Option<usize> {
+    match ty.trim_start_matches("starknet::") {
+        "()" => Some(0),
+        "felt252" | "bool" | "u8" | "u16" | "u32" | "u64" | "u128" | "ContractAddress"
+        | "ClassHash" | "StorageAddress" => Some(1),
+        "u256" => Some(2),
+        _ => None,
+    }
+}
+
 fn get_empty_impl(name: &str, derived_trait: &str) -> String {
     format!("impl {name}{derived_trait} of {derived_trait}::<{name}>;\n")
 }
+
+/// Returns impls of `starknet::StorageAccess` and `starknet::StorageAccessInternal`, which lay out
+/// the members of a struct one after the other, and the index of the variant of an enum followed by
+/// its value, over consecutive storage addresses.
+fn get_storage_access_impl(name: &str, extra_info: &ExtraInfo) -> String {
+    let base_access_functions = formatdoc! {"
+            fn read(
+                address_domain: u32, base: starknet::StorageBaseAddress
+            ) -> starknet::SyscallResult<{name}> {{
+                starknet::StorageAccessInternal::<{name}>::read_at_offset_internal(
+                    address_domain, base, 0_u8
+                )
+            }}
+            fn write(
+                address_domain: u32, base: starknet::StorageBaseAddress, value: {name}
+            ) -> starknet::SyscallResult<()> {{
+                starknet::StorageAccessInternal::<{name}>::write_at_offset_internal(
+                    address_domain, base, 0_u8, value
+                )
+            }}"
+    }
+    .replace('\n', "\n    ");
+    match extra_info {
+        ExtraInfo::Enum(variants) => {
+            formatdoc! {"
+                    impl {name}StorageAccess of starknet::StorageAccess::<{name}> {{
+                        {base_access_functions}
+                    }}
+                    impl {name}StorageAccessInternal of starknet::StorageAccessInternal::<{name}> {{
+                        fn read_at_offset_internal(
+                            address_domain: u32, base: starknet::StorageBaseAddress, offset: u8
+                        ) -> starknet::SyscallResult<{name}> {{
+                            let idx = starknet::StorageAccessInternal::<felt252>::read_at_offset_internal(
+                                address_domain, base, offset
+                            )?;
+                            let offset = offset + 1_u8;
+                            {}{{
+                                let mut message = array::ArrayTrait::new();
+                                array::ArrayTrait::append(ref message, 'Unknown enum indicator:');
+                                array::ArrayTrait::append(ref message, idx);
+                                Result::Err(message)
+                            }}
+                        }}
+                        fn write_at_offset_internal(
+                            address_domain: u32, base: starknet::StorageBaseAddress, offset: u8, value: {name}
+                        ) -> starknet::SyscallResult<()> {{
+                            match value {{
+                                {}
+                            }}
+                        }}
+                        #[inline(always)]
+                        fn size_internal() -> u8 {{
+                            let mut size = 0_u8;
+                            {}
+                            size + 1_u8
+                        }}
+                    }}
+                ",
+                variants.iter().enumerate().map(|(idx, MemberInfo { name: variant, ty })| {
+                    format!(
+                        "if idx == {idx} {{\n            Result::Ok(\n                \
+                         {name}::{variant}(\n                    \
+                         starknet::StorageAccessInternal::<{ty}>::read_at_offset_internal(\n                        \
+                         address_domain, base, offset\n                    )?\n                \
+                         )\n            )\n        }} else ",
+                    )
+                }).join(""),
+                variants.iter().enumerate().map(|(idx, MemberInfo { name: variant, ty })| {
+                    format!(
+                        "{name}::{variant}(x) => {{\n                \
+                         starknet::StorageAccessInternal::<felt252>::write_at_offset_internal(\n                    \
+                         address_domain, base, offset, {idx}\n                )?;\n                \
+                         starknet::StorageAccessInternal::<{ty}>::write_at_offset_internal(\n                    \
+                         address_domain, base, offset + 1_u8, x\n                )\n            }},",
+                    )
+                }).join("\n            "),
+                variants.iter().map(|MemberInfo { ty, .. }| {
+                    format!(
+                        "let variant_size = starknet::StorageAccessInternal::<{ty}>::size_internal();\n        \
+                         if variant_size > size {{\n            size = variant_size;\n        }}",
+                    )
+                }).join("\n        "),
+            }
+        }
+        ExtraInfo::Struct(members) => {
+            let member_names = members.iter().map(|MemberInfo { name, .. }| name).join(", ");
+            let offset_updates = members
+                .iter()
+                .map(|MemberInfo { ty, .. }| {
+                    format!(
+                        "let offset = offset + \
+                         starknet::StorageAccessInternal::<{ty}>::size_internal();"
+                    )
+                })
+                .collect_vec();
+            formatdoc! {"
+                    impl {name}StorageAccess of starknet::StorageAccess::<{name}> {{
+                        {base_access_functions}
+                    }}
+                    impl {name}StorageAccessInternal of starknet::StorageAccessInternal::<{name}> {{
+                        fn read_at_offset_internal(
+                            address_domain: u32, base: starknet::StorageBaseAddress, offset: u8
+                        ) -> starknet::SyscallResult<{name}> {{
+                            {}
+                            Result::Ok({name} {{ {member_names} }})
+                        }}
+                        fn write_at_offset_internal(
+                            address_domain: u32, base: starknet::StorageBaseAddress, offset: u8, value: {name}
+                        ) -> starknet::SyscallResult<()> {{
+                            let {name} {{ {member_names} }} = value;
+                            {}
+                            Result::Ok(())
+                        }}
+                        #[inline(always)]
+                        fn size_internal() -> u8 {{
+                            {}
+                        }}
+                    }}
+                ",
+                // The offset is advanced past each member but the last.
+                members.iter().zip(chain!([None], offset_updates.iter().map(Some))).map(
+                    |(MemberInfo { name: member, ty }, offset_update)| {
+                        format!(
+                            "{}let {member} = starknet::StorageAccessInternal::<{ty}>::read_at_offset_internal(\n            \
+                             address_domain, base, offset\n        )?;",
+                            offset_update.map(|update| format!("{update}\n        ")).unwrap_or_default(),
+                        )
+                    }
+                ).join("\n        "),
+                members.iter().zip(chain!([None], offset_updates.iter().map(Some))).map(
+                    |(MemberInfo { name: member, ty }, offset_update)| {
+                        format!(
+                            "{}starknet::StorageAccessInternal::<{ty}>::write_at_offset_internal(\n            \
+                             address_domain, base, offset, {member}\n        )?;",
+                            offset_update.map(|update| format!("{update}\n        ")).unwrap_or_default(),
+                        )
+                    }
+                ).join("\n        "),
+                if members.is_empty() {
+                    "0_u8".to_string()
+                } else {
+                    members.iter().map(|MemberInfo { ty, .. }| {
+                        format!("starknet::StorageAccessInternal::<{ty}>::size_internal()")
+                    }).join("\n            + ")
+                },
+            }
+        }
+        ExtraInfo::Extern => unreachable!(),
+    }
+}
@@ -0,0 +1,45 @@
+//! Parsing of the arguments of a function to run, as given on the command line.
+
+use anyhow::{bail, Context};
+use cairo_felt::Felt252;
+use num_bigint::BigInt;
+
+use crate::Arg;
+
+/// The arguments of the function to run.
+#[derive(Clone, Debug)]
+pub struct FunctionArgs(pub Vec<Arg>);
+
+/// Parses the arguments of the function to run.
+pub fn parse_args(input: &str) -> anyhow::Result<FunctionArgs> {
+    let mut args = vec![];
+    let mut array: Option<Vec<Felt252>> = None;
+    for token in input.replace('[', " [ ").replace(']', " ] ").replace(',', " ").split_whitespace()
+    {
+        match (token, &mut array) {
+            ("[", None) => array = Some(vec![]),
+            ("]", Some(_)) => args.push(Arg::Array(array.take().unwrap())),
+            ("[" | "]", _) => bail!("Nested or unbalanced brackets in arguments."),
+            (value, None) => args.push(Arg::Value(parse_felt252(value)?)),
+            (value, Some(values)) => values.push(parse_felt252(value)?),
+        }
+    }
+    if array.is_some() {
+        bail!("Unclosed bracket in arguments.");
+    }
+    Ok(FunctionArgs(args))
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal, possibly negative, felt252 value.
+pub fn parse_felt252(value: &str) -> anyhow::Result<Felt252> {
+    let (negative, abs) = match value.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, value),
+    };
+    let abs = match abs.strip_prefix("0x") {
+        Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16),
+        None => BigInt::parse_bytes(abs.as_bytes(), 10),
+    }
+    .with_context(|| format!("Invalid argument value `{value}`."))?;
+    Ok(Felt252::from(if negative { -abs } else { abs }))
+}
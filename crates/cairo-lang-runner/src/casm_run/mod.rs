@@ -20,7 +20,8 @@ use cairo_vm::types::program::Program;
 use cairo_vm::types::relocatable::{MaybeRelocatable, Relocatable};
 use cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
-use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::runners::cairo_runner::{CairoRunner, ExecutionResources};
+use cairo_vm::vm::trace::trace_entry::TraceEntry;
 use cairo_vm::vm::vm_core::VirtualMachine;
 use dict_manager::DictManagerExecScope;
 use num_bigint::BigUint;
@@ -29,6 +30,7 @@ use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
 use self::dict_manager::DictSquashExecScope;
 use crate::short_string::as_cairo_short_string;
+use crate::RunnerError;
 
 #[cfg(test)]
 mod test;
@@ -111,6 +113,7 @@ macro_rules! insert_value_to_cellref {
 
 /// Execution scope for starknet related data.
 /// All values will be 0 and by default if not setup by the test.
+#[derive(Default)]
 struct StarknetExecScope {
     /// The values of addresses in the simulated storage per contract.
     storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
@@ -488,6 +491,66 @@ impl HintProcessor for CairoHintProcessor {
                 starknet_execution_scope(exec_scopes)?.exec_info.contract_address =
                     get_val(vm, value)?;
             }
+            Hint::SetVersion { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.version =
+                    get_val(vm, value)?;
+            }
+            Hint::SetAccountContractAddress { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.account_contract_address =
+                    get_val(vm, value)?;
+            }
+            Hint::SetMaxFee { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.max_fee =
+                    get_val(vm, value)?;
+            }
+            Hint::SetTransactionHash { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.transaction_hash =
+                    get_val(vm, value)?;
+            }
+            Hint::SetChainId { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.chain_id =
+                    get_val(vm, value)?;
+            }
+            Hint::SetNonce { value } => {
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.nonce =
+                    get_val(vm, value)?;
+            }
+            Hint::SetSignature { start, end } => {
+                let (cell, offset) = extract_buffer(start);
+                let start = get_ptr(vm, cell, &offset)?;
+                let (cell, offset) = extract_buffer(end);
+                let end = get_ptr(vm, cell, &offset)?;
+                let signature = vm
+                    .get_integer_range(start, (end - start)?)?
+                    .into_iter()
+                    .map(|value| value.into_owned())
+                    .collect();
+                starknet_execution_scope(exec_scopes)?.exec_info.tx_info.signature = signature;
+            }
+            Hint::SetStorageAt { contract_address, address, value } => {
+                let contract_address = get_val(vm, contract_address)?;
+                let address = get_val(vm, address)?;
+                let value = get_val(vm, value)?;
+                starknet_execution_scope(exec_scopes)?
+                    .storage
+                    .entry(contract_address)
+                    .or_default()
+                    .insert(address, value);
+            }
+            Hint::GetStorageAt { contract_address, address, dst } => {
+                let contract_address = get_val(vm, contract_address)?;
+                let address = get_val(vm, address)?;
+                let value = starknet_execution_scope(exec_scopes)?
+                    .storage
+                    .get(&contract_address)
+                    .and_then(|contract_storage| contract_storage.get(&address))
+                    .cloned()
+                    .unwrap_or_else(|| Felt252::from(0));
+                insert_value_to_cellref!(vm, dst, value)?;
+            }
+            Hint::ResetState => {
+                *starknet_execution_scope(exec_scopes)? = StarknetExecScope::default();
+            }
             Hint::AllocFelt252Dict { segment_arena_ptr } => {
                 let (cell, base_offset) = extract_buffer(segment_arena_ptr);
                 let dict_manager_address = get_ptr(vm, cell, &base_offset)?;
@@ -767,12 +830,7 @@ fn starknet_execution_scope(
     Ok(exec_scopes
         .get_local_variables_mut()?
         .entry("starknet_exec_scope".to_string())
-        .or_insert_with(|| {
-            Box::new(StarknetExecScope {
-                storage: HashMap::default(),
-                exec_info: ExecutionInfo::default(),
-            })
-        })
+        .or_insert_with(|| Box::<StarknetExecScope>::default())
         .downcast_mut::<StarknetExecScope>()
         .unwrap())
 }
@@ -795,14 +853,29 @@ pub struct RunFunctionContext<'a> {
     pub data_len: usize,
 }
 
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+/// The result of running a function on the vm.
+pub struct RunFunctionResult {
+    /// The relocated memory layout after the run.
+    pub memory: Vec<Option<Felt252>>,
+    /// The final ap value.
+    pub ap: usize,
+    /// The relocated execution trace of the run.
+    pub trace: Vec<TraceEntry>,
+    /// The resources used by the run.
+    pub used_resources: ExecutionResources,
+}
+
+/// Runs `program` on layout with prime, and returns the memory layout, the ap value, the
+/// relocated execution trace and the used resources.
+/// Fails if the run does not end within `max_steps` steps, if given.
 pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
     instructions: Instructions,
     builtins: Vec<BuiltinName>,
+    max_steps: Option<usize>,
     additional_initialization: fn(
         context: RunFunctionContext<'_>,
     ) -> Result<(), Box<VirtualMachineError>>,
-) -> Result<(Vec<Option<Felt252>>, usize), Box<VirtualMachineError>> {
+) -> Result<RunFunctionResult, RunnerError> {
     let data: Vec<MaybeRelocatable> = instructions
         .clone()
         .flat_map(|inst| inst.assemble().encode())
@@ -836,8 +909,31 @@ pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
 
     additional_initialization(RunFunctionContext { vm: &mut vm, data_len })?;
 
-    runner.run_until_pc(end, &mut vm, &mut hint_processor)?;
+    match max_steps {
+        Some(max_steps) => match runner.run_for_steps(max_steps, &mut vm, &mut hint_processor) {
+            Ok(()) if vm.get_pc() != end => {
+                return Err(RunnerError::StepLimitExceeded { max_steps });
+            }
+            // Reaching the end of the program before the limit is a valid end of the run.
+            Ok(()) | Err(VirtualMachineError::EndOfProgram(_)) => {}
+            Err(err) => return Err(Box::new(err).into()),
+        },
+        None => runner.run_until_pc(end, &mut vm, &mut hint_processor).map_err(Box::new)?,
+    }
     runner.end_run(true, false, &mut vm, &mut hint_processor).map_err(Box::new)?;
+    let used_resources =
+        runner.get_execution_resources(&vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
     runner.relocate(&mut vm, true).map_err(VirtualMachineError::from).map_err(Box::new)?;
-    Ok((runner.relocated_memory, vm.get_relocated_trace().unwrap().last().unwrap().ap))
+    let trace: Vec<TraceEntry> = vm
+        .get_relocated_trace()
+        .unwrap()
+        .iter()
+        .map(|entry| TraceEntry { pc: entry.pc, ap: entry.ap, fp: entry.fp })
+        .collect();
+    Ok(RunFunctionResult {
+        memory: runner.relocated_memory,
+        ap: trace.last().unwrap().ap,
+        trace,
+        used_resources,
+    })
 }
@@ -1,11 +1,14 @@
 use cairo_felt::Felt252;
 use cairo_lang_casm::inline::CasmContext;
+use cairo_lang_casm::parser::parse_casm;
 use cairo_lang_casm::{casm, deref};
+use indoc::indoc;
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use test_case::test_case;
 
-use crate::casm_run::run_function;
+use crate::casm_run::{run_function, RunFunctionResult};
+use crate::RunnerError;
 
 #[test_case(
     casm! {
@@ -101,8 +104,9 @@ use crate::casm_run::run_function;
     "simple_division"
 )]
 fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
-    let (cells, ap) = run_function(function.instructions.iter(), vec![], |_| Ok(()))
-        .expect("Running code failed.");
+    let RunFunctionResult { memory: cells, ap, .. } =
+        run_function(function.instructions.iter(), vec![], None, |_| Ok(()))
+            .expect("Running code failed.");
     let cells = cells.into_iter().skip(ap - n_returns);
     assert_eq!(
         cells.take(n_returns).map(|cell| cell.unwrap()).collect_vec(),
@@ -112,7 +116,7 @@ fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
 
 #[test]
 fn test_allocate_segment() {
-    let (memory, ap) = run_function(
+    let RunFunctionResult { memory, ap, .. } = run_function(
         casm! {
             [ap] = 1337, ap++;
             %{ memory[ap] = segments.add() %}
@@ -122,6 +126,7 @@ fn test_allocate_segment() {
         .instructions
         .iter(),
         vec![],
+        None,
         |_| Ok(()),
     )
     .expect("Running code failed.");
@@ -132,3 +137,46 @@ fn test_allocate_segment() {
         .expect("Number not in index range.");
     assert_eq!(memory[ptr], Some(Felt252::from(1337)));
 }
+
+#[test]
+fn test_parsed_casm() {
+    let instructions = parse_casm(indoc! {"
+        [ap + 0] = 5, ap++;
+        [ap + 0] = 39, ap++;
+        %{ (memory[ap + 0], memory[ap + 1]) = divmod(memory[ap + -1], memory[ap + -2]) %}
+        ap += 2;
+        [ap + 0] = [ap + -2] * [ap + -4], ap++;
+        ret;
+    "})
+    .expect("Parsing code failed.");
+    let RunFunctionResult { memory, ap, .. } =
+        run_function(instructions.iter(), vec![], None, |_| Ok(())).expect("Running code failed.");
+    assert_eq!(
+        memory[ap - 3..ap].iter().map(|cell| cell.clone().unwrap()).collect_vec(),
+        [7, 4, 35].map(Felt252::from)
+    );
+}
+
+#[test]
+fn test_max_steps() {
+    let program = casm! {
+        [ap] = 1, ap++;
+        [ap] = 2, ap++;
+        ret;
+    };
+    let result = run_function(program.instructions.iter(), vec![], Some(3), |_| Ok(()))
+        .expect("Running code failed.");
+    assert_eq!(result.used_resources.n_steps, 3);
+    assert!(matches!(
+        run_function(program.instructions.iter(), vec![], Some(2), |_| Ok(())),
+        Err(RunnerError::StepLimitExceeded { max_steps: 2 })
+    ));
+}
+
+#[test]
+fn test_max_steps_infinite_loop() {
+    assert!(matches!(
+        run_function(casm! { jmp rel 0; }.instructions.iter(), vec![], Some(100), |_| Ok(())),
+        Err(RunnerError::StepLimitExceeded { max_steps: 100 })
+    ));
+}
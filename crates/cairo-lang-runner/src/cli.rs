@@ -1,56 +1,140 @@
 //! Compiles and runs a Cairo program.
 
-use std::path::Path;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Ok};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::ToOption;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_runner::args::{parse_args, FunctionArgs};
+use cairo_lang_runner::profiler::ProfilingInfo;
 use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_sierra::binary_serde::{is_binary_program, program_from_bytes};
+use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::get_sierra_program_with_statements_locations;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
 use clap::Parser;
+use itertools::Itertools;
 
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
 #[clap(version, verbatim_doc_comment)]
 struct Args {
-    /// The file to compile and run.
+    /// The file to compile and run, or a binary Sierra program (compiled with readable ids) to
+    /// run.
     path: String,
+    /// The function to run, given by a suffix of its full path.
+    #[arg(long, default_value = "::main")]
+    function: String,
+    /// The arguments of the function, separated by whitespace. Values may be decimal or
+    /// `0x`-prefixed hexadecimal, and arrays are given in brackets, e.g. `1 0x2 [3 4 5]`.
+    #[arg(long, value_parser = parse_args, default_value = "", allow_hyphen_values = true)]
+    args: FunctionArgs,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
+    /// The maximal number of steps of the run.
+    #[arg(long)]
+    max_steps: Option<usize>,
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// Whether to print the resources used by the run.
+    #[arg(long, default_value_t = false)]
+    print_resources: bool,
+    /// A file to write the execution trace of the run to, in the binary format used by provers.
+    #[arg(long, alias = "trace")]
+    trace_file: Option<PathBuf>,
+    /// A file to write the memory of the run to, in the binary format used by provers.
+    #[arg(long)]
+    memory_file: Option<PathBuf>,
+    /// A file to write a profile of the run to, in the folded stacks format used by flamegraph
+    /// tools. The steps executed by each Sierra and source function are printed as well.
+    #[arg(long)]
+    profile: Option<PathBuf>,
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-
+/// Compiles the Cairo project at the given path into a Sierra program with readable ids, together
+/// with the names of the source functions its statements were generated from. The names are known
+/// only if `add_statements_locations` is set.
+fn compile_program(
+    path: &str,
+    add_statements_locations: bool,
+) -> anyhow::Result<(Program, Vec<Option<String>>)> {
     let db = &mut RootDatabase::builder().detect_corelib().build()?;
+    db.set_add_statements_locations(add_statements_locations);
 
-    let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+    let main_crate_ids = setup_project(db, Path::new(path))?;
 
     if DiagnosticsReporter::stderr().check(db) {
-        anyhow::bail!("failed to compile: {}", args.path);
+        anyhow::bail!("failed to compile: {}", path);
+    }
+
+    let (sierra_program, statements_locations) =
+        get_sierra_program_with_statements_locations(db, &main_crate_ids)
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?;
+    let statements_functions = statements_locations
+        .iter()
+        .map(|location| source_function_name(db, location.as_ref()?))
+        .collect();
+    Ok((replace_sierra_ids_in_program(db, &sierra_program), statements_functions))
+}
+
+/// Returns the full path of the source function containing a location, if any.
+fn source_function_name(db: &RootDatabase, location: &StableLocation) -> Option<String> {
+    let file_id = db.module_file(location.module_file_id).ok()?;
+    let syntax_db = db.upcast();
+    let mut node =
+        db.file_syntax(file_id).ok()?.as_syntax_node().lookup_ptr(syntax_db, location.stable_ptr);
+    while node.kind(syntax_db) != SyntaxKind::FunctionWithBody {
+        node = node.parent()?;
+    }
+    let function = ast::FunctionWithBody::from_syntax_node(syntax_db, node.clone());
+    let mut path =
+        vec![function.declaration(syntax_db).name(syntax_db).text(syntax_db).to_string()];
+    // Functions of impls are named by their impl.
+    while let Some(parent) = node.parent() {
+        if parent.kind(syntax_db) == SyntaxKind::ItemImpl {
+            let item_impl = ast::ItemImpl::from_syntax_node(syntax_db, parent.clone());
+            path.push(item_impl.name(syntax_db).text(syntax_db).to_string());
+        }
+        node = parent;
     }
+    path.push(location.module_file_id.0.full_path(db));
+    Some(path.iter().rev().join("::"))
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
 
-    let sierra_program = db
-        .get_sierra_program(main_crate_ids)
-        .to_option()
-        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let (sierra_program, statements_functions) = match fs::read(&args.path) {
+        std::result::Result::Ok(bytes) if is_binary_program(&bytes) => (
+            program_from_bytes(&bytes).with_context(|| "Failed to decode Sierra program.")?,
+            vec![],
+        ),
+        _ => compile_program(&args.path, args.profile.is_some())?,
+    };
     let runner = SierraCasmRunner::new(
-        replace_sierra_ids_in_program(db, &sierra_program),
+        sierra_program,
         if args.available_gas.is_some() { Some(Default::default()) } else { None },
     )
     .with_context(|| "Failed setting up runner.")?;
     let result = runner
-        .run_function("::main", &[], args.available_gas)
+        .run_function(&args.function, &args.args.0, args.available_gas, args.max_steps)
         .with_context(|| "Failed to run the function.")?;
-    match result.value {
+    match &result.value {
         cairo_lang_runner::RunResultValue::Success(values) => {
             println!("Run completed successfully, returning {values:?}")
         }
@@ -58,7 +142,7 @@ fn main() -> anyhow::Result<()> {
             println!("Run panicked with err values: {values:?}")
         }
     }
-    if let Some(gas) = result.gas_counter {
+    if let Some(gas) = &result.gas_counter {
         println!("Remaining gas: {gas}");
     }
     if args.print_full_memory {
@@ -71,5 +155,40 @@ fn main() -> anyhow::Result<()> {
         }
         println!("]");
     }
+    if args.print_resources {
+        let resources = &result.used_resources;
+        println!("Resources:");
+        println!("  steps: {}", resources.n_steps);
+        println!("  memory holes: {}", resources.n_memory_holes);
+        println!("  builtins:");
+        for builtin in ["range_check", "pedersen", "poseidon", "bitwise", "ec_op"] {
+            let usage =
+                resources.builtin_instance_counter.get(builtin).copied().unwrap_or_default();
+            println!("    {builtin}: {usage}");
+        }
+    }
+    if let Some(trace_file) = &args.trace_file {
+        let mut writer = BufWriter::new(fs::File::create(trace_file)?);
+        result.write_encoded_trace(&mut writer).with_context(|| "Failed to write the trace.")?;
+        writer.flush()?;
+    }
+    if let Some(memory_file) = &args.memory_file {
+        let mut writer = BufWriter::new(fs::File::create(memory_file)?);
+        result.write_encoded_memory(&mut writer).with_context(|| "Failed to write the memory.")?;
+        writer.flush()?;
+    }
+    if let Some(profile) = &args.profile {
+        let profiling_info = ProfilingInfo::new(&runner, &args.function, &result)
+            .with_context(|| "Failed to profile the run.")?;
+        fs::write(profile, profiling_info.folded_stacks(&statements_functions))?;
+        println!("Steps per Sierra function:");
+        for (function, steps) in profiling_info.sierra_functions_steps() {
+            println!("  {function}: {steps}");
+        }
+        println!("Steps per source function:");
+        for (function, steps) in profiling_info.source_functions_steps(&statements_functions) {
+            println!("  {function}: {steps}");
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,444 @@
+//! Source level debugging of a run of a Sierra program.
+//!
+//! The debugged function is run to completion first, and the debugger then replays the run: the
+//! vm trace is mapped to the executed Sierra statements, which are mapped to the source code they
+//! were generated from. As memory cells are written at most once, the values of the variables at
+//! any point of the run are read from the final memory of the run.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use cairo_felt::Felt252;
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate, Register};
+use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
+use cairo_lang_sierra::ids::{ConcreteTypeId, FunctionId, VarId};
+use cairo_lang_sierra::program::{Statement, StatementIdx};
+use cairo_lang_sierra_to_casm::compiler::{
+    compile_with_variables_debug_info, SierraVariableDebugInfo,
+};
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::{Arg, RunResult, RunResultValue, RunnerError, SierraCasmRunner};
+
+#[cfg(test)]
+#[path = "debugger_test.rs"]
+mod test;
+
+#[derive(Debug, Error)]
+pub enum DebuggerError {
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+    #[error("The execution trace does not match the code of statement #{0}.")]
+    TraceMismatch(StatementIdx),
+}
+
+/// A location in a source file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The path of the source file.
+    pub path: PathBuf,
+    /// The line number, 1 based.
+    pub line: usize,
+}
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.path.display(), self.line)
+    }
+}
+
+/// The reason the debugged run stopped.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StopReason {
+    /// The requested step was completed.
+    Step,
+    /// A line with a breakpoint was reached.
+    Breakpoint,
+    /// The run of the debugged function was completed.
+    Finished,
+}
+
+/// A frame of the call stack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub function: FunctionId,
+    /// The statement executed by the frame.
+    pub statement: StatementIdx,
+    /// The source location of the statement, if known.
+    pub location: Option<SourceLocation>,
+}
+
+/// A Sierra variable alive at the current statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Variable {
+    pub var_id: VarId,
+    pub ty: ConcreteTypeId,
+    /// The values of the cells of the variable, or `None` for cells that were never written.
+    pub values: Vec<Option<Felt252>>,
+}
+
+/// An execution of a Sierra statement during the run.
+#[derive(Debug)]
+pub(crate) struct SierraStep {
+    pub statement: StatementIdx,
+    /// The function executing the statement.
+    pub function: FunctionId,
+    /// The number of frames below the frame executing the statement.
+    pub depth: usize,
+    /// The value of `ap` at the start of the statement's code.
+    pub ap: usize,
+    /// The value of `fp` at the start of the statement's code.
+    pub fp: usize,
+    /// The number of vm steps executed by the statement's code, excluding the code of called
+    /// Sierra functions.
+    pub vm_steps: usize,
+}
+
+/// Debugger replaying a run of a function of a Sierra program.
+pub struct Debugger {
+    /// The source locations of the Sierra statements.
+    statements_locations: Vec<Option<SourceLocation>>,
+    /// The variables alive at the start of each Sierra statement.
+    statements_variables: Vec<Vec<SierraVariableDebugInfo>>,
+    /// The executed Sierra statements, in order.
+    steps: Vec<SierraStep>,
+    /// The index of the current step, equal to the number of steps once the run is finished.
+    position: usize,
+    /// The source lines with breakpoints.
+    breakpoints: Vec<SourceLocation>,
+    run_result: RunResult,
+}
+impl Debugger {
+    /// Runs the function matching `name_suffix` and creates a debugger stopped at its first
+    /// statement. `statements_locations` are the source locations of the Sierra statements.
+    pub fn new(
+        runner: &SierraCasmRunner,
+        name_suffix: &str,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        statements_locations: Vec<Option<SourceLocation>>,
+    ) -> Result<Self, DebuggerError> {
+        let run_result = runner.run_function(name_suffix, args, available_gas, None)?;
+        let function = runner.find_function(name_suffix)?;
+        let steps = sierra_steps(runner, &function.id, &run_result)?;
+        // The variables are not collected by the compilation of the runner, as only debugging
+        // requires them.
+        let (_, statements_variables) = compile_with_variables_debug_info(
+            &runner.sierra_program,
+            &runner.metadata,
+            runner.gas_usage_check,
+        )
+        .map_err(RunnerError::from)?;
+        Ok(Self {
+            statements_locations,
+            statements_variables,
+            steps,
+            position: 0,
+            breakpoints: vec![],
+            run_result,
+        })
+    }
+
+    /// Returns the result of the debugged run.
+    pub fn result(&self) -> &RunResultValue {
+        &self.run_result.value
+    }
+
+    /// Returns whether the run of the debugged function was completed.
+    pub fn is_finished(&self) -> bool {
+        self.position == self.steps.len()
+    }
+
+    /// Adds a breakpoint on a source line. The path of the breakpoint may be a suffix of the path
+    /// of the source file. Returns false if no code is generated from the line.
+    pub fn add_breakpoint(&mut self, breakpoint: SourceLocation) -> bool {
+        let has_code = self
+            .statements_locations
+            .iter()
+            .flatten()
+            .any(|location| location_matches(location, &breakpoint));
+        self.breakpoints.push(breakpoint);
+        has_code
+    }
+
+    /// Removes a breakpoint from a source line. Returns false if there was no such breakpoint.
+    pub fn remove_breakpoint(&mut self, breakpoint: &SourceLocation) -> bool {
+        let original_len = self.breakpoints.len();
+        self.breakpoints.retain(|existing| existing != breakpoint);
+        self.breakpoints.len() != original_len
+    }
+
+    /// Returns the source lines with breakpoints.
+    pub fn breakpoints(&self) -> &[SourceLocation] {
+        &self.breakpoints
+    }
+
+    /// Continues the run until a line with a breakpoint is reached.
+    pub fn continue_run(&mut self) -> StopReason {
+        self.advance(|debugger, position| {
+            let Some(location) = debugger.step_location(position) else { return false; };
+            // Only stop when entering the line, ignoring statements without a known location.
+            let depth = debugger.steps[position].depth;
+            let previous = (0..position).rev().find_map(|previous| {
+                Some((debugger.step_location(previous)?, debugger.steps[previous].depth))
+            });
+            if previous == Some((location, depth)) {
+                return false;
+            }
+            debugger.breakpoints.iter().any(|breakpoint| location_matches(location, breakpoint))
+        })
+        .map_or(StopReason::Finished, |_| StopReason::Breakpoint)
+    }
+
+    /// Continues the run until a different source line is reached, stepping into function calls.
+    pub fn step_into(&mut self) -> StopReason {
+        let (depth, location) = self.current_depth_and_location();
+        self.stop_on_step(move |debugger, position| match debugger.step_location(position) {
+            Some(new_location) => {
+                Some(new_location) != location.as_ref() || debugger.steps[position].depth != depth
+            }
+            None => false,
+        })
+    }
+
+    /// Continues the run until a different source line is reached in the current function or one
+    /// of its callers.
+    pub fn step_over(&mut self) -> StopReason {
+        let (depth, location) = self.current_depth_and_location();
+        self.stop_on_step(move |debugger, position| {
+            let new_depth = debugger.steps[position].depth;
+            match debugger.step_location(position) {
+                Some(new_location) if new_depth <= depth => {
+                    Some(new_location) != location.as_ref() || new_depth != depth
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Continues the run until the current function returns to its caller.
+    pub fn step_out(&mut self) -> StopReason {
+        let (depth, _) = self.current_depth_and_location();
+        self.stop_on_step(move |debugger, position| {
+            debugger.steps[position].depth < depth && debugger.step_location(position).is_some()
+        })
+    }
+
+    /// Returns the source location of the current statement, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        if self.is_finished() { None } else { self.step_location(self.position) }
+    }
+
+    /// Returns the call stack at the current statement, starting from the innermost frame.
+    pub fn call_stack(&self) -> Vec<Frame> {
+        let mut frames = vec![];
+        let Some(step) = self.steps.get(self.position) else { return frames; };
+        let mut depth = step.depth;
+        frames.push(self.frame(self.position));
+        // The frame of the caller is at the last statement executed in the lower depth.
+        for position in (0..self.position).rev() {
+            if depth == 0 {
+                break;
+            }
+            if self.steps[position].depth == depth - 1 {
+                frames.push(self.frame(position));
+                depth -= 1;
+            }
+        }
+        frames
+    }
+
+    /// Returns the Sierra variables alive at the current statement, with their values.
+    pub fn variables(&self) -> Vec<Variable> {
+        let Some(step) = self.steps.get(self.position) else { return vec![]; };
+        self.statements_variables[step.statement.0]
+            .iter()
+            .map(|SierraVariableDebugInfo { var_id, ty, expression }| Variable {
+                var_id: var_id.clone(),
+                ty: ty.clone(),
+                values: expression.cells.iter().map(|cell| self.evaluate(step, cell)).collect(),
+            })
+            .collect()
+    }
+
+    /// Returns the depth and the source location of the current statement.
+    fn current_depth_and_location(&self) -> (usize, Option<SourceLocation>) {
+        match self.steps.get(self.position) {
+            Some(step) => (step.depth, self.step_location(self.position).cloned()),
+            None => (0, None),
+        }
+    }
+
+    /// Advances to the first following step for which `stop` holds, returning the reason.
+    fn stop_on_step(&mut self, stop: impl Fn(&Self, usize) -> bool) -> StopReason {
+        match self.advance(stop) {
+            None => StopReason::Finished,
+            Some(position) if self.hits_breakpoint(position) => StopReason::Breakpoint,
+            Some(_) => StopReason::Step,
+        }
+    }
+
+    /// Advances to the first following step for which `stop` holds, and returns its position.
+    /// If there is no such step, advances to the end of the run and returns `None`.
+    fn advance(&mut self, stop: impl Fn(&Self, usize) -> bool) -> Option<usize> {
+        let position = (self.position + 1..self.steps.len()).find(|position| stop(self, *position));
+        self.position = position.unwrap_or(self.steps.len());
+        position
+    }
+
+    /// Returns whether the step in the given position is on a line with a breakpoint.
+    fn hits_breakpoint(&self, position: usize) -> bool {
+        match self.step_location(position) {
+            Some(location) => {
+                self.breakpoints.iter().any(|breakpoint| location_matches(location, breakpoint))
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the source location of the step in the given position, if known.
+    fn step_location(&self, position: usize) -> Option<&SourceLocation> {
+        self.statements_locations.get(self.steps[position].statement.0)?.as_ref()
+    }
+
+    /// Returns the frame executing the step in the given position.
+    fn frame(&self, position: usize) -> Frame {
+        let step = &self.steps[position];
+        Frame {
+            function: step.function.clone(),
+            statement: step.statement,
+            location: self.step_location(position).cloned(),
+        }
+    }
+
+    /// Evaluates a cell expression at the start of the given step.
+    fn evaluate(&self, step: &SierraStep, cell: &CellExpression) -> Option<Felt252> {
+        let memory = &self.run_result.memory;
+        let address = |cell_ref: &CellRef| {
+            let base = match cell_ref.register {
+                Register::AP => step.ap,
+                Register::FP => step.fp,
+            };
+            base.checked_add_signed(cell_ref.offset as isize)
+        };
+        let deref = |cell_ref: &CellRef| memory.get(address(cell_ref)?)?.clone();
+        match cell {
+            CellExpression::Deref(cell_ref) => deref(cell_ref),
+            CellExpression::DoubleDeref(cell_ref, offset) => {
+                let pointer = deref(cell_ref)?.to_usize()?;
+                memory.get(pointer.checked_add_signed(*offset as isize)?)?.clone()
+            }
+            CellExpression::Immediate(value) => Some(Felt252::from(value.clone())),
+            CellExpression::BinOp { op, a, b } => {
+                let a = deref(a)?;
+                let b = match b {
+                    DerefOrImmediate::Deref(cell_ref) => deref(cell_ref)?,
+                    DerefOrImmediate::Immediate(value) => Felt252::from(value.value.clone()),
+                };
+                Some(match op {
+                    CellOperator::Add => a + b,
+                    CellOperator::Sub => a - b,
+                    CellOperator::Mul => a * b,
+                    CellOperator::Div => a / b,
+                })
+            }
+        }
+    }
+}
+
+/// Returns whether a source location is on the line of a breakpoint, whose path may be a suffix of
+/// the location's path.
+fn location_matches(location: &SourceLocation, breakpoint: &SourceLocation) -> bool {
+    location.line == breakpoint.line && location.path.ends_with(Path::new(&breakpoint.path))
+}
+
+/// Maps the vm trace of a run of the given function to the executed Sierra statements.
+pub(crate) fn sierra_steps(
+    runner: &SierraCasmRunner,
+    function_id: &FunctionId,
+    run_result: &RunResult,
+) -> Result<Vec<SierraStep>, DebuggerError> {
+    let program = &runner.sierra_program;
+    let statements_info = &runner.casm_program.debug_info.sierra_statement_info;
+    let code_size = statements_info.last().unwrap().code_offset;
+    let entry_points: HashMap<_, _> =
+        program.funcs.iter().map(|function| (function.id.clone(), function.entry_point)).collect();
+    // The code segment is relocated to start at address 1.
+    let code_offset = |pc: usize| pc.checked_sub(1 + run_result.program_offset);
+    let trace = &run_result.trace;
+
+    let mut steps = vec![];
+    // The index in the trace of the start of each step.
+    let mut steps_starts = vec![];
+    // The callers of the current function, with the statements to return to.
+    let mut callers: Vec<(FunctionId, StatementIdx)> = vec![];
+    let mut function = function_id.clone();
+    let mut statement = entry_points[&function];
+    // Skip the entry code.
+    let mut trace_idx = trace
+        .iter()
+        .position(|entry| code_offset(entry.pc) == Some(statements_info[statement.0].code_offset))
+        .ok_or(DebuggerError::TraceMismatch(statement))?;
+    loop {
+        let entry = trace.get(trace_idx).ok_or(DebuggerError::TraceMismatch(statement))?;
+        steps.push(SierraStep {
+            statement,
+            function: function.clone(),
+            depth: callers.len(),
+            ap: entry.ap,
+            fp: entry.fp,
+            vm_steps: 0,
+        });
+        steps_starts.push(trace_idx);
+        let invocation = match &program.statements[statement.0] {
+            Statement::Return(_) => {
+                // Skip the `ret` instruction.
+                trace_idx += 1;
+                let Some((caller, return_statement)) = callers.pop() else { break; };
+                function = caller;
+                statement = return_statement;
+                continue;
+            }
+            Statement::Invocation(invocation) => invocation,
+        };
+        let libfunc = runner.sierra_program_registry.get_libfunc(&invocation.libfunc_id).unwrap();
+        if let CoreConcreteLibfunc::FunctionCall(libfunc) = libfunc {
+            // Skip the `call` instruction.
+            trace_idx += 1;
+            callers.push((
+                std::mem::replace(&mut function, libfunc.function.id.clone()),
+                statement.next(&invocation.branches[0].target),
+            ));
+            statement = libfunc.function.entry_point;
+            continue;
+        }
+        // Skip the code of the statement, including code called outside of the program.
+        let code =
+            statements_info[statement.0].code_offset..statements_info[statement.0 + 1].code_offset;
+        while let Some(offset) = trace.get(trace_idx).and_then(|entry| code_offset(entry.pc)) {
+            if !code.contains(&offset) && offset < code_size {
+                break;
+            }
+            trace_idx += 1;
+        }
+        let offset = trace.get(trace_idx).and_then(|entry| code_offset(entry.pc));
+        let next_statement = invocation
+            .branches
+            .iter()
+            .map(|branch| statement.next(&branch.target))
+            .find(|next_statement| {
+                invocation.branches.len() == 1
+                    || Some(statements_info[next_statement.0].code_offset) == offset
+            })
+            .ok_or(DebuggerError::TraceMismatch(statement))?;
+        statement = next_statement;
+    }
+    for (step, (start, end)) in
+        steps.iter_mut().zip(steps_starts.iter().copied().chain([trace_idx]).tuple_windows())
+    {
+        step.vm_steps = end - start;
+    }
+    Ok(steps)
+}
@@ -0,0 +1,226 @@
+//! Compiles a Cairo program and debugs a run of one of its functions.
+
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_diagnostics::{DiagnosticLocation, ToOption};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_runner::args::{parse_args, FunctionArgs};
+use cairo_lang_runner::debugger::{Debugger, SourceLocation, StopReason};
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
+use cairo_lang_sierra::program::Program;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::get_sierra_program_with_statements_locations;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use clap::Parser;
+use itertools::Itertools;
+
+/// Command line args parser.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The file to compile and debug.
+    path: String,
+    /// The function to run, given by a suffix of its full path.
+    #[arg(long, default_value = "::main")]
+    function: String,
+    /// The arguments of the function, separated by whitespace. Values may be decimal or
+    /// `0x`-prefixed hexadecimal, and arrays are given in brackets, e.g. `1 0x2 [3 4 5]`.
+    #[arg(long, value_parser = parse_args, default_value = "", allow_hyphen_values = true)]
+    args: FunctionArgs,
+    /// In cases where gas is available, the amount of provided gas.
+    #[arg(long)]
+    available_gas: Option<usize>,
+}
+
+const HELP: &str = "\
+Commands:
+  break <file>:<line>   Stop when the line is reached. The file may be a suffix of the path.
+  delete <file>:<line>  Remove a breakpoint.
+  continue              Continue until a breakpoint is reached.
+  step                  Step to the next line, into function calls.
+  next                  Step to the next line, over function calls.
+  finish                Step out of the current function.
+  backtrace             Print the call stack.
+  vars                  Print the Sierra variables alive at the current statement.
+  list                  Print the source around the current line.
+  quit                  Stop debugging.";
+
+/// Compiles the Cairo project at the given path into a Sierra program with readable ids, together
+/// with the source locations of its statements. Only statements generated from code of the project
+/// are mapped to a location, so code inlined from the corelib is stepped over.
+fn compile_program(path: &str) -> anyhow::Result<(Program, Vec<Option<SourceLocation>>)> {
+    let db = &mut RootDatabase::builder().detect_corelib().build()?;
+    db.set_add_statements_locations(true);
+
+    let main_crate_ids = setup_project(db, Path::new(path))?;
+
+    if DiagnosticsReporter::stderr().check(db) {
+        anyhow::bail!("failed to compile: {}", path);
+    }
+
+    let (sierra_program, statements_locations) =
+        get_sierra_program_with_statements_locations(db, &main_crate_ids)
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?;
+    let statements_locations = statements_locations
+        .iter()
+        .map(|location| {
+            let location = location.as_ref()?;
+            if !main_crate_ids.contains(&location.module_file_id.0.owning_crate(db)) {
+                return None;
+            }
+            let DiagnosticLocation { file_id, span } = location.diagnostic_location(db);
+            let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) else { return None; };
+            let position = span.start.position_in_file(db, file_id)?;
+            Some(SourceLocation { path, line: position.line + 1 })
+        })
+        .collect();
+    Ok((replace_sierra_ids_in_program(db, &sierra_program), statements_locations))
+}
+
+/// Parses a `<file>:<line>` source location.
+fn parse_location(input: &str) -> anyhow::Result<SourceLocation> {
+    let (path, line) = input.rsplit_once(':').with_context(|| "Expected `<file>:<line>`.")?;
+    let line = line.parse().with_context(|| format!("Invalid line number `{line}`."))?;
+    Ok(SourceLocation { path: PathBuf::from(path), line })
+}
+
+/// Prints the current location of the debugger, or the result of the run if it is finished.
+fn print_stop(debugger: &Debugger, reason: StopReason) {
+    if reason == StopReason::Finished {
+        match debugger.result() {
+            RunResultValue::Success(values) => {
+                println!("Run completed successfully, returning {values:?}")
+            }
+            RunResultValue::Panic(values) => println!("Run panicked with err values: {values:?}"),
+        }
+        return;
+    }
+    let Some(frame) = debugger.call_stack().into_iter().next() else { return; };
+    let prefix = if reason == StopReason::Breakpoint { "Breakpoint hit at" } else { "At" };
+    match &frame.location {
+        Some(location) => {
+            println!("{prefix} {location} in {}", frame.function);
+            print_source(location, 0);
+        }
+        None => println!("{prefix} statement #{} in {}", frame.statement, frame.function),
+    }
+}
+
+/// Prints the source lines within `radius` of a location.
+fn print_source(location: &SourceLocation, radius: usize) {
+    let Ok(source) = fs::read_to_string(&location.path) else { return; };
+    let first = location.line.saturating_sub(radius).max(1);
+    for (line, text) in source.lines().enumerate().skip(first - 1).take(2 * radius + 1) {
+        let marker = if line + 1 == location.line { "->" } else { "  " };
+        println!("{marker} {:>4} {text}", line + 1);
+    }
+}
+
+/// Handles a single command. Returns false if debugging should stop.
+fn handle_command(debugger: &mut Debugger, command: &str) -> anyhow::Result<bool> {
+    let (command, argument) = command.split_once(' ').unwrap_or((command, ""));
+    let argument = argument.trim();
+    let reason = match command {
+        "b" | "break" => {
+            let location = parse_location(argument)?;
+            if !debugger.add_breakpoint(location.clone()) {
+                println!("No code is generated from {location}, the breakpoint will not be hit.");
+            }
+            return Ok(true);
+        }
+        "d" | "delete" => {
+            if !debugger.remove_breakpoint(&parse_location(argument)?) {
+                println!("No breakpoint at {argument}.");
+            }
+            return Ok(true);
+        }
+        "bt" | "backtrace" => {
+            for (i, frame) in debugger.call_stack().iter().enumerate() {
+                match &frame.location {
+                    Some(location) => println!("#{i} {} at {location}", frame.function),
+                    None => println!("#{i} {} at statement #{}", frame.function, frame.statement),
+                }
+            }
+            return Ok(true);
+        }
+        "v" | "vars" => {
+            for variable in debugger.variables() {
+                let values = variable
+                    .values
+                    .iter()
+                    .map(|value| value.as_ref().map_or("?".to_string(), |value| value.to_string()))
+                    .join(", ");
+                println!("{}: {} = [{values}]", variable.var_id, variable.ty);
+            }
+            return Ok(true);
+        }
+        "l" | "list" => {
+            if let Some(location) = debugger.location() {
+                print_source(location, 5);
+            }
+            return Ok(true);
+        }
+        "h" | "help" => {
+            println!("{HELP}");
+            return Ok(true);
+        }
+        "q" | "quit" => return Ok(false),
+        _ if debugger.is_finished() => {
+            println!("The run is finished.");
+            return Ok(true);
+        }
+        "c" | "continue" => debugger.continue_run(),
+        "s" | "step" => debugger.step_into(),
+        "n" | "next" => debugger.step_over(),
+        "f" | "finish" => debugger.step_out(),
+        _ => anyhow::bail!("Unknown command `{command}`, type `help` for the list of commands."),
+    };
+    print_stop(debugger, reason);
+    Ok(true)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let (sierra_program, statements_locations) = compile_program(&args.path)?;
+    let runner = SierraCasmRunner::new(
+        sierra_program,
+        if args.available_gas.is_some() { Some(Default::default()) } else { None },
+    )
+    .with_context(|| "Failed setting up runner.")?;
+    let mut debugger = Debugger::new(
+        &runner,
+        &args.function,
+        &args.args.0,
+        args.available_gas,
+        statements_locations,
+    )
+    .with_context(|| "Failed to run the function.")?;
+    print_stop(&debugger, StopReason::Step);
+
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        print!("(cairo-debug) ");
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next() else { break; };
+        let line = line?;
+        let command = line.trim();
+        if command.is_empty() {
+            continue;
+        }
+        match handle_command(&mut debugger, command) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => println!("{err}"),
+        }
+    }
+    Ok(())
+}
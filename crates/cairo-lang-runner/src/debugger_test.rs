@@ -0,0 +1,123 @@
+use cairo_felt::Felt252;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::{Debugger, SourceLocation, StopReason};
+use crate::{Arg, RunResultValue, SierraCasmRunner};
+
+/// A program computing `double(double(x))`, with the source line of each statement.
+const PROGRAM: &str = indoc! {"
+    type felt252 = felt252;
+
+    libfunc felt252_add = felt252_add;
+    libfunc felt252_dup = dup<felt252>;
+    libfunc store_temp_felt252 = store_temp<felt252>;
+    libfunc call_double = function_call<user@double>;
+
+    felt252_dup([0]) -> ([0], [1]);
+    felt252_add([0], [1]) -> ([2]);
+    store_temp_felt252([2]) -> ([2]);
+    return([2]);
+    store_temp_felt252([0]) -> ([0]);
+    call_double([0]) -> ([1]);
+    store_temp_felt252([1]) -> ([1]);
+    call_double([1]) -> ([2]);
+    return([2]);
+
+    double@0([0]: felt252) -> (felt252);
+    main@4([0]: felt252) -> (felt252);
+"};
+const STATEMENT_LINES: [usize; 9] = [2, 2, 2, 3, 6, 6, 7, 7, 8];
+
+fn runner() -> SierraCasmRunner {
+    SierraCasmRunner::new(ProgramParser::new().parse(PROGRAM).unwrap(), None).unwrap()
+}
+
+fn location(line: usize) -> SourceLocation {
+    SourceLocation { path: "src/lib.cairo".into(), line }
+}
+
+fn debugger(runner: &SierraCasmRunner) -> Debugger {
+    let locations = STATEMENT_LINES.iter().map(|line| Some(location(*line))).collect();
+    Debugger::new(runner, "main", &[Arg::Value(Felt252::from(5))], None, locations).unwrap()
+}
+
+/// Returns the function names and lines of the call stack.
+fn call_stack(debugger: &Debugger) -> Vec<String> {
+    debugger
+        .call_stack()
+        .into_iter()
+        .map(|frame| format!("{}:{}", frame.function, frame.location.unwrap().line))
+        .collect()
+}
+
+#[test]
+fn stepping() {
+    let runner = runner();
+    let mut debugger = debugger(&runner);
+    assert_eq!(debugger.location(), Some(&location(6)));
+    assert_eq!(call_stack(&debugger), vec!["main:6"]);
+
+    assert_eq!(debugger.step_into(), StopReason::Step);
+    assert_eq!(call_stack(&debugger), vec!["double:2", "main:6"]);
+    assert_eq!(debugger.step_over(), StopReason::Step);
+    assert_eq!(call_stack(&debugger), vec!["double:3", "main:6"]);
+    assert_eq!(debugger.step_out(), StopReason::Step);
+    assert_eq!(call_stack(&debugger), vec!["main:7"]);
+    assert_eq!(debugger.step_over(), StopReason::Step);
+    assert_eq!(call_stack(&debugger), vec!["main:8"]);
+
+    assert_eq!(debugger.step_over(), StopReason::Finished);
+    assert!(debugger.is_finished());
+    assert_eq!(debugger.location(), None);
+    assert_eq!(debugger.result(), &RunResultValue::Success(vec![Felt252::from(20)]));
+}
+
+#[test]
+fn breakpoints() {
+    let runner = runner();
+    let mut debugger = debugger(&runner);
+    assert!(debugger.add_breakpoint(SourceLocation { path: "lib.cairo".into(), line: 2 }));
+    assert!(!debugger.add_breakpoint(location(4)));
+
+    // Both calls of `double` stop at the breakpoint.
+    assert_eq!(debugger.continue_run(), StopReason::Breakpoint);
+    assert_eq!(call_stack(&debugger), vec!["double:2", "main:6"]);
+    assert_eq!(debugger.continue_run(), StopReason::Breakpoint);
+    assert_eq!(call_stack(&debugger), vec!["double:2", "main:7"]);
+    assert_eq!(debugger.continue_run(), StopReason::Finished);
+
+    let mut debugger = self::debugger(&runner);
+    debugger.add_breakpoint(location(2));
+    assert!(debugger.remove_breakpoint(&location(2)));
+    assert!(!debugger.remove_breakpoint(&location(2)));
+    assert_eq!(debugger.continue_run(), StopReason::Finished);
+}
+
+#[test]
+fn variables() {
+    let runner = runner();
+    let mut debugger = debugger(&runner);
+    let variables = |debugger: &Debugger| {
+        debugger
+            .variables()
+            .into_iter()
+            .map(|variable| {
+                let values = variable.values.into_iter().map(|value| value.unwrap()).collect_vec();
+                (variable.var_id.to_string(), variable.ty.to_string(), values)
+            })
+            .collect_vec()
+    };
+    let felt252 = |value: usize| vec![Felt252::from(value)];
+    assert_eq!(variables(&debugger), vec![("[0]".into(), "felt252".into(), felt252(5))]);
+
+    // Step to the return of the second call of `double`.
+    debugger.step_into();
+    debugger.step_out();
+    debugger.step_into();
+    debugger.step_over();
+    assert_eq!(call_stack(&debugger), vec!["double:3", "main:7"]);
+    assert_eq!(variables(&debugger), vec![("[2]".into(), "felt252".into(), felt252(20))]);
+}
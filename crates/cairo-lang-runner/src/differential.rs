@@ -0,0 +1,942 @@
+//! Differential testing of the CASM lowering of libfuncs against their Sierra semantics.
+//!
+//! The tested libfunc is wrapped by a program with a single function, taking the parameters of the
+//! libfunc and returning the outputs of the taken branch, as the variant of an enum matching the
+//! branch. The function is run on random inputs, both by the Sierra simulation and on the vm, and
+//! the taken branches and the outputs are compared. The vm run is additionally checked against the
+//! gas cost (`core_libfunc_cost`) and the ap change (`core_libfunc_ap_change`) the compiler assumes
+//! for the libfunc, where the gas used by the run is priced by the token costs of the runner.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::core::{
+    CoreConcreteLibfunc, CoreLibfunc, CoreType, CoreTypeConcrete,
+};
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::extensions::lib_func::ParamSignature;
+use cairo_lang_sierra::extensions::starknet::StarkNetTypeConcrete;
+use cairo_lang_sierra::extensions::{ConcreteLibfunc, ConcreteType};
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
+use cairo_lang_sierra::simulation::starknet::StarknetState;
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra::{simulation, ProgramParser};
+use cairo_lang_sierra_ap_change::core_libfunc_ap_change::{
+    core_libfunc_ap_change, InvocationApChangeInfoProvider,
+};
+use cairo_lang_sierra_ap_change::ApChange;
+use cairo_lang_sierra_gas::core_libfunc_cost::{core_libfunc_cost, InvocationCostInfoProvider};
+use cairo_lang_sierra_gas::objects::ConstCost;
+use cairo_lang_sierra_to_casm::metadata::{Metadata, MetadataComputationConfig};
+use cairo_vm::vm::runners::builtin_runner::{
+    BITWISE_BUILTIN_NAME, EC_OP_BUILTIN_NAME, HASH_BUILTIN_NAME, POSEIDON_BUILTIN_NAME,
+    RANGE_CHECK_BUILTIN_NAME,
+};
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, ToPrimitive, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use thiserror::Error;
+
+use crate::{
+    Arg, RunResult, RunResultValue, RunnerError, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST,
+};
+
+#[cfg(test)]
+#[path = "differential_test.rs"]
+mod test;
+
+/// The id of the tested libfunc in the declarations given to the tester.
+pub const TESTED_LIBFUNC: &str = "tested";
+/// The name of the function wrapping the tested libfunc.
+const WRAPPER_FUNCTION: &str = "differential_wrapper";
+/// The index of the tested libfunc invocation in the wrapper function.
+const TESTED_STATEMENT: StatementIdx = StatementIdx(0);
+/// The number of statements handling the outputs of a single branch of the tested libfunc.
+const BRANCH_STATEMENTS: usize = 5;
+/// The maximal number of steps of a single vm run.
+const MAX_STEPS: usize = 100000;
+/// The maximal length of a generated array input.
+const MAX_ARRAY_LEN: usize = 8;
+/// The maximal value of a generated gas counter input.
+const MAX_GAS: usize = 100000;
+/// The probability of generating an edge case value instead of a uniformly distributed one.
+const EDGE_CASE_PROBABILITY: f64 = 0.2;
+/// The number of bits of the addresses and class hashes of Starknet.
+const ADDRESS_BITS: usize = 251;
+
+/// The declarations of the libfuncs checked by default, together with the types they use.
+/// The checked libfunc is always declared as `tested`.
+pub const CORE_LIBFUNC_DECLARATIONS: &[&str] = &[
+    "type felt252 = felt252; libfunc tested = felt252_add;",
+    "type felt252 = felt252; libfunc tested = felt252_sub;",
+    "type felt252 = felt252; libfunc tested = felt252_mul;",
+    "type felt252 = felt252; type NonZeroFelt252 = NonZero<felt252>;
+     libfunc tested = felt252_div;",
+    "type felt252 = felt252; type NonZeroFelt252 = NonZero<felt252>;
+     libfunc tested = felt252_is_zero;",
+    "type felt252 = felt252; libfunc tested = felt252_const<-5>;",
+    "type RangeCheck = RangeCheck; type u8 = u8; libfunc tested = u8_overflowing_add;",
+    "type RangeCheck = RangeCheck; type u8 = u8; libfunc tested = u8_overflowing_sub;",
+    "type RangeCheck = RangeCheck; type u8 = u8; libfunc tested = u8_lt;",
+    "type RangeCheck = RangeCheck; type u8 = u8; libfunc tested = u8_le;",
+    "type u8 = u8; libfunc tested = u8_eq;",
+    "type RangeCheck = RangeCheck; type u8 = u8; type NonZeroU8 = NonZero<u8>;
+     libfunc tested = u8_safe_divmod;",
+    "type RangeCheck = RangeCheck; type felt252 = felt252; type u8 = u8;
+     libfunc tested = u8_try_from_felt252;",
+    "type RangeCheck = RangeCheck; type u16 = u16; libfunc tested = u16_overflowing_add;",
+    "type RangeCheck = RangeCheck; type u16 = u16; libfunc tested = u16_overflowing_sub;",
+    "type RangeCheck = RangeCheck; type u16 = u16; libfunc tested = u16_lt;",
+    "type RangeCheck = RangeCheck; type u32 = u32; libfunc tested = u32_overflowing_add;",
+    "type RangeCheck = RangeCheck; type u32 = u32; libfunc tested = u32_overflowing_sub;",
+    "type RangeCheck = RangeCheck; type u32 = u32; type NonZeroU32 = NonZero<u32>;
+     libfunc tested = u32_safe_divmod;",
+    "type RangeCheck = RangeCheck; type u64 = u64; libfunc tested = u64_overflowing_add;",
+    "type RangeCheck = RangeCheck; type u64 = u64; libfunc tested = u64_le;",
+    "type u64 = u64; type u128 = u128; libfunc tested = u64_wide_mul;",
+    "type u64 = u64; type NonZeroU64 = NonZero<u64>; libfunc tested = u64_is_zero;",
+    "type RangeCheck = RangeCheck; type u128 = u128; libfunc tested = u128_overflowing_add;",
+    "type RangeCheck = RangeCheck; type u128 = u128; libfunc tested = u128_overflowing_sub;",
+    "type RangeCheck = RangeCheck; type u128 = u128; libfunc tested = u128_lt;",
+    "type RangeCheck = RangeCheck; type u128 = u128; libfunc tested = u128_le;",
+    "type u128 = u128; libfunc tested = u128_eq;",
+    "type RangeCheck = RangeCheck; type u128 = u128; type NonZeroU128 = NonZero<u128>;
+     libfunc tested = u128_safe_divmod;",
+    "type RangeCheck = RangeCheck; type felt252 = felt252; type u128 = u128;
+     libfunc tested = u128s_from_felt252;",
+    "type u128 = u128; type felt252 = felt252; libfunc tested = u128_to_felt252;",
+    "type RangeCheck = RangeCheck; type u8 = u8; type u64 = u64;
+     libfunc tested = downcast<u64, u8>;",
+    "type u16 = u16; type u128 = u128; libfunc tested = upcast<u16, u128>;",
+    "type Bitwise = Bitwise; type u128 = u128; libfunc tested = bitwise;",
+    "type Pedersen = Pedersen; type felt252 = felt252; libfunc tested = pedersen;",
+    "type Poseidon = Poseidon; type felt252 = felt252; libfunc tested = hades_permutation;",
+    "type Unit = Struct<ut@Tuple>; type bool = Enum<ut@core::bool, Unit, Unit>;
+     libfunc tested = bool_xor_impl;",
+    "type felt252 = felt252; type Array = Array<felt252>; libfunc tested = array_new<felt252>;",
+    "type felt252 = felt252; type Array = Array<felt252>;
+     libfunc tested = array_append<felt252>;",
+    "type felt252 = felt252; type Array = Array<felt252>; type Box = Box<felt252>;
+     libfunc tested = array_pop_front<felt252>;",
+    "type RangeCheck = RangeCheck; type felt252 = felt252; type u32 = u32;
+     type Array = Array<felt252>; type Snapshot = Snapshot<Array>; type Box = Box<felt252>;
+     libfunc tested = array_get<felt252>;",
+    "type felt252 = felt252; type u32 = u32; type Array = Array<felt252>;
+     type Snapshot = Snapshot<Array>; libfunc tested = array_len<felt252>;",
+    "type RangeCheck = RangeCheck; type GasBuiltin = GasBuiltin; libfunc tested = withdraw_gas;",
+    "type GasBuiltin = GasBuiltin; libfunc tested = redeposit_gas;",
+    "type GasBuiltin = GasBuiltin; type u128 = u128; libfunc tested = get_available_gas;",
+    "libfunc tested = revoke_ap_tracking;",
+    "libfunc tested = branch_align;",
+    "libfunc tested = jump;",
+    "type felt252 = felt252; libfunc tested = drop<felt252>;",
+    "type felt252 = felt252; libfunc tested = dup<felt252>;",
+    "type felt252 = felt252; libfunc tested = store_temp<felt252>;",
+    "type felt252 = felt252; libfunc tested = rename<felt252>;",
+    "type felt252 = felt252; type Array = Array<felt252>; type Snapshot = Snapshot<Array>;
+     libfunc tested = snapshot_take<Array>;",
+    "type felt252 = felt252; type NonZeroFelt252 = NonZero<felt252>;
+     libfunc tested = unwrap_non_zero<felt252>;",
+    "type felt252 = felt252; type Box = Box<felt252>; libfunc tested = into_box<felt252>;",
+    "type felt252 = felt252; type Nullable = Nullable<felt252>;
+     libfunc tested = null<felt252>;",
+    "type felt252 = felt252; type Box = Box<felt252>; type Nullable = Nullable<felt252>;
+     libfunc tested = match_nullable<felt252>;",
+    "type felt252 = felt252; type u8 = u8; type Pair = Struct<ut@Pair, felt252, u8>;
+     libfunc tested = struct_construct<Pair>;",
+    "type felt252 = felt252; type u8 = u8; type Pair = Struct<ut@Pair, felt252, u8>;
+     libfunc tested = struct_deconstruct<Pair>;",
+    "type felt252 = felt252; type Array = Array<felt252>; type SnapshotArray = Snapshot<Array>;
+     type Pair = Struct<ut@Pair, felt252, Array>; type SnapshotPair = Snapshot<Pair>;
+     libfunc tested = struct_snapshot_deconstruct<Pair>;",
+    "type felt252 = felt252; type u8 = u8; type Choice = Enum<ut@Choice, felt252, u8, felt252>;
+     libfunc tested = enum_init<Choice, 1>;",
+    "type felt252 = felt252; type u8 = u8; type Choice = Enum<ut@Choice, felt252, u8, felt252>;
+     libfunc tested = enum_match<Choice>;",
+    "type felt252 = felt252; type Array = Array<felt252>; type SnapshotArray = Snapshot<Array>;
+     type Choice = Enum<ut@Choice, felt252, Array>; type SnapshotChoice = Snapshot<Choice>;
+     libfunc tested = enum_snapshot_match<Choice>;",
+    "type u128 = u128; type u256 = Struct<ut@core::integer::u256, u128, u128>;
+     type NonZeroU256 = NonZero<u256>; libfunc tested = u256_is_zero;",
+    "type felt252 = felt252; type EcPoint = EcPoint; libfunc tested = ec_point_zero;",
+    "type felt252 = felt252; type EcPoint = EcPoint; type NonZeroEcPoint = NonZero<EcPoint>;
+     libfunc tested = ec_point_try_new_nz;",
+    "type RangeCheck = RangeCheck; type felt252 = felt252; type EcPoint = EcPoint;
+     type NonZeroEcPoint = NonZero<EcPoint>; libfunc tested = ec_point_from_x_nz;",
+    "type EcState = EcState; libfunc tested = ec_state_init;",
+    "type SegmentArena = SegmentArena; type felt252 = felt252;
+     type Dict = Felt252Dict<felt252>; libfunc tested = felt252_dict_new<felt252>;",
+    "type felt252 = felt252; type Array = Array<felt252>; libfunc tested = print;",
+    "type felt252 = felt252; type ContractAddress = ContractAddress;
+     libfunc tested = contract_address_const<5>;",
+    "type RangeCheck = RangeCheck; type felt252 = felt252; type ContractAddress = ContractAddress;
+     libfunc tested = contract_address_try_from_felt252;",
+    "type felt252 = felt252; type StorageBaseAddress = StorageBaseAddress;
+     type StorageAddress = StorageAddress; libfunc tested = storage_address_from_base;",
+    "type GasBuiltin = GasBuiltin; type System = System; type felt252 = felt252; type u32 = u32;
+     type StorageAddress = StorageAddress; type Array = Array<felt252>;
+     libfunc tested = storage_read_syscall;",
+    "type GasBuiltin = GasBuiltin; type System = System; type felt252 = felt252; type u32 = u32;
+     type StorageAddress = StorageAddress; type Array = Array<felt252>;
+     libfunc tested = storage_write_syscall;",
+];
+
+macro_rules! libfunc_families {
+    ($($family:ident),* $(,)?) => {
+        /// The families of the core libfuncs - the variants of `CoreConcreteLibfunc`.
+        pub const CORE_LIBFUNC_FAMILIES: &[&str] = &[$(stringify!($family)),*];
+
+        /// Returns the family of a core libfunc.
+        pub fn libfunc_family(libfunc: &CoreConcreteLibfunc) -> &'static str {
+            match libfunc {
+                $(CoreConcreteLibfunc::$family(_) => stringify!($family)),*
+            }
+        }
+    };
+}
+
+libfunc_families! {
+    ApTracking,
+    Array,
+    Bitwise,
+    BranchAlign,
+    Bool,
+    Box,
+    Cast,
+    Drop,
+    Dup,
+    Ec,
+    Felt252,
+    FunctionCall,
+    Gas,
+    Uint8,
+    Uint16,
+    Uint32,
+    Uint64,
+    Uint128,
+    Uint256,
+    Mem,
+    Nullable,
+    UnwrapNonZero,
+    UnconditionalJump,
+    Enum,
+    Struct,
+    Felt252Dict,
+    Pedersen,
+    Poseidon,
+    StarkNet,
+    Debug,
+    SnapshotTake,
+}
+
+/// The families of the core libfuncs that have no entry in `CORE_LIBFUNC_DECLARATIONS`, with the
+/// reason they can't be tested.
+pub const UNTESTED_LIBFUNC_FAMILIES: &[(&str, &str)] =
+    &[("FunctionCall", "Calls a user function, which a declarations-only program can't define.")];
+
+/// The configuration of the differential tester.
+#[derive(Clone, Copy, Debug)]
+pub struct DifferentialConfig {
+    /// The number of random inputs each libfunc is run with.
+    pub runs: usize,
+    /// The seed of the random inputs generator.
+    pub seed: u64,
+}
+
+/// Error occurring while setting up the differential test of a libfunc.
+#[derive(Debug, Error)]
+pub enum DifferentialError {
+    #[error("Failed parsing the libfunc declarations: {0}")]
+    ParseError(String),
+    #[error("The libfunc to test must be declared as `{TESTED_LIBFUNC}`.")]
+    MissingTestedLibfunc,
+    #[error("Type `{0}` is not supported by the differential tester.")]
+    UnsupportedType(ConcreteTypeId),
+    #[error(transparent)]
+    ProgramRegistryError(#[from] Box<ProgramRegistryError>),
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+}
+
+/// A difference between the Sierra semantics and the CASM execution of a libfunc.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum Mismatch {
+    #[error("The simulation failed, while the vm run succeeded: {0}")]
+    SimulationFailed(String),
+    #[error("The vm run failed, while the simulation succeeded: {0}")]
+    CasmFailed(String),
+    #[error("The simulation failed with `{simulated}`, and the vm run failed with `{casm}`.")]
+    BothFailed { simulated: String, casm: String },
+    #[error("The vm run panicked with {0:?}.")]
+    CasmPanicked(Vec<BigInt>),
+    #[error("The simulation took branch {simulated}, while the vm run took branch {casm}.")]
+    Branch { simulated: usize, casm: usize },
+    #[error("The simulation returned {simulated:?}, while the vm run returned {casm:?}.")]
+    Outputs { simulated: Vec<CoreValue>, casm: Vec<CoreValue> },
+    #[error("Branch {branch} is charged {charged} gas, but the vm run used {used}.")]
+    Gas { branch: usize, charged: i64, used: i64 },
+    #[error(
+        "Branch {branch} has an ap change of {expected}, while the vm run changed ap by {actual}."
+    )]
+    ApChange { branch: usize, expected: usize, actual: usize },
+}
+
+/// A mismatch found for a libfunc, with the inputs it was found for.
+#[derive(Debug, Eq, PartialEq)]
+pub struct MismatchReport {
+    /// The tested libfunc.
+    pub libfunc: String,
+    /// The inputs of the run the mismatch was found in.
+    pub inputs: Vec<CoreValue>,
+    pub mismatch: Mismatch,
+}
+impl std::fmt::Display for MismatchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on inputs {:?}: {}", self.libfunc, self.inputs, self.mismatch)
+    }
+}
+
+/// Runs the libfunc declared as `tested` in `declarations` on random inputs, both by the Sierra
+/// simulation and on the vm, and returns the mismatches found between the runs.
+pub fn test_libfunc(
+    declarations: &str,
+    config: DifferentialConfig,
+) -> Result<Vec<MismatchReport>, DifferentialError> {
+    let tester = LibfuncTester::new(declarations)?;
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut reports = vec![];
+    for _ in 0..config.runs {
+        let inputs = tester.random_inputs(&mut rng)?;
+        if let Some(mismatch) = tester.check(&inputs)? {
+            reports.push(MismatchReport {
+                libfunc: tester.libfunc_name.clone(),
+                inputs: inputs.into_iter().map(|input| input.value).collect(),
+                mismatch,
+            });
+        }
+    }
+    Ok(reports)
+}
+
+/// Creates the code of the program wrapping the tested libfunc.
+fn wrapper_program_code(
+    declarations: &Program,
+    libfunc: &CoreConcreteLibfunc,
+) -> Result<String, std::fmt::Error> {
+    let branches = libfunc.branch_signatures();
+    // The fallthrough branch, if any, is handled right after the libfunc invocation.
+    let fallthrough = libfunc.fallthrough();
+    let branch_order = fallthrough
+        .into_iter()
+        .chain((0..branches.len()).filter(|branch| Some(*branch) != fallthrough))
+        .collect_vec();
+    let branch_start =
+        |branch| 1 + BRANCH_STATEMENTS * branch_order.iter().position(|b| *b == branch).unwrap();
+    let mut code = String::new();
+    for declaration in &declarations.type_declarations {
+        writeln!(code, "{declaration};")?;
+    }
+    for (branch, signature) in branches.iter().enumerate() {
+        write!(code, "type DifferentialBranch{branch} = Struct<ut@DifferentialBranch{branch}")?;
+        for var in &signature.vars {
+            write!(code, ", {}", var.ty)?;
+        }
+        writeln!(code, ">;")?;
+    }
+    write!(code, "type DifferentialResult = Enum<ut@DifferentialResult")?;
+    for branch in 0..branches.len() {
+        write!(code, ", DifferentialBranch{branch}")?;
+    }
+    writeln!(code, ">;")?;
+    for declaration in &declarations.libfunc_declarations {
+        writeln!(code, "{declaration};")?;
+    }
+    writeln!(code, "libfunc differential_branch_align = branch_align;")?;
+    writeln!(code, "libfunc differential_store = store_temp<DifferentialResult>;")?;
+    for branch in 0..branches.len() {
+        writeln!(
+            code,
+            "libfunc differential_construct{branch} = \
+             struct_construct<DifferentialBranch{branch}>;"
+        )?;
+        writeln!(
+            code,
+            "libfunc differential_init{branch} = enum_init<DifferentialResult, {branch}>;"
+        )?;
+    }
+    let params = libfunc.param_signatures();
+    let mut next_var = params.len();
+    let mut alloc_vars = |count: usize| {
+        let vars = (next_var..next_var + count).map(|var| format!("[{var}]")).join(", ");
+        next_var += count;
+        vars
+    };
+    let branch_outputs = branches.iter().map(|branch| alloc_vars(branch.vars.len())).collect_vec();
+    let targets = branch_outputs
+        .iter()
+        .enumerate()
+        .map(|(branch, outputs)| {
+            if Some(branch) == fallthrough {
+                format!("fallthrough({outputs})")
+            } else {
+                format!("{}({outputs})", branch_start(branch))
+            }
+        })
+        .join(" ");
+    let args = (0..params.len()).map(|var| format!("[{var}]")).join(", ");
+    writeln!(code, "{TESTED_LIBFUNC}({args}) {{ {targets} }};")?;
+    for branch in branch_order {
+        let outputs = &branch_outputs[branch];
+        let [structure, enm] = [alloc_vars(1), alloc_vars(1)];
+        writeln!(code, "differential_branch_align() -> ();")?;
+        writeln!(code, "differential_construct{branch}({outputs}) -> ({structure});")?;
+        writeln!(code, "differential_init{branch}({structure}) -> ({enm});")?;
+        writeln!(code, "differential_store({enm}) -> ({enm});")?;
+        writeln!(code, "return({enm});")?;
+    }
+    let params =
+        params.iter().enumerate().map(|(var, param)| format!("[{var}]: {}", param.ty)).join(", ");
+    writeln!(code, "{WRAPPER_FUNCTION}@0({params}) -> (DifferentialResult);")?;
+    Ok(code)
+}
+
+/// An input of the tested libfunc.
+struct Input {
+    /// The logical value of the input, used by the simulation.
+    value: CoreValue,
+    /// The arguments of the wrapper function representing the input on the vm.
+    args: Vec<Arg>,
+}
+
+/// The outcome of running the wrapper function.
+struct Outcome {
+    /// The index of the taken branch.
+    branch: usize,
+    /// The outputs of the taken branch.
+    outputs: Vec<CoreValue>,
+}
+
+/// Helper for running a single libfunc both by the simulation and on the vm.
+struct LibfuncTester {
+    runner: SierraCasmRunner,
+    /// The display name of the tested libfunc.
+    libfunc_name: String,
+    /// The gas variables of the wrapper program, as expected by the simulation.
+    statement_gas_info: HashMap<(StatementIdx, CostTokenType), i64>,
+}
+impl LibfuncTester {
+    fn new(declarations: &str) -> Result<Self, DifferentialError> {
+        let declarations_program = ProgramParser::new()
+            .parse(declarations)
+            .map_err(|err| DifferentialError::ParseError(err.to_string()))?;
+        let libfunc_name = declarations_program
+            .libfunc_declarations
+            .iter()
+            .find(|declaration| declaration.id == TESTED_LIBFUNC.into())
+            .ok_or(DifferentialError::MissingTestedLibfunc)?
+            .long_id
+            .to_string();
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&declarations_program)?;
+        let libfunc = registry.get_libfunc(&TESTED_LIBFUNC.into())?;
+        let code = wrapper_program_code(&declarations_program, libfunc).unwrap();
+        let program = ProgramParser::new()
+            .parse(&code)
+            .map_err(|err| DifferentialError::ParseError(err.to_string()))?;
+        let runner = SierraCasmRunner::new(program, Some(MetadataComputationConfig::default()))?;
+        let statement_gas_info = runner
+            .metadata
+            .gas_info
+            .variable_values
+            .iter()
+            .map(|(key, value)| (*key, *value))
+            .collect();
+        Ok(Self { runner, libfunc_name, statement_gas_info })
+    }
+
+    /// Returns the tested libfunc.
+    fn libfunc(&self) -> &CoreConcreteLibfunc {
+        self.runner.sierra_program_registry.get_libfunc(&TESTED_LIBFUNC.into()).unwrap()
+    }
+
+    /// Returns the concrete type of the given type id.
+    fn get_type(&self, ty: &ConcreteTypeId) -> &CoreTypeConcrete {
+        self.runner.sierra_program_registry.get_type(ty).unwrap()
+    }
+
+    /// Generates random inputs for the tested libfunc.
+    fn random_inputs(&self, rng: &mut StdRng) -> Result<Vec<Input>, DifferentialError> {
+        self.libfunc()
+            .param_signatures()
+            .iter()
+            .map(|ParamSignature { ty, .. }| self.random_input(rng, ty))
+            .collect()
+    }
+
+    /// Generates a random input of the given type.
+    fn random_input(
+        &self,
+        rng: &mut StdRng,
+        ty: &ConcreteTypeId,
+    ) -> Result<Input, DifferentialError> {
+        let felt_input = |value: BigUint| Input {
+            args: vec![Arg::Value(Felt252::from(value.clone()))],
+            value: CoreValue::Felt252(value.into()),
+        };
+        let uint_input = |value: u128, wrap: fn(u128) -> CoreValue| Input {
+            args: vec![Arg::Value(Felt252::from(value))],
+            value: wrap(value),
+        };
+        let builtin_input = |value: CoreValue| Input { value, args: vec![] };
+        Ok(match self.get_type(ty) {
+            CoreTypeConcrete::Felt252(_) => felt_input(random_below(rng, &Felt252::prime())),
+            CoreTypeConcrete::Uint8(_) => {
+                uint_input(random_uint(rng, 8), |value| CoreValue::Uint8(value as u8))
+            }
+            CoreTypeConcrete::Uint16(_) => {
+                uint_input(random_uint(rng, 16), |value| CoreValue::Uint16(value as u16))
+            }
+            CoreTypeConcrete::Uint32(_) => {
+                uint_input(random_uint(rng, 32), |value| CoreValue::Uint32(value as u32))
+            }
+            CoreTypeConcrete::Uint64(_) => {
+                uint_input(random_uint(rng, 64), |value| CoreValue::Uint64(value as u64))
+            }
+            CoreTypeConcrete::Uint128(_) => uint_input(random_uint(rng, 128), CoreValue::Uint128),
+            CoreTypeConcrete::RangeCheck(_) => builtin_input(CoreValue::RangeCheck),
+            CoreTypeConcrete::Bitwise(_) => builtin_input(CoreValue::Bitwise),
+            CoreTypeConcrete::EcOp(_) => builtin_input(CoreValue::EcOp),
+            CoreTypeConcrete::Pedersen(_) => builtin_input(CoreValue::Pedersen),
+            CoreTypeConcrete::Poseidon(_) => builtin_input(CoreValue::Poseidon),
+            CoreTypeConcrete::SegmentArena(_) => builtin_input(CoreValue::SegmentArena),
+            CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::System(_)) => {
+                builtin_input(CoreValue::System)
+            }
+            CoreTypeConcrete::StarkNet(
+                StarkNetTypeConcrete::ClassHash(_)
+                | StarkNetTypeConcrete::ContractAddress(_)
+                | StarkNetTypeConcrete::StorageBaseAddress(_)
+                | StarkNetTypeConcrete::StorageAddress(_),
+            ) => felt_input(random_below(rng, &(BigUint::one() << ADDRESS_BITS))),
+            // The tester can't allocate boxed values, so nullable inputs are always null.
+            CoreTypeConcrete::Nullable(_) => {
+                Input { value: CoreValue::Nullable(None), args: vec![Arg::Value(Felt252::zero())] }
+            }
+            CoreTypeConcrete::GasBuiltin(_) => {
+                builtin_input(CoreValue::GasBuiltin(rng.gen_range(0..MAX_GAS) as i64))
+            }
+            CoreTypeConcrete::NonZero(info) => loop {
+                let input = self.random_input(rng, &info.ty)?;
+                if input.args.iter().any(|arg| !matches!(arg, Arg::Value(value) if value.is_zero()))
+                {
+                    break Input { value: CoreValue::NonZero(Box::new(input.value)), ..input };
+                }
+            },
+            CoreTypeConcrete::Snapshot(info) => self.random_input(rng, &info.ty)?,
+            CoreTypeConcrete::Struct(info) => {
+                let members: Vec<_> = info
+                    .members
+                    .iter()
+                    .map(|member| self.random_input(rng, member))
+                    .collect::<Result<_, _>>()?;
+                Input {
+                    args: members.iter().flat_map(|member| member.args.clone()).collect(),
+                    value: CoreValue::Struct(members.into_iter().map(|m| m.value).collect()),
+                }
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let index = rng.gen_range(0..info.variants.len());
+                let variant = &info.variants[index];
+                let inner = self.random_input(rng, variant)?;
+                let padding = info.info.size - 1 - self.get_type(variant).info().size;
+                Input {
+                    args: [Arg::Value(enum_selector(index, info.variants.len()))]
+                        .into_iter()
+                        .chain((0..padding).map(|_| Arg::Value(Felt252::zero())))
+                        .chain(inner.args)
+                        .collect(),
+                    value: CoreValue::Enum { value: Box::new(inner.value), index },
+                }
+            }
+            CoreTypeConcrete::Array(info) => {
+                let len = rng.gen_range(0..=MAX_ARRAY_LEN);
+                let (values, cells) = (0..len)
+                    .map(|_| {
+                        let element = self.random_input(rng, &info.ty)?;
+                        match <[Arg; 1]>::try_from(element.args) {
+                            Ok([Arg::Value(cell)]) => Ok((element.value, cell)),
+                            _ => Err(DifferentialError::UnsupportedType(ty.clone())),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .unzip();
+                Input { value: CoreValue::Array(values), args: vec![Arg::Array(cells)] }
+            }
+            _ => return Err(DifferentialError::UnsupportedType(ty.clone())),
+        })
+    }
+
+    /// Runs the wrapper function on the given inputs, both by the simulation and on the vm, and
+    /// returns the first mismatch found between the runs, if any.
+    fn check(&self, inputs: &[Input]) -> Result<Option<Mismatch>, DifferentialError> {
+        let func = self.runner.find_function(WRAPPER_FUNCTION)?;
+        let simulated = simulation::run_with_state(
+            &self.runner.sierra_program,
+            &self.statement_gas_info,
+            &func.id,
+            inputs.iter().map(|input| input.value.clone()).collect(),
+            &mut StarknetState::default(),
+        );
+        let initial_gas = inputs.iter().find_map(|input| match input.value {
+            CoreValue::GasBuiltin(gas) => Some(gas as usize),
+            _ => None,
+        });
+        let args = inputs.iter().flat_map(|input| input.args.clone()).collect_vec();
+        let available_gas = initial_gas.map(|gas| gas + self.runner.get_required_gas(func));
+        let run_result =
+            self.runner.run_function(WRAPPER_FUNCTION, &args, available_gas, Some(MAX_STEPS));
+        // The wrapper function returns the outcome of any branch, so it never panics.
+        if let Ok(RunResult { value: RunResultValue::Panic(payload), .. }) = &run_result {
+            return Ok(Some(Mismatch::CasmPanicked(
+                payload.iter().map(|felt| felt.to_bigint()).collect(),
+            )));
+        }
+        let (simulated, run_result) = match (simulated, run_result) {
+            (Ok(simulated), Ok(run_result)) => (simulated, run_result),
+            // Invalid inputs are rejected by a branch of the libfunc rather than by an error, so
+            // failures of the runs are mismatches even when both fail.
+            (Err(simulated), Err(casm)) => {
+                return Ok(Some(Mismatch::BothFailed {
+                    simulated: simulated.to_string(),
+                    casm: casm.to_string(),
+                }));
+            }
+            (Err(err), Ok(_)) => return Ok(Some(Mismatch::SimulationFailed(err.to_string()))),
+            (Ok(_), Err(err)) => return Ok(Some(Mismatch::CasmFailed(err.to_string()))),
+        };
+        let mut simulated = self.simulated_outcome(simulated);
+        let casm = self.casm_outcome(&run_result)?;
+        if simulated.branch != casm.branch {
+            return Ok(Some(Mismatch::Branch { simulated: simulated.branch, casm: casm.branch }));
+        }
+        let branch_vars = &self.libfunc().branch_signatures()[simulated.branch].vars;
+        for (output, var) in simulated.outputs.iter_mut().zip(branch_vars) {
+            if matches!(
+                self.get_type(&var.ty),
+                CoreTypeConcrete::Felt252Dict(_)
+                    | CoreTypeConcrete::SquashedFelt252Dict(_)
+                    | CoreTypeConcrete::EcState(_)
+            ) {
+                *output = CoreValue::Uninitialized;
+            }
+        }
+        if simulated.outputs != casm.outputs {
+            return Ok(Some(Mismatch::Outputs {
+                simulated: simulated.outputs,
+                casm: casm.outputs,
+            }));
+        }
+        let branch = casm.branch;
+        let (steps, ap_change) = self.libfunc_execution(&run_result);
+        let info_provider = InvocationInfoProvider { tester: self };
+        let charged_cost = core_libfunc_cost(
+            &self.runner.metadata.gas_info,
+            &TESTED_STATEMENT,
+            self.libfunc(),
+            &info_provider,
+        )
+        .swap_remove(branch)
+        .map(|cost| {
+            cost.iter().map(|(token_type, count)| count * token_gas_cost(*token_type)).sum::<i64>()
+        });
+        if let Some(charged_cost) = charged_cost {
+            // Gas withdrawn from the gas counter is charged as well.
+            let final_gas = casm.outputs.iter().find_map(|output| match output {
+                CoreValue::GasBuiltin(gas) => Some(*gas),
+                _ => None,
+            });
+            let withdrawn =
+                initial_gas.zip(final_gas).map_or(0, |(initial, last)| initial as i64 - last);
+            let charged = charged_cost + withdrawn;
+            let used = used_gas(&run_result, steps);
+            // Only undercharging is a mismatch. A branch is charged for its longest execution path,
+            // and some libfuncs are deliberately charged for costs the vm run does not include,
+            // such as the steps of the OS executing a system call, or the proving cost of the hint
+            // of `felt252_div`.
+            if charged < used {
+                return Ok(Some(Mismatch::Gas { branch, charged, used }));
+            }
+        }
+        if let ApChange::Known(expected) =
+            core_libfunc_ap_change(self.libfunc(), &info_provider).swap_remove(branch)
+        {
+            if expected != ap_change {
+                return Ok(Some(Mismatch::ApChange { branch, expected, actual: ap_change }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Extracts the outcome of the simulation of the wrapper function.
+    fn simulated_outcome(&self, outputs: Vec<CoreValue>) -> Outcome {
+        let Ok([CoreValue::Enum { value, index }]) = <[CoreValue; 1]>::try_from(outputs) else {
+            panic!("The wrapper function returns a single enum.");
+        };
+        let CoreValue::Struct(outputs) = *value else {
+            panic!("The wrapper function returns the branch outputs in a struct.");
+        };
+        Outcome { branch: index, outputs }
+    }
+
+    /// Extracts the outcome of the vm run of the wrapper function.
+    fn casm_outcome(&self, run_result: &RunResult) -> Result<Outcome, DifferentialError> {
+        let RunResultValue::Success(values) = &run_result.value else {
+            panic!("Panics of the wrapper function are reported before its outcome is extracted.");
+        };
+        let func = self.runner.find_function(WRAPPER_FUNCTION)?;
+        let result = self.decode_value(&func.signature.ret_types[0], values, &run_result.memory)?;
+        let CoreValue::Enum { value, index } = result else {
+            panic!("The wrapper function returns an enum.");
+        };
+        let CoreValue::Struct(outputs) = *value else {
+            panic!("The wrapper function returns the branch outputs in a struct.");
+        };
+        Ok(Outcome { branch: index, outputs })
+    }
+
+    /// Decodes a value of the given type from its cells in memory.
+    fn decode_value(
+        &self,
+        ty: &ConcreteTypeId,
+        cells: &[Felt252],
+        memory: &[Option<Felt252>],
+    ) -> Result<CoreValue, DifferentialError> {
+        let unsupported = || DifferentialError::UnsupportedType(ty.clone());
+        let cell = || cells[0].to_biguint();
+        let read_memory = |start: usize, size: usize| {
+            memory[start..start + size].iter().cloned().collect::<Option<Vec<_>>>()
+        };
+        Ok(match self.get_type(ty) {
+            CoreTypeConcrete::Felt252(_) => CoreValue::Felt252(cell().into()),
+            CoreTypeConcrete::Uint8(_) => CoreValue::Uint8(cell().to_u8().ok_or_else(unsupported)?),
+            CoreTypeConcrete::Uint16(_) => {
+                CoreValue::Uint16(cell().to_u16().ok_or_else(unsupported)?)
+            }
+            CoreTypeConcrete::Uint32(_) => {
+                CoreValue::Uint32(cell().to_u32().ok_or_else(unsupported)?)
+            }
+            CoreTypeConcrete::Uint64(_) => {
+                CoreValue::Uint64(cell().to_u64().ok_or_else(unsupported)?)
+            }
+            CoreTypeConcrete::Uint128(_) => {
+                CoreValue::Uint128(cell().to_u128().ok_or_else(unsupported)?)
+            }
+            CoreTypeConcrete::GasBuiltin(_) => {
+                CoreValue::GasBuiltin(cell().to_i64().ok_or_else(unsupported)?)
+            }
+            CoreTypeConcrete::RangeCheck(_) => CoreValue::RangeCheck,
+            CoreTypeConcrete::Bitwise(_) => CoreValue::Bitwise,
+            CoreTypeConcrete::EcOp(_) => CoreValue::EcOp,
+            CoreTypeConcrete::Pedersen(_) => CoreValue::Pedersen,
+            CoreTypeConcrete::Poseidon(_) => CoreValue::Poseidon,
+            CoreTypeConcrete::SegmentArena(_) => CoreValue::SegmentArena,
+            CoreTypeConcrete::StarkNet(StarkNetTypeConcrete::System(_)) => CoreValue::System,
+            CoreTypeConcrete::StarkNet(_) => CoreValue::Felt252(cell().into()),
+            CoreTypeConcrete::EcPoint(_) => {
+                CoreValue::EcPoint(cell().into(), cells[1].to_biguint().into())
+            }
+            CoreTypeConcrete::NonZero(info) => {
+                CoreValue::NonZero(Box::new(self.decode_value(&info.ty, cells, memory)?))
+            }
+            // The values of dicts and EC states can't be read back from the memory, so they are
+            // masked in the simulated outputs as well.
+            CoreTypeConcrete::Felt252Dict(_)
+            | CoreTypeConcrete::SquashedFelt252Dict(_)
+            | CoreTypeConcrete::EcState(_) => CoreValue::Uninitialized,
+            CoreTypeConcrete::Nullable(info) => {
+                let start = cell().to_usize().ok_or_else(unsupported)?;
+                CoreValue::Nullable(if start == 0 {
+                    None
+                } else {
+                    let size = self.get_type(&info.ty).info().size as usize;
+                    let inner_cells = read_memory(start, size).ok_or_else(unsupported)?;
+                    Some(Box::new(self.decode_value(&info.ty, &inner_cells, memory)?))
+                })
+            }
+            CoreTypeConcrete::Snapshot(info) => self.decode_value(&info.ty, cells, memory)?,
+            CoreTypeConcrete::Box(info) => {
+                let size = self.get_type(&info.ty).info().size as usize;
+                let start = cell().to_usize().ok_or_else(unsupported)?;
+                let inner_cells = read_memory(start, size).ok_or_else(unsupported)?;
+                self.decode_value(&info.ty, &inner_cells, memory)?
+            }
+            CoreTypeConcrete::Array(info) => {
+                let size = self.get_type(&info.ty).info().size as usize;
+                let start = cell().to_usize().ok_or_else(unsupported)?;
+                let end = cells[1].to_biguint().to_usize().ok_or_else(unsupported)?;
+                let element_cells = read_memory(start, end - start).ok_or_else(unsupported)?;
+                CoreValue::Array(
+                    element_cells
+                        .chunks(size)
+                        .map(|element| self.decode_value(&info.ty, element, memory))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            CoreTypeConcrete::Struct(info) => {
+                let mut offset = 0;
+                let mut members = vec![];
+                for member in &info.members {
+                    let size = self.get_type(member).info().size as usize;
+                    members.push(self.decode_value(
+                        member,
+                        &cells[offset..offset + size],
+                        memory,
+                    )?);
+                    offset += size;
+                }
+                CoreValue::Struct(members)
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let index = enum_index(&cells[0], info.variants.len()).ok_or_else(unsupported)?;
+                let variant = &info.variants[index];
+                let size = self.get_type(variant).info().size as usize;
+                let value = self.decode_value(variant, &cells[cells.len() - size..], memory)?;
+                CoreValue::Enum { value: Box::new(value), index }
+            }
+            _ => return Err(unsupported()),
+        })
+    }
+
+    /// Returns the number of steps of the execution of the tested libfunc on the vm, and the
+    /// change of `ap` it made.
+    fn libfunc_execution(&self, run_result: &RunResult) -> (usize, usize) {
+        let statements_info = &self.runner.casm_program.debug_info.sierra_statement_info;
+        let code_start = statements_info[TESTED_STATEMENT.0].code_offset;
+        let code_end = statements_info[TESTED_STATEMENT.0 + 1].code_offset;
+        // The code segment is relocated to start at address 1.
+        let code_offset = |pc: usize| pc.checked_sub(1 + run_result.program_offset);
+        let trace = &run_result.trace;
+        let start = trace
+            .iter()
+            .position(|entry| code_offset(entry.pc) == Some(code_start))
+            .expect("The wrapper function was not called.");
+        let end = start
+            + trace[start..]
+                .iter()
+                .position(
+                    |entry| !matches!(code_offset(entry.pc), Some(offset) if offset < code_end),
+                )
+                .expect("The wrapper function did not return.");
+        (end - start, trace[end].ap - trace[start].ap)
+    }
+}
+
+/// Provides the information about the invocation of the tested libfunc, required for computing
+/// its costs.
+struct InvocationInfoProvider<'a> {
+    tester: &'a LibfuncTester,
+}
+impl InvocationInfoProvider<'_> {
+    fn metadata(&self) -> &Metadata {
+        &self.tester.runner.metadata
+    }
+}
+impl InvocationCostInfoProvider for InvocationInfoProvider<'_> {
+    fn type_size(&self, ty: &ConcreteTypeId) -> usize {
+        self.tester.get_type(ty).info().size as usize
+    }
+
+    fn token_usages(&self, token_type: CostTokenType) -> usize {
+        InvocationApChangeInfoProvider::token_usages(self, token_type)
+    }
+
+    fn ap_change_var_value(&self) -> usize {
+        self.metadata()
+            .ap_change_info
+            .variable_values
+            .get(&TESTED_STATEMENT)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+impl InvocationApChangeInfoProvider for InvocationInfoProvider<'_> {
+    fn type_size(&self, ty: &ConcreteTypeId) -> usize {
+        InvocationCostInfoProvider::type_size(self, ty)
+    }
+
+    fn token_usages(&self, token_type: CostTokenType) -> usize {
+        self.metadata()
+            .gas_info
+            .variable_values
+            .get(&(TESTED_STATEMENT, token_type))
+            .copied()
+            .unwrap_or_default() as usize
+    }
+}
+
+/// Returns the gas used by the vm run of the wrapper function, given the number of steps of the
+/// tested libfunc. All the builtins of the run are used by the tested libfunc.
+fn used_gas(run_result: &RunResult, steps: usize) -> i64 {
+    let builtin_usages = |name: &str| {
+        run_result.used_resources.builtin_instance_counter.get(name).copied().unwrap_or_default()
+            as i64
+    };
+    // The holes left by the libfunc are not counted, which can only hide undercharging.
+    let const_cost = ConstCost {
+        steps: steps as i32,
+        holes: 0,
+        range_checks: builtin_usages(RANGE_CHECK_BUILTIN_NAME) as i32,
+    };
+    [
+        (CostTokenType::Pedersen, HASH_BUILTIN_NAME),
+        (CostTokenType::Poseidon, POSEIDON_BUILTIN_NAME),
+        (CostTokenType::Bitwise, BITWISE_BUILTIN_NAME),
+        (CostTokenType::EcOp, EC_OP_BUILTIN_NAME),
+    ]
+    .into_iter()
+    .map(|(token_type, name)| builtin_usages(name) * token_gas_cost(token_type))
+    .sum::<i64>()
+        + const_cost.cost() as i64
+}
+
+/// Returns the gas cost of a single token of the given type, as set by the runner.
+fn token_gas_cost(token_type: CostTokenType) -> i64 {
+    match token_type {
+        CostTokenType::Const => 1,
+        _ => DUMMY_BUILTIN_GAS_COST as i64,
+    }
+}
+
+/// Returns the variant selector of the variant in the given index of an enum.
+fn enum_selector(index: usize, num_variants: usize) -> Felt252 {
+    // Enums with more than two variants use the relative jump to the variant's match arm as the
+    // selector.
+    Felt252::from(if num_variants <= 2 { index } else { 2 * (num_variants - index) - 1 })
+}
+
+/// Returns the index of the variant of an enum with the given variant selector.
+fn enum_index(selector: &Felt252, num_variants: usize) -> Option<usize> {
+    (0..num_variants).find(|index| enum_selector(*index, num_variants) == *selector)
+}
+
+/// Generates a random unsigned integer of the given number of bits, favoring edge cases.
+fn random_uint(rng: &mut StdRng, bits: usize) -> u128 {
+    random_below(rng, &(BigUint::one() << bits)).to_u128().unwrap()
+}
+
+/// Generates a random value in `[0, bound)`, favoring edge cases.
+fn random_below(rng: &mut StdRng, bound: &BigUint) -> BigUint {
+    if rng.gen_bool(EDGE_CASE_PROBABILITY) {
+        let max = bound - 1u32;
+        let mut edge_cases = vec![BigUint::zero(), BigUint::one(), &max - 1u32, max];
+        return edge_cases.swap_remove(rng.gen_range(0..edge_cases.len()));
+    }
+    let n_bytes = bound.to_bytes_le().len();
+    let bytes: Vec<u8> = (0..n_bytes).map(|_| rng.gen()).collect();
+    BigUint::from_bytes_le(&bytes) % bound
+}
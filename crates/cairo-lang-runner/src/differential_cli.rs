@@ -0,0 +1,55 @@
+//! Checks the CASM lowering of libfuncs against their Sierra semantics.
+
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_runner::differential::{
+    test_libfunc, DifferentialConfig, CORE_LIBFUNC_DECLARATIONS,
+};
+use clap::Parser;
+
+/// Command line args parser.
+/// Exits with 0/1 if no mismatches/some mismatches were found.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// Files with Sierra declarations of the libfunc to check, declared as `tested`, and the types
+    /// it uses. If none are given, a default set of core libfuncs is checked.
+    paths: Vec<String>,
+    /// The number of random inputs each libfunc is run with.
+    #[arg(long, default_value_t = 100)]
+    runs: usize,
+    /// The seed for generating the inputs. Random if not provided.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let config =
+        DifferentialConfig { runs: args.runs, seed: args.seed.unwrap_or_else(rand::random) };
+    println!("Running with seed {}.", config.seed);
+
+    let declarations = if args.paths.is_empty() {
+        CORE_LIBFUNC_DECLARATIONS.iter().map(|declarations| declarations.to_string()).collect()
+    } else {
+        args.paths
+            .iter()
+            .map(|path| fs::read_to_string(path).with_context(|| format!("Failed to read {path}.")))
+            .collect::<anyhow::Result<Vec<_>>>()?
+    };
+    let mut found_mismatches = false;
+    for declarations in &declarations {
+        let reports = test_libfunc(declarations, config)
+            .with_context(|| format!("Failed testing libfunc declared by `{declarations}`."))?;
+        for report in &reports {
+            println!("{report}");
+        }
+        found_mismatches |= !reports.is_empty();
+    }
+    if found_mismatches {
+        std::process::exit(1);
+    }
+    println!("No mismatches found.");
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::{
+    libfunc_family, test_libfunc, wrapper_program_code, DifferentialConfig, DifferentialError,
+    CORE_LIBFUNC_DECLARATIONS, CORE_LIBFUNC_FAMILIES, TESTED_LIBFUNC, UNTESTED_LIBFUNC_FAMILIES,
+};
+
+#[test]
+fn core_libfuncs_match_simulation() {
+    let config = DifferentialConfig { runs: 30, seed: 0 };
+    let failures = CORE_LIBFUNC_DECLARATIONS
+        .iter()
+        .flat_map(|declarations| match test_libfunc(declarations, config) {
+            Ok(reports) => reports.iter().map(|report| report.to_string()).collect_vec(),
+            Err(err) => vec![format!("{declarations}: {err}")],
+        })
+        .collect_vec();
+    assert_eq!(failures, Vec::<String>::new());
+}
+
+#[test]
+fn core_libfunc_declarations_cover_all_families() {
+    let tested_families: HashSet<_> = CORE_LIBFUNC_DECLARATIONS
+        .iter()
+        .map(|declarations| {
+            let program = ProgramParser::new().parse(declarations).unwrap();
+            let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+            libfunc_family(registry.get_libfunc(&TESTED_LIBFUNC.into()).unwrap())
+        })
+        .chain(UNTESTED_LIBFUNC_FAMILIES.iter().map(|(family, _)| *family))
+        .collect();
+    let untested_families = CORE_LIBFUNC_FAMILIES
+        .iter()
+        .filter(|family| !tested_families.contains(*family))
+        .collect_vec();
+    assert_eq!(untested_families, Vec::<&&str>::new());
+}
+
+#[test]
+fn wrapper_program() {
+    let declarations = "type RangeCheck = RangeCheck; type u8 = u8; libfunc tested = u8_lt;";
+    let program = ProgramParser::new().parse(declarations).unwrap();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+    let libfunc = registry.get_libfunc(&TESTED_LIBFUNC.into()).unwrap();
+    assert_eq!(
+        wrapper_program_code(&program, libfunc).unwrap(),
+        indoc! {"
+            type RangeCheck = RangeCheck;
+            type u8 = u8;
+            type DifferentialBranch0 = Struct<ut@DifferentialBranch0, RangeCheck>;
+            type DifferentialBranch1 = Struct<ut@DifferentialBranch1, RangeCheck>;
+            type DifferentialResult = Enum<ut@DifferentialResult, DifferentialBranch0, DifferentialBranch1>;
+            libfunc tested = u8_lt;
+            libfunc differential_branch_align = branch_align;
+            libfunc differential_store = store_temp<DifferentialResult>;
+            libfunc differential_construct0 = struct_construct<DifferentialBranch0>;
+            libfunc differential_init0 = enum_init<DifferentialResult, 0>;
+            libfunc differential_construct1 = struct_construct<DifferentialBranch1>;
+            libfunc differential_init1 = enum_init<DifferentialResult, 1>;
+            tested([0], [1], [2]) { fallthrough([3]) 6([4]) };
+            differential_branch_align() -> ();
+            differential_construct0([3]) -> ([5]);
+            differential_init0([5]) -> ([6]);
+            differential_store([6]) -> ([6]);
+            return([6]);
+            differential_branch_align() -> ();
+            differential_construct1([4]) -> ([7]);
+            differential_init1([7]) -> ([8]);
+            differential_store([8]) -> ([8]);
+            return([8]);
+            differential_wrapper@0([0]: RangeCheck, [1]: u8, [2]: u8) -> (DifferentialResult);
+        "}
+    );
+}
+
+#[test]
+fn missing_tested_libfunc() {
+    let config = DifferentialConfig { runs: 1, seed: 0 };
+    assert!(matches!(
+        test_libfunc("type felt252 = felt252; libfunc other = felt252_add;", config),
+        Err(DifferentialError::MissingTestedLibfunc)
+    ));
+}
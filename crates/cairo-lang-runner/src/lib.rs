@@ -1,5 +1,6 @@
 //! Basic runner for running a Sierra program on the vm.
 use std::collections::HashMap;
+use std::io::Write;
 
 use cairo_felt::Felt252;
 use cairo_lang_casm::instructions::Instruction;
@@ -26,11 +27,18 @@ use cairo_lang_sierra_to_casm::metadata::{
 use cairo_lang_utils::extract_matches;
 use cairo_vm::serde::deserialize_program::BuiltinName;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use cairo_vm::vm::trace::trace_entry::TraceEntry;
+use casm_run::RunFunctionResult;
 use itertools::chain;
 use num_traits::ToPrimitive;
 use thiserror::Error;
 
+pub mod args;
 mod casm_run;
+pub mod debugger;
+pub mod differential;
+pub mod profiler;
 pub mod short_string;
 
 #[derive(Debug, Error)]
@@ -47,6 +55,10 @@ pub enum RunnerError {
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("Run did not end within the limit of {max_steps} steps.")]
+    StepLimitExceeded { max_steps: usize },
+    #[error("Arguments are too large to be passed to the function.")]
+    ArgumentsTooLarge,
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
@@ -57,11 +69,65 @@ pub enum RunnerError {
     VirtualMachineError(#[from] Box<VirtualMachineError>),
 }
 
+/// An argument to a sierra function run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Arg {
+    /// A single felt252 value.
+    Value(Felt252),
+    /// An array of felt252 values, passed to the function as a `(start, end)` pointer pair.
+    Array(Vec<Felt252>),
+}
+impl Arg {
+    /// The number of memory cells the argument takes as a function parameter.
+    pub fn size(&self) -> usize {
+        match self {
+            Arg::Value(_) => 1,
+            Arg::Array(_) => 2,
+        }
+    }
+}
+impl From<Felt252> for Arg {
+    fn from(value: Felt252) -> Self {
+        Arg::Value(value)
+    }
+}
+
 /// The full result of a run.
 pub struct RunResult {
     pub gas_counter: Option<Felt252>,
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
+    /// The relocated execution trace of the run.
+    pub trace: Vec<TraceEntry>,
+    /// The offset of the code of the Sierra program in the executed code, following the entry
+    /// code.
+    pub program_offset: usize,
+    /// The resources used by the run.
+    pub used_resources: ExecutionResources,
+}
+impl RunResult {
+    /// Writes the execution trace in the binary format used by provers, where each entry is
+    /// encoded as its `ap`, `fp` and `pc` values, each as 8 bytes in little endian.
+    pub fn write_encoded_trace(&self, dest: &mut impl Write) -> std::io::Result<()> {
+        for entry in &self.trace {
+            for value in [entry.ap, entry.fp, entry.pc] {
+                dest.write_all(&(value as u64).to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the memory in the binary format used by provers, where each written cell is encoded
+    /// as its address as 8 bytes in little endian, followed by its value as 32 bytes in little
+    /// endian.
+    pub fn write_encoded_memory(&self, dest: &mut impl Write) -> std::io::Result<()> {
+        for (address, cell) in self.memory.iter().enumerate() {
+            let Some(value) = cell else { continue; };
+            dest.write_all(&(address as u64).to_le_bytes())?;
+            dest.write_all(&value.to_le_bytes())?;
+        }
+        Ok(())
+    }
 }
 
 /// The ran function return value.
@@ -86,6 +152,8 @@ pub struct SierraCasmRunner {
     sierra_program_registry: ProgramRegistry<CoreType, CoreLibfunc>,
     /// The casm program matching the Sierra code.
     casm_program: CairoProgram,
+    /// Whether the gas usage of the program was checked when compiling it.
+    gas_usage_check: bool,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -101,24 +169,72 @@ impl SierraCasmRunner {
             &metadata,
             gas_usage_check,
         )?;
-        Ok(Self { sierra_program, metadata, sierra_program_registry, casm_program })
+        Ok(Self {
+            sierra_program,
+            metadata,
+            sierra_program_registry,
+            casm_program,
+            gas_usage_check,
+        })
+    }
+
+    /// Returns the number of times each Sierra statement was executed during the given run.
+    /// A statement is considered executed whenever the first instruction of its code is executed.
+    /// Statements compiled into no code at all can't be tracked, and are `None`.
+    pub fn sierra_statements_hits(&self, run_result: &RunResult) -> Vec<Option<usize>> {
+        let mut pc_hits = HashMap::<usize, usize>::new();
+        for entry in &run_result.trace {
+            // The code segment is relocated to start at address 1.
+            if let Some(offset) = entry.pc.checked_sub(1 + run_result.program_offset) {
+                *pc_hits.entry(offset).or_default() += 1;
+            }
+        }
+        self.sierra_statements_code_ranges()
+            .map(|(start, end)| {
+                (end > start).then(|| pc_hits.get(&start).copied().unwrap_or_default())
+            })
+            .collect()
+    }
+
+    /// Returns the number of times each Sierra statement was executed before any run - zero for
+    /// statements that can be tracked, and `None` for statements compiled into no code at all.
+    pub fn empty_sierra_statements_hits(&self) -> Vec<Option<usize>> {
+        self.sierra_statements_code_ranges()
+            .map(|(start, end)| (end > start).then_some(0))
+            .collect()
+    }
+
+    /// Returns the start and end offsets of the code of each Sierra statement.
+    fn sierra_statements_code_ranges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let statements_info = &self.casm_program.debug_info.sierra_statement_info;
+        statements_info.iter().enumerate().map(|(i, info)| {
+            let code_end = statements_info.get(i + 1).map_or_else(
+                || self.casm_program.instructions.iter().map(|inst| inst.body.op_size()).sum(),
+                |next_info| next_info.code_offset,
+            );
+            (info.code_offset, code_end)
+        })
     }
 
     /// Runs the vm starting from a function. Function may have implicits, but no other ref params.
     /// The cost of the function is deducted from available_gas before the execution begins.
+    /// The run fails if it does not end within `max_steps` steps, if given.
     pub fn run_function(
         &self,
         name_suffix: &str,
-        args: &[Felt252],
+        args: &[Arg],
         available_gas: Option<usize>,
+        max_steps: Option<usize>,
     ) -> Result<RunResult, RunnerError> {
         let func = self.find_function(name_suffix)?;
         let initial_gas = self.get_initial_available_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
         let footer = self.create_code_footer();
-        let (cells, ap) = casm_run::run_function(
+        let program_offset = entry_code.iter().map(|instruction| instruction.body.op_size()).sum();
+        let run_result = casm_run::run_function(
             chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter()),
             builtins,
+            max_steps,
             |context| {
                 let vm = context.vm;
                 // Create the builtin cost segment, with dummy values.
@@ -138,6 +254,7 @@ impl SierraCasmRunner {
                 Ok(())
             },
         )?;
+        let RunFunctionResult { memory: cells, ap, trace, used_resources } = run_result;
         let mut results_data = self.get_results_data(func, &cells, ap)?;
         // Handling implicits.
         let mut gas_counter = None;
@@ -166,7 +283,7 @@ impl SierraCasmRunner {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
             self.handle_main_return_value(ty, values, &cells)?
         };
-        Ok(RunResult { gas_counter, memory: cells, value })
+        Ok(RunResult { gas_counter, memory: cells, value, trace, program_offset, used_resources })
     }
 
     /// Handling the main return value to create a `RunResultValue`.
@@ -250,7 +367,7 @@ impl SierraCasmRunner {
     fn create_entry_code(
         &self,
         func: &Function,
-        args: &[Felt252],
+        args: &[Arg],
         initial_gas: usize,
     ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError> {
         let mut arg_iter = args.iter();
@@ -272,6 +389,29 @@ impl SierraCasmRunner {
             (EcOpType::ID, 4),
             (PoseidonType::ID, 3),
         ]);
+        // Allocating and filling the segments of the array arguments first, as the arguments
+        // themselves must be consecutive in memory. The entry code starts at `fp`, so the start of
+        // the `i`th array is kept at `[fp + array_start_offsets[i]]`.
+        let mut array_start_offsets = vec![];
+        let mut entry_cells = 0;
+        for arg in args {
+            let Arg::Array(values) = arg else { continue; };
+            let array_start_offset = to_offset(entry_cells)?;
+            casm_extend! {ctx,
+                %{ memory[ap + 0] = segments.add() %}
+                ap += 1;
+            }
+            for (i, value) in values.iter().enumerate() {
+                let index = to_offset(i)?;
+                casm_extend! {ctx,
+                    [ap + 0] = (value.to_bigint()), ap++;
+                    [ap - 1] = [[fp + array_start_offset] + index];
+                }
+            }
+            array_start_offsets.push(array_start_offset);
+            entry_cells += 1 + values.len();
+        }
+        let mut array_start_offsets = array_start_offsets.into_iter();
         if func
             .signature
             .param_types
@@ -308,26 +448,39 @@ impl SierraCasmRunner {
                     [ap + 0] = initial_gas, ap++;
                 }
             } else if generic_ty == &SegmentArenaType::ID {
-                let offset = -(i as i16) - 3;
+                let offset = -to_offset(i + 3)?;
                 casm_extend! {ctx,
                     [ap + 0] = [ap + offset] + 3, ap++;
                 }
             } else {
-                let arg_size = info.size;
-                expected_arguments_size += arg_size as usize;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.to_bigint()), ap++;
+                let arg_size = info.size as usize;
+                expected_arguments_size += arg_size;
+                let mut taken_size = 0;
+                while taken_size < arg_size {
+                    let Some(arg) = arg_iter.next() else { break; };
+                    taken_size += arg.size();
+                    match arg {
+                        Arg::Value(value) => {
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.to_bigint()), ap++;
+                            }
+                        }
+                        Arg::Array(values) => {
+                            let array_start_offset = array_start_offsets.next().unwrap();
+                            casm_extend! {ctx,
+                                [ap + 0] = [fp + array_start_offset], ap++;
+                                [ap + 0] = [ap - 1] + (values.len()), ap++;
+                            }
                         }
                     }
                 }
             }
         }
-        if expected_arguments_size != args.len() {
+        let actual_arguments_size = args.iter().map(Arg::size).sum();
+        if expected_arguments_size != actual_arguments_size {
             return Err(RunnerError::ArgumentsSizeMismatch {
                 expected: expected_arguments_size,
-                actual: args.len(),
+                actual: actual_arguments_size,
             });
         }
         let before_final_call = ctx.current_code_offset;
@@ -355,9 +508,14 @@ impl SierraCasmRunner {
             return Ok(0);
         }
         let Some(available_gas) = available_gas else { return Ok(0); };
+        available_gas
+            .checked_sub(self.get_required_gas(func))
+            .ok_or(RunnerError::NotEnoughGasToCall)
+    }
 
-        // Compute the initial gas required by the function.
-        let required_gas = self.metadata.gas_info.function_costs[func.id.clone()]
+    /// Returns the initial gas required by the function, deducted from the available gas.
+    fn get_required_gas(&self, func: &Function) -> usize {
+        self.metadata.gas_info.function_costs[func.id.clone()]
             .iter()
             .map(|(cost_token_type, val)| {
                 let val_usize: usize = (*val).try_into().unwrap();
@@ -368,9 +526,7 @@ impl SierraCasmRunner {
                 };
                 val_usize * token_cost
             })
-            .sum();
-
-        available_gas.checked_sub(required_gas).ok_or(RunnerError::NotEnoughGasToCall)
+            .sum()
     }
 
     /// Creates a list of instructions that will be appended to the program's bytecode.
@@ -404,3 +560,8 @@ fn create_metadata(
         })
     }
 }
+
+/// Converts a cell index of the entry code into an offset of an instruction operand.
+fn to_offset(index: usize) -> Result<i16, RunnerError> {
+    i16::try_from(index).map_err(|_| RunnerError::ArgumentsTooLarge)
+}
@@ -0,0 +1,121 @@
+//! Profiling of a run of a Sierra program.
+//!
+//! The vm trace of the run is mapped to the executed Sierra statements, and the vm steps of each
+//! statement are attributed to the call stack executing it.
+
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::{chain, Itertools};
+
+use crate::debugger::{sierra_steps, DebuggerError};
+use crate::{RunResult, SierraCasmRunner};
+
+#[cfg(test)]
+#[path = "profiler_test.rs"]
+mod test;
+
+/// A frame of a profiled call stack: a function and the statement it executes. For all frames but
+/// the innermost one, the statement is the call of the next frame's function.
+pub type ProfileFrame = (FunctionId, StatementIdx);
+
+/// The vm steps of a run, aggregated by the call stacks executing them.
+#[derive(Debug, Default)]
+pub struct ProfilingInfo {
+    /// The number of vm steps executed in each call stack, given from the outermost frame.
+    /// Steps of the entry code of the run are not included.
+    pub stacks_steps: OrderedHashMap<Vec<ProfileFrame>, usize>,
+}
+impl ProfilingInfo {
+    /// Profiles a run of the function matching `name_suffix`.
+    pub fn new(
+        runner: &SierraCasmRunner,
+        name_suffix: &str,
+        run_result: &RunResult,
+    ) -> Result<Self, DebuggerError> {
+        let function = runner.find_function(name_suffix)?;
+        let mut stacks_steps = OrderedHashMap::<Vec<ProfileFrame>, usize>::default();
+        let mut stack: Vec<ProfileFrame> = vec![];
+        for step in sierra_steps(runner, &function.id, run_result)? {
+            stack.truncate(step.depth);
+            stack.push((step.function, step.statement));
+            *stacks_steps.entry(stack.clone()).or_default() += step.vm_steps;
+        }
+        Ok(Self { stacks_steps })
+    }
+
+    /// Returns the total number of profiled vm steps.
+    pub fn total_steps(&self) -> usize {
+        self.stacks_steps.values().sum()
+    }
+
+    /// Returns the number of vm steps executed by each Sierra function, excluding the functions it
+    /// called, in decreasing order.
+    pub fn sierra_functions_steps(&self) -> Vec<(String, usize)> {
+        self.aggregate(|(function, _)| function.to_string())
+    }
+
+    /// Returns the number of vm steps executed by each source function, excluding the functions it
+    /// called, in decreasing order. `statements_functions` are the names of the source functions
+    /// the Sierra statements were generated from, where statements without a known source function
+    /// are attributed to the Sierra function executing them.
+    pub fn source_functions_steps(
+        &self,
+        statements_functions: &[Option<String>],
+    ) -> Vec<(String, usize)> {
+        self.aggregate(|(function, statement)| {
+            source_function(statements_functions, *statement)
+                .unwrap_or_else(|| function.to_string())
+        })
+    }
+
+    /// Returns the profile in the folded stacks format used by flamegraph tools, where each line
+    /// is a `;` separated call stack, from the outermost frame, followed by its number of vm steps.
+    /// Each Sierra function frame is followed by a frame of the source function of its statement,
+    /// if known and different, which makes code inlined into the Sierra function visible.
+    pub fn folded_stacks(&self, statements_functions: &[Option<String>]) -> String {
+        let mut folded = OrderedHashMap::<String, usize>::default();
+        for (stack, steps) in self.stacks_steps.iter() {
+            let frames = stack
+                .iter()
+                .flat_map(|(function, statement)| {
+                    let function_name = function.to_string();
+                    let source_name = source_function(statements_functions, *statement)
+                        .filter(|source_name| *source_name != function_name);
+                    chain!([function_name], source_name)
+                })
+                .join(";");
+            *folded.entry(frames).or_default() += steps;
+        }
+        folded
+            .into_iter()
+            .filter(|(_, steps)| *steps != 0)
+            .sorted()
+            .map(|(stack, steps)| format!("{stack} {steps}\n"))
+            .collect()
+    }
+
+    /// Aggregates the vm steps by a key of the innermost frame of their stack, in decreasing
+    /// order. Keys without any steps are omitted.
+    fn aggregate(&self, key: impl Fn(&ProfileFrame) -> String) -> Vec<(String, usize)> {
+        let mut aggregated = OrderedHashMap::<String, usize>::default();
+        for (stack, steps) in self.stacks_steps.iter() {
+            *aggregated.entry(key(stack.last().unwrap())).or_default() += steps;
+        }
+        aggregated
+            .into_iter()
+            .filter(|(_, steps)| *steps != 0)
+            .sorted_by(|(name_a, steps_a), (name_b, steps_b)| {
+                steps_b.cmp(steps_a).then_with(|| name_a.cmp(name_b))
+            })
+            .collect()
+    }
+}
+
+/// Returns the name of the source function of a statement, if known.
+fn source_function(
+    statements_functions: &[Option<String>],
+    statement: StatementIdx,
+) -> Option<String> {
+    statements_functions.get(statement.0)?.clone()
+}
@@ -0,0 +1,71 @@
+use cairo_felt::Felt252;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::ProfilingInfo;
+use crate::{Arg, SierraCasmRunner};
+
+/// A program computing `double(double(x))`, where `double` is generated from `add` inlined into
+/// it.
+const PROGRAM: &str = indoc! {"
+    type felt252 = felt252;
+
+    libfunc felt252_add = felt252_add;
+    libfunc felt252_dup = dup<felt252>;
+    libfunc store_temp_felt252 = store_temp<felt252>;
+    libfunc call_double = function_call<user@double>;
+
+    felt252_dup([0]) -> ([0], [1]);
+    felt252_add([0], [1]) -> ([2]);
+    store_temp_felt252([2]) -> ([2]);
+    return([2]);
+    store_temp_felt252([0]) -> ([0]);
+    call_double([0]) -> ([1]);
+    store_temp_felt252([1]) -> ([1]);
+    call_double([1]) -> ([2]);
+    return([2]);
+
+    double@0([0]: felt252) -> (felt252);
+    main@4([0]: felt252) -> (felt252);
+"};
+
+/// The source functions of the statements of the program.
+fn statements_functions() -> Vec<Option<String>> {
+    ["add", "add", "add", "double", "main", "main", "main", "main", "main"]
+        .into_iter()
+        .map(|name| Some(name.to_string()))
+        .collect()
+}
+
+fn profiling_info() -> ProfilingInfo {
+    let runner = SierraCasmRunner::new(ProgramParser::new().parse(PROGRAM).unwrap(), None).unwrap();
+    let result = runner.run_function("main", &[Arg::Value(Felt252::from(5))], None, None).unwrap();
+    ProfilingInfo::new(&runner, "main", &result).unwrap()
+}
+
+#[test]
+fn functions_steps() {
+    let profiling_info = profiling_info();
+    assert_eq!(
+        profiling_info.sierra_functions_steps(),
+        vec![("main".to_string(), 5), ("double".to_string(), 4)]
+    );
+    assert_eq!(
+        profiling_info.source_functions_steps(&statements_functions()),
+        vec![("main".to_string(), 5), ("add".to_string(), 2), ("double".to_string(), 2)]
+    );
+    assert_eq!(profiling_info.total_steps(), 9);
+}
+
+#[test]
+fn folded_stacks() {
+    assert_eq!(
+        profiling_info().folded_stacks(&statements_functions()),
+        indoc! {"
+            main 5
+            main;double 2
+            main;double;add 2
+        "}
+    );
+}
@@ -2,6 +2,7 @@
 #[path = "block_generator_test.rs"]
 mod test;
 
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, enumerate, zip_eq, Itertools};
@@ -52,6 +53,14 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        let source_location = match statement {
+            lowering::Statement::Call(statement_call) => statement_call.location,
+            _ => statement
+                .outputs()
+                .first()
+                .map_or(StableLocationOption::None, |var| context.get_var_location(*var)),
+        };
+        add_location_marker(context, source_location, &mut statements);
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -67,6 +76,22 @@ pub fn generate_block_body_code(
     Ok(statements)
 }
 
+/// Adds a [pre_sierra::Statement::Location] marker for the given source location, if it is known
+/// and the locations of the statements are requested (see
+/// [SierraGenGroup::add_statements_locations](crate::db::SierraGenGroup::add_statements_locations)).
+fn add_location_marker(
+    context: &ExprGeneratorContext<'_>,
+    source_location: StableLocationOption,
+    statements: &mut Vec<pre_sierra::Statement>,
+) {
+    if !context.get_db().add_statements_locations() {
+        return;
+    }
+    if let StableLocationOption::Some(location) = source_location {
+        statements.push(pre_sierra::Statement::Location(location));
+    }
+}
+
 /// Adds calls to the `drop` libfunc for the given [DropLocation], according to the `drops`
 /// argument (computed by [find_variable_lifetime](crate::lifetime::find_variable_lifetime)).
 fn add_drop_statements(
@@ -149,6 +174,11 @@ pub fn generate_block_code(
                 ));
             }
 
+            let source_location = match info {
+                lowering::MatchInfo::Extern(s) => s.location,
+                lowering::MatchInfo::Enum(s) => context.get_var_location(s.input),
+            };
+            add_location_marker(context, source_location, &mut statements);
             statements.extend(match info {
                 lowering::MatchInfo::Extern(s) => {
                     generate_match_extern_code(context, s, &statement_location)?
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
@@ -17,6 +18,11 @@ use crate::{ap_change, function_generator, pre_sierra};
 
 #[salsa::query_group(SierraGenDatabase)]
 pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
+    /// Whether to mark the generated code with the source code locations of its statements, as
+    /// required for [SierraGenGroup::get_sierra_program_statements_locations].
+    #[salsa::input]
+    fn add_statements_locations(&self) -> bool;
+
     #[salsa::interned]
     fn intern_label_id(&self, id: pre_sierra::LabelLongId) -> pre_sierra::LabelId;
 
@@ -86,6 +92,17 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
 
+    /// Returns the source code locations of the statements of the
+    /// [cairo_lang_sierra::program::Program] object of the requested functions (see
+    /// [SierraGenGroup::get_sierra_program_for_functions]). The location of each statement is
+    /// the location of the lowering statement it was generated from, if known. The locations are
+    /// known only if [SierraGenGroup::add_statements_locations] is set.
+    #[salsa::invoke(program_generator::get_sierra_program_statements_locations)]
+    fn get_sierra_program_statements_locations(
+        &self,
+        requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+    ) -> Maybe<Arc<Vec<Option<StableLocation>>>>;
+
     /// Returns the [cairo_lang_sierra::program::Program] object of the requested crates.
     #[salsa::invoke(program_generator::get_sierra_program)]
     fn get_sierra_program(
@@ -94,6 +111,12 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
 }
 
+/// Initializes a database with SierraGenGroup.
+pub fn init_sierra_gen_group(db: &mut (dyn SierraGenGroup + 'static)) {
+    // Initialize inputs.
+    db.set_add_statements_locations(false);
+}
+
 fn get_function_signature(
     db: &dyn SierraGenGroup,
     function_id: cairo_lang_sierra::ids::FunctionId,
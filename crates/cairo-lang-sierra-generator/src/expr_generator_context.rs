@@ -1,3 +1,4 @@
+use cairo_lang_defs::diagnostic_utils::StableLocationOption;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_lowering as lowering;
 use cairo_lang_semantic::TypeId;
@@ -147,6 +148,11 @@ impl<'a> ExprGeneratorContext<'a> {
         self.lifetime.last_use.contains(use_location)
     }
 
+    /// Returns the location of the variable given by `var_id`.
+    pub fn get_var_location(&self, var_id: VariableId) -> StableLocationOption {
+        self.lowered.variables[var_id].location
+    }
+
     /// Returns the type of the variable given by `var_id`.
     pub fn get_var_type(&self, var_id: VariableId) -> TypeId {
         self.lowered.variables[var_id].ty
@@ -1,3 +1,4 @@
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra as sierra;
 use cairo_lang_sierra::ids::ConcreteTypeId;
@@ -51,6 +52,9 @@ pub enum Statement {
     /// Note that push values does not guarantee that new copies of the values will be pushed.
     /// If a prefix of the values is already on the stack, they will not be re-pushed.
     PushValues(Vec<PushValue>),
+    /// A marker of the source code location of the statements that follow it. Does not generate
+    /// any Sierra code.
+    Location(StableLocation),
 }
 impl std::fmt::Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -72,6 +76,7 @@ impl std::fmt::Display for Statement {
                 )?;
                 write!(f, ")")
             }
+            Statement::Location(location) => write!(f, "// {location:?}"),
         }
     }
 }
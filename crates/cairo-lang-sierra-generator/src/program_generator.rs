@@ -1,6 +1,7 @@
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
@@ -49,7 +50,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -131,25 +133,9 @@ pub fn get_sierra_program_for_functions(
     db: &dyn SierraGenGroup,
     requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
-    let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
-    let mut statements: Vec<pre_sierra::Statement> = vec![];
-    let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
-    let mut function_id_queue: VecDeque<ConcreteFunctionWithBodyId> =
-        requested_function_ids.into_iter().collect();
-    while let Some(function_id) = function_id_queue.pop_front() {
-        if !processed_function_ids.insert(function_id) {
-            continue;
-        }
-        let function: Arc<pre_sierra::Function> = db.function_with_body_sierra(function_id)?;
-        functions.push(function.clone());
-        statements.extend_from_slice(&function.body);
-
-        for statement in &function.body {
-            if let Some(related_function_id) = try_get_function_with_body_id(db, statement) {
-                function_id_queue.push_back(related_function_id);
-            }
-        }
-    }
+    let functions = collect_program_functions(db, requested_function_ids)?;
+    let statements: Vec<pre_sierra::Statement> =
+        functions.iter().flat_map(|function| function.body.iter().cloned()).collect();
 
     let libfunc_declarations =
         generate_libfunc_declarations(db, collect_used_libfuncs(&statements).iter());
@@ -178,6 +164,50 @@ pub fn get_sierra_program_for_functions(
     }))
 }
 
+/// Query implementation of [SierraGenGroup::get_sierra_program_statements_locations].
+pub fn get_sierra_program_statements_locations(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<Vec<Option<StableLocation>>>> {
+    let mut locations = vec![];
+    for function in collect_program_functions(db, requested_function_ids)? {
+        let mut current_location = None;
+        for statement in &function.body {
+            match statement {
+                pre_sierra::Statement::Sierra(_) => locations.push(current_location),
+                pre_sierra::Statement::Location(location) => current_location = Some(*location),
+                pre_sierra::Statement::Label(_) | pre_sierra::Statement::PushValues(_) => {}
+            }
+        }
+    }
+    Ok(Arc::new(locations))
+}
+
+/// Collects the functions of the program containing the requested functions, in the order in
+/// which their code is placed in the program.
+fn collect_program_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Vec<Arc<pre_sierra::Function>>> {
+    let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
+    let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
+    let mut function_id_queue: VecDeque<ConcreteFunctionWithBodyId> =
+        requested_function_ids.into_iter().collect();
+    while let Some(function_id) = function_id_queue.pop_front() {
+        if !processed_function_ids.insert(function_id) {
+            continue;
+        }
+        let function: Arc<pre_sierra::Function> = db.function_with_body_sierra(function_id)?;
+        for statement in &function.body {
+            if let Some(related_function_id) = try_get_function_with_body_id(db, statement) {
+                function_id_queue.push_back(related_function_id);
+            }
+        }
+        functions.push(function);
+    }
+    Ok(functions)
+}
+
 /// Tries extracting a ConcreteFunctionWithBodyId from a pre-Sierra statement.
 fn try_get_function_with_body_id(
     db: &dyn SierraGenGroup,
@@ -206,9 +236,30 @@ pub fn get_sierra_program(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    db.get_sierra_program_for_functions(find_free_functions(db, &requested_crate_ids)?)
+}
+
+/// Returns the [cairo_lang_sierra::program::Program] object of the requested crates, together with
+/// the locations of the code its statements were generated from.
+pub fn get_sierra_program_with_statements_locations(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: &[CrateId],
+) -> Maybe<(Arc<cairo_lang_sierra::program::Program>, Arc<Vec<Option<StableLocation>>>)> {
+    let requested_function_ids = find_free_functions(db, requested_crate_ids)?;
+    Ok((
+        db.get_sierra_program_for_functions(requested_function_ids.clone())?,
+        db.get_sierra_program_statements_locations(requested_function_ids)?,
+    ))
+}
+
+/// Returns the free functions without generic parameters of the requested crates.
+pub fn find_free_functions(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: &[CrateId],
+) -> Maybe<Vec<ConcreteFunctionWithBodyId>> {
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
-        for module_id in db.crate_modules(crate_id).iter() {
+        for module_id in db.crate_modules(*crate_id).iter() {
             for (free_func_id, _) in db.module_free_functions(*module_id)? {
                 // TODO(spapini): Search Impl functions.
                 if let Some(function) =
@@ -219,5 +270,5 @@ pub fn get_sierra_program(
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    Ok(requested_function_ids)
 }
@@ -2,6 +2,9 @@ use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleItemId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::dead_code_elimination::eliminate_dead_code;
+use cairo_lang_sierra::debug_info::DebugInfo;
+use cairo_lang_sierra::program::Program;
 use cairo_lang_utils::try_extract_matches;
 use indoc::indoc;
 use itertools::Itertools;
@@ -144,3 +147,44 @@ fn test_only_include_dependencies(func_name: &str, sierra_used_funcs: &[&str]) {
         sierra_used_funcs
     );
 }
+
+#[test]
+fn test_eliminate_dead_code_of_crate() {
+    // Compiling the whole crate, and keeping only the code required for `foo`.
+    let program = checked_compile_to_sierra(indoc! {"
+                fn foo(a: felt252) -> felt252 {
+                    a + a
+                }
+
+                fn unused(a: u128, b: u128) -> u128 {
+                    a & b
+                }
+            "});
+    let function_names =
+        |program: &Program| program.funcs.iter().map(|f| f.id.to_string()).collect_vec();
+    assert_eq!(function_names(&program), ["test::foo", "test::unused"]);
+    let foo = program.funcs[0].id.clone();
+    let result = eliminate_dead_code(&program, &[foo]).unwrap();
+
+    assert_eq!(
+        result.program.to_string(),
+        indoc! {"
+            type felt252 = felt252;
+
+            libfunc dup<felt252> = dup<felt252>;
+            libfunc felt252_add = felt252_add;
+            libfunc store_temp<felt252> = store_temp<felt252>;
+
+            dup<felt252>([0]) -> ([0], [2]);
+            felt252_add([2], [0]) -> ([1]);
+            store_temp<felt252>([1]) -> ([3]);
+            return([3]);
+
+            test::foo@0([0]: felt252) -> (felt252);
+        "}
+    ); // The names of the ids kept by the elimination are still those declared in the program.
+    assert_eq!(
+        result.map_debug_info(&DebugInfo::extract(&program)),
+        DebugInfo::extract(&result.program)
+    );
+}
@@ -40,6 +40,30 @@ pub trait SierraIdReplacer {
         program
     }
 
+    /// Returns new debug info where the ids of all the names are replaced.
+    fn apply_debug_info(
+        &self,
+        debug_info: &cairo_lang_sierra::debug_info::DebugInfo,
+    ) -> cairo_lang_sierra::debug_info::DebugInfo {
+        cairo_lang_sierra::debug_info::DebugInfo {
+            type_names: debug_info
+                .type_names
+                .iter()
+                .map(|(id, name)| (self.replace_type_id(id), name.clone()))
+                .collect(),
+            libfunc_names: debug_info
+                .libfunc_names
+                .iter()
+                .map(|(id, name)| (self.replace_libfunc_id(id), name.clone()))
+                .collect(),
+            user_func_names: debug_info
+                .user_func_names
+                .iter()
+                .map(|(id, name)| (self.replace_function_id(id), name.clone()))
+                .collect(),
+        }
+    }
+
     // Replaces libfunc_ids
     fn replace_libfunc_id(
         &self,
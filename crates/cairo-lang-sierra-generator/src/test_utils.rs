@@ -19,7 +19,7 @@ use cairo_lang_utils::Upcast;
 use salsa::{InternId, InternKey};
 use {cairo_lang_defs as defs, cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
-use crate::db::{SierraGenDatabase, SierraGenGroup};
+use crate::db::{init_sierra_gen_group, SierraGenDatabase, SierraGenGroup};
 use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids_in_program;
 use crate::utils::{jump_statement, return_statement, simple_statement};
@@ -42,6 +42,7 @@ impl Default for SierraGenDatabaseForTesting {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         init_lowering_group(&mut res);
+        init_sierra_gen_group(&mut res);
         res.set_semantic_plugins(get_default_plugins());
         let corelib_path = detect_corelib().expect("Corelib not found in default location.");
         init_dev_corelib(&mut res, corelib_path);
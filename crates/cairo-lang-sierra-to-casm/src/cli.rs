@@ -1,8 +1,10 @@
 use std::fs;
 
 use anyhow::Context;
+use cairo_lang_sierra::binary_serde::{is_binary_program, program_from_bytes};
 use cairo_lang_sierra::ProgramParser;
 use cairo_lang_sierra_to_casm::metadata::calc_metadata;
+use cairo_lang_sierra_to_casm::report::CompilationReport;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 use indoc::indoc;
@@ -12,9 +14,13 @@ use indoc::indoc;
 #[derive(Parser, Debug)]
 #[clap(version, verbatim_doc_comment)]
 struct Args {
-    /// The file to compile
+    /// The file to compile - either textual or binary Sierra.
     file: String,
     output: String,
+    /// A file to write a JSON report of the compilation to, with the code size and costs of each
+    /// function.
+    #[arg(long)]
+    report: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -23,21 +29,33 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
-    let sierra_code = fs::read_to_string(args.file).with_context(|| "Could not read file!")?;
-    let Ok(program) = ProgramParser::new().parse(&sierra_code) else {
-        anyhow::bail!(indoc!{"
-            Failed to parse sierra program.
-            Note: StarkNet contracts should be compiled with `starknet-sierra-compile`."
-    })};
+    let sierra_code = fs::read(args.file).with_context(|| "Could not read file!")?;
+    let program = if is_binary_program(&sierra_code) {
+        program_from_bytes(&sierra_code).with_context(|| "Failed to decode sierra program.")?
+    } else {
+        let sierra_code = String::from_utf8(sierra_code).with_context(|| "Could not read file!")?;
+        let Ok(program) = ProgramParser::new().parse(&sierra_code) else {
+            anyhow::bail!(indoc!{"
+                Failed to parse sierra program.
+                Note: StarkNet contracts should be compiled with `starknet-sierra-compile`."
+        })};
+        program
+    };
 
     let gas_usage_check = true;
-    let cairo_program = cairo_lang_sierra_to_casm::compiler::compile(
-        &program,
-        &calc_metadata(&program, Default::default())
-            .with_context(|| "Failed calculating Sierra variables.")?,
-        gas_usage_check,
-    )
-    .with_context(|| "Compilation failed.")?;
+    let metadata = calc_metadata(&program, Default::default())
+        .with_context(|| "Failed calculating Sierra variables.")?;
+    let cairo_program =
+        cairo_lang_sierra_to_casm::compiler::compile(&program, &metadata, gas_usage_check)
+            .with_context(|| "Compilation failed.")?;
+
+    if let Some(report_path) = args.report {
+        let report = CompilationReport::new(&program, &metadata, &cairo_program)
+            .with_context(|| "Failed creating the compilation report.")?;
+        let report = serde_json::to_string_pretty(&report)
+            .with_context(|| "Report serialization failed.")?;
+        fs::write(report_path, report).with_context(|| "Failed to write report.")?;
+    }
 
     fs::write(args.output, format!("{cairo_program}")).with_context(|| "Failed to write output.")
 }
@@ -4,7 +4,7 @@ use cairo_lang_casm::instructions::{Instruction, InstructionBody, RetInstruction
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::ConcreteLibfunc;
-use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
 use cairo_lang_sierra::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use itertools::zip_eq;
@@ -15,7 +15,9 @@ use crate::invocations::{
     check_references_on_stack, compile_invocation, InvocationError, ProgramInfo,
 };
 use crate::metadata::Metadata;
-use crate::references::{check_types_match, ReferencesError};
+use crate::references::{
+    check_types_match, ReferenceExpression, ReferenceValue, ReferencesError, StatementRefs,
+};
 use crate::relocations::{relocate_instructions, RelocationEntry};
 use crate::type_sizes::get_type_size_map;
 
@@ -71,6 +73,16 @@ pub struct SierraStatementDebugInfo {
     pub code_offset: usize,
 }
 
+/// The debug information of a Sierra variable alive at a statement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SierraVariableDebugInfo {
+    pub var_id: VarId,
+    pub ty: ConcreteTypeId,
+    /// The cells of the variable, relative to the `ap` and `fp` at the start of the statement's
+    /// code.
+    pub expression: ReferenceExpression,
+}
+
 /// The debug information of a compilation from Sierra to casm.
 #[derive(Debug, Eq, PartialEq)]
 pub struct CairoProgramDebugInfo {
@@ -106,6 +118,32 @@ pub fn compile(
     program: &Program,
     metadata: &Metadata,
     gas_usage_check: bool,
+) -> Result<CairoProgram, Box<CompilationError>> {
+    compile_ex(program, metadata, gas_usage_check, None)
+}
+
+/// Compiles the program as `compile` does, and returns the debug information of the variables
+/// alive at the start of each of its statements as well.
+/// The variables are only collected for debugging, as they are costly to collect for every
+/// statement.
+pub fn compile_with_variables_debug_info(
+    program: &Program,
+    metadata: &Metadata,
+    gas_usage_check: bool,
+) -> Result<(CairoProgram, Vec<Vec<SierraVariableDebugInfo>>), Box<CompilationError>> {
+    let mut statement_variables = Vec::with_capacity(program.statements.len());
+    let cairo_program =
+        compile_ex(program, metadata, gas_usage_check, Some(&mut statement_variables))?;
+    Ok((cairo_program, statement_variables))
+}
+
+/// Compiles the program, pushing the variables alive at the start of each statement to
+/// `statement_variables` if provided.
+fn compile_ex(
+    program: &Program,
+    metadata: &Metadata,
+    gas_usage_check: bool,
+    mut statement_variables: Option<&mut Vec<Vec<SierraVariableDebugInfo>>>,
 ) -> Result<CairoProgram, Box<CompilationError>> {
     let mut instructions = Vec::new();
     let mut relocations: Vec<RelocationEntry> = Vec::new();
@@ -141,6 +179,12 @@ pub fn compile(
                     .get_annotations_after_take_args(statement_idx, ref_ids.iter())
                     .map_err(|err| Box::new(err.into()))?;
 
+                if let Some(statement_variables) = &mut statement_variables {
+                    statement_variables.push(variables_debug_info(
+                        &annotations.refs,
+                        ref_ids.iter().zip(return_refs.iter()),
+                    ));
+                }
                 if let Some(var_id) = annotations.refs.keys().next() {
                     return Err(Box::new(CompilationError::DanglingReferences {
                         statement_idx,
@@ -172,6 +216,12 @@ pub fn compile(
                 let (annotations, invoke_refs) = program_annotations
                     .get_annotations_after_take_args(statement_idx, invocation.args.iter())
                     .map_err(|err| Box::new(err.into()))?;
+                if let Some(statement_variables) = &mut statement_variables {
+                    statement_variables.push(variables_debug_info(
+                        &annotations.refs,
+                        invocation.args.iter().zip(invoke_refs.iter()),
+                    ));
+                }
 
                 let libfunc = registry
                     .get_libfunc(&invocation.libfunc_id)
@@ -280,3 +330,22 @@ fn is_branch_align(
 
     Ok(false)
 }
+
+/// Returns the debug information of the variables alive at the start of a statement, given the
+/// references of the variables not used by the statement, and the ones used by it.
+fn variables_debug_info<'a>(
+    unused_refs: &'a StatementRefs,
+    used_refs: impl Iterator<Item = (&'a VarId, &'a ReferenceValue)>,
+) -> Vec<SierraVariableDebugInfo> {
+    let mut variables: Vec<_> = unused_refs
+        .iter()
+        .chain(used_refs)
+        .map(|(var_id, value)| SierraVariableDebugInfo {
+            var_id: var_id.clone(),
+            ty: value.ty.clone(),
+            expression: value.expression.clone(),
+        })
+        .collect();
+    variables.sort_by_key(|variable| variable.var_id.id);
+    variables
+}
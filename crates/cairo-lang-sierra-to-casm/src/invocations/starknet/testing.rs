@@ -11,6 +11,24 @@ use crate::invocations::{
 pub fn build(
     libfunc: &TestingConcreteLibfunc,
     builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        TestingConcreteLibfunc::SetSignature(_) => build_set_signature(builder),
+        TestingConcreteLibfunc::SetStorageAt(_) => build_set_storage_at(builder),
+        TestingConcreteLibfunc::GetStorageAt(_) => build_get_storage_at(builder),
+        TestingConcreteLibfunc::ResetState(_) => {
+            let mut casm_builder = CasmBuilder::default();
+            casm_build_extend! {casm_builder, hint ResetState {} into {}; };
+            build_no_output(builder, casm_builder)
+        }
+        _ => build_setter(libfunc, builder),
+    }
+}
+
+/// Builds instructions for setting a single value of the execution info.
+fn build_setter(
+    libfunc: &TestingConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
 ) -> Result<CompiledInvocation, InvocationError> {
     let [value] = builder.try_get_single_cells()?;
     let mut casm_builder = CasmBuilder::default();
@@ -33,7 +51,98 @@ pub fn build(
         TestingConcreteLibfunc::SetSequencerAddress(_) => {
             casm_build_extend! {casm_builder, hint SetSequencerAddress {value: value}; };
         }
+        TestingConcreteLibfunc::SetVersion(_) => {
+            casm_build_extend! {casm_builder, hint SetVersion {value: value}; };
+        }
+        TestingConcreteLibfunc::SetAccountContractAddress(_) => {
+            casm_build_extend! {casm_builder, hint SetAccountContractAddress {value: value}; };
+        }
+        TestingConcreteLibfunc::SetMaxFee(_) => {
+            casm_build_extend! {casm_builder, hint SetMaxFee {value: value}; };
+        }
+        TestingConcreteLibfunc::SetTransactionHash(_) => {
+            casm_build_extend! {casm_builder, hint SetTransactionHash {value: value}; };
+        }
+        TestingConcreteLibfunc::SetChainId(_) => {
+            casm_build_extend! {casm_builder, hint SetChainId {value: value}; };
+        }
+        TestingConcreteLibfunc::SetNonce(_) => {
+            casm_build_extend! {casm_builder, hint SetNonce {value: value}; };
+        }
+        TestingConcreteLibfunc::SetSignature(_)
+        | TestingConcreteLibfunc::SetStorageAt(_)
+        | TestingConcreteLibfunc::GetStorageAt(_)
+        | TestingConcreteLibfunc::ResetState(_) => unreachable!("Not a single value setter."),
     }
+    build_no_output(builder, casm_builder)
+}
+
+/// Builds instructions for setting the signature of the transaction.
+fn build_set_signature(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [start, end] = builder.try_get_refs::<1>()?[0].try_unpack()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) start;
+        buffer(0) end;
+    };
+    casm_build_extend! {casm_builder, hint SetSignature {start: start, end: end} into {}; };
+    build_no_output(builder, casm_builder)
+}
+
+/// Builds instructions for setting a storage value of a contract.
+fn build_set_storage_at(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [contract_address, address, value] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref contract_address;
+        deref address;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        hint SetStorageAt {
+            contract_address: contract_address,
+            address: address,
+            value: value
+        } into {};
+    };
+    build_no_output(builder, casm_builder)
+}
+
+/// Builds instructions for getting a storage value of a contract.
+fn build_get_storage_at(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [contract_address, address] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref contract_address;
+        deref address;
+    };
+    casm_build_extend! {casm_builder,
+        tempvar value;
+        hint GetStorageAt {
+            contract_address: contract_address,
+            address: address
+        } into {dst: value};
+        ap += 1;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[value]], None)],
+        CostValidationInfo::default(),
+    ))
+}
+
+/// Finalizes the instructions of a testing libfunc without outputs.
+fn build_no_output(
+    builder: CompiledInvocationBuilder<'_>,
+    mut casm_builder: CasmBuilder,
+) -> Result<CompiledInvocation, InvocationError> {
+    // Since we can't have hints not carried on actual instructions.
     casm_build_extend! {casm_builder, ap += 0; };
     Ok(builder.build_from_casm_builder(
         casm_builder,
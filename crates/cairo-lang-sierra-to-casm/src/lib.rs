@@ -7,8 +7,10 @@ pub mod compiler;
 pub mod environment;
 pub mod invocations;
 pub mod metadata;
+pub mod program_checks;
 pub mod references;
 pub mod relocations;
+pub mod report;
 #[cfg(any(feature = "testing", test))]
 pub mod test_utils;
 pub mod type_sizes;
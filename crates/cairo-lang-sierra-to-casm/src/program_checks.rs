@@ -0,0 +1,545 @@
+//! Validation of Sierra programs, reporting all the issues found in a program at once, instead of
+//! failing on the first one as the compilation does.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::lib_func::ParamSignature;
+use cairo_lang_sierra::extensions::ConcreteLibfunc;
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId};
+use cairo_lang_sierra::program::{
+    BranchTarget, GenericArg, Invocation, Program, Statement, StatementIdx,
+};
+use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::compiler::check_basic_structure;
+use crate::metadata::{calc_metadata, MetadataError};
+
+#[cfg(test)]
+#[path = "program_checks_test.rs"]
+mod test;
+
+/// An issue found in a Sierra program.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ProgramIssue {
+    #[error("{}", describe_registry_error(.0))]
+    InvalidDeclaration(ProgramRegistryError),
+    #[error("Function `{function_id}` has an out of bounds entry point #{entry_point}.")]
+    EntryPointOutOfBounds { function_id: FunctionId, entry_point: StatementIdx },
+    #[error("Statement #{statement_idx} has an out of bounds branch target #{target}.")]
+    BranchTargetOutOfBounds { statement_idx: StatementIdx, target: StatementIdx },
+    #[error("Statement #{0} does not match the signature of its libfunc.")]
+    InvocationMismatch(StatementIdx),
+    #[error("Libfunc `{libfunc_id}` of `{generic_id}` is not in the allowed libfuncs list.")]
+    DisallowedLibfunc { libfunc_id: ConcreteLibfuncId, generic_id: GenericLibfuncId },
+    #[error("Failed computing the gas or ap changes of function `{function_id}`: {error}")]
+    UnsolvableFunction { function_id: FunctionId, error: MetadataError },
+    #[error("Function `{function_id}` calls the unsolvable function `{callee_id}`.")]
+    CallsUnsolvableFunction { function_id: FunctionId, callee_id: FunctionId },
+    #[error("Function `{0}` never returns, so its gas and ap changes can't be computed.")]
+    NonReturningFunction(FunctionId),
+    #[error("Statements #{start} to #{end} are unreachable.")]
+    UnreachableStatements { start: StatementIdx, end: StatementIdx },
+    #[error("Type `{0}` is declared but never used.")]
+    UnusedType(ConcreteTypeId),
+    #[error("Libfunc `{0}` is declared but never used.")]
+    UnusedLibfunc(ConcreteLibfuncId),
+}
+impl ProgramIssue {
+    /// Returns true if the issue does not prevent the compilation of the program.
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            ProgramIssue::UnreachableStatements { .. }
+                | ProgramIssue::UnusedType(_)
+                | ProgramIssue::UnusedLibfunc(_)
+        )
+    }
+}
+
+/// Describes an error of a declaration in the program, including the id of the declaration.
+fn describe_registry_error(error: &ProgramRegistryError) -> String {
+    match error {
+        ProgramRegistryError::FunctionIdAlreadyExists(id) => {
+            format!("Function `{id}` is declared more than once.")
+        }
+        ProgramRegistryError::MissingFunction(id) => format!("Function `{id}` is not declared."),
+        ProgramRegistryError::TypeSpecialization { concrete_id, error } => {
+            format!("Failed specializing type `{concrete_id}`: {error}.")
+        }
+        ProgramRegistryError::TypeConcreteIdAlreadyExists(id) => {
+            format!("Type `{id}` is declared more than once.")
+        }
+        ProgramRegistryError::TypeAlreadyDeclared(declaration) => format!(
+            "Type `{}` is a redeclaration of an already declared concrete type.",
+            declaration.id
+        ),
+        ProgramRegistryError::MissingType(id) => format!("Type `{id}` is not declared."),
+        ProgramRegistryError::LibfuncSpecialization { concrete_id, error } => {
+            format!("Failed specializing libfunc `{concrete_id}`: {error}")
+        }
+        ProgramRegistryError::LibfuncConcreteIdAlreadyExists(id) => {
+            format!("Libfunc `{id}` is declared more than once.")
+        }
+        ProgramRegistryError::MissingLibfunc(id) => format!("Libfunc `{id}` is not declared."),
+        ProgramRegistryError::TypeInfoDeclarationMismatch(id) => {
+            format!("The declared info of type `{id}` does not match the type.")
+        }
+    }
+}
+
+/// Checks the program, returning all the issues found in it.
+/// If `allowed_libfuncs` is provided, libfuncs not in it are reported as well.
+pub fn check_program(
+    program: &Program,
+    allowed_libfuncs: Option<&HashSet<GenericLibfuncId>>,
+) -> Vec<ProgramIssue> {
+    let (registry, registry_errors) =
+        ProgramRegistry::<CoreType, CoreLibfunc>::new_partial(program);
+    let mut issues: Vec<ProgramIssue> =
+        registry_errors.into_iter().map(ProgramIssue::InvalidDeclaration).collect();
+
+    let declared_types: HashSet<&ConcreteTypeId> =
+        program.type_declarations.iter().map(|declaration| &declaration.id).collect();
+    let declared_libfuncs: HashSet<&ConcreteLibfuncId> =
+        program.libfunc_declarations.iter().map(|declaration| &declaration.id).collect();
+    for func in &program.funcs {
+        for ty in func.signature.param_types.iter().chain(&func.signature.ret_types) {
+            if !declared_types.contains(ty) {
+                issues.push(ProgramIssue::InvalidDeclaration(ProgramRegistryError::MissingType(
+                    ty.clone(),
+                )));
+            }
+        }
+        if program.get_statement(&func.entry_point).is_none() {
+            issues.push(ProgramIssue::EntryPointOutOfBounds {
+                function_id: func.id.clone(),
+                entry_point: func.entry_point,
+            });
+        }
+    }
+    for (statement_idx, statement) in program.statements.iter().enumerate() {
+        let statement_idx = StatementIdx(statement_idx);
+        let Statement::Invocation(invocation) = statement else { continue; };
+        for target in statement_targets(statement_idx, invocation) {
+            if target.0 >= program.statements.len() {
+                issues.push(ProgramIssue::BranchTargetOutOfBounds { statement_idx, target });
+            }
+        }
+        if let Ok(libfunc) = registry.get_libfunc(&invocation.libfunc_id) {
+            if check_basic_structure(statement_idx, invocation, libfunc).is_err() {
+                issues.push(ProgramIssue::InvocationMismatch(statement_idx));
+            }
+        } else if !declared_libfuncs.contains(&invocation.libfunc_id) {
+            issues.push(ProgramIssue::InvalidDeclaration(ProgramRegistryError::MissingLibfunc(
+                invocation.libfunc_id.clone(),
+            )));
+        }
+    }
+
+    if let Some(allowed_libfuncs) = allowed_libfuncs {
+        for declaration in &program.libfunc_declarations {
+            if !allowed_libfuncs.contains(&declaration.long_id.generic_id) {
+                issues.push(ProgramIssue::DisallowedLibfunc {
+                    libfunc_id: declaration.id.clone(),
+                    generic_id: declaration.long_id.generic_id.clone(),
+                });
+            }
+        }
+    }
+
+    // Solving the gas and ap changes requires a valid program, but not one using only the allowed
+    // libfuncs.
+    if issues.iter().all(|issue| matches!(issue, ProgramIssue::DisallowedLibfunc { .. })) {
+        issues.extend(check_unsolvable_functions(program));
+    }
+
+    let reachable = reachable_statements(program, program.funcs.iter().map(|f| f.entry_point));
+    let unreachable_ranges = (0..program.statements.len())
+        .filter(|idx| !reachable.contains(&StatementIdx(*idx)))
+        .map(|idx| (idx, idx))
+        .coalesce(|(start, end), (next, _)| {
+            if end + 1 == next { Ok((start, next)) } else { Err(((start, end), (next, next))) }
+        });
+    for (start, end) in unreachable_ranges {
+        issues.push(ProgramIssue::UnreachableStatements {
+            start: StatementIdx(start),
+            end: StatementIdx(end),
+        });
+    }
+
+    let used_types = used_types(program, &registry);
+    for declaration in &program.type_declarations {
+        if !used_types.contains(&declaration.id) {
+            issues.push(ProgramIssue::UnusedType(declaration.id.clone()));
+        }
+    }
+    let used_libfuncs: HashSet<&ConcreteLibfuncId> = program
+        .statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Invocation(invocation) => Some(&invocation.libfunc_id),
+            Statement::Return(_) => None,
+        })
+        .chain(program.libfunc_declarations.iter().flat_map(|declaration| {
+            declaration.long_id.generic_args.iter().filter_map(|arg| match arg {
+                GenericArg::Libfunc(id) => Some(id),
+                _ => None,
+            })
+        }))
+        .collect();
+    for declaration in &program.libfunc_declarations {
+        if !used_libfuncs.contains(&declaration.id) {
+            issues.push(ProgramIssue::UnusedLibfunc(declaration.id.clone()));
+        }
+    }
+    issues
+}
+
+/// Returns the statements the invocation at the given index may continue to.
+fn statement_targets(
+    statement_idx: StatementIdx,
+    invocation: &Invocation,
+) -> impl Iterator<Item = StatementIdx> + '_ {
+    invocation.branches.iter().map(move |branch| statement_idx.next(&branch.target))
+}
+
+/// Returns the statements reachable from the given statements, ignoring out of bounds targets.
+fn reachable_statements(
+    program: &Program,
+    starts: impl IntoIterator<Item = StatementIdx>,
+) -> OrderedHashSet<StatementIdx> {
+    let mut reachable = OrderedHashSet::default();
+    let mut queue: VecDeque<StatementIdx> = starts.into_iter().collect();
+    while let Some(statement_idx) = queue.pop_front() {
+        let Some(statement) = program.get_statement(&statement_idx) else { continue; };
+        if !reachable.insert(statement_idx) {
+            continue;
+        }
+        if let Statement::Invocation(invocation) = statement {
+            queue.extend(statement_targets(statement_idx, invocation));
+        }
+    }
+    reachable
+}
+
+/// Returns the types used by the program, either explicitly by the declarations and the functions,
+/// or implicitly by the signatures of the libfuncs.
+fn used_types(
+    program: &Program,
+    registry: &ProgramRegistry<CoreType, CoreLibfunc>,
+) -> HashSet<ConcreteTypeId> {
+    let generic_args = program
+        .type_declarations
+        .iter()
+        .flat_map(|declaration| &declaration.long_id.generic_args)
+        .chain(
+            program
+                .libfunc_declarations
+                .iter()
+                .flat_map(|declaration| &declaration.long_id.generic_args),
+        )
+        .filter_map(|arg| match arg {
+            GenericArg::Type(ty) => Some(ty.clone()),
+            _ => None,
+        });
+    let signatures = program
+        .funcs
+        .iter()
+        .flat_map(|func| func.signature.param_types.iter().chain(&func.signature.ret_types))
+        .cloned();
+    let libfunc_signatures = program
+        .libfunc_declarations
+        .iter()
+        .filter_map(|declaration| registry.get_libfunc(&declaration.id).ok())
+        .flat_map(|libfunc| {
+            libfunc
+                .param_signatures()
+                .iter()
+                .map(|param: &ParamSignature| param.ty.clone())
+                .chain(libfunc.output_types().into_iter().flatten())
+                .collect_vec()
+        });
+    generic_args.chain(signatures).chain(libfunc_signatures).collect()
+}
+
+/// Returns the issues of the functions whose gas or ap changes can't be computed.
+///
+/// Functions that never return are reported without solving them, as their costs are not
+/// constrained by the equations. The rest of the program is solved once, and only if it fails, the
+/// failing functions are found by bisecting the functions in callee-first order, which takes a
+/// logarithmic number of solves per failing function.
+fn check_unsolvable_functions(program: &Program) -> Vec<ProgramIssue> {
+    let solve = |program: &Program| calc_metadata(program, Default::default());
+    let called_functions = called_functions(program);
+    let callees: OrderedHashMap<FunctionId, OrderedHashSet<FunctionId>> = program
+        .funcs
+        .iter()
+        .map(|func| {
+            let callees = reachable_statements(program, [func.entry_point])
+                .into_iter()
+                .filter_map(|idx| match &program.statements[idx.0] {
+                    Statement::Invocation(invocation) => {
+                        Some(called_functions[invocation.libfunc_id.clone()].iter().cloned())
+                    }
+                    Statement::Return(_) => None,
+                })
+                .flatten()
+                .collect();
+            (func.id.clone(), callees)
+        })
+        .collect();
+
+    let mut direct_callers = HashMap::<FunctionId, Vec<FunctionId>>::new();
+    for (caller, callees) in callees.iter() {
+        for callee in callees {
+            direct_callers.entry(callee.clone()).or_default().push(caller.clone());
+        }
+    }
+
+    let returning = returning_functions(program, &called_functions);
+    let mut issues: OrderedHashMap<FunctionId, ProgramIssue> = program
+        .funcs
+        .iter()
+        .filter(|func| !returning.contains(&func.id))
+        .map(|func| (func.id.clone(), ProgramIssue::NonReturningFunction(func.id.clone())))
+        .collect();
+
+    let mut remaining = callee_first_order(&callees)
+        .into_iter()
+        .filter(|function_id| returning.contains(function_id))
+        .collect_vec();
+    while !remaining.is_empty() {
+        let Err(mut error) =
+            solve(&subprogram(program, &remaining, &callees, &called_functions))
+        else {
+            break;
+        };
+        // The callees of a function precede it, so a prefix of the functions is solvable if and
+        // only if it does not contain an unsolvable function.
+        let (mut solvable, mut failing) = (0, remaining.len());
+        while failing - solvable > 1 {
+            let mid = (solvable + failing) / 2;
+            match solve(&subprogram(program, &remaining[..mid], &callees, &called_functions)) {
+                Ok(_) => solvable = mid,
+                Err(mid_error) => {
+                    failing = mid;
+                    error = mid_error;
+                }
+            }
+        }
+        let unsolvable = remaining[failing - 1].clone();
+        // The functions calling the unsolvable function, directly or indirectly, can't be solved
+        // either.
+        let mut callers = OrderedHashSet::<FunctionId>::default();
+        let mut queue = VecDeque::from([unsolvable.clone()]);
+        while let Some(function_id) = queue.pop_front() {
+            for caller in direct_callers.get(&function_id).into_iter().flatten() {
+                if callers.insert(caller.clone()) {
+                    queue.push_back(caller.clone());
+                }
+            }
+        }
+        for caller in callers.iter().filter(|caller| **caller != unsolvable) {
+            issues.insert(
+                caller.clone(),
+                ProgramIssue::CallsUnsolvableFunction {
+                    function_id: caller.clone(),
+                    callee_id: unsolvable.clone(),
+                },
+            );
+        }
+        issues.insert(
+            unsolvable.clone(),
+            ProgramIssue::UnsolvableFunction { function_id: unsolvable.clone(), error },
+        );
+        remaining
+            .retain(|function_id| *function_id != unsolvable && !callers.contains(function_id));
+    }
+    program.funcs.iter().filter_map(|func| issues.swap_remove(&func.id)).collect()
+}
+
+/// Returns the user functions called by each of the declared libfuncs.
+fn called_functions(program: &Program) -> OrderedHashMap<ConcreteLibfuncId, Vec<FunctionId>> {
+    program
+        .libfunc_declarations
+        .iter()
+        .map(|declaration| {
+            let functions = declaration
+                .long_id
+                .generic_args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArg::UserFunc(id) => Some(id.clone()),
+                    _ => None,
+                })
+                .collect();
+            (declaration.id.clone(), functions)
+        })
+        .collect()
+}
+
+/// Returns the functions that may return: the functions with a path from their entry point to a
+/// `return` statement, which only calls functions that may return.
+fn returning_functions(
+    program: &Program,
+    called_functions: &OrderedHashMap<ConcreteLibfuncId, Vec<FunctionId>>,
+) -> HashSet<FunctionId> {
+    // For every statement, the statements continuing to it and the number of the functions it
+    // calls that are not known to return yet.
+    let mut predecessors = vec![vec![]; program.statements.len()];
+    let mut pending_calls = vec![0; program.statements.len()];
+    let mut callers = HashMap::<&FunctionId, Vec<StatementIdx>>::new();
+    let mut queue = VecDeque::new();
+    for (idx, statement) in program.statements.iter().enumerate() {
+        let statement_idx = StatementIdx(idx);
+        match statement {
+            Statement::Invocation(invocation) => {
+                for target in statement_targets(statement_idx, invocation) {
+                    predecessors[target.0].push(statement_idx);
+                }
+                let called = called_functions[invocation.libfunc_id.clone()].iter().unique();
+                for function_id in called {
+                    callers.entry(function_id).or_default().push(statement_idx);
+                    pending_calls[idx] += 1;
+                }
+            }
+            Statement::Return(_) => queue.push_back(statement_idx),
+        }
+    }
+    let mut entry_points = HashMap::<StatementIdx, Vec<&FunctionId>>::new();
+    for func in &program.funcs {
+        entry_points.entry(func.entry_point).or_default().push(&func.id);
+    }
+
+    // A statement may return once it calls only returning functions and one of its targets may
+    // return.
+    let mut has_returning_target = vec![false; program.statements.len()];
+    let mut returning_statements = HashSet::new();
+    let mut returning = HashSet::new();
+    while let Some(statement_idx) = queue.pop_front() {
+        if !returning_statements.insert(statement_idx) {
+            continue;
+        }
+        let mut updated = predecessors[statement_idx.0].clone();
+        for predecessor in &updated {
+            has_returning_target[predecessor.0] = true;
+        }
+        for function_id in entry_points.get(&statement_idx).into_iter().flatten() {
+            returning.insert((*function_id).clone());
+            for caller in callers.get(function_id).into_iter().flatten() {
+                pending_calls[caller.0] -= 1;
+                updated.push(*caller);
+            }
+        }
+        queue.extend(
+            updated
+                .into_iter()
+                .filter(|idx| has_returning_target[idx.0] && pending_calls[idx.0] == 0),
+        );
+    }
+    returning
+}
+
+/// Returns the functions ordered such that every function follows the functions it calls, except
+/// for the functions it is mutually recursive with.
+fn callee_first_order(
+    callees: &OrderedHashMap<FunctionId, OrderedHashSet<FunctionId>>,
+) -> Vec<FunctionId> {
+    let mut visited = HashSet::new();
+    let mut order = vec![];
+    for function_id in callees.keys() {
+        // A depth first search, adding every function after visiting its callees.
+        let mut stack = vec![(function_id.clone(), false)];
+        while let Some((function_id, callees_visited)) = stack.pop() {
+            if callees_visited {
+                order.push(function_id);
+                continue;
+            }
+            if !visited.insert(function_id.clone()) {
+                continue;
+            }
+            stack.push((function_id.clone(), true));
+            for callee in callees[function_id].iter().rev() {
+                if !visited.contains(callee) {
+                    stack.push((callee.clone(), false));
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Returns a program containing only the given functions, the functions they call (directly or
+/// indirectly), and the statements reachable from them.
+fn subprogram(
+    program: &Program,
+    roots: &[FunctionId],
+    callees: &OrderedHashMap<FunctionId, OrderedHashSet<FunctionId>>,
+    called_functions: &OrderedHashMap<ConcreteLibfuncId, Vec<FunctionId>>,
+) -> Program {
+    let mut function_ids = OrderedHashSet::<FunctionId>::default();
+    let mut queue: VecDeque<FunctionId> = roots.iter().cloned().collect();
+    while let Some(function_id) = queue.pop_front() {
+        if function_ids.insert(function_id.clone()) {
+            queue.extend(callees[function_id].iter().cloned());
+        }
+    }
+    let entry_points = program
+        .funcs
+        .iter()
+        .filter(|func| function_ids.contains(&func.id))
+        .map(|func| func.entry_point);
+    // Since the statements are reachable, a statement continuing to the next statement is always
+    // followed by it in the new program as well.
+    let statements = reachable_statements(program, entry_points)
+        .into_iter()
+        .sorted_by_key(|idx| idx.0)
+        .collect_vec();
+    let new_idx = |idx: &StatementIdx| {
+        StatementIdx(statements.binary_search_by_key(&idx.0, |idx| idx.0).unwrap())
+    };
+    Program {
+        type_declarations: program.type_declarations.clone(),
+        // Libfuncs of functions outside of the subprogram can't be specialized.
+        libfunc_declarations: program
+            .libfunc_declarations
+            .iter()
+            .filter(|declaration| {
+                called_functions[declaration.id.clone()]
+                    .iter()
+                    .all(|function_id| function_ids.contains(function_id))
+            })
+            .cloned()
+            .collect(),
+        statements: statements
+            .iter()
+            .map(|idx| match &program.statements[idx.0] {
+                Statement::Invocation(invocation) => {
+                    let mut invocation = invocation.clone();
+                    for branch in &mut invocation.branches {
+                        if let BranchTarget::Statement(target) = &mut branch.target {
+                            *target = new_idx(target);
+                        }
+                    }
+                    Statement::Invocation(invocation)
+                }
+                statement @ Statement::Return(_) => statement.clone(),
+            })
+            .collect(),
+        funcs: program
+            .funcs
+            .iter()
+            .filter(|func| function_ids.contains(&func.id))
+            .map(|func| {
+                let mut func = func.clone();
+                func.entry_point = new_idx(&func.entry_point);
+                func
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_sierra::program_registry::ProgramRegistryError;
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{check_program, ProgramIssue};
+use crate::test_utils::read_sierra_example_file;
+
+#[test]
+fn test_valid_program() {
+    let program = ProgramParser::new().parse(&read_sierra_example_file("fib_jumps")).unwrap();
+    assert_eq!(check_program(&program, None), vec![]);
+}
+
+#[test_case(indoc! {"
+                type felt252 = felt252;
+                type u128 = u128;
+                type NonZeroBad = NonZero<Missing>;
+
+                libfunc felt252_dup = dup<felt252>;
+                libfunc felt252_drop = drop<felt252>;
+                libfunc bad = no_such_libfunc;
+
+                felt252_dup([1]) -> ([1], [2]);
+                felt252_drop([2]) -> ();
+                return([1]);
+                felt252_drop([1]) -> ();
+                return();
+
+                foo@0([1]: felt252) -> (felt252);
+            "},
+            vec![
+                ProgramIssue::InvalidDeclaration(ProgramRegistryError::TypeSpecialization {
+                    concrete_id: "NonZeroBad".into(),
+                    error: cairo_lang_sierra::extensions::ExtensionError::TypeSpecialization {
+                        type_id: "NonZero".into(),
+                        error:
+                        cairo_lang_sierra::extensions::SpecializationError::MissingTypeInfo(
+                            "Missing".into()
+                        ),
+                    },
+                }),
+                ProgramIssue::InvalidDeclaration(ProgramRegistryError::LibfuncSpecialization {
+                    concrete_id: "bad".into(),
+                    error: cairo_lang_sierra::extensions::ExtensionError::LibfuncSpecialization {
+                        libfunc_id: "no_such_libfunc".into(),
+                        generic_args: vec![],
+                        error: cairo_lang_sierra::extensions::SpecializationError::UnsupportedId(
+                            "no_such_libfunc".into()
+                        ),
+                    },
+                }),
+                ProgramIssue::UnreachableStatements {
+                    start: StatementIdx(3),
+                    end: StatementIdx(4),
+                },
+                ProgramIssue::UnusedType("u128".into()),
+                ProgramIssue::UnusedType("NonZeroBad".into()),
+                ProgramIssue::UnusedLibfunc("bad".into()),
+            ];
+            "invalid declarations and dead code")]
+#[test_case(indoc! {"
+                type felt252 = felt252;
+
+                libfunc felt252_dup = dup<felt252>;
+                libfunc jump = jump;
+
+                felt252_dup([1]) -> ([1]);
+                jump() { 7() };
+                undeclared([1]) -> ([1]);
+                return([1]);
+
+                foo@0([1]: felt252) -> (felt252);
+                bar@9([1]: Undeclared) -> ();
+            "},
+            vec![
+                ProgramIssue::InvalidDeclaration(ProgramRegistryError::MissingType(
+                    "Undeclared".into()
+                )),
+                ProgramIssue::EntryPointOutOfBounds {
+                    function_id: "bar".into(),
+                    entry_point: StatementIdx(9),
+                },
+                ProgramIssue::InvocationMismatch(StatementIdx(0)),
+                ProgramIssue::BranchTargetOutOfBounds {
+                    statement_idx: StatementIdx(1),
+                    target: StatementIdx(7),
+                },
+                ProgramIssue::InvalidDeclaration(ProgramRegistryError::MissingLibfunc(
+                    "undeclared".into()
+                )),
+                ProgramIssue::UnreachableStatements {
+                    start: StatementIdx(2),
+                    end: StatementIdx(3),
+                },
+            ];
+            "invalid statements")]
+fn test_program_issues(program: &str, expected: Vec<ProgramIssue>) {
+    let program = ProgramParser::new().parse(program).unwrap();
+    assert_eq!(check_program(&program, None), expected);
+}
+
+#[test]
+fn test_disallowed_libfuncs() {
+    let program = ProgramParser::new().parse(&read_sierra_example_file("fib_jumps")).unwrap();
+    let allowed_libfuncs = HashSet::from(["felt252_add".into(), "felt252_sub".into()]);
+    let disallowed = check_program(&program, Some(&allowed_libfuncs))
+        .into_iter()
+        .map(|issue| match issue {
+            ProgramIssue::DisallowedLibfunc { generic_id, .. } => generic_id.to_string(),
+            _ => panic!("Unexpected issue: {issue}"),
+        })
+        .collect::<HashSet<_>>();
+    assert!(!disallowed.is_empty());
+    assert!(!disallowed.contains("felt252_add"));
+    assert!(!disallowed.contains("felt252_sub"));
+}
+
+#[test]
+fn test_unsolvable_functions() {
+    // `countdown` calls itself without ever withdrawing gas, so it (and its caller) can't be
+    // solved, while `id` is solvable even though it is in the same program.
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type NonZeroFelt252 = NonZero<felt252>;
+
+            libfunc branch_align = branch_align;
+            libfunc drop_nz_felt252 = drop<NonZeroFelt252>;
+            libfunc felt252_const_0 = felt252_const<0>;
+            libfunc felt252_is_zero = felt252_is_zero;
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_countdown = function_call<user@countdown>;
+
+            store_temp_felt252([1]) -> ([1]);
+            return([1]);
+            felt252_is_zero([1]) { fallthrough() 7([2]) };
+            branch_align() -> ();
+            felt252_const_0() -> ([1]);
+            store_temp_felt252([1]) -> ([1]);
+            return([1]);
+            branch_align() -> ();
+            drop_nz_felt252([2]) -> ();
+            felt252_const_0() -> ([1]);
+            store_temp_felt252([1]) -> ([1]);
+            call_countdown([1]) -> ([1]);
+            return([1]);
+            store_temp_felt252([1]) -> ([1]);
+            call_countdown([1]) -> ([1]);
+            return([1]);
+
+            id@0([1]: felt252) -> (felt252);
+            countdown@2([1]: felt252) -> (felt252);
+            caller@13([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let issues = check_program(&program, None);
+    let [
+        ProgramIssue::UnsolvableFunction { function_id, error: _ },
+        ProgramIssue::CallsUnsolvableFunction { function_id: caller_id, callee_id },
+    ] = &issues[..] else {
+        panic!("Unexpected issues: {issues:?}");
+    };
+    assert_eq!(
+        [function_id, caller_id, callee_id].map(|id| id.to_string()),
+        ["countdown", "caller", "countdown"]
+    );
+}
+
+#[test]
+fn test_non_returning_functions() {
+    // `recursive` always calls itself, so neither it nor its caller ever return.
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_recursive = function_call<user@recursive>;
+
+            store_temp_felt252([1]) -> ([1]);
+            return([1]);
+            store_temp_felt252([1]) -> ([1]);
+            call_recursive([1]) -> ([1]);
+            return([1]);
+            store_temp_felt252([1]) -> ([1]);
+            call_recursive([1]) -> ([1]);
+            return([1]);
+
+            id@0([1]: felt252) -> (felt252);
+            recursive@2([1]: felt252) -> (felt252);
+            caller@5([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    assert_eq!(
+        check_program(&program, None),
+        vec![
+            ProgramIssue::NonReturningFunction("recursive".into()),
+            ProgramIssue::NonReturningFunction("caller".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_disallowed_libfuncs_and_non_returning_functions() {
+    // The gas and ap changes are checked regardless of the disallowed `function_call`.
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_recursive = function_call<user@recursive>;
+
+            store_temp_felt252([1]) -> ([1]);
+            call_recursive([1]) -> ([1]);
+            return([1]);
+
+            recursive@0([1]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let allowed_libfuncs = HashSet::from(["store_temp".into()]);
+    assert_eq!(
+        check_program(&program, Some(&allowed_libfuncs)),
+        vec![
+            ProgramIssue::DisallowedLibfunc {
+                libfunc_id: "call_recursive".into(),
+                generic_id: "function_call".into(),
+            },
+            ProgramIssue::NonReturningFunction("recursive".into()),
+        ]
+    );
+}
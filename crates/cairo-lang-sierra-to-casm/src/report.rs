@@ -0,0 +1,139 @@
+//! Reports of the compilation of Sierra programs to casm, for tracking the code size and costs of
+//! the compiled functions.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use cairo_lang_sierra::extensions::mem::MemConcreteLibfunc;
+use cairo_lang_sierra::program::{Program, Statement};
+use cairo_lang_sierra::program_registry::ProgramRegistry;
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::compiler::{CairoProgram, CompilationError};
+use crate::metadata::Metadata;
+use crate::type_sizes::get_type_size_map;
+
+#[cfg(test)]
+#[path = "report_test.rs"]
+mod test;
+
+/// The report of the compilation of a single Sierra function.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct FunctionReport {
+    /// The name of the function, or its id if it has no debug name.
+    pub name: String,
+    /// The offset of the code of the function in the bytecode.
+    pub code_offset: usize,
+    /// The number of felt252s of the code of the function in the bytecode.
+    pub bytecode_length: usize,
+    /// The gas the function requires before being called, per cost token.
+    pub gas_costs: BTreeMap<String, i64>,
+    /// The change of `ap` made by the function, or `None` if it is not known at compile time.
+    pub ap_change: Option<usize>,
+    /// The number of memory cells allocated for the local variables of the function.
+    pub locals: usize,
+    /// The names of the functions called by the function.
+    pub called_functions: Vec<String>,
+}
+
+/// The report of the compilation of a Sierra program.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct CompilationReport {
+    /// The reports of the functions of the program, in the order of their code.
+    pub functions: Vec<FunctionReport>,
+}
+impl CompilationReport {
+    /// Creates the report of a program compiled to `cairo_program`, using the metadata it was
+    /// compiled with. The code of a function is assumed to span from its entry point to the entry
+    /// point of the next function, as placed by the Sierra generator.
+    pub fn new(
+        program: &Program,
+        metadata: &Metadata,
+        cairo_program: &CairoProgram,
+    ) -> Result<Self, Box<CompilationError>> {
+        let registry = ProgramRegistry::<CoreType, CoreLibfunc>::with_ap_change(
+            program,
+            metadata.ap_change_info.function_ap_change.clone(),
+        )
+        .map_err(CompilationError::ProgramRegistryError)?;
+        let type_sizes = get_type_size_map(program, &registry)
+            .ok_or(CompilationError::FailedBuildingTypeInformation)?;
+        let statements_info = &cairo_program.debug_info.sierra_statement_info;
+
+        let functions = program.funcs.iter().sorted_by_key(|function| function.entry_point.0);
+        let ends = program
+            .funcs
+            .iter()
+            .map(|function| function.entry_point.0)
+            .sorted()
+            .skip(1)
+            .chain([program.statements.len()]);
+        let mut reports = vec![];
+        for (function, end) in functions.zip(ends) {
+            let mut locals = 0;
+            let mut called_functions = vec![];
+            for statement in &program.statements[function.entry_point.0..end] {
+                let Statement::Invocation(invocation) = statement else { continue; };
+                match registry
+                    .get_libfunc(&invocation.libfunc_id)
+                    .map_err(CompilationError::ProgramRegistryError)?
+                {
+                    CoreConcreteLibfunc::Mem(MemConcreteLibfunc::AllocLocal(libfunc)) => {
+                        locals += type_sizes[&libfunc.ty] as usize;
+                    }
+                    CoreConcreteLibfunc::FunctionCall(libfunc) => {
+                        let name = libfunc.function.id.to_string();
+                        if !called_functions.contains(&name) {
+                            called_functions.push(name);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let code_offset = statements_info[function.entry_point.0].code_offset;
+            reports.push(FunctionReport {
+                name: function.id.to_string(),
+                code_offset,
+                bytecode_length: statements_info[end].code_offset - code_offset,
+                gas_costs: metadata
+                    .gas_info
+                    .function_costs
+                    .get(&function.id)
+                    .into_iter()
+                    .flat_map(|costs| costs.iter())
+                    .map(|(token_type, cost)| (token_type.name(), *cost))
+                    .collect(),
+                ap_change: metadata.ap_change_info.function_ap_change.get(&function.id).copied(),
+                locals,
+                called_functions,
+            });
+        }
+        Ok(Self { functions: reports })
+    }
+
+    /// Returns the report of the function with the given name.
+    pub fn function(&self, name: &str) -> Option<&FunctionReport> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Returns the number of felt252s of the code of the function with the given name and all the
+    /// functions it may call, directly or indirectly.
+    pub fn reachable_bytecode_length(&self, name: &str) -> usize {
+        let functions: HashMap<_, _> =
+            self.functions.iter().map(|function| (function.name.as_str(), function)).collect();
+        let mut visited = HashSet::from([name]);
+        let mut queue = vec![name];
+        let mut length = 0;
+        while let Some(name) = queue.pop() {
+            let Some(function) = functions.get(name) else { continue; };
+            length += function.bytecode_length;
+            for called_function in &function.called_functions {
+                if visited.insert(called_function.as_str()) {
+                    queue.push(called_function);
+                }
+            }
+        }
+        length
+    }
+}
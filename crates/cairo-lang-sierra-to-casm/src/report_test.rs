@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use cairo_lang_sierra::ProgramParser;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{CompilationReport, FunctionReport};
+use crate::compiler::compile;
+use crate::test_utils::{build_metadata, read_sierra_example_file, strip_comments_and_linebreaks};
+
+#[test]
+fn functions_report() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type UninitializedFelt252 = Uninitialized<felt252>;
+
+            libfunc alloc_local_felt252 = alloc_local<felt252>;
+            libfunc finalize_locals = finalize_locals;
+            libfunc store_local_felt252 = store_local<felt252>;
+            libfunc store_temp_felt252 = store_temp<felt252>;
+            libfunc call_foo = function_call<user@foo>;
+
+            store_temp_felt252([0]) -> ([0]);
+            call_foo([0]) -> ([1]);
+            return([1]);
+            alloc_local_felt252() -> ([1]);
+            finalize_locals() -> ();
+            store_local_felt252([1], [0]) -> ([0]);
+            store_temp_felt252([0]) -> ([0]);
+            return([0]);
+
+            main@0([0]: felt252) -> (felt252);
+            foo@3([0]: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let metadata = build_metadata(&program, false);
+    let cairo_program = compile(&program, &metadata, false).unwrap();
+    let report = CompilationReport::new(&program, &metadata, &cairo_program).unwrap();
+    assert_eq!(
+        report.functions,
+        vec![
+            FunctionReport {
+                name: "main".into(),
+                code_offset: 0,
+                bytecode_length: 4,
+                gas_costs: BTreeMap::new(),
+                ap_change: Some(5),
+                locals: 0,
+                called_functions: vec!["foo".into()],
+            },
+            FunctionReport {
+                name: "foo".into(),
+                code_offset: 4,
+                bytecode_length: 5,
+                gas_costs: BTreeMap::new(),
+                ap_change: Some(2),
+                locals: 1,
+                called_functions: vec![],
+            },
+        ]
+    );
+    assert_eq!(report.reachable_bytecode_length("main"), 9);
+    assert_eq!(report.reachable_bytecode_length("foo"), 5);
+}
+
+#[test]
+fn gas_costs_report() {
+    let program = ProgramParser::new()
+        .parse(&strip_comments_and_linebreaks(&read_sierra_example_file("fib_jumps")))
+        .unwrap();
+    let metadata = build_metadata(&program, true);
+    let cairo_program = compile(&program, &metadata, true).unwrap();
+    let report = CompilationReport::new(&program, &metadata, &cairo_program).unwrap();
+    let [function] = &report.functions[..] else { panic!("Expected a single function."); };
+    assert_eq!(function.name, "Fibonacci");
+    assert_eq!(function.gas_costs, BTreeMap::from([("const".into(), 1470)]));
+    assert_eq!(
+        function.bytecode_length,
+        cairo_program.instructions.iter().map(|instruction| instruction.body.op_size()).sum()
+    );
+}
@@ -0,0 +1,475 @@
+//! A compact, versioned binary encoding of Sierra programs, and a felt252 encoding based on it.
+//!
+//! The binary encoding starts with [BINARY_MAGIC] followed by the format version and a table of all
+//! the strings used by the program, which are then referred to by index. It keeps all the
+//! information of the program - including ids and debug names - so decoding an encoded program
+//! results in the same program.
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use num_bigint::{BigInt, BigUint, Sign};
+use smol_str::SmolStr;
+use thiserror::Error;
+
+use crate::ids::{
+    ConcreteLibfuncId, ConcreteTypeId, FunctionId, GenericLibfuncId, GenericTypeId, UserTypeId,
+    VarId,
+};
+use crate::program::{
+    BranchInfo, BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, DeclaredTypeInfo,
+    Function, FunctionSignature, GenericArg, Invocation, LibfuncDeclaration, Param, Program,
+    Statement, StatementIdx, TypeDeclaration,
+};
+
+#[cfg(test)]
+#[path = "binary_serde_test.rs"]
+mod test;
+
+/// The prefix of every binary encoded program. Starts with a NUL so it is never valid Sierra text.
+pub const BINARY_MAGIC: &[u8; 4] = b"\0SRA";
+/// The current version of the binary encoding.
+pub const BINARY_FORMAT_VERSION: u64 = 1;
+/// The number of bytes packed into every felt252 of the felt252 encoding.
+const BYTES_PER_FELT: usize = 31;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum BinarySerdeError {
+    #[error("Input is not a binary Sierra program.")]
+    InvalidMagic,
+    #[error("Unsupported binary Sierra format version {0}.")]
+    UnsupportedVersion(u64),
+    #[error("Unexpected end of input.")]
+    UnexpectedEnd,
+    #[error("Unexpected data after the end of the program.")]
+    TrailingData,
+    #[error("Invalid value for deserialization.")]
+    InvalidValue,
+}
+
+/// Returns true if the given bytes look like a binary encoded program.
+pub fn is_binary_program(bytes: &[u8]) -> bool {
+    bytes.starts_with(BINARY_MAGIC)
+}
+
+/// Serializes a Sierra program into its binary encoding.
+pub fn program_to_bytes(program: &Program) -> Vec<u8> {
+    let mut body = Writer::default();
+    program.serialize(&mut body);
+    // The strings table is only known after serializing the program, but is required before it
+    // for deserialization.
+    let mut output = Writer { bytes: BINARY_MAGIC.to_vec(), strings: Default::default() };
+    BINARY_FORMAT_VERSION.serialize(&mut output);
+    body.strings.len().serialize(&mut output);
+    for s in body.strings.keys() {
+        s.len().serialize(&mut output);
+        output.bytes.extend_from_slice(s.as_bytes());
+    }
+    output.bytes.extend(body.bytes);
+    output.bytes
+}
+
+/// Deserializes a Sierra program from its binary encoding.
+pub fn program_from_bytes(bytes: &[u8]) -> Result<Program, BinarySerdeError> {
+    let bytes = bytes.strip_prefix(BINARY_MAGIC).ok_or(BinarySerdeError::InvalidMagic)?;
+    let mut input = Reader { bytes, strings: vec![] };
+    let version = u64::deserialize(&mut input)?;
+    if version != BINARY_FORMAT_VERSION {
+        return Err(BinarySerdeError::UnsupportedVersion(version));
+    }
+    let size = usize::deserialize(&mut input)?;
+    for _ in 0..size {
+        let len = usize::deserialize(&mut input)?;
+        let s = std::str::from_utf8(input.read_bytes(len)?)
+            .map_err(|_| BinarySerdeError::InvalidValue)?;
+        input.strings.push(s.into());
+    }
+    let program = Program::deserialize(&mut input)?;
+    if !input.bytes.is_empty() {
+        return Err(BinarySerdeError::TrailingData);
+    }
+    Ok(program)
+}
+
+/// Serializes a Sierra program into felt252s - the number of bytes of the binary encoding, followed
+/// by the bytes packed in big-endian chunks of 31 bytes.
+pub fn program_to_felt252s(program: &Program) -> Vec<BigUint> {
+    let bytes = program_to_bytes(program);
+    let mut felts = vec![BigUint::from(bytes.len())];
+    felts.extend(bytes.chunks(BYTES_PER_FELT).map(BigUint::from_bytes_be));
+    felts
+}
+
+/// Deserializes a Sierra program from felt252s created by [program_to_felt252s].
+pub fn program_from_felt252s(felts: &[BigUint]) -> Result<Program, BinarySerdeError> {
+    let (len, chunks) = felts.split_first().ok_or(BinarySerdeError::UnexpectedEnd)?;
+    let len: usize = len.try_into().map_err(|_| BinarySerdeError::InvalidValue)?;
+    // The chunks should exactly fit the bytes, with the last one possibly partial.
+    let capacity = chunks.len() * BYTES_PER_FELT;
+    if capacity < len || capacity >= len + BYTES_PER_FELT {
+        return Err(BinarySerdeError::InvalidValue);
+    }
+    let mut bytes = Vec::with_capacity(len);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let chunk_len = std::cmp::min(BYTES_PER_FELT, len - i * BYTES_PER_FELT);
+        let chunk_bytes = chunk.to_bytes_be();
+        if chunk_bytes.len() > chunk_len {
+            return Err(BinarySerdeError::InvalidValue);
+        }
+        // Restoring the leading zeros dropped by the conversion into a number.
+        bytes.resize(bytes.len() + chunk_len - chunk_bytes.len(), 0);
+        bytes.extend(chunk_bytes);
+    }
+    program_from_bytes(&bytes)
+}
+
+/// Trait for serializing and deserializing into the binary encoding.
+trait BinarySerde: Sized {
+    fn serialize(&self, output: &mut Writer);
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError>;
+}
+
+/// The output of the serialization.
+#[derive(Default)]
+struct Writer {
+    /// The serialized bytes.
+    bytes: Vec<u8>,
+    /// The strings used so far, mapped to their index in the strings table.
+    strings: OrderedHashMap<SmolStr, usize>,
+}
+
+/// The input of the deserialization.
+struct Reader<'a> {
+    /// The bytes yet to be deserialized.
+    bytes: &'a [u8],
+    /// The strings table.
+    strings: Vec<SmolStr>,
+}
+impl<'a> Reader<'a> {
+    /// Reads a single byte.
+    fn read_byte(&mut self) -> Result<u8, BinarySerdeError> {
+        let (head, tail) = self.bytes.split_first().ok_or(BinarySerdeError::UnexpectedEnd)?;
+        self.bytes = tail;
+        Ok(*head)
+    }
+
+    /// Reads `len` bytes.
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], BinarySerdeError> {
+        if self.bytes.len() < len {
+            return Err(BinarySerdeError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+}
+
+// Impls for basic types.
+
+/// Unsigned numbers are encoded in LEB128 - 7 bits per byte, with the high bit marking that more
+/// bytes follow.
+impl BinarySerde for u64 {
+    fn serialize(&self, output: &mut Writer) {
+        let mut value = *self;
+        while value >= 0x80 {
+            output.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        output.bytes.push(value as u8);
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = input.read_byte()?;
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(BinarySerdeError::InvalidValue);
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(BinarySerdeError::InvalidValue)
+    }
+}
+
+impl BinarySerde for usize {
+    fn serialize(&self, output: &mut Writer) {
+        (*self as u64).serialize(output)
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        u64::deserialize(input)?.try_into().map_err(|_| BinarySerdeError::InvalidValue)
+    }
+}
+
+/// Signed numbers are zigzag encoded, so small negative numbers are encoded in a single byte.
+impl BinarySerde for i16 {
+    fn serialize(&self, output: &mut Writer) {
+        let value = i64::from(*self);
+        (((value << 1) ^ (value >> 63)) as u64).serialize(output)
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let value = u64::deserialize(input)?;
+        (((value >> 1) as i64) ^ -((value & 1) as i64))
+            .try_into()
+            .map_err(|_| BinarySerdeError::InvalidValue)
+    }
+}
+
+impl BinarySerde for bool {
+    fn serialize(&self, output: &mut Writer) {
+        output.bytes.push(u8::from(*self));
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        match input.read_byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(BinarySerdeError::InvalidValue),
+        }
+    }
+}
+
+/// Strings are encoded as their index in the strings table.
+impl BinarySerde for SmolStr {
+    fn serialize(&self, output: &mut Writer) {
+        let next_index = output.strings.len();
+        let index = *output.strings.entry(self.clone()).or_insert(next_index);
+        index.serialize(output);
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let index = usize::deserialize(input)?;
+        input.strings.get(index).cloned().ok_or(BinarySerdeError::InvalidValue)
+    }
+}
+
+impl BinarySerde for BigUint {
+    fn serialize(&self, output: &mut Writer) {
+        let bytes = self.to_bytes_be();
+        bytes.len().serialize(output);
+        output.bytes.extend(bytes);
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let len = usize::deserialize(input)?;
+        Ok(BigUint::from_bytes_be(input.read_bytes(len)?))
+    }
+}
+
+impl BinarySerde for BigInt {
+    fn serialize(&self, output: &mut Writer) {
+        (self.sign() == Sign::Minus).serialize(output);
+        self.magnitude().serialize(output);
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let negative = bool::deserialize(input)?;
+        let magnitude = BigUint::deserialize(input)?;
+        Ok(BigInt::from_biguint(if negative { Sign::Minus } else { Sign::Plus }, magnitude))
+    }
+}
+
+impl<T: BinarySerde> BinarySerde for Option<T> {
+    fn serialize(&self, output: &mut Writer) {
+        self.is_some().serialize(output);
+        if let Some(value) = self {
+            value.serialize(output);
+        }
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        Ok(if bool::deserialize(input)? { Some(T::deserialize(input)?) } else { None })
+    }
+}
+
+impl<T: BinarySerde> BinarySerde for Vec<T> {
+    fn serialize(&self, output: &mut Writer) {
+        self.len().serialize(output);
+        for e in self {
+            e.serialize(output);
+        }
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        let size = usize::deserialize(input)?;
+        // Not trusting the size for the allocation, as every element takes at least a byte.
+        let mut result = Vec::with_capacity(std::cmp::min(size, input.bytes.len()));
+        for _ in 0..size {
+            result.push(T::deserialize(input)?);
+        }
+        Ok(result)
+    }
+}
+
+// Impls for ids.
+
+macro_rules! generic_id_serde {
+    ($Obj:ident) => {
+        impl BinarySerde for $Obj {
+            fn serialize(&self, output: &mut Writer) {
+                self.0.serialize(output)
+            }
+
+            fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+                Ok(Self(SmolStr::deserialize(input)?))
+            }
+        }
+    };
+}
+
+generic_id_serde!(GenericTypeId);
+generic_id_serde!(GenericLibfuncId);
+
+/// Ids are encoded as a tag followed by their content - as ids created from a name only require the
+/// name.
+macro_rules! id_serde {
+    ($Obj:ident) => {
+        impl BinarySerde for $Obj {
+            fn serialize(&self, output: &mut Writer) {
+                match &self.debug_name {
+                    None => {
+                        output.bytes.push(0);
+                        self.id.serialize(output);
+                    }
+                    Some(name) if $Obj::from_string(name.clone()).id == self.id => {
+                        output.bytes.push(1);
+                        name.serialize(output);
+                    }
+                    Some(name) => {
+                        output.bytes.push(2);
+                        self.id.serialize(output);
+                        name.serialize(output);
+                    }
+                }
+            }
+
+            fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+                match input.read_byte()? {
+                    0 => Ok(Self::new(u64::deserialize(input)?)),
+                    1 => Ok(Self::from_string(SmolStr::deserialize(input)?)),
+                    2 => Ok(Self {
+                        id: u64::deserialize(input)?,
+                        debug_name: Some(SmolStr::deserialize(input)?),
+                    }),
+                    _ => Err(BinarySerdeError::InvalidValue),
+                }
+            }
+        }
+    };
+}
+
+id_serde!(ConcreteTypeId);
+id_serde!(ConcreteLibfuncId);
+id_serde!(VarId);
+id_serde!(FunctionId);
+
+impl BinarySerde for UserTypeId {
+    fn serialize(&self, output: &mut Writer) {
+        self.id.serialize(output);
+        self.debug_name.serialize(output);
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        Ok(Self { id: BigUint::deserialize(input)?, debug_name: Option::deserialize(input)? })
+    }
+}
+
+// Impls for structs.
+
+macro_rules! struct_serde {
+    ($Obj:ident { $($field_name:ident),* $(,)? }) => {
+        impl BinarySerde for $Obj {
+            fn serialize(&self, output: &mut Writer) {
+                $(self.$field_name.serialize(output);)*
+            }
+
+            fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+                $(let $field_name = BinarySerde::deserialize(input)?;)*
+                Ok(Self { $($field_name),* })
+            }
+        }
+    };
+}
+
+struct_serde! { Program { type_declarations, libfunc_declarations, statements, funcs } }
+struct_serde! { TypeDeclaration { id, long_id, declared_type_info } }
+struct_serde! { DeclaredTypeInfo { storable, droppable, duplicatable, size } }
+struct_serde! { ConcreteTypeLongId { generic_id, generic_args } }
+struct_serde! { LibfuncDeclaration { id, long_id } }
+struct_serde! { ConcreteLibfuncLongId { generic_id, generic_args } }
+struct_serde! { Function { id, signature, params, entry_point } }
+struct_serde! { FunctionSignature { param_types, ret_types } }
+struct_serde! { Param { id, ty } }
+struct_serde! { Invocation { libfunc_id, args, branches } }
+struct_serde! { BranchInfo { target, results } }
+
+impl BinarySerde for StatementIdx {
+    fn serialize(&self, output: &mut Writer) {
+        self.0.serialize(output)
+    }
+
+    fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+        Ok(Self(usize::deserialize(input)?))
+    }
+}
+
+// Impls for enums.
+
+macro_rules! enum_serde {
+    ($Obj:ident { $($variant_name:ident $(($variant_type:ty))? = $variant_id:literal),* $(,)? }) => {
+        impl BinarySerde for $Obj {
+            fn serialize(&self, output: &mut Writer) {
+                match self {
+                    $(enum_serde!(@pattern $Obj $variant_name value $($variant_type)?) => {
+                        output.bytes.push($variant_id);
+                        $(<$variant_type>::serialize(value, output);)?
+                    })*
+                }
+            }
+
+            fn deserialize(input: &mut Reader<'_>) -> Result<Self, BinarySerdeError> {
+                match input.read_byte()? {
+                    $($variant_id => Ok(
+                        enum_serde!(@value $Obj $variant_name input $($variant_type)?)
+                    ),)*
+                    _ => Err(BinarySerdeError::InvalidValue),
+                }
+            }
+        }
+    };
+    (@pattern $Obj:ident $variant_name:ident $value:ident $variant_type:ty) => {
+        $Obj::$variant_name($value)
+    };
+    (@pattern $Obj:ident $variant_name:ident $value:ident) => { $Obj::$variant_name };
+    (@value $Obj:ident $variant_name:ident $input:ident $variant_type:ty) => {
+        $Obj::$variant_name(<$variant_type>::deserialize($input)?)
+    };
+    (@value $Obj:ident $variant_name:ident $input:ident) => { $Obj::$variant_name };
+}
+
+enum_serde! {
+    Statement {
+        Invocation(Invocation) = 0,
+        Return(Vec::<VarId>) = 1,
+    }
+}
+
+enum_serde! {
+    GenericArg {
+        UserType(UserTypeId) = 0,
+        Type(ConcreteTypeId) = 1,
+        Value(BigInt) = 2,
+        UserFunc(FunctionId) = 3,
+        Libfunc(ConcreteLibfuncId) = 4,
+    }
+}
+
+enum_serde! {
+    BranchTarget {
+        Fallthrough = 0,
+        Statement(StatementIdx) = 1,
+    }
+}
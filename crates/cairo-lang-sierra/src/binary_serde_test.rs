@@ -0,0 +1,96 @@
+use indoc::indoc;
+use num_bigint::BigUint;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use test_log::test;
+
+use super::{
+    is_binary_program, program_from_bytes, program_from_felt252s, program_to_bytes,
+    program_to_felt252s, BinarySerdeError, BINARY_MAGIC,
+};
+use crate::program::Program;
+use crate::ProgramParser;
+
+fn get_example_program(name: &str) -> Program {
+    let path = [env!("CARGO_MANIFEST_DIR"), "examples", &format!("{name}.sierra")]
+        .iter()
+        .collect::<std::path::PathBuf>();
+    ProgramParser::new().parse(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn get_generic_args_program() -> Program {
+    ProgramParser::new()
+        .parse(indoc! {"
+            type u128 = u128 with_info {
+                storable: true, droppable: true, duplicatable: true, size: 1,
+            };
+            type NegativeSize = u128 with_info {
+                storable: false, droppable: false, duplicatable: false, size: -300,
+            };
+            type Option = Enum<ut@core::option::Option::<u128>, u128, Unit>;
+            type Unit = Struct<ut@Tuple>;
+            type Large = Large<12345678901234567890123456789012345678901234567890, -7>;
+
+            libfunc call_foo = function_call<user@foo>;
+            libfunc apply = apply<call_foo, u128>;
+
+            call_foo() -> ();
+            apply([0]) { fallthrough([1]) 0([0], [1]) };
+            return([1]);
+
+            foo@0() -> ();
+            bar@1([0]: u128) -> (u128);
+        "})
+        .unwrap()
+}
+
+#[test_case(get_example_program("fib_jumps"); "fib_jumps")]
+#[test_case(get_example_program("fib_no_gas"); "fib_no_gas")]
+#[test_case(get_generic_args_program(); "generic args")]
+fn test_round_trip(program: Program) {
+    let bytes = program_to_bytes(&program);
+    assert!(is_binary_program(&bytes));
+    let decoded = program_from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, program);
+    // Debug names are ignored by the comparison of ids, but are part of the printed program.
+    assert_eq!(decoded.to_string(), program.to_string());
+
+    let felts = program_to_felt252s(&program);
+    assert!(felts.iter().all(|felt| felt.bits() <= 248));
+    let decoded = program_from_felt252s(&felts).unwrap();
+    assert_eq!(decoded.to_string(), program.to_string());
+}
+
+#[test]
+fn test_binary_is_smaller_than_text() {
+    let program = get_example_program("fib_jumps");
+    let (binary, text) = (program_to_bytes(&program).len(), program.to_string().len());
+    assert!(binary < text, "{binary} >= {text}");
+}
+
+#[test_case(b"type felt252 = felt252;", BinarySerdeError::InvalidMagic; "text program")]
+#[test_case(b"\0SRA\x02", BinarySerdeError::UnsupportedVersion(2); "unsupported version")]
+#[test_case(b"\0SRA\x01\x01", BinarySerdeError::UnexpectedEnd; "truncated")]
+#[test_case(b"\0SRA\x01\0\0\0\0\0\0", BinarySerdeError::TrailingData; "trailing data")]
+#[test_case(b"\0SRA\x01\0\0\0\x01\x07", BinarySerdeError::InvalidValue; "invalid statement kind")]
+#[test_case(
+    b"\0SRA\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01",
+    BinarySerdeError::InvalidValue;
+    "version overflow"
+)]
+fn test_invalid_bytes(bytes: &[u8], error: BinarySerdeError) {
+    assert_eq!(program_from_bytes(bytes), Err(error));
+}
+
+#[test]
+fn test_invalid_felts() {
+    let mut felts = program_to_felt252s(&get_example_program("fib_jumps"));
+    assert_eq!(felts[1].to_bytes_be()[..BINARY_MAGIC.len() - 1], BINARY_MAGIC[1..]);
+    felts.pop();
+    assert_eq!(program_from_felt252s(&felts), Err(BinarySerdeError::InvalidValue));
+    assert_eq!(program_from_felt252s(&[]), Err(BinarySerdeError::UnexpectedEnd));
+    assert_eq!(
+        program_from_felt252s(&[BigUint::from(1u8), BigUint::from(256u32)]),
+        Err(BinarySerdeError::InvalidValue)
+    );
+}
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::debug_info::DebugInfo;
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::extensions::lib_func::ConcreteLibfunc;
+use crate::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
+use crate::program::{
+    BranchInfo, BranchTarget, Function, GenericArg, Invocation, Program, Statement, StatementIdx,
+};
+use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
+
+#[cfg(test)]
+#[path = "dead_code_elimination_test.rs"]
+mod test;
+
+/// A program after the elimination of its dead code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadCodeElimination {
+    /// The program, containing only the code reachable from the requested functions.
+    pub program: Program,
+    /// The index of every statement of the original program in the new program, or `None` if the
+    /// statement was removed.
+    pub statement_map: Vec<Option<StatementIdx>>,
+}
+impl DeadCodeElimination {
+    /// Returns the index in the new program of a statement of the original program, if kept.
+    pub fn map_statement(&self, idx: StatementIdx) -> Option<StatementIdx> {
+        self.statement_map.get(idx.0).copied().flatten()
+    }
+
+    /// Returns the debug info of the original program, without the names of removed ids.
+    pub fn map_debug_info(&self, debug_info: &DebugInfo) -> DebugInfo {
+        let types: HashSet<_> = self.program.type_declarations.iter().map(|d| &d.id).collect();
+        let libfuncs: HashSet<_> =
+            self.program.libfunc_declarations.iter().map(|d| &d.id).collect();
+        let funcs: HashSet<_> = self.program.funcs.iter().map(|f| &f.id).collect();
+        DebugInfo {
+            type_names: debug_info
+                .type_names
+                .iter()
+                .filter(|(id, _)| types.contains(id))
+                .map(|(id, name)| (id.clone(), name.clone()))
+                .collect(),
+            libfunc_names: debug_info
+                .libfunc_names
+                .iter()
+                .filter(|(id, _)| libfuncs.contains(id))
+                .map(|(id, name)| (id.clone(), name.clone()))
+                .collect(),
+            user_func_names: debug_info
+                .user_func_names
+                .iter()
+                .filter(|(id, _)| funcs.contains(id))
+                .map(|(id, name)| (id.clone(), name.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Removes from the program everything that is not required for running the `roots` functions:
+/// unreachable functions and statements, and unused type and libfunc declarations.
+///
+/// The ids of the remaining declarations and functions are kept as is, as well as their order,
+/// while the statements are compacted - see [DeadCodeElimination::statement_map].
+pub fn eliminate_dead_code(
+    program: &Program,
+    roots: &[FunctionId],
+) -> Result<DeadCodeElimination, Box<ProgramRegistryError>> {
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(program)?;
+    let libfunc_args: HashMap<&ConcreteLibfuncId, &Vec<GenericArg>> = program
+        .libfunc_declarations
+        .iter()
+        .map(|declaration| (&declaration.id, &declaration.long_id.generic_args))
+        .collect();
+    let type_args: HashMap<&ConcreteTypeId, &Vec<GenericArg>> = program
+        .type_declarations
+        .iter()
+        .map(|declaration| (&declaration.id, &declaration.long_id.generic_args))
+        .collect();
+
+    // Finding the reachable functions and statements.
+    let mut used_funcs: HashSet<FunctionId> = HashSet::new();
+    let mut used_libfuncs: HashSet<ConcreteLibfuncId> = HashSet::new();
+    let mut reachable = vec![false; program.statements.len()];
+    let mut pending_funcs: VecDeque<FunctionId> = roots.iter().cloned().collect();
+    while let Some(function_id) = pending_funcs.pop_front() {
+        if !used_funcs.insert(function_id.clone()) {
+            continue;
+        }
+        let mut pending_statements = vec![registry.get_function(&function_id)?.entry_point];
+        while let Some(idx) = pending_statements.pop() {
+            if idx.0 >= reachable.len() || reachable[idx.0] {
+                continue;
+            }
+            reachable[idx.0] = true;
+            let Statement::Invocation(invocation) = &program.statements[idx.0] else {
+                continue;
+            };
+            pending_statements
+                .extend(invocation.branches.iter().map(|branch| idx.next(&branch.target)));
+            let mut pending_libfuncs = vec![invocation.libfunc_id.clone()];
+            while let Some(libfunc_id) = pending_libfuncs.pop() {
+                if !used_libfuncs.insert(libfunc_id.clone()) {
+                    continue;
+                }
+                for arg in libfunc_args.get(&libfunc_id).into_iter().copied().flatten() {
+                    match arg {
+                        GenericArg::UserFunc(function_id) => {
+                            pending_funcs.push_back(function_id.clone())
+                        }
+                        GenericArg::Libfunc(libfunc_id) => {
+                            pending_libfuncs.push(libfunc_id.clone())
+                        }
+                        GenericArg::Type(_) | GenericArg::UserType(_) | GenericArg::Value(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    // Finding the used types - the ones used by the functions and the libfuncs, and the ones used
+    // by them in turn.
+    let mut pending_types: Vec<ConcreteTypeId> = vec![];
+    for func in program.funcs.iter().filter(|func| used_funcs.contains(&func.id)) {
+        pending_types.extend(func.signature.param_types.iter().cloned());
+        pending_types.extend(func.signature.ret_types.iter().cloned());
+        pending_types.extend(func.params.iter().map(|param| param.ty.clone()));
+    }
+    for declaration in program
+        .libfunc_declarations
+        .iter()
+        .filter(|declaration| used_libfuncs.contains(&declaration.id))
+    {
+        pending_types.extend(generic_arg_types(&declaration.long_id.generic_args));
+        let libfunc = registry.get_libfunc(&declaration.id)?;
+        pending_types.extend(libfunc.param_signatures().iter().map(|param| param.ty.clone()));
+        pending_types.extend(libfunc.output_types().into_iter().flatten());
+    }
+    let mut used_types: HashSet<ConcreteTypeId> = HashSet::new();
+    while let Some(type_id) = pending_types.pop() {
+        if !used_types.insert(type_id.clone()) {
+            continue;
+        }
+        if let Some(generic_args) = type_args.get(&type_id) {
+            pending_types.extend(generic_arg_types(generic_args));
+        }
+    }
+
+    // Compacting the statements.
+    let mut statement_map = vec![None; program.statements.len()];
+    let mut next_idx = 0;
+    for (idx, is_reachable) in reachable.iter().enumerate() {
+        if *is_reachable {
+            statement_map[idx] = Some(StatementIdx(next_idx));
+            next_idx += 1;
+        }
+    }
+    let remap = |idx: StatementIdx| statement_map[idx.0].expect("Reachable statement was removed.");
+    let statements = program
+        .statements
+        .iter()
+        .zip(&reachable)
+        .filter(|(_, is_reachable)| **is_reachable)
+        .map(|(statement, _)| match statement {
+            Statement::Return(vars) => Statement::Return(vars.clone()),
+            Statement::Invocation(invocation) => Statement::Invocation(Invocation {
+                libfunc_id: invocation.libfunc_id.clone(),
+                args: invocation.args.clone(),
+                branches: invocation
+                    .branches
+                    .iter()
+                    .map(|branch| BranchInfo {
+                        target: match branch.target {
+                            BranchTarget::Fallthrough => BranchTarget::Fallthrough,
+                            BranchTarget::Statement(target) => {
+                                BranchTarget::Statement(remap(target))
+                            }
+                        },
+                        results: branch.results.clone(),
+                    })
+                    .collect(),
+            }),
+        })
+        .collect();
+    let funcs = program
+        .funcs
+        .iter()
+        .filter(|func| used_funcs.contains(&func.id))
+        .map(|func| Function { entry_point: remap(func.entry_point), ..func.clone() })
+        .collect();
+
+    Ok(DeadCodeElimination {
+        program: Program {
+            type_declarations: program
+                .type_declarations
+                .iter()
+                .filter(|declaration| used_types.contains(&declaration.id))
+                .cloned()
+                .collect(),
+            libfunc_declarations: program
+                .libfunc_declarations
+                .iter()
+                .filter(|declaration| used_libfuncs.contains(&declaration.id))
+                .cloned()
+                .collect(),
+            statements,
+            funcs,
+        },
+        statement_map,
+    })
+}
+
+/// Returns the types directly used as generic args.
+fn generic_arg_types(generic_args: &[GenericArg]) -> impl Iterator<Item = ConcreteTypeId> + '_ {
+    generic_args.iter().filter_map(|arg| match arg {
+        GenericArg::Type(ty) => Some(ty.clone()),
+        _ => None,
+    })
+}
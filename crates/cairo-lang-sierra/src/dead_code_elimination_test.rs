@@ -0,0 +1,112 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::eliminate_dead_code;
+use crate::debug_info::DebugInfo;
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::program::StatementIdx;
+use crate::program_registry::ProgramRegistry;
+use crate::ProgramParser;
+
+#[test]
+fn eliminate_dead_code_of_program() {
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+            type u128 = u128;
+            type NonZeroFelt252 = NonZero<felt252>;
+            type NonZeroU128 = NonZero<u128>;
+
+            libfunc felt252_is_zero = felt252_is_zero;
+            libfunc felt252_drop = drop<felt252>;
+            libfunc nz_felt252_drop = drop<NonZeroFelt252>;
+            libfunc u128_drop = drop<u128>;
+            libfunc jump = jump;
+            libfunc call_helper = function_call<user@helper>;
+            libfunc call_unused = function_call<user@unused>;
+
+            felt252_is_zero([1]) { fallthrough() 4([2]) };
+            call_helper() -> ();
+            jump() { 7() };
+            u128_drop([1]) -> ();
+            nz_felt252_drop([2]) -> ();
+            return();
+            call_unused() -> ();
+            return();
+            return();
+            call_unused() -> ();
+            return();
+
+            main@0([1]: felt252) -> ();
+            helper@8() -> ();
+            unused@9() -> ();
+        "})
+        .unwrap();
+    let result = eliminate_dead_code(&program, &["main".into()]).unwrap();
+    assert_eq!(
+        result.program,
+        ProgramParser::new()
+            .parse(indoc! {"
+                type felt252 = felt252;
+                type NonZeroFelt252 = NonZero<felt252>;
+
+                libfunc felt252_is_zero = felt252_is_zero;
+                libfunc nz_felt252_drop = drop<NonZeroFelt252>;
+                libfunc jump = jump;
+                libfunc call_helper = function_call<user@helper>;
+
+                felt252_is_zero([1]) { fallthrough() 3([2]) };
+                call_helper() -> ();
+                jump() { 5() };
+                nz_felt252_drop([2]) -> ();
+                return();
+                return();
+                return();
+
+                main@0([1]: felt252) -> ();
+                helper@6() -> ();
+            "})
+            .unwrap()
+    );
+    assert_eq!(
+        result.statement_map,
+        [Some(0), Some(1), Some(2), None, Some(3), Some(4), None, Some(5), Some(6), None, None]
+            .into_iter()
+            .map(|idx| idx.map(StatementIdx))
+            .collect::<Vec<_>>()
+    );
+    ProgramRegistry::<CoreType, CoreLibfunc>::new(&result.program).unwrap();
+
+    let debug_info = result.map_debug_info(&DebugInfo::extract(&program));
+    assert_eq!(debug_info, DebugInfo::extract(&result.program));
+}
+
+#[test]
+fn keep_types_of_libfunc_signatures() {
+    // `u128_overflowing_add` uses `RangeCheck` and `u128` without getting them as generic args.
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type RangeCheck = RangeCheck;
+            type u128 = u128;
+            type felt252 = felt252;
+
+            libfunc u128_overflowing_add = u128_overflowing_add;
+            libfunc u128_drop = drop<u128>;
+
+            u128_overflowing_add([1], [2], [3]) { fallthrough([1], [2]) 3([1], [2]) };
+            u128_drop([2]) -> ();
+            return([1]);
+            u128_drop([2]) -> ();
+            return([1]);
+
+            add@0([1]: RangeCheck, [2]: u128, [3]: u128) -> (RangeCheck);
+        "})
+        .unwrap();
+    let result = eliminate_dead_code(&program, &["add".into()]).unwrap();
+    assert_eq!(
+        result.program.type_declarations.iter().map(|d| d.id.to_string()).collect::<Vec<_>>(),
+        vec!["RangeCheck", "u128"]
+    );
+    assert_eq!(result.program.statements, program.statements);
+}
@@ -1,11 +1,16 @@
 use std::marker::PhantomData;
 
+use super::felt252_span_ty;
 use super::interoperability::ContractAddressType;
+use super::storage::StorageAddressType;
 use crate::define_libfunc_hierarchy;
+use crate::extensions::felt252::Felt252Type;
 use crate::extensions::lib_func::{
-    LibfuncSignature, SierraApChange, SignatureSpecializationContext,
+    LibfuncSignature, OutputVarInfo, OutputVarReferenceInfo, SierraApChange,
+    SignatureSpecializationContext,
 };
 use crate::extensions::uint::Uint64Type;
+use crate::extensions::uint128::Uint128Type;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, SpecializationError,
 };
@@ -95,6 +100,130 @@ impl TestSetterTraits for SetSequencerAddressTrait {
     type ValueType = ContractAddressType;
 }
 
+#[derive(Default)]
+pub struct SetVersionTrait {}
+impl TestSetterTraits for SetVersionTrait {
+    const STR_ID: &'static str = "set_version";
+    type ValueType = Felt252Type;
+}
+
+#[derive(Default)]
+pub struct SetAccountContractAddressTrait {}
+impl TestSetterTraits for SetAccountContractAddressTrait {
+    const STR_ID: &'static str = "set_account_contract_address";
+    type ValueType = ContractAddressType;
+}
+
+#[derive(Default)]
+pub struct SetMaxFeeTrait {}
+impl TestSetterTraits for SetMaxFeeTrait {
+    const STR_ID: &'static str = "set_max_fee";
+    type ValueType = Uint128Type;
+}
+
+#[derive(Default)]
+pub struct SetTransactionHashTrait {}
+impl TestSetterTraits for SetTransactionHashTrait {
+    const STR_ID: &'static str = "set_transaction_hash";
+    type ValueType = Felt252Type;
+}
+
+#[derive(Default)]
+pub struct SetChainIdTrait {}
+impl TestSetterTraits for SetChainIdTrait {
+    const STR_ID: &'static str = "set_chain_id";
+    type ValueType = Felt252Type;
+}
+
+#[derive(Default)]
+pub struct SetNonceTrait {}
+impl TestSetterTraits for SetNonceTrait {
+    const STR_ID: &'static str = "set_nonce";
+    type ValueType = Felt252Type;
+}
+
+#[derive(Default)]
+pub struct SetSignatureTrait {}
+impl TestSetterTraitsEx for SetSignatureTrait {
+    const STR_ID: &'static str = "set_signature";
+
+    fn value_type_id(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<ConcreteTypeId, SpecializationError> {
+        felt252_span_ty(context)
+    }
+}
+
+/// Libfunc for setting the value of a storage address of a given contract.
+#[derive(Default)]
+pub struct SetStorageAtLibfunc {}
+impl NoGenericArgsGenericLibfunc for SetStorageAtLibfunc {
+    const STR_ID: &'static str = "set_storage_at";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // Contract address.
+                context.get_concrete_type(ContractAddressType::id(), &[])?,
+                // Storage address.
+                context.get_concrete_type(StorageAddressType::id(), &[])?,
+                // Value.
+                context.get_concrete_type(Felt252Type::id(), &[])?,
+            ],
+            vec![],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for getting the value of a storage address of a given contract.
+#[derive(Default)]
+pub struct GetStorageAtLibfunc {}
+impl NoGenericArgsGenericLibfunc for GetStorageAtLibfunc {
+    const STR_ID: &'static str = "get_storage_at";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // Contract address.
+                context.get_concrete_type(ContractAddressType::id(), &[])?,
+                // Storage address.
+                context.get_concrete_type(StorageAddressType::id(), &[])?,
+            ],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for resetting the simulated state - the storage of all the contracts and the execution
+/// info.
+#[derive(Default)]
+pub struct ResetStateLibfunc {}
+impl NoGenericArgsGenericLibfunc for ResetStateLibfunc {
+    const STR_ID: &'static str = "reset_state";
+
+    fn specialize_signature(
+        &self,
+        _context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 define_libfunc_hierarchy! {
     pub enum TestingLibfunc {
          SetBlockNumber(TestSetterLibfunc<SetBlockNumberTrait>),
@@ -102,5 +231,15 @@ define_libfunc_hierarchy! {
          SetCallerAddress(TestSetterLibfunc<SetCallerAddressTrait>),
          SetContractAddress(TestSetterLibfunc<SetContractAddressTrait>),
          SetSequencerAddress(TestSetterLibfunc<SetSequencerAddressTrait>),
+         SetVersion(TestSetterLibfunc<SetVersionTrait>),
+         SetAccountContractAddress(TestSetterLibfunc<SetAccountContractAddressTrait>),
+         SetMaxFee(TestSetterLibfunc<SetMaxFeeTrait>),
+         SetTransactionHash(TestSetterLibfunc<SetTransactionHashTrait>),
+         SetChainId(TestSetterLibfunc<SetChainIdTrait>),
+         SetNonce(TestSetterLibfunc<SetNonceTrait>),
+         SetSignature(TestSetterLibfunc<SetSignatureTrait>),
+         SetStorageAt(SetStorageAtLibfunc),
+         GetStorageAt(GetStorageAtLibfunc),
+         ResetState(ResetStateLibfunc),
     }, TestingConcreteLibfunc
 }
@@ -11,6 +11,8 @@
 
 use lalrpop_util::lalrpop_mod;
 
+pub mod binary_serde;
+pub mod dead_code_elimination;
 pub mod debug_info;
 pub mod edit_state;
 pub mod extensions;
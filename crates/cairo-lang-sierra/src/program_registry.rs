@@ -68,8 +68,35 @@ impl<TType: GenericType, TLibfunc: GenericLibfunc> ProgramRegistry<TType, TLibfu
         program: &Program,
         function_ap_change: OrderedHashMap<FunctionId, usize>,
     ) -> Result<ProgramRegistry<TType, TLibfunc>, Box<ProgramRegistryError>> {
-        let functions = get_functions(program)?;
-        let (concrete_types, concrete_type_ids) = get_concrete_types_maps::<TType>(program)?;
+        let (registry, errors) = Self::partial_with_ap_change(program, function_ap_change);
+        match errors.into_iter().next() {
+            Some(error) => Err(Box::new(error)),
+            None => Ok(registry),
+        }
+    }
+
+    pub fn new(
+        program: &Program,
+    ) -> Result<ProgramRegistry<TType, TLibfunc>, Box<ProgramRegistryError>> {
+        Self::with_ap_change(program, Default::default())
+    }
+
+    /// Create a registry for the program, skipping the declarations that fail to be registered.
+    /// Returns the registry alongside the errors of all the skipped declarations, in program order.
+    pub fn new_partial(
+        program: &Program,
+    ) -> (ProgramRegistry<TType, TLibfunc>, Vec<ProgramRegistryError>) {
+        Self::partial_with_ap_change(program, Default::default())
+    }
+
+    fn partial_with_ap_change(
+        program: &Program,
+        function_ap_change: OrderedHashMap<FunctionId, usize>,
+    ) -> (ProgramRegistry<TType, TLibfunc>, Vec<ProgramRegistryError>) {
+        let mut errors = vec![];
+        let functions = get_functions(program, &mut errors);
+        let (concrete_types, concrete_type_ids) =
+            get_concrete_types_maps::<TType>(program, &mut errors);
         let concrete_libfuncs = get_concrete_libfuncs::<TType, TLibfunc>(
             program,
             &SpecializationContextForRegistry {
@@ -78,14 +105,9 @@ impl<TType: GenericType, TLibfunc: GenericLibfunc> ProgramRegistry<TType, TLibfu
                 concrete_types: &concrete_types,
                 function_ap_change,
             },
-        )?;
-        Ok(ProgramRegistry { functions, concrete_types, concrete_libfuncs })
-    }
-
-    pub fn new(
-        program: &Program,
-    ) -> Result<ProgramRegistry<TType, TLibfunc>, Box<ProgramRegistryError>> {
-        Self::with_ap_change(program, Default::default())
+            &mut errors,
+        );
+        (ProgramRegistry { functions, concrete_types, concrete_libfuncs }, errors)
     }
     /// Gets a function from the input program.
     pub fn get_function<'a>(
@@ -116,18 +138,20 @@ impl<TType: GenericType, TLibfunc: GenericLibfunc> ProgramRegistry<TType, TLibfu
     }
 }
 
-/// Creates the functions map.
-fn get_functions(program: &Program) -> Result<FunctionMap, Box<ProgramRegistryError>> {
+/// Creates the functions map, adding the errors of the skipped functions to `errors`.
+fn get_functions(program: &Program, errors: &mut Vec<ProgramRegistryError>) -> FunctionMap {
     let mut functions = FunctionMap::new();
     for func in &program.funcs {
         match functions.entry(func.id.clone()) {
             Entry::Occupied(_) => {
-                Err(ProgramRegistryError::FunctionIdAlreadyExists(func.id.clone()))
+                errors.push(ProgramRegistryError::FunctionIdAlreadyExists(func.id.clone()));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(func.clone());
             }
-            Entry::Vacant(entry) => Ok(entry.insert(func.clone())),
-        }?;
+        }
     }
-    Ok(functions)
+    functions
 }
 
 struct TypeSpecializationContextForRegistry<'a, TType: GenericType> {
@@ -146,10 +170,11 @@ impl<TType: GenericType> TypeSpecializationContext
 }
 
 /// Creates the type-id to concrete type map, and the reverse map from generic-id and arguments to
-/// concrete-id.
-fn get_concrete_types_maps<TType: GenericType>(
-    program: &Program,
-) -> Result<(TypeMap<TType::Concrete>, ConcreteTypeIdMap<'_>), Box<ProgramRegistryError>> {
+/// concrete-id, adding the errors of the skipped types to `errors`.
+fn get_concrete_types_maps<'a, TType: GenericType>(
+    program: &'a Program,
+    errors: &mut Vec<ProgramRegistryError>,
+) -> (TypeMap<TType::Concrete>, ConcreteTypeIdMap<'a>) {
     let mut concrete_types = HashMap::new();
     let mut concrete_type_ids = HashMap::<(GenericTypeId, &[GenericArg]), ConcreteTypeId>::new();
     let declared_type_info = program
@@ -166,45 +191,57 @@ fn get_concrete_types_maps<TType: GenericType>(
         })
         .collect();
     for declaration in &program.type_declarations {
-        let concrete_type = TType::specialize_by_id(
+        let concrete_type = match TType::specialize_by_id(
             &TypeSpecializationContextForRegistry::<TType> {
                 concrete_types: &concrete_types,
                 declared_type_info: &declared_type_info,
             },
             &declaration.long_id.generic_id,
             &declaration.long_id.generic_args,
-        )
-        .map_err(|error| {
-            Box::new(ProgramRegistryError::TypeSpecialization {
-                concrete_id: declaration.id.clone(),
-                error,
-            })
-        })?;
+        ) {
+            Ok(concrete_type) => concrete_type,
+            Err(error) => {
+                errors.push(ProgramRegistryError::TypeSpecialization {
+                    concrete_id: declaration.id.clone(),
+                    error,
+                });
+                continue;
+            }
+        };
         // Check that the info is consistent with declaration.
         if let Some(declared_info) = declared_type_info.get(&declaration.id) {
             if concrete_type.info() != declared_info {
-                return Err(Box::new(ProgramRegistryError::TypeInfoDeclarationMismatch(
+                errors.push(ProgramRegistryError::TypeInfoDeclarationMismatch(
                     declaration.id.clone(),
-                )));
+                ));
+                continue;
             }
         }
 
         match concrete_types.entry(declaration.id.clone()) {
-            Entry::Occupied(_) => Err(Box::new(ProgramRegistryError::TypeConcreteIdAlreadyExists(
-                declaration.id.clone(),
-            ))),
-            Entry::Vacant(entry) => Ok(entry.insert(concrete_type)),
-        }?;
+            Entry::Occupied(_) => {
+                errors.push(ProgramRegistryError::TypeConcreteIdAlreadyExists(
+                    declaration.id.clone(),
+                ));
+                continue;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(concrete_type);
+            }
+        }
         match concrete_type_ids
             .entry((declaration.long_id.generic_id.clone(), &declaration.long_id.generic_args[..]))
         {
-            Entry::Occupied(_) => Err(Box::new(ProgramRegistryError::TypeAlreadyDeclared(
-                Box::new(declaration.clone()),
-            ))),
-            Entry::Vacant(entry) => Ok(entry.insert(declaration.id.clone())),
-        }?;
+            Entry::Occupied(_) => {
+                errors
+                    .push(ProgramRegistryError::TypeAlreadyDeclared(Box::new(declaration.clone())));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(declaration.id.clone());
+            }
+        }
     }
-    Ok((concrete_types, concrete_type_ids))
+    (concrete_types, concrete_type_ids)
 }
 
 /// Context required for specialization process.
@@ -257,28 +294,38 @@ impl<TType: GenericType> SpecializationContext for SpecializationContextForRegis
     }
 }
 
-/// Creates the libfuncs map.
+/// Creates the libfuncs map, adding the errors of the skipped libfuncs to `errors`.
 fn get_concrete_libfuncs<TType: GenericType, TLibfunc: GenericLibfunc>(
     program: &Program,
     context: &SpecializationContextForRegistry<'_, TType>,
-) -> Result<LibfuncMap<TLibfunc::Concrete>, Box<ProgramRegistryError>> {
+    errors: &mut Vec<ProgramRegistryError>,
+) -> LibfuncMap<TLibfunc::Concrete> {
     let mut concrete_libfuncs = HashMap::new();
     for declaration in &program.libfunc_declarations {
-        let concrete_libfunc = TLibfunc::specialize_by_id(
+        let concrete_libfunc = match TLibfunc::specialize_by_id(
             context,
             &declaration.long_id.generic_id,
             &declaration.long_id.generic_args,
-        )
-        .map_err(|error| ProgramRegistryError::LibfuncSpecialization {
-            concrete_id: declaration.id.clone(),
-            error,
-        })?;
+        ) {
+            Ok(concrete_libfunc) => concrete_libfunc,
+            Err(error) => {
+                errors.push(ProgramRegistryError::LibfuncSpecialization {
+                    concrete_id: declaration.id.clone(),
+                    error,
+                });
+                continue;
+            }
+        };
         match concrete_libfuncs.entry(declaration.id.clone()) {
             Entry::Occupied(_) => {
-                Err(ProgramRegistryError::LibfuncConcreteIdAlreadyExists(declaration.id.clone()))
+                errors.push(ProgramRegistryError::LibfuncConcreteIdAlreadyExists(
+                    declaration.id.clone(),
+                ));
             }
-            Entry::Vacant(entry) => Ok(entry.insert(concrete_libfunc)),
-        }?;
+            Entry::Vacant(entry) => {
+                entry.insert(concrete_libfunc);
+            }
+        }
     }
-    Ok(concrete_libfuncs)
+    concrete_libfuncs
 }
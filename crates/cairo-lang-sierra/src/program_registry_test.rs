@@ -1,3 +1,4 @@
+use assert_matches::assert_matches;
 use indoc::indoc;
 use test_log::test;
 
@@ -97,3 +98,33 @@ fn libfunc_id_double_declaration() {
         Err(Box::new(ProgramRegistryError::LibfuncConcreteIdAlreadyExists("used_id".into())))
     );
 }
+
+#[test]
+fn partial_registry_collects_all_errors() {
+    let (registry, errors) = ProgramRegistry::<CoreType, CoreLibfunc>::new_partial(
+        &ProgramParser::new()
+            .parse(indoc! {"
+                type u128 = u128;
+                type used_id = GasBuiltin;
+                type used_id = u128;
+                type Bad = NonZero<NoSuchType>;
+                libfunc rename_u128 = rename<u128>;
+                libfunc bad = no_such_libfunc<u128>;
+                Func1@0() -> ();
+                Func1@1() -> ();
+            "})
+            .unwrap(),
+    );
+    assert_matches!(
+        &errors[..],
+        [
+            ProgramRegistryError::FunctionIdAlreadyExists(_),
+            ProgramRegistryError::TypeConcreteIdAlreadyExists(_),
+            ProgramRegistryError::TypeSpecialization { .. },
+            ProgramRegistryError::LibfuncSpecialization { .. },
+        ]
+    );
+    assert!(registry.get_type(&"u128".into()).is_ok());
+    assert!(registry.get_libfunc(&"rename_u128".into()).is_ok());
+    assert!(registry.get_libfunc(&"bad".into()).is_err());
+}
@@ -2,17 +2,20 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use cairo_lang_utils::extract_matches;
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::{BigInt, Sign, ToBigInt};
 use num_traits::{ToPrimitive, Zero};
+use starknet_crypto::{pedersen_hash, poseidon_permute_comp, FieldElement};
 
+use super::starknet::{simulate_starknet_libfunc, StarknetState};
 use super::value::CoreValue;
 use super::LibfuncSimulationError;
 use crate::extensions::array::ArrayConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
+use crate::extensions::casts::{CastConcreteLibfunc, DowncastConcreteLibfunc};
 use crate::extensions::core::CoreConcreteLibfunc::{
-    self, ApTracking, Array, Bitwise, Bool, BranchAlign, Drop, Dup, Ec, Enum, Felt252,
-    FunctionCall, Gas, Mem, Struct, Uint128, Uint16, Uint32, Uint64, Uint8, UnconditionalJump,
-    UnwrapNonZero,
+    self, ApTracking, Array, Bitwise, Bool, BranchAlign, Cast, Drop, Dup, Ec, Enum, Felt252,
+    Felt252Dict, FunctionCall, Gas, Mem, Nullable, Pedersen, Poseidon, StarkNet, Struct, Uint128,
+    Uint16, Uint256, Uint32, Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use crate::extensions::ec::EcConcreteLibfunc;
 use crate::extensions::enm::{EnumConcreteLibfunc, EnumInitConcreteLibfunc};
@@ -22,19 +25,25 @@ use crate::extensions::felt252::{
 };
 use crate::extensions::felt252_dict::Felt252DictConcreteLibfunc;
 use crate::extensions::function_call::FunctionCallConcreteLibfunc;
+use crate::extensions::gas::CostTokenType;
 use crate::extensions::gas::GasConcreteLibfunc::{
     BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
+use crate::extensions::lib_func::SignatureOnlyConcreteLibfunc;
 use crate::extensions::mem::MemConcreteLibfunc::{
     AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
+use crate::extensions::nullable::NullableConcreteLibfunc;
+use crate::extensions::pedersen::PedersenConcreteLibfunc;
+use crate::extensions::poseidon::PoseidonConcreteLibfunc;
 use crate::extensions::structure::StructConcreteLibfunc;
 use crate::extensions::uint::{
     IntOperator, Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete,
     UintConstConcreteLibfunc,
 };
 use crate::extensions::uint128::Uint128Concrete;
-use crate::ids::FunctionId;
+use crate::extensions::uint256::Uint256Concrete;
+use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 
 // TODO(orizi): This def is duplicated.
 /// Returns the Beta value of the Starkware elliptic curve.
@@ -43,32 +52,50 @@ fn get_beta() -> BigInt {
         .unwrap()
 }
 
+/// The cost of a single usage of every builtin, as returned by `get_builtin_costs`. Matches the
+/// dummy costs used by the Cairo runner.
+const BUILTIN_COST: i64 = 10000;
+
+// TODO(orizi): This def is duplicated.
+/// The gas refunded by `felt252_dict_squash` for every access to a key beyond the first one.
+const DICT_SQUASH_UNIQUE_KEY_COST: i64 = 5620;
+
 // TODO(spapini): Proper errors when converting from bigint to u128.
 /// Simulates the run of a single libfunc. Returns the value representations of the outputs, and
 /// the chosen branch given the inputs.
 ///
+/// `get_statement_gas_info` returns the gas variable of the given token type at the current
+/// statement, and `get_type_generic_id` returns the generic id of a concrete type.
 /// `simulate_function` is a function that simulates running of a user function. It is provided here
 /// for the case where the extensions need to use it.
 pub fn simulate<
-    GetStatementGasInfo: Fn() -> Option<i64>,
-    SimulateFunction: Fn(&FunctionId, Vec<CoreValue>) -> Result<Vec<CoreValue>, LibfuncSimulationError>,
+    GetStatementGasInfo: Fn(CostTokenType) -> Option<i64>,
+    GetTypeGenericId: Fn(&ConcreteTypeId) -> Option<GenericTypeId>,
+    SimulateFunction: Fn(
+        &FunctionId,
+        Vec<CoreValue>,
+        &mut StarknetState,
+    ) -> Result<Vec<CoreValue>, LibfuncSimulationError>,
 >(
     libfunc: &CoreConcreteLibfunc,
     inputs: Vec<CoreValue>,
+    starknet_state: &mut StarknetState,
     get_statement_gas_info: GetStatementGasInfo,
+    get_type_generic_id: GetTypeGenericId,
     simulate_function: SimulateFunction,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     match libfunc {
         Bitwise(_) => match &inputs[..] {
-            [CoreValue::Uint128(a), CoreValue::Uint128(b)] => Ok((
+            [CoreValue::Bitwise, CoreValue::Uint128(a), CoreValue::Uint128(b)] => Ok((
                 vec![
+                    CoreValue::Bitwise,
                     CoreValue::Uint128(a & b),
-                    CoreValue::Uint128(a | b),
                     CoreValue::Uint128(a ^ b),
+                    CoreValue::Uint128(a | b),
                 ],
                 0,
             )),
-            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Drop(_) => match &inputs[..] {
@@ -79,33 +106,12 @@ pub fn simulate<
             [value] => Ok((vec![value.clone(), value.clone()], 0)),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Ec(libfunc) => match libfunc {
-            EcConcreteLibfunc::TryNew(_) => match &inputs[..] {
-                [CoreValue::Felt252(x), CoreValue::Felt252(y)] => {
-                    // If the point is on the curve use the fallthrough branch and return the point.
-                    if y * y == x * x * x + x + get_beta() {
-                        Ok((vec![CoreValue::EcPoint(x.clone(), y.clone())], 0))
-                    } else {
-                        Ok((vec![], 1))
-                    }
-                }
-                [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-            },
-            EcConcreteLibfunc::UnwrapPoint(_) => match &inputs[..] {
-                [CoreValue::EcPoint(x, y)] => {
-                    Ok((vec![CoreValue::Felt252(x.clone()), CoreValue::Felt252(y.clone())], 0))
-                }
-                [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-            },
-            _ => unimplemented!(),
-        },
+        Ec(libfunc) => simulate_ec_libfunc(libfunc, &inputs),
         FunctionCall(FunctionCallConcreteLibfunc { function, .. }) => {
-            Ok((simulate_function(&function.id, inputs)?, 0))
+            Ok((simulate_function(&function.id, inputs, starknet_state)?, 0))
         }
         Gas(WithdrawGas(_)) => {
-            let count = get_statement_gas_info()
+            let count = get_statement_gas_info(CostTokenType::Const)
                 .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             let gas_counter = match &inputs[..] {
                 [CoreValue::RangeCheck, CoreValue::GasBuiltin(value)] => Ok(value),
@@ -120,8 +126,50 @@ pub fn simulate<
                 Ok((vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(*gas_counter)], 1))
             }
         }
+        Gas(BuiltinWithdrawGas(_)) => {
+            let (gas_counter, builtin_costs) = match &inputs[..] {
+                [
+                    CoreValue::RangeCheck,
+                    CoreValue::GasBuiltin(gas_counter),
+                    CoreValue::BuiltinCosts(builtin_costs),
+                ] => Ok((gas_counter, builtin_costs)),
+                [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }?;
+            let mut count = get_statement_gas_info(CostTokenType::Const)
+                .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
+            for token_type in CostTokenType::iter_precost() {
+                let token_count = get_statement_gas_info(*token_type)
+                    .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
+                let token_cost = builtin_costs
+                    .get(token_type)
+                    .ok_or(LibfuncSimulationError::MemoryLayoutMismatch)?;
+                count += token_count * token_cost;
+            }
+            if *gas_counter >= count {
+                // Have enough gas - return reduced counter and jump to success branch.
+                Ok((vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0))
+            } else {
+                // Don't have enough gas - return the same counter and jump to failure branch.
+                Ok((vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(*gas_counter)], 1))
+            }
+        }
+        Gas(GetBuiltinCosts(_)) => {
+            if inputs.is_empty() {
+                Ok((
+                    vec![CoreValue::BuiltinCosts(
+                        CostTokenType::iter_precost()
+                            .map(|token_type| (*token_type, BUILTIN_COST))
+                            .collect(),
+                    )],
+                    0,
+                ))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
         Gas(RedepositGas(_)) => {
-            let count = get_statement_gas_info()
+            let count = get_statement_gas_info(CostTokenType::Const)
                 .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             let gas_counter = match &inputs[..] {
                 [CoreValue::GasBuiltin(value)] => Ok(value),
@@ -141,91 +189,12 @@ pub fn simulate<
                 0,
             ))
         }
-        Gas(BuiltinWithdrawGas(_) | GetBuiltinCosts(_)) => {
-            unimplemented!("Simulation of the builtin cost functionality is not implemented yet.")
-        }
         BranchAlign(_) => {
-            get_statement_gas_info().ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
+            get_statement_gas_info(CostTokenType::Const)
+                .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             Ok((vec![], 0))
         }
-        Array(ArrayConcreteLibfunc::New(_)) => {
-            if inputs.is_empty() {
-                Ok((vec![CoreValue::Array(vec![])], 0))
-            } else {
-                Err(LibfuncSimulationError::WrongNumberOfArgs)
-            }
-        }
-        Array(ArrayConcreteLibfunc::Append(_)) => match &inputs[..] {
-            [CoreValue::Array(_), _] => {
-                let mut iter = inputs.into_iter();
-                let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                arr.push(iter.next().unwrap());
-                Ok((vec![CoreValue::Array(arr)], 0))
-            }
-            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
-        Array(ArrayConcreteLibfunc::PopFront(_)) => match &inputs[..] {
-            [CoreValue::Array(_)] => {
-                let mut iter = inputs.into_iter();
-                let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                if arr.is_empty() {
-                    Ok((vec![CoreValue::Array(arr)], 1))
-                } else {
-                    let front = arr.remove(0);
-                    Ok((vec![CoreValue::Array(arr), front], 0))
-                }
-            }
-            [_] => Err(LibfuncSimulationError::WrongArgType),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
-        Array(ArrayConcreteLibfunc::Get(_)) => match &inputs[..] {
-            [CoreValue::RangeCheck, CoreValue::Array(_), CoreValue::Uint64(_)] => {
-                let mut iter = inputs.into_iter();
-                iter.next(); // Ignore range check.
-                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                let idx = extract_matches!(iter.next().unwrap(), CoreValue::Uint64) as usize;
-                match arr.get(idx).cloned() {
-                    Some(element) => Ok((vec![CoreValue::RangeCheck, element], 0)),
-                    None => Ok((vec![CoreValue::RangeCheck], 1)),
-                }
-            }
-            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
-        Array(ArrayConcreteLibfunc::Slice(_)) => match &inputs[..] {
-            [
-                CoreValue::RangeCheck,
-                CoreValue::Array(_),
-                CoreValue::Uint32(_),
-                CoreValue::Uint32(_),
-            ] => {
-                let mut iter = inputs.into_iter();
-                iter.next(); // Ignore range check.
-                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
-                let start = extract_matches!(iter.next().unwrap(), CoreValue::Uint32) as usize;
-                let length = extract_matches!(iter.next().unwrap(), CoreValue::Uint32) as usize;
-                match arr.get(start..(start + length)) {
-                    Some(elements) => {
-                        Ok((vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0))
-                    }
-                    None => Ok((vec![CoreValue::RangeCheck], 1)),
-                }
-            }
-            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
-        Array(ArrayConcreteLibfunc::Len(_)) => match &inputs[..] {
-            [CoreValue::Array(_)] => {
-                let arr = extract_matches!(inputs.into_iter().next().unwrap(), CoreValue::Array);
-                let len = arr.len();
-                Ok((vec![CoreValue::Uint64(len as u64)], 0))
-            }
-            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-        },
-        Array(ArrayConcreteLibfunc::SnapshotPopFront(_)) => todo!(),
-        Array(ArrayConcreteLibfunc::SnapshotPopBack(_)) => todo!(),
+        Array(libfunc) => simulate_array_libfunc(libfunc, inputs),
         Uint8(libfunc) => simulate_u8_libfunc(libfunc, &inputs),
         Uint16(libfunc) => simulate_u16_libfunc(libfunc, &inputs),
         Uint32(libfunc) => simulate_u32_libfunc(libfunc, &inputs),
@@ -238,6 +207,8 @@ pub fn simulate<
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
+        Uint256(libfunc) => simulate_u256_libfunc(libfunc, &inputs),
+        Cast(libfunc) => simulate_cast_libfunc(libfunc, &inputs, get_type_generic_id),
         Mem(Rename(_) | StoreTemp(_)) | CoreConcreteLibfunc::Box(_) => {
             if inputs.len() == 1 {
                 Ok((inputs, 0))
@@ -291,62 +262,59 @@ pub fn simulate<
             [_] => Err(LibfuncSimulationError::WrongArgType),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::New(_)) => {
+        Felt252Dict(libfunc) => simulate_felt252_dict_libfunc(libfunc, inputs, get_type_generic_id),
+        Pedersen(PedersenConcreteLibfunc::PedersenHash(_)) => match &inputs[..] {
+            [CoreValue::Pedersen, CoreValue::Felt252(a), CoreValue::Felt252(b)] => {
+                let hash = pedersen_hash(&to_field_element(a), &to_field_element(b));
+                Ok((vec![CoreValue::Pedersen, from_field_element(hash)], 0))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Poseidon(PoseidonConcreteLibfunc::HadesPermutation(_)) => match &inputs[..] {
+            [
+                CoreValue::Poseidon,
+                CoreValue::Felt252(s0),
+                CoreValue::Felt252(s1),
+                CoreValue::Felt252(s2),
+            ] => {
+                let mut state = [to_field_element(s0), to_field_element(s1), to_field_element(s2)];
+                poseidon_permute_comp(&mut state);
+                let [s0, s1, s2] = state;
+                Ok((
+                    vec![
+                        CoreValue::Poseidon,
+                        from_field_element(s0),
+                        from_field_element(s1),
+                        from_field_element(s2),
+                    ],
+                    0,
+                ))
+            }
+            [_, _, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNet(libfunc) => simulate_starknet_libfunc(libfunc, &inputs, starknet_state),
+        Nullable(NullableConcreteLibfunc::Null(_)) => {
             if inputs.is_empty() {
-                Ok((vec![CoreValue::Dict(HashMap::new())], 0))
+                Ok((vec![CoreValue::Nullable(None)], 0))
             } else {
                 Err(LibfuncSimulationError::WrongNumberOfArgs)
             }
         }
-        CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::Read(_)) => {
-            match &inputs[..] {
-                [CoreValue::Dict(map), CoreValue::Felt252(key)] => {
-                    // Returns 0 as a default value.
-                    // TODO(Gil): correct this behavior when dict behavior is decided on key not
-                    // found.
-                    Ok((vec![map.get(key).map_or(CoreValue::Felt252(0.into()), |x| x.clone())], 0))
-                }
-                [_, _] => Err(LibfuncSimulationError::WrongArgType),
-                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-            }
-        }
-        CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::Write(_)) => match &inputs[..]
-        {
-            [CoreValue::Dict(_), CoreValue::Felt252(_), _] => {
-                let mut iter = inputs.into_iter();
-                let mut dict = extract_matches!(iter.next().unwrap(), CoreValue::Dict);
-                let key = extract_matches!(iter.next().unwrap(), CoreValue::Felt252);
-                dict.insert(key, iter.next().unwrap());
-                Ok((vec![CoreValue::Dict(dict)], 0))
+        Nullable(NullableConcreteLibfunc::NullableFromBox(_)) => match &inputs[..] {
+            [_] => {
+                let value = inputs.into_iter().next().unwrap();
+                Ok((vec![CoreValue::Nullable(Some(Box::new(value)))], 0))
             }
-            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::Squash(_)) => {
-            match &inputs[..] {
-                [CoreValue::RangeCheck, CoreValue::Dict(_)] => {
-                    let mut iter = inputs.into_iter();
-                    iter.next();
-                    // Returning the same dict since it is exactly the same as the squashed one.
-                    let dict = extract_matches!(iter.next().unwrap(), CoreValue::Dict);
-                    Ok((vec![CoreValue::RangeCheck, CoreValue::Dict(dict)], 0))
-                }
-                [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
-                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
-            }
-        }
-        CoreConcreteLibfunc::Pedersen(_) => {
-            unimplemented!("Simulation of the Pedersen hash function is not implemented yet.");
-        }
-        CoreConcreteLibfunc::Poseidon(_) => {
-            unimplemented!("Simulation of the Poseidon hash function is not implemented yet.");
-        }
-        CoreConcreteLibfunc::StarkNet(_) => {
-            unimplemented!("Simulation of the StarkNet functionalities is not implemented yet.")
-        }
-        CoreConcreteLibfunc::Nullable(_) => {
-            unimplemented!("Simulation of nullable is not implemented yet.")
-        }
+        Nullable(NullableConcreteLibfunc::MatchNullable(_)) => match &inputs[..] {
+            [CoreValue::Nullable(None)] => Ok((vec![], 0)),
+            [CoreValue::Nullable(Some(value))] => Ok((vec![*value.clone()], 1)),
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         CoreConcreteLibfunc::Debug(_) => {
             if inputs.len() == 1 {
                 let arr = extract_matches!(&inputs[0], CoreValue::Array);
@@ -372,8 +340,291 @@ pub fn simulate<
             [value] => Ok((vec![value.clone(), value.clone()], 0)),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        CoreConcreteLibfunc::Cast(_) => unimplemented!(),
-        CoreConcreteLibfunc::Uint256(_) => unimplemented!(),
+    }
+}
+
+/// Simulate array library functions.
+fn simulate_array_libfunc(
+    libfunc: &ArrayConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        ArrayConcreteLibfunc::New(_) => {
+            if inputs.is_empty() {
+                Ok((vec![CoreValue::Array(vec![])], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        ArrayConcreteLibfunc::Append(_) => match &inputs[..] {
+            [CoreValue::Array(_), _] => {
+                let mut iter = inputs.into_iter();
+                let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                arr.push(iter.next().unwrap());
+                Ok((vec![CoreValue::Array(arr)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        ArrayConcreteLibfunc::PopFront(_) | ArrayConcreteLibfunc::SnapshotPopFront(_) => {
+            match &inputs[..] {
+                [CoreValue::Array(_)] => {
+                    let mut iter = inputs.into_iter();
+                    let mut arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                    if arr.is_empty() {
+                        Ok((vec![CoreValue::Array(arr)], 1))
+                    } else {
+                        let front = arr.remove(0);
+                        Ok((vec![CoreValue::Array(arr), front], 0))
+                    }
+                }
+                [_] => Err(LibfuncSimulationError::WrongArgType),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }
+        }
+        ArrayConcreteLibfunc::SnapshotPopBack(_) => match &inputs[..] {
+            [CoreValue::Array(_)] => {
+                let mut arr =
+                    extract_matches!(inputs.into_iter().next().unwrap(), CoreValue::Array);
+                match arr.pop() {
+                    Some(back) => Ok((vec![CoreValue::Array(arr), back], 0)),
+                    None => Ok((vec![CoreValue::Array(arr)], 1)),
+                }
+            }
+            [_] => Err(LibfuncSimulationError::WrongArgType),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        ArrayConcreteLibfunc::Get(_) => match &inputs[..] {
+            [CoreValue::RangeCheck, CoreValue::Array(_), CoreValue::Uint32(_)] => {
+                let mut iter = inputs.into_iter();
+                iter.next(); // Ignore range check.
+                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                let idx = extract_matches!(iter.next().unwrap(), CoreValue::Uint32) as usize;
+                match arr.get(idx).cloned() {
+                    Some(element) => Ok((vec![CoreValue::RangeCheck, element], 0)),
+                    None => Ok((vec![CoreValue::RangeCheck], 1)),
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        ArrayConcreteLibfunc::Slice(_) => match &inputs[..] {
+            [
+                CoreValue::RangeCheck,
+                CoreValue::Array(_),
+                CoreValue::Uint32(_),
+                CoreValue::Uint32(_),
+            ] => {
+                let mut iter = inputs.into_iter();
+                iter.next(); // Ignore range check.
+                let arr = extract_matches!(iter.next().unwrap(), CoreValue::Array);
+                let start = extract_matches!(iter.next().unwrap(), CoreValue::Uint32) as usize;
+                let length = extract_matches!(iter.next().unwrap(), CoreValue::Uint32) as usize;
+                match arr.get(start..(start + length)) {
+                    Some(elements) => {
+                        Ok((vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0))
+                    }
+                    None => Ok((vec![CoreValue::RangeCheck], 1)),
+                }
+            }
+            [_, _, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        ArrayConcreteLibfunc::Len(_) => match &inputs[..] {
+            [CoreValue::Array(arr)] => Ok((vec![CoreValue::Uint32(arr.len() as u32)], 0)),
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Simulate elliptic curve library functions.
+fn simulate_ec_libfunc(
+    libfunc: &EcConcreteLibfunc,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        EcConcreteLibfunc::Zero(_) => {
+            if inputs.is_empty() {
+                Ok((vec![CoreValue::EcPoint(BigInt::zero(), BigInt::zero())], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        EcConcreteLibfunc::TryNew(_) => match inputs {
+            [CoreValue::Felt252(x), CoreValue::Felt252(y)] => {
+                // If the point is on the curve use the fallthrough branch and return the point.
+                if felt252_reduce(y * y) == ec_y_squared(x) {
+                    Ok((vec![nonzero_ec_point(x.clone(), y.clone())], 0))
+                } else {
+                    Ok((vec![], 1))
+                }
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::PointFromX(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt252(x)] => {
+                Ok(match felt252_sqrt(&ec_y_squared(x)) {
+                    Some(y) => (vec![CoreValue::RangeCheck, nonzero_ec_point(x.clone(), y)], 0),
+                    None => (vec![CoreValue::RangeCheck], 1),
+                })
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::UnwrapPoint(_) => match inputs {
+            [point] => {
+                let (x, y) = extract_nonzero_ec_point(point)?;
+                Ok((vec![CoreValue::Felt252(x.clone()), CoreValue::Felt252(y.clone())], 0))
+            }
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::IsZero(_) => match inputs {
+            [CoreValue::EcPoint(x, y)] => {
+                if x.is_zero() && y.is_zero() {
+                    // Zero - jumping to the failure branch.
+                    Ok((vec![], 0))
+                } else {
+                    // Non-zero - jumping to the success branch and providing a NonZero wrap to the
+                    // given point.
+                    Ok((vec![nonzero_ec_point(x.clone(), y.clone())], 1))
+                }
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::Neg(_) => match inputs {
+            [CoreValue::EcPoint(x, y)] => {
+                Ok((vec![CoreValue::EcPoint(x.clone(), felt252_reduce(-y))], 0))
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::StateInit(_) => {
+            if inputs.is_empty() {
+                Ok((vec![CoreValue::EcState(BigInt::zero(), BigInt::zero())], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        EcConcreteLibfunc::StateAdd(_) => match inputs {
+            [CoreValue::EcState(x, y), point] => {
+                let (x, y) = ec_add((x, y), extract_nonzero_ec_point(point)?);
+                Ok((vec![CoreValue::EcState(x, y)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::StateAddMul(_) => match inputs {
+            [CoreValue::EcOp, CoreValue::EcState(x, y), CoreValue::Felt252(scalar), point] => {
+                let product = ec_mul(scalar, extract_nonzero_ec_point(point)?);
+                let (x, y) = ec_add((x, y), (&product.0, &product.1));
+                Ok((vec![CoreValue::EcOp, CoreValue::EcState(x, y)], 0))
+            }
+            [_, _, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        EcConcreteLibfunc::StateFinalize(_) => match inputs {
+            [CoreValue::EcState(x, y)] => {
+                if x.is_zero() && y.is_zero() {
+                    // The accumulated point is zero - jumping to the failure branch.
+                    Ok((vec![], 1))
+                } else {
+                    Ok((vec![nonzero_ec_point(x.clone(), y.clone())], 0))
+                }
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Simulate felt252_dict library functions.
+fn simulate_felt252_dict_libfunc<GetTypeGenericId: Fn(&ConcreteTypeId) -> Option<GenericTypeId>>(
+    libfunc: &Felt252DictConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type_generic_id: GetTypeGenericId,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Felt252DictConcreteLibfunc::New(_) => match &inputs[..] {
+            [CoreValue::SegmentArena] => Ok((
+                vec![
+                    CoreValue::SegmentArena,
+                    CoreValue::Felt252Dict { entries: HashMap::new(), n_accesses: 0 },
+                ],
+                0,
+            )),
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Felt252DictConcreteLibfunc::Read(SignatureOnlyConcreteLibfunc { signature }) => {
+            match &inputs[..] {
+                [CoreValue::Felt252Dict { .. }, CoreValue::Felt252(_)] => {
+                    let mut iter = inputs.into_iter();
+                    let Some(CoreValue::Felt252Dict { mut entries, n_accesses }) = iter.next() else {
+                        unreachable!("Arm only handles dict inputs.");
+                    };
+                    let key = extract_matches!(iter.next().unwrap(), CoreValue::Felt252);
+                    let value = match entries.get(&key) {
+                        Some(value) => value.clone(),
+                        None => {
+                            // Reading a missing key initializes it to the default value.
+                            let value_generic_id =
+                                get_type_generic_id(&signature.branch_signatures[0].vars[1].ty)
+                                    .ok_or(LibfuncSimulationError::UnresolvedType)?;
+                            let value = felt252_dict_default_value(&value_generic_id)
+                                .ok_or(LibfuncSimulationError::UnresolvedType)?;
+                            entries.insert(key, value.clone());
+                            value
+                        }
+                    };
+                    Ok((
+                        vec![CoreValue::Felt252Dict { entries, n_accesses: n_accesses + 1 }, value],
+                        0,
+                    ))
+                }
+                [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            }
+        }
+        Felt252DictConcreteLibfunc::Write(_) => match &inputs[..] {
+            [CoreValue::Felt252Dict { .. }, CoreValue::Felt252(_), _] => {
+                let mut iter = inputs.into_iter();
+                let Some(CoreValue::Felt252Dict { mut entries, n_accesses }) = iter.next() else {
+                    unreachable!("Arm only handles dict inputs.");
+                };
+                let key = extract_matches!(iter.next().unwrap(), CoreValue::Felt252);
+                entries.insert(key, iter.next().unwrap());
+                Ok((vec![CoreValue::Felt252Dict { entries, n_accesses: n_accesses + 1 }], 0))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Felt252DictConcreteLibfunc::Squash(_) => match &inputs[..] {
+            [
+                CoreValue::RangeCheck,
+                CoreValue::GasBuiltin(gas_counter),
+                CoreValue::SegmentArena,
+                CoreValue::Felt252Dict { entries, n_accesses },
+            ] => {
+                // Every access to a key beyond the first one is refunded.
+                let n_refunded_accesses = (n_accesses - entries.len()) as i64;
+                Ok((
+                    vec![
+                        CoreValue::RangeCheck,
+                        CoreValue::GasBuiltin(
+                            gas_counter + n_refunded_accesses * DICT_SQUASH_UNIQUE_KEY_COST,
+                        ),
+                        CoreValue::SegmentArena,
+                        CoreValue::SquashedFelt252Dict(entries.clone()),
+                    ],
+                    0,
+                ))
+            }
+            [_, _, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
     }
 }
 
@@ -475,13 +726,24 @@ fn simulate_u128_libfunc(
         Uint128Concrete::FromFelt252(_) => match inputs {
             [CoreValue::RangeCheck, CoreValue::Felt252(value)] => Ok(match u128::try_from(value) {
                 Ok(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], 0),
-                Err(_) => (vec![CoreValue::RangeCheck], 1),
+                Err(_) => {
+                    // The value does not fit in a single u128 - splitting it into two words.
+                    let (high, low) = split_u256(value);
+                    (
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint128(high),
+                            CoreValue::Uint128(low),
+                        ],
+                        1,
+                    )
+                }
             }),
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint128Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint128(value)] => {
+            [CoreValue::Uint128(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -647,7 +909,7 @@ fn simulate_u8_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint8Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint8(value)] => {
+            [CoreValue::Uint8(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -661,8 +923,35 @@ fn simulate_u8_libfunc(
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Uint8Concrete::IsZero(_) => unimplemented!(),
-        Uint8Concrete::Divmod(_) => unimplemented!(),
+        Uint8Concrete::IsZero(_) => match inputs {
+            // Zero - jumping to the failure branch.
+            [CoreValue::Uint8(0)] => Ok((vec![], 0)),
+            // Non-zero - jumping to the success branch and providing a NonZero wrap to the given
+            // value.
+            [CoreValue::Uint8(value)] => {
+                Ok((vec![CoreValue::NonZero(Box::new(CoreValue::Uint8(*value)))], 1))
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint8Concrete::Divmod(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint8(lhs), CoreValue::NonZero(non_zero)] => {
+                if let CoreValue::Uint8(rhs) = **non_zero {
+                    Ok((
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint8(lhs / rhs),
+                            CoreValue::Uint8(lhs % rhs),
+                        ],
+                        0,
+                    ))
+                } else {
+                    Err(LibfuncSimulationError::MemoryLayoutMismatch)
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint8Concrete::WideMul(_) => match inputs {
             [CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] => {
                 Ok((vec![CoreValue::Uint16(u16::from(*lhs) * u16::from(*rhs))], 0))
@@ -733,7 +1022,7 @@ fn simulate_u16_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint16Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint16(value)] => {
+            [CoreValue::Uint16(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -747,8 +1036,35 @@ fn simulate_u16_libfunc(
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Uint16Concrete::IsZero(_) => unimplemented!(),
-        Uint16Concrete::Divmod(_) => unimplemented!(),
+        Uint16Concrete::IsZero(_) => match inputs {
+            // Zero - jumping to the failure branch.
+            [CoreValue::Uint16(0)] => Ok((vec![], 0)),
+            // Non-zero - jumping to the success branch and providing a NonZero wrap to the given
+            // value.
+            [CoreValue::Uint16(value)] => {
+                Ok((vec![CoreValue::NonZero(Box::new(CoreValue::Uint16(*value)))], 1))
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint16Concrete::Divmod(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint16(lhs), CoreValue::NonZero(non_zero)] => {
+                if let CoreValue::Uint16(rhs) = **non_zero {
+                    Ok((
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint16(lhs / rhs),
+                            CoreValue::Uint16(lhs % rhs),
+                        ],
+                        0,
+                    ))
+                } else {
+                    Err(LibfuncSimulationError::MemoryLayoutMismatch)
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint16Concrete::WideMul(_) => match inputs {
             [CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)] => {
                 Ok((vec![CoreValue::Uint32(u32::from(*lhs) * u32::from(*rhs))], 0))
@@ -819,7 +1135,7 @@ fn simulate_u32_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint32Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint32(value)] => {
+            [CoreValue::Uint32(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -833,8 +1149,35 @@ fn simulate_u32_libfunc(
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Uint32Concrete::IsZero(_) => unimplemented!(),
-        Uint32Concrete::Divmod(_) => unimplemented!(),
+        Uint32Concrete::IsZero(_) => match inputs {
+            // Zero - jumping to the failure branch.
+            [CoreValue::Uint32(0)] => Ok((vec![], 0)),
+            // Non-zero - jumping to the success branch and providing a NonZero wrap to the given
+            // value.
+            [CoreValue::Uint32(value)] => {
+                Ok((vec![CoreValue::NonZero(Box::new(CoreValue::Uint32(*value)))], 1))
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint32Concrete::Divmod(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint32(lhs), CoreValue::NonZero(non_zero)] => {
+                if let CoreValue::Uint32(rhs) = **non_zero {
+                    Ok((
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint32(lhs / rhs),
+                            CoreValue::Uint32(lhs % rhs),
+                        ],
+                        0,
+                    ))
+                } else {
+                    Err(LibfuncSimulationError::MemoryLayoutMismatch)
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint32Concrete::WideMul(_) => match inputs {
             [CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)] => {
                 Ok((vec![CoreValue::Uint64(u64::from(*lhs) * u64::from(*rhs))], 0))
@@ -905,7 +1248,7 @@ fn simulate_u64_libfunc(
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
         Uint64Concrete::ToFelt252(_) => match inputs {
-            [CoreValue::RangeCheck, CoreValue::Uint64(value)] => {
+            [CoreValue::Uint64(value)] => {
                 Ok((vec![CoreValue::Felt252(value.to_bigint().unwrap())], 0))
             }
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -919,8 +1262,35 @@ fn simulate_u64_libfunc(
             [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
             _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
         },
-        Uint64Concrete::IsZero(_) => unimplemented!(),
-        Uint64Concrete::Divmod(_) => unimplemented!(),
+        Uint64Concrete::IsZero(_) => match inputs {
+            // Zero - jumping to the failure branch.
+            [CoreValue::Uint64(0)] => Ok((vec![], 0)),
+            // Non-zero - jumping to the success branch and providing a NonZero wrap to the given
+            // value.
+            [CoreValue::Uint64(value)] => {
+                Ok((vec![CoreValue::NonZero(Box::new(CoreValue::Uint64(*value)))], 1))
+            }
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint64Concrete::Divmod(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Uint64(lhs), CoreValue::NonZero(non_zero)] => {
+                if let CoreValue::Uint64(rhs) = **non_zero {
+                    Ok((
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint64(lhs / rhs),
+                            CoreValue::Uint64(lhs % rhs),
+                        ],
+                        0,
+                    ))
+                } else {
+                    Err(LibfuncSimulationError::MemoryLayoutMismatch)
+                }
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
         Uint64Concrete::WideMul(_) => match inputs {
             [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] => {
                 Ok((vec![CoreValue::Uint128(u128::from(*lhs) * u128::from(*rhs))], 0))
@@ -939,7 +1309,7 @@ fn simulate_felt252_libfunc(
     match libfunc {
         Felt252Concrete::Const(Felt252ConstConcreteLibfunc { c, .. }) => {
             if inputs.is_empty() {
-                Ok((vec![CoreValue::Felt252(c.to_bigint().unwrap())], 0))
+                Ok((vec![felt252_value(c.clone())], 0))
             } else {
                 Err(LibfuncSimulationError::WrongNumberOfArgs)
             }
@@ -952,21 +1322,13 @@ fn simulate_felt252_libfunc(
                 Felt252BinaryOperator::Add
                 | Felt252BinaryOperator::Sub
                 | Felt252BinaryOperator::Mul,
-            ) => Ok((
-                vec![CoreValue::Felt252(match operator {
-                    Felt252BinaryOperator::Add => lhs + rhs,
-                    Felt252BinaryOperator::Sub => lhs - rhs,
-                    Felt252BinaryOperator::Mul => lhs * rhs,
-                    _ => unreachable!("Arm only handles these cases."),
-                })],
-                0,
-            )),
+            ) => Ok((vec![felt252_value(felt252_binary_operation(*operator, lhs, rhs))], 0)),
             (
-                [CoreValue::Felt252(_lhs), CoreValue::NonZero(non_zero)],
+                [CoreValue::Felt252(lhs), CoreValue::NonZero(non_zero)],
                 Felt252BinaryOperator::Div,
             ) => {
-                if let CoreValue::Felt252(_rhs) = *non_zero.clone() {
-                    todo!("Support felt252_div operation.")
+                if let CoreValue::Felt252(rhs) = &**non_zero {
+                    Ok((vec![felt252_value(felt252_binary_operation(*operator, lhs, rhs))], 0))
                 } else {
                     Err(LibfuncSimulationError::MemoryLayoutMismatch)
                 }
@@ -978,12 +1340,11 @@ fn simulate_felt252_libfunc(
             Felt252OperationWithConstConcreteLibfunc { operator, c, .. },
         )) => match inputs {
             [CoreValue::Felt252(value)] => Ok((
-                vec![CoreValue::Felt252(match operator {
-                    Felt252BinaryOperator::Add => value + c.clone(),
-                    Felt252BinaryOperator::Sub => value - c.clone(),
-                    Felt252BinaryOperator::Mul => value * c.clone(),
-                    Felt252BinaryOperator::Div => todo!("Support full felt252 operations."),
-                })],
+                vec![felt252_value(felt252_binary_operation(
+                    *operator,
+                    value,
+                    &felt252_reduce(c.clone()),
+                ))],
                 0,
             )),
             [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
@@ -1006,3 +1367,278 @@ fn simulate_felt252_libfunc(
         }
     }
 }
+
+/// Returns the result of a felt252 binary operation, before the reduction modulo the prime.
+fn felt252_binary_operation(operator: Felt252BinaryOperator, lhs: &BigInt, rhs: &BigInt) -> BigInt {
+    match operator {
+        Felt252BinaryOperator::Add => lhs + rhs,
+        Felt252BinaryOperator::Sub => lhs - rhs,
+        Felt252BinaryOperator::Mul => lhs * rhs,
+        Felt252BinaryOperator::Div => lhs * felt252_inverse(rhs),
+    }
+}
+
+/// Simulate u256 library functions.
+fn simulate_u256_libfunc(
+    libfunc: &Uint256Concrete,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Uint256Concrete::IsZero(_) => match inputs {
+            [value] => {
+                if extract_u256(value)?.is_zero() {
+                    // Zero - jumping to the failure branch.
+                    Ok((vec![], 0))
+                } else {
+                    // Non-zero - jumping to the success branch and providing a NonZero wrap to the
+                    // given value.
+                    Ok((vec![CoreValue::NonZero(Box::new(value.clone()))], 1))
+                }
+            }
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Uint256Concrete::Divmod(_) => match inputs {
+            [CoreValue::RangeCheck, lhs, CoreValue::NonZero(rhs)] => {
+                let lhs = extract_u256(lhs)?;
+                let rhs = extract_u256(rhs)?;
+                if rhs.is_zero() {
+                    return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+                }
+                Ok((vec![CoreValue::RangeCheck, u256_value(&lhs / &rhs), u256_value(lhs % rhs)], 0))
+            }
+            [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Returns the value of a `u256` struct.
+fn extract_u256(value: &CoreValue) -> Result<BigInt, LibfuncSimulationError> {
+    match value {
+        CoreValue::Struct(members) => match &members[..] {
+            [CoreValue::Uint128(low), CoreValue::Uint128(high)] => {
+                Ok((BigInt::from(*high) << 128) + BigInt::from(*low))
+            }
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+    }
+}
+
+/// Returns the `u256` struct representing the given value.
+fn u256_value(value: BigInt) -> CoreValue {
+    let (high, low) = split_u256(&value);
+    CoreValue::Struct(vec![CoreValue::Uint128(low), CoreValue::Uint128(high)])
+}
+
+/// Splits a value smaller than 2^256 into its high and low 128 bits.
+fn split_u256(value: &BigInt) -> (u128, u128) {
+    let u128_limit = BigInt::from(u128::MAX) + 1;
+    let high: BigInt = value / &u128_limit;
+    let low: BigInt = value % u128_limit;
+    (high.to_u128().unwrap(), low.to_u128().unwrap())
+}
+
+/// Simulate cast library functions.
+fn simulate_cast_libfunc<GetTypeGenericId: Fn(&ConcreteTypeId) -> Option<GenericTypeId>>(
+    libfunc: &CastConcreteLibfunc,
+    inputs: &[CoreValue],
+    get_type_generic_id: GetTypeGenericId,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        CastConcreteLibfunc::Downcast(DowncastConcreteLibfunc { to_ty, .. }) => match inputs {
+            [CoreValue::RangeCheck, value] => {
+                let to_generic_id =
+                    get_type_generic_id(to_ty).ok_or(LibfuncSimulationError::UnresolvedType)?;
+                Ok(match uint_value(&to_generic_id, extract_uint(value)?) {
+                    Some(value) => (vec![CoreValue::RangeCheck, value], 0),
+                    None => (vec![CoreValue::RangeCheck], 1),
+                })
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        CastConcreteLibfunc::Upcast(SignatureOnlyConcreteLibfunc { signature }) => match inputs {
+            [value] => {
+                let to_generic_id = get_type_generic_id(&signature.branch_signatures[0].vars[0].ty)
+                    .ok_or(LibfuncSimulationError::UnresolvedType)?;
+                let value = uint_value(&to_generic_id, extract_uint(value)?)
+                    .ok_or(LibfuncSimulationError::MemoryLayoutMismatch)?;
+                Ok((vec![value], 0))
+            }
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Returns the value of an unsigned integer.
+fn extract_uint(value: &CoreValue) -> Result<u128, LibfuncSimulationError> {
+    match value {
+        CoreValue::Uint8(value) => Ok((*value).into()),
+        CoreValue::Uint16(value) => Ok((*value).into()),
+        CoreValue::Uint32(value) => Ok((*value).into()),
+        CoreValue::Uint64(value) => Ok((*value).into()),
+        CoreValue::Uint128(value) => Ok(*value),
+        _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+    }
+}
+
+/// Returns the unsigned integer value of the type with the given generic id, or `None` if `value`
+/// does not fit in the type.
+fn uint_value(generic_id: &GenericTypeId, value: u128) -> Option<CoreValue> {
+    Some(match generic_id.0.as_str() {
+        "u8" => CoreValue::Uint8(value.try_into().ok()?),
+        "u16" => CoreValue::Uint16(value.try_into().ok()?),
+        "u32" => CoreValue::Uint32(value.try_into().ok()?),
+        "u64" => CoreValue::Uint64(value.try_into().ok()?),
+        "u128" => CoreValue::Uint128(value),
+        _ => return None,
+    })
+}
+
+/// Returns the value a `Felt252Dict` entry of the type with the given generic id has before it is
+/// first written.
+fn felt252_dict_default_value(generic_id: &GenericTypeId) -> Option<CoreValue> {
+    match generic_id.0.as_str() {
+        "felt252" => Some(CoreValue::Felt252(BigInt::zero())),
+        "Nullable" => Some(CoreValue::Nullable(None)),
+        _ => uint_value(generic_id, 0),
+    }
+}
+
+/// Returns the prime defining the felt252 field.
+fn felt252_prime() -> BigInt {
+    (BigInt::from(1) << 251) + BigInt::from(17) * (BigInt::from(1) << 192) + 1
+}
+
+/// Returns the representative of `value` modulo the prime, in the range `[0, PRIME)`.
+fn felt252_reduce(value: BigInt) -> BigInt {
+    let prime = felt252_prime();
+    ((value % &prime) + &prime) % prime
+}
+
+/// Returns the felt252 value equal to `value` modulo the prime.
+pub(super) fn felt252_value(value: BigInt) -> CoreValue {
+    CoreValue::Felt252(felt252_reduce(value))
+}
+
+/// Returns the multiplicative inverse of a non-zero felt252.
+fn felt252_inverse(value: &BigInt) -> BigInt {
+    let prime = felt252_prime();
+    felt252_reduce(value.clone()).modpow(&(&prime - 2), &prime)
+}
+
+/// Returns the smaller square root of a felt252, or `None` if it is not a quadratic residue.
+/// Uses the Tonelli-Shanks algorithm.
+fn felt252_sqrt(value: &BigInt) -> Option<BigInt> {
+    let prime = felt252_prime();
+    let one = BigInt::from(1);
+    if value.is_zero() {
+        return Some(BigInt::zero());
+    }
+    let is_residue = |v: &BigInt| v.modpow(&((&prime - 1) >> 1), &prime) == one;
+    if !is_residue(value) {
+        return None;
+    }
+    // Writing `PRIME - 1 = odd * 2^two_adicity`.
+    let prime_minus_one: BigInt = &prime - 1;
+    let two_adicity = prime_minus_one.trailing_zeros().unwrap();
+    let odd = prime_minus_one >> two_adicity;
+    let mut non_residue = BigInt::from(2);
+    while is_residue(&non_residue) {
+        non_residue += 1;
+    }
+    let mut m = two_adicity;
+    let mut c = non_residue.modpow(&odd, &prime);
+    let mut t = value.modpow(&odd, &prime);
+    let mut root = value.modpow(&((&odd + 1) >> 1), &prime);
+    while t != one {
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = &t_pow * &t_pow % &prime;
+            i += 1;
+        }
+        let b = c.modpow(&(BigInt::from(1) << (m - i - 1)), &prime);
+        m = i;
+        c = &b * &b % &prime;
+        t = t * &c % &prime;
+        root = root * b % &prime;
+    }
+    let other_root = &prime - &root;
+    Some(std::cmp::min(root, other_root))
+}
+
+/// Returns the sum of two points on the curve, where `(0, 0)` represents the zero point.
+fn ec_add(lhs: (&BigInt, &BigInt), rhs: (&BigInt, &BigInt)) -> (BigInt, BigInt) {
+    let ((x0, y0), (x1, y1)) = (lhs, rhs);
+    if x0.is_zero() && y0.is_zero() {
+        return (x1.clone(), y1.clone());
+    }
+    if x1.is_zero() && y1.is_zero() {
+        return (x0.clone(), y0.clone());
+    }
+    let slope = if x0 == x1 {
+        if felt252_reduce(y0 + y1).is_zero() {
+            return (BigInt::zero(), BigInt::zero());
+        }
+        // Doubling - the slope of the tangent, where the curve's alpha is 1.
+        (BigInt::from(3) * x0 * x0 + 1) * felt252_inverse(&(y0 * 2))
+    } else {
+        (y1 - y0) * felt252_inverse(&(x1 - x0))
+    };
+    let slope = felt252_reduce(slope);
+    let x = felt252_reduce(&slope * &slope - x0 - x1);
+    let y = felt252_reduce(slope * (x0 - &x) - y0);
+    (x, y)
+}
+
+/// Returns the multiplication of a point on the curve by a scalar.
+fn ec_mul(scalar: &BigInt, point: (&BigInt, &BigInt)) -> (BigInt, BigInt) {
+    let mut result = (BigInt::zero(), BigInt::zero());
+    let mut power = (point.0.clone(), point.1.clone());
+    for i in 0..scalar.bits() {
+        if scalar.bit(i) {
+            result = ec_add((&result.0, &result.1), (&power.0, &power.1));
+        }
+        power = ec_add((&power.0, &power.1), (&power.0, &power.1));
+    }
+    result
+}
+
+/// Returns `x^3 + x + beta` - the square of the y coordinate of the curve points with the given x
+/// coordinate.
+fn ec_y_squared(x: &BigInt) -> BigInt {
+    felt252_reduce(x * x * x + x + get_beta())
+}
+
+/// Returns a `NonZero<EcPoint>` value.
+fn nonzero_ec_point(x: BigInt, y: BigInt) -> CoreValue {
+    CoreValue::NonZero(Box::new(CoreValue::EcPoint(x, y)))
+}
+
+/// Returns the coordinates of a `NonZero<EcPoint>` value.
+fn extract_nonzero_ec_point(
+    value: &CoreValue,
+) -> Result<(&BigInt, &BigInt), LibfuncSimulationError> {
+    match value {
+        CoreValue::NonZero(point) => match &**point {
+            CoreValue::EcPoint(x, y) => Ok((x, y)),
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+    }
+}
+
+/// Converts a felt252 to the field element representation used by the hash functions.
+fn to_field_element(value: &BigInt) -> FieldElement {
+    let (_, bytes) = value.to_bytes_be();
+    let mut buffer = [0; 32];
+    buffer[32 - bytes.len()..].copy_from_slice(&bytes);
+    FieldElement::from_bytes_be(&buffer).unwrap()
+}
+
+/// Converts a field element used by the hash functions to a felt252.
+fn from_field_element(value: FieldElement) -> CoreValue {
+    CoreValue::Felt252(BigInt::from_bytes_be(Sign::Plus, &value.to_bytes_be()))
+}
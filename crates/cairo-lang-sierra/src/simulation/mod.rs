@@ -3,14 +3,17 @@ use std::collections::HashMap;
 use itertools::izip;
 use thiserror::Error;
 
+use self::starknet::StarknetState;
 use self::value::CoreValue;
 use crate::edit_state::{put_results, take_args, EditStateError};
 use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
-use crate::ids::{FunctionId, VarId};
+use crate::extensions::gas::CostTokenType;
+use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId, VarId};
 use crate::program::{Program, Statement, StatementIdx};
 use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
 
 pub mod core;
+pub mod starknet;
 #[cfg(test)]
 mod test;
 pub mod value;
@@ -26,6 +29,8 @@ pub enum LibfuncSimulationError {
     MemoryLayoutMismatch,
     #[error("Could not resolve requested symbol value")]
     UnresolvedStatementGasInfo,
+    #[error("Could not resolve a type required for the simulation")]
+    UnresolvedType,
     #[error("Error occurred during user function call")]
     FunctionSimulationError(FunctionId, Box<SimulationError>),
 }
@@ -47,26 +52,50 @@ pub enum SimulationError {
     FunctionDidNotConsumeAllArgs(FunctionId, StatementIdx),
 }
 
-/// Runs a function from the program with the given inputs.
+/// Runs a function from the program with the given inputs, where `statement_gas_info` holds the
+/// const gas variables of the statements.
 pub fn run(
     program: &Program,
     statement_gas_info: &HashMap<StatementIdx, i64>,
     function_id: &FunctionId,
     inputs: Vec<CoreValue>,
+) -> Result<Vec<CoreValue>, SimulationError> {
+    let statement_gas_info = statement_gas_info
+        .iter()
+        .map(|(idx, value)| ((*idx, CostTokenType::Const), *value))
+        .collect();
+    run_with_state(program, &statement_gas_info, function_id, inputs, &mut StarknetState::default())
+}
+
+/// Runs a function from the program with the given inputs, on the given Starknet state.
+/// `statement_gas_info` holds the gas variables of the statements, per token type.
+pub fn run_with_state(
+    program: &Program,
+    statement_gas_info: &HashMap<(StatementIdx, CostTokenType), i64>,
+    function_id: &FunctionId,
+    inputs: Vec<CoreValue>,
+    starknet_state: &mut StarknetState,
 ) -> Result<Vec<CoreValue>, SimulationError> {
     let context = SimulationContext {
         program,
         statement_gas_info,
         registry: &ProgramRegistry::new(program)?,
+        type_generic_ids: program
+            .type_declarations
+            .iter()
+            .map(|declaration| (declaration.id.clone(), declaration.long_id.generic_id.clone()))
+            .collect(),
     };
-    context.simulate_function(function_id, inputs)
+    context.simulate_function(function_id, inputs, starknet_state)
 }
 
 /// Helper class for running the simulation.
 struct SimulationContext<'a> {
     pub program: &'a Program,
-    pub statement_gas_info: &'a HashMap<StatementIdx, i64>,
+    pub statement_gas_info: &'a HashMap<(StatementIdx, CostTokenType), i64>,
     pub registry: &'a ProgramRegistry<CoreType, CoreLibfunc>,
+    /// The generic ids of the concrete types declared in the program.
+    pub type_generic_ids: HashMap<ConcreteTypeId, GenericTypeId>,
 }
 impl SimulationContext<'_> {
     /// Simulates the run of a function, even recursively.
@@ -74,6 +103,7 @@ impl SimulationContext<'_> {
         &self,
         function_id: &FunctionId,
         inputs: Vec<CoreValue>,
+        starknet_state: &mut StarknetState,
     ) -> Result<Vec<CoreValue>, SimulationError> {
         let func = self.registry.get_function(function_id)?;
         let mut current_statement_id = func.entry_point;
@@ -118,6 +148,7 @@ impl SimulationContext<'_> {
                         libfunc,
                         inputs,
                         current_statement_id,
+                        starknet_state,
                     )?;
                     let branch_info = &invocation.branches[chosen_branch];
                     state = put_results(
@@ -140,13 +171,16 @@ impl SimulationContext<'_> {
         libfunc: &CoreConcreteLibfunc,
         inputs: Vec<CoreValue>,
         current_statement_id: StatementIdx,
+        starknet_state: &mut StarknetState,
     ) -> Result<(Vec<CoreValue>, usize), SimulationError> {
         core::simulate(
             libfunc,
             inputs,
-            || self.statement_gas_info.get(idx).copied(),
-            |function_id, inputs| {
-                self.simulate_function(function_id, inputs).map_err(|error| {
+            starknet_state,
+            |token_type| self.statement_gas_info.get(&(*idx, token_type)).copied(),
+            |ty| self.type_generic_ids.get(ty).cloned(),
+            |function_id, inputs, starknet_state| {
+                self.simulate_function(function_id, inputs, starknet_state).map_err(|error| {
                     LibfuncSimulationError::FunctionSimulationError(
                         function_id.clone(),
                         Box::new(error),
@@ -0,0 +1,416 @@
+use std::collections::HashMap;
+
+use num_bigint::{BigInt, Sign};
+
+use super::core::felt252_value;
+use super::value::CoreValue;
+use super::LibfuncSimulationError;
+use crate::extensions::consts::SignatureAndConstConcreteLibfunc;
+use crate::extensions::starknet::testing::TestingConcreteLibfunc;
+use crate::extensions::starknet::StarkNetConcreteLibfunc;
+
+/// The gas cost of the `storage_read_syscall` libfunc, as charged by the Cairo runner.
+const STORAGE_READ_GAS_COST: i64 = 100;
+/// The gas cost of the `storage_write_syscall` libfunc, as charged by the Cairo runner.
+const STORAGE_WRITE_GAS_COST: i64 = 1000;
+/// The gas cost of the rest of the system calls.
+const SYSCALL_GAS_COST: i64 = 50;
+
+/// The block info, as returned by `get_execution_info_syscall`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BlockInfo {
+    pub block_number: u64,
+    pub block_timestamp: u64,
+    pub sequencer_address: BigInt,
+}
+
+/// The transaction info, as returned by `get_execution_info_syscall`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TxInfo {
+    pub version: BigInt,
+    pub account_contract_address: BigInt,
+    pub max_fee: u128,
+    pub signature: Vec<BigInt>,
+    pub transaction_hash: BigInt,
+    pub chain_id: BigInt,
+    pub nonce: BigInt,
+}
+
+/// The execution info, as returned by `get_execution_info_syscall`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExecutionInfo {
+    pub block_info: BlockInfo,
+    pub tx_info: TxInfo,
+    pub caller_address: BigInt,
+    pub contract_address: BigInt,
+    pub entry_point_selector: BigInt,
+}
+
+/// An event emitted by a contract.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Event {
+    pub contract_address: BigInt,
+    pub keys: Vec<BigInt>,
+    pub data: Vec<BigInt>,
+}
+
+/// A message sent from a contract to L1.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct L2ToL1Message {
+    pub from_address: BigInt,
+    pub to_address: BigInt,
+    pub payload: Vec<BigInt>,
+}
+
+/// The Starknet state, read and modified by the simulation of the Starknet libfuncs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StarknetState {
+    /// The storage of every contract, by contract address and then by storage address.
+    pub storage: HashMap<BigInt, HashMap<BigInt, BigInt>>,
+    /// The info of the current execution - the contract address is the address of the running
+    /// contract.
+    pub exec_info: ExecutionInfo,
+    /// The emitted events, in emission order.
+    pub events: Vec<Event>,
+    /// The sent L2 to L1 messages, in sending order.
+    pub l2_to_l1_messages: Vec<L2ToL1Message>,
+    /// The class hashes replaced using `replace_class_syscall`, by contract address.
+    pub replaced_classes: HashMap<BigInt, BigInt>,
+}
+
+/// Simulates the run of a Starknet libfunc, given its inputs and the Starknet state.
+pub fn simulate_starknet_libfunc(
+    libfunc: &StarkNetConcreteLibfunc,
+    inputs: &[CoreValue],
+    state: &mut StarknetState,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        StarkNetConcreteLibfunc::ClassHashConst(SignatureAndConstConcreteLibfunc { c, .. })
+        | StarkNetConcreteLibfunc::ContractAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        })
+        | StarkNetConcreteLibfunc::StorageBaseAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        }) => {
+            if inputs.is_empty() {
+                Ok((vec![felt252_value(c.clone())], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        StarkNetConcreteLibfunc::ClassHashTryFromFelt252(_)
+        | StarkNetConcreteLibfunc::ContractAddressTryFromFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressTryFromFelt252(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt252(value)] => {
+                if *value < BigInt::from(1) << 251 {
+                    Ok((vec![CoreValue::RangeCheck, CoreValue::Felt252(value.clone())], 0))
+                } else {
+                    Ok((vec![CoreValue::RangeCheck], 1))
+                }
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNetConcreteLibfunc::ClassHashToFelt252(_)
+        | StarkNetConcreteLibfunc::ContractAddressToFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressToFelt252(_)
+        | StarkNetConcreteLibfunc::StorageAddressFromBase(_) => match inputs {
+            [CoreValue::Felt252(value)] => Ok((vec![CoreValue::Felt252(value.clone())], 0)),
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNetConcreteLibfunc::StorageBaseAddressFromFelt252(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt252(value)] => {
+                // Values out of the address range are wrapped into it.
+                let bound = (BigInt::from(1) << 251) - 256;
+                let address = if *value < bound { value.clone() } else { value - bound };
+                Ok((vec![CoreValue::RangeCheck, CoreValue::Felt252(address)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => match inputs {
+            [CoreValue::Felt252(base), CoreValue::Uint8(offset)] => {
+                Ok((vec![felt252_value(base + offset)], 0))
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNetConcreteLibfunc::StorageRead(_) => {
+            simulate_syscall(inputs, STORAGE_READ_GAS_COST, |args| match args {
+                [CoreValue::Uint32(address_domain), CoreValue::Felt252(address)] => {
+                    if *address_domain != 0 {
+                        return Ok(Err(revert_reason(b"Unsupported address domain")));
+                    }
+                    let value = state
+                        .storage
+                        .get(&state.exec_info.contract_address)
+                        .and_then(|contract_storage| contract_storage.get(address))
+                        .cloned()
+                        .unwrap_or_default();
+                    Ok(Ok(vec![CoreValue::Felt252(value)]))
+                }
+                [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            })
+        }
+        StarkNetConcreteLibfunc::StorageWrite(_) => {
+            simulate_syscall(inputs, STORAGE_WRITE_GAS_COST, |args| match args {
+                [
+                    CoreValue::Uint32(address_domain),
+                    CoreValue::Felt252(address),
+                    CoreValue::Felt252(value),
+                ] => {
+                    if *address_domain != 0 {
+                        return Ok(Err(revert_reason(b"Unsupported address domain")));
+                    }
+                    state
+                        .storage
+                        .entry(state.exec_info.contract_address.clone())
+                        .or_default()
+                        .insert(address.clone(), value.clone());
+                    Ok(Ok(vec![]))
+                }
+                [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            })
+        }
+        StarkNetConcreteLibfunc::GetExecutionInfo(_) => {
+            simulate_syscall(inputs, SYSCALL_GAS_COST, |args| {
+                if args.is_empty() {
+                    Ok(Ok(vec![execution_info_value(&state.exec_info)]))
+                } else {
+                    Err(LibfuncSimulationError::WrongNumberOfArgs)
+                }
+            })
+        }
+        StarkNetConcreteLibfunc::EmitEvent(_) => {
+            simulate_syscall(inputs, SYSCALL_GAS_COST, |args| match args {
+                [keys, data] => {
+                    state.events.push(Event {
+                        contract_address: state.exec_info.contract_address.clone(),
+                        keys: extract_felt252_span(keys)?,
+                        data: extract_felt252_span(data)?,
+                    });
+                    Ok(Ok(vec![]))
+                }
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            })
+        }
+        StarkNetConcreteLibfunc::SendMessageToL1(_) => {
+            simulate_syscall(inputs, SYSCALL_GAS_COST, |args| match args {
+                [CoreValue::Felt252(to_address), payload] => {
+                    state.l2_to_l1_messages.push(L2ToL1Message {
+                        from_address: state.exec_info.contract_address.clone(),
+                        to_address: to_address.clone(),
+                        payload: extract_felt252_span(payload)?,
+                    });
+                    Ok(Ok(vec![]))
+                }
+                [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            })
+        }
+        StarkNetConcreteLibfunc::ReplaceClass(_) => {
+            simulate_syscall(inputs, SYSCALL_GAS_COST, |args| match args {
+                [CoreValue::Felt252(class_hash)] => {
+                    state
+                        .replaced_classes
+                        .insert(state.exec_info.contract_address.clone(), class_hash.clone());
+                    Ok(Ok(vec![]))
+                }
+                [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+                _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+            })
+        }
+        // Running other contracts is not supported by the simulation.
+        StarkNetConcreteLibfunc::CallContract(_)
+        | StarkNetConcreteLibfunc::LibraryCall(_)
+        | StarkNetConcreteLibfunc::Deploy(_) => match inputs {
+            [CoreValue::GasBuiltin(gas_counter), CoreValue::System, ..] => Ok((
+                vec![
+                    CoreValue::GasBuiltin(*gas_counter),
+                    CoreValue::System,
+                    revert_reason(b"Unsupported syscall"),
+                ],
+                1,
+            )),
+            [_, _, ..] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        StarkNetConcreteLibfunc::Testing(libfunc) => {
+            simulate_testing_libfunc(libfunc, inputs, state).map(|outputs| (outputs, 0))
+        }
+    }
+}
+
+/// Simulates a system call costing `cost` gas, where `handler` gets the non-implicit inputs, and
+/// returns either the non-implicit outputs of the success branch, or the revert reason.
+fn simulate_syscall(
+    inputs: &[CoreValue],
+    cost: i64,
+    handler: impl FnOnce(
+        &[CoreValue],
+    ) -> Result<Result<Vec<CoreValue>, CoreValue>, LibfuncSimulationError>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let (gas_counter, args) = match inputs {
+        [CoreValue::GasBuiltin(gas_counter), CoreValue::System, args @ ..] => (*gas_counter, args),
+        [_, _, ..] => return Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        _ => return Err(LibfuncSimulationError::WrongNumberOfArgs),
+    };
+    let reason = if gas_counter < cost {
+        revert_reason(b"Syscall out of gas")
+    } else {
+        match handler(args)? {
+            Ok(outputs) => {
+                let mut all_outputs =
+                    vec![CoreValue::GasBuiltin(gas_counter - cost), CoreValue::System];
+                all_outputs.extend(outputs);
+                return Ok((all_outputs, 0));
+            }
+            Err(reason) => reason,
+        }
+    };
+    Ok((vec![CoreValue::GasBuiltin(gas_counter), CoreValue::System, reason], 1))
+}
+
+/// Simulates the run of a testing libfunc, which sets or gets a part of the Starknet state.
+fn simulate_testing_libfunc(
+    libfunc: &TestingConcreteLibfunc,
+    inputs: &[CoreValue],
+    state: &mut StarknetState,
+) -> Result<Vec<CoreValue>, LibfuncSimulationError> {
+    let exec_info = &mut state.exec_info;
+    match (libfunc, inputs) {
+        (TestingConcreteLibfunc::SetBlockNumber(_), [CoreValue::Uint64(value)]) => {
+            exec_info.block_info.block_number = *value;
+        }
+        (TestingConcreteLibfunc::SetBlockTimestamp(_), [CoreValue::Uint64(value)]) => {
+            exec_info.block_info.block_timestamp = *value;
+        }
+        (TestingConcreteLibfunc::SetCallerAddress(_), [CoreValue::Felt252(value)]) => {
+            exec_info.caller_address = value.clone();
+        }
+        (TestingConcreteLibfunc::SetContractAddress(_), [CoreValue::Felt252(value)]) => {
+            exec_info.contract_address = value.clone();
+        }
+        (TestingConcreteLibfunc::SetSequencerAddress(_), [CoreValue::Felt252(value)]) => {
+            exec_info.block_info.sequencer_address = value.clone();
+        }
+        (TestingConcreteLibfunc::SetVersion(_), [CoreValue::Felt252(value)]) => {
+            exec_info.tx_info.version = value.clone();
+        }
+        (TestingConcreteLibfunc::SetAccountContractAddress(_), [CoreValue::Felt252(value)]) => {
+            exec_info.tx_info.account_contract_address = value.clone();
+        }
+        (TestingConcreteLibfunc::SetMaxFee(_), [CoreValue::Uint128(value)]) => {
+            exec_info.tx_info.max_fee = *value;
+        }
+        (TestingConcreteLibfunc::SetTransactionHash(_), [CoreValue::Felt252(value)]) => {
+            exec_info.tx_info.transaction_hash = value.clone();
+        }
+        (TestingConcreteLibfunc::SetChainId(_), [CoreValue::Felt252(value)]) => {
+            exec_info.tx_info.chain_id = value.clone();
+        }
+        (TestingConcreteLibfunc::SetNonce(_), [CoreValue::Felt252(value)]) => {
+            exec_info.tx_info.nonce = value.clone();
+        }
+        (TestingConcreteLibfunc::SetSignature(_), [signature]) => {
+            exec_info.tx_info.signature = extract_felt252_span(signature)?;
+        }
+        (
+            TestingConcreteLibfunc::SetStorageAt(_),
+            [
+                CoreValue::Felt252(contract_address),
+                CoreValue::Felt252(address),
+                CoreValue::Felt252(value),
+            ],
+        ) => {
+            state
+                .storage
+                .entry(contract_address.clone())
+                .or_default()
+                .insert(address.clone(), value.clone());
+        }
+        (
+            TestingConcreteLibfunc::GetStorageAt(_),
+            [CoreValue::Felt252(contract_address), CoreValue::Felt252(address)],
+        ) => {
+            let value = state
+                .storage
+                .get(contract_address)
+                .and_then(|contract_storage| contract_storage.get(address))
+                .cloned()
+                .unwrap_or_default();
+            return Ok(vec![CoreValue::Felt252(value)]);
+        }
+        (TestingConcreteLibfunc::ResetState(_), []) => {
+            *state = StarknetState::default();
+        }
+        (TestingConcreteLibfunc::ResetState(_), _)
+        | (TestingConcreteLibfunc::SetSignature(_), _) => {
+            return Err(LibfuncSimulationError::WrongNumberOfArgs);
+        }
+        (TestingConcreteLibfunc::SetStorageAt(_), [_, _, _])
+        | (TestingConcreteLibfunc::GetStorageAt(_), [_, _])
+        | (_, [_]) => return Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        _ => return Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+    Ok(vec![])
+}
+
+/// Returns the value of the boxed `ExecutionInfo` struct.
+fn execution_info_value(exec_info: &ExecutionInfo) -> CoreValue {
+    let block_info = &exec_info.block_info;
+    let tx_info = &exec_info.tx_info;
+    CoreValue::Struct(vec![
+        CoreValue::Struct(vec![
+            CoreValue::Uint64(block_info.block_number),
+            CoreValue::Uint64(block_info.block_timestamp),
+            CoreValue::Felt252(block_info.sequencer_address.clone()),
+        ]),
+        CoreValue::Struct(vec![
+            CoreValue::Felt252(tx_info.version.clone()),
+            CoreValue::Felt252(tx_info.account_contract_address.clone()),
+            CoreValue::Uint128(tx_info.max_fee),
+            felt252_span_value(&tx_info.signature),
+            CoreValue::Felt252(tx_info.transaction_hash.clone()),
+            CoreValue::Felt252(tx_info.chain_id.clone()),
+            CoreValue::Felt252(tx_info.nonce.clone()),
+        ]),
+        CoreValue::Felt252(exec_info.caller_address.clone()),
+        CoreValue::Felt252(exec_info.contract_address.clone()),
+        CoreValue::Felt252(exec_info.entry_point_selector.clone()),
+    ])
+}
+
+/// Returns the value of a `Span<felt252>` of the given felt252s.
+fn felt252_span_value(values: &[BigInt]) -> CoreValue {
+    CoreValue::Struct(vec![CoreValue::Array(
+        values.iter().cloned().map(CoreValue::Felt252).collect(),
+    )])
+}
+
+/// Returns the felt252s of a `Span<felt252>` value.
+fn extract_felt252_span(value: &CoreValue) -> Result<Vec<BigInt>, LibfuncSimulationError> {
+    let CoreValue::Struct(members) = value else {
+        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+    };
+    let [CoreValue::Array(elements)] = &members[..] else {
+        return Err(LibfuncSimulationError::MemoryLayoutMismatch);
+    };
+    elements
+        .iter()
+        .map(|element| match element {
+            CoreValue::Felt252(value) => Ok(value.clone()),
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        })
+        .collect()
+}
+
+/// Returns the revert reason array of a failed system call, holding the given short string.
+fn revert_reason(reason: &[u8]) -> CoreValue {
+    CoreValue::Array(vec![CoreValue::Felt252(BigInt::from_bytes_be(Sign::Plus, reason))])
+}
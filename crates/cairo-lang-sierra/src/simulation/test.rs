@@ -1,15 +1,23 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use bimap::BiMap;
-use num_bigint::BigInt;
+use indoc::indoc;
+use num_bigint::{BigInt, Sign};
 use test_case::test_case;
 
+use super::starknet::StarknetState;
 use super::value::CoreValue::{
-    self, Array, GasBuiltin, NonZero, RangeCheck, Uint128, Uint64, Uninitialized,
+    self, Array, EcPoint, EcState, Felt252, Felt252Dict, GasBuiltin, NonZero, Nullable, RangeCheck,
+    SegmentArena, SquashedFelt252Dict, Struct, System, Uint128, Uint32, Uint64, Uint8,
+    Uninitialized,
 };
 use super::LibfuncSimulationError::{
     self, FunctionSimulationError, MemoryLayoutMismatch, WrongNumberOfArgs,
 };
 use super::{core, SimulationError};
-use crate::extensions::core::CoreLibfunc;
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::extensions::gas::CostTokenType;
 use crate::extensions::lib_func::{
     SierraApChange, SignatureSpecializationContext, SpecializationContext,
 };
@@ -18,7 +26,9 @@ use crate::extensions::types::TypeInfo;
 use crate::extensions::GenericLibfunc;
 use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 use crate::program::{ConcreteTypeLongId, Function, FunctionSignature, GenericArg, StatementIdx};
+use crate::program_registry::ProgramRegistry;
 use crate::test_utils::build_bijective_mapping;
+use crate::ProgramParser;
 
 fn type_arg(name: &str) -> GenericArg {
     GenericArg::Type(name.into())
@@ -118,14 +128,14 @@ fn simulate(
     generic_args: Vec<GenericArg>,
     inputs: Vec<CoreValue>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let context = MockSpecializationContext::new();
     core::simulate(
-        &CoreLibfunc::by_id(&id.into())
-            .unwrap()
-            .specialize(&MockSpecializationContext::new(), &generic_args)
-            .unwrap(),
+        &CoreLibfunc::by_id(&id.into()).unwrap().specialize(&context, &generic_args).unwrap(),
         inputs,
-        || Some(4),
-        |id, inputs| {
+        &mut StarknetState::default(),
+        |_| Some(4),
+        |ty| Some(context.mapping.get_by_left(ty)?.generic_id.clone()),
+        |id, inputs, _| {
             if id == &"drop_all_inputs".into() {
                 Ok(vec![])
             } else if id == &"identity".into() {
@@ -170,10 +180,10 @@ fn simulate_branch(
 #[test_case("array_new", vec![type_arg("u128")], vec![] => Ok(vec![Array(vec![])]); "array_new()")]
 #[test_case("array_append", vec![type_arg("u128")], vec![Array(vec![]), Uint128(4)] =>
             Ok(vec![Array(vec![Uint128(4)])]); "array_append([], 4)")]
-#[test_case("array_get", vec![type_arg("u128")], vec![RangeCheck, Array(vec![Uint128(5)]), Uint64(0)]
+#[test_case("array_get", vec![type_arg("u128")], vec![RangeCheck, Array(vec![Uint128(5)]), Uint32(0)]
              => Ok(vec![RangeCheck, Uint128(5)]); "array_get([5], 0)")]
 #[test_case("array_len", vec![type_arg("u128")], vec![Array(vec![])] =>
-            Ok(vec![Uint64(0)]); "array_len([])")]
+            Ok(vec![Uint32(0)]); "array_len([])")]
 #[test_case("u128_safe_divmod", vec![], vec![RangeCheck, Uint128(32), NonZero(Box::new(Uint128(5)))]
              => Ok(vec![RangeCheck, Uint128(6), Uint128(2)]); "u128_safe_divmod(32, 5)")]
 #[test_case("u128_const", vec![value_arg(3)], vec![] => Ok(vec![Uint128(3)]);
@@ -243,3 +253,352 @@ fn simulate_error(
 ) -> LibfuncSimulationError {
     simulate(id, generic_args, inputs).err().unwrap()
 }
+
+/// Returns the prime defining the felt252 field.
+fn prime() -> BigInt {
+    BigInt::from_str("3618502788666131213697322783095070105623107215331596699973092056135872020481")
+        .unwrap()
+}
+
+/// Returns the felt252 equal to `value` modulo the prime.
+fn felt252(value: i128) -> CoreValue {
+    Felt252((BigInt::from(value) + prime()) % prime())
+}
+
+/// Simulates the libfunc declared with the id `tested` in the given declarations, with the given
+/// inputs and Starknet state. Every statement is assumed to have a const gas variable of 4, and
+/// a variable of 1 for every other token type.
+fn simulate_declared(
+    declarations: &str,
+    inputs: Vec<CoreValue>,
+    starknet_state: &mut StarknetState,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let program = ProgramParser::new().parse(declarations).unwrap();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+    let type_generic_ids: HashMap<_, _> = program
+        .type_declarations
+        .iter()
+        .map(|declaration| (declaration.id.clone(), declaration.long_id.generic_id.clone()))
+        .collect();
+    core::simulate(
+        registry.get_libfunc(&"tested".into()).unwrap(),
+        inputs,
+        starknet_state,
+        |token_type| Some(if token_type == CostTokenType::Const { 4 } else { 1 }),
+        |ty| type_generic_ids.get(ty).cloned(),
+        |_, _, _| unreachable!("No functions are declared."),
+    )
+}
+
+#[test_case("felt252_sub", vec![felt252(0), felt252(1)] => Ok((vec![felt252(-1)], 0));
+            "felt252_sub(0, 1)")]
+#[test_case("felt252_mul", vec![felt252(-1), felt252(-1)] => Ok((vec![felt252(1)], 0));
+            "felt252_mul(-1, -1)")]
+#[test_case("felt252_div", vec![felt252(6), NonZero(Box::new(felt252(-3)))]
+             => Ok((vec![felt252(-2)], 0)); "felt252_div(6, -3)")]
+#[test_case("felt252_const<-1>", vec![] => Ok((vec![felt252(-1)], 0)); "felt252_const<-1>()")]
+#[test_case("u8_safe_divmod", vec![RangeCheck, Uint8(200), NonZero(Box::new(Uint8(7)))]
+             => Ok((vec![RangeCheck, Uint8(28), Uint8(4)], 0)); "u8_safe_divmod(200, 7)")]
+#[test_case("u64_is_zero", vec![Uint64(0)] => Ok((vec![], 0)); "u64_is_zero(0)")]
+#[test_case("u64_is_zero", vec![Uint64(3)] => Ok((vec![NonZero(Box::new(Uint64(3)))], 1));
+            "u64_is_zero(3)")]
+#[test_case("u8_to_felt252", vec![Uint8(3)] => Ok((vec![felt252(3)], 0)); "u8_to_felt252(3)")]
+#[test_case("u128s_from_felt252", vec![RangeCheck, felt252(-1)]
+             => Ok((vec![
+                RangeCheck,
+                Uint128(0x8000000000000110000000000000000),
+                Uint128(0)
+             ], 1)); "u128s_from_felt252(-1)")]
+#[test_case("downcast<u128, u8>", vec![RangeCheck, Uint128(200)]
+             => Ok((vec![RangeCheck, Uint8(200)], 0)); "downcast<u128, u8>(200)")]
+#[test_case("downcast<u128, u8>", vec![RangeCheck, Uint128(300)] => Ok((vec![RangeCheck], 1));
+            "downcast<u128, u8>(300)")]
+#[test_case("upcast<u8, u64>", vec![Uint8(5)] => Ok((vec![Uint64(5)], 0)); "upcast<u8, u64>(5)")]
+#[test_case("u256_is_zero", vec![Struct(vec![Uint128(0), Uint128(1)])]
+             => Ok((vec![NonZero(Box::new(Struct(vec![Uint128(0), Uint128(1)])))], 1));
+            "u256_is_zero(2^128)")]
+#[test_case("u256_safe_divmod", vec![
+                RangeCheck,
+                Struct(vec![Uint128(5), Uint128(1)]),
+                NonZero(Box::new(Struct(vec![Uint128(2), Uint128(0)]))),
+            ] => Ok((vec![
+                RangeCheck,
+                Struct(vec![Uint128((1 << 127) + 2), Uint128(0)]),
+                Struct(vec![Uint128(1), Uint128(0)]),
+            ], 0)); "u256_safe_divmod(2^128 + 5, 2)")]
+#[test_case("null<u128>", vec![] => Ok((vec![Nullable(None)], 0)); "null<u128>()")]
+#[test_case("nullable_from_box<u128>", vec![Uint128(3)]
+             => Ok((vec![Nullable(Some(Box::new(Uint128(3))))], 0));
+            "nullable_from_box<u128>(3)")]
+#[test_case("match_nullable<u128>", vec![Nullable(None)] => Ok((vec![], 0));
+            "match_nullable<u128>(null)")]
+#[test_case("match_nullable<u128>", vec![Nullable(Some(Box::new(Uint128(3))))]
+             => Ok((vec![Uint128(3)], 1)); "match_nullable<u128>(3)")]
+#[test_case("bitwise", vec![CoreValue::Bitwise, Uint128(0b1100), Uint128(0b1010)]
+             => Ok((vec![CoreValue::Bitwise, Uint128(0b1000), Uint128(0b0110), Uint128(0b1110)], 0));
+            "bitwise(12, 10)")]
+#[test_case("pedersen", vec![CoreValue::Pedersen, felt252(1), felt252(2)]
+             => Ok((vec![CoreValue::Pedersen, Felt252(BigInt::from_str(
+                "2592987851775965742543459319508348457290966253241455514226127639100457844774"
+             ).unwrap())], 0)); "pedersen(1, 2)")]
+#[test_case("withdraw_gas_all", vec![RangeCheck, GasBuiltin(40004), CoreValue::BuiltinCosts(
+                CostTokenType::iter_precost().map(|token_type| (*token_type, 10000)).collect()
+            )] => Ok((vec![RangeCheck, GasBuiltin(0)], 0)); "withdraw_gas_all(40004)")]
+#[test_case("withdraw_gas_all", vec![RangeCheck, GasBuiltin(40003), CoreValue::BuiltinCosts(
+                CostTokenType::iter_precost().map(|token_type| (*token_type, 10000)).collect()
+            )] => Ok((vec![RangeCheck, GasBuiltin(40003)], 1)); "withdraw_gas_all(40003)")]
+fn simulate_core_libfunc(
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_declared(
+        &format!(
+            "{}libfunc tested = {libfunc};",
+            indoc! {"
+                type felt252 = felt252;
+                type u8 = u8;
+                type u64 = u64;
+                type u128 = u128;
+                type u256 = Struct<ut@core::integer::u256, u128, u128>;
+                type NonZeroFelt252 = NonZero<felt252>;
+                type NonZeroU8 = NonZero<u8>;
+                type NonZeroU64 = NonZero<u64>;
+                type NonZeroU256 = NonZero<u256>;
+                type BoxU128 = Box<u128>;
+                type NullableU128 = Nullable<u128>;
+                type RangeCheck = RangeCheck;
+                type Bitwise = Bitwise;
+                type Pedersen = Pedersen;
+                type GasBuiltin = GasBuiltin;
+                type BuiltinCosts = BuiltinCosts;
+            "}
+        ),
+        inputs,
+        &mut StarknetState::default(),
+    )
+}
+
+/// Simulates a sequence of EC libfuncs, each given by its name and inputs.
+fn simulate_ec_libfuncs(invocations: Vec<(&str, Vec<CoreValue>)>) -> Vec<(Vec<CoreValue>, usize)> {
+    invocations
+        .into_iter()
+        .map(|(libfunc, inputs)| {
+            simulate_declared(
+                &format!(
+                    "{}libfunc tested = {libfunc};",
+                    indoc! {"
+                        type felt252 = felt252;
+                        type RangeCheck = RangeCheck;
+                        type EcOp = EcOp;
+                        type EcPoint = EcPoint;
+                        type EcState = EcState;
+                        type NonZeroEcPoint = NonZero<EcPoint>;
+                    "}
+                ),
+                inputs,
+                &mut StarknetState::default(),
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn simulate_ec() {
+    let gen_x = BigInt::from_str(
+        "874739451078007766457464989774322083649278607533249481151382481072868806602",
+    )
+    .unwrap();
+    let gen_y = BigInt::from_str(
+        "152666792071518830868575557812948353041420400780739481342941381225525861407",
+    )
+    .unwrap();
+    let gen = || NonZero(Box::new(EcPoint(gen_x.clone(), gen_y.clone())));
+    let neg_gen_y = prime() - &gen_y;
+    let results = simulate_ec_libfuncs(vec![
+        ("ec_point_try_new_nz", vec![Felt252(gen_x.clone()), Felt252(gen_y.clone())]),
+        ("ec_point_try_new_nz", vec![Felt252(gen_x.clone()), Felt252(gen_x.clone())]),
+        ("ec_point_from_x_nz", vec![RangeCheck, Felt252(gen_x.clone())]),
+        ("ec_neg", vec![EcPoint(gen_x.clone(), gen_y.clone())]),
+        ("ec_point_is_zero", vec![EcPoint(BigInt::from(0), BigInt::from(0))]),
+        ("ec_state_add", vec![EcState(gen_x.clone(), gen_y.clone()), gen()]),
+        (
+            "ec_state_add_mul",
+            vec![CoreValue::EcOp, EcState(gen_x.clone(), gen_y.clone()), felt252(2), gen()],
+        ),
+        ("ec_state_add", vec![EcState(gen_x.clone(), neg_gen_y.clone()), gen()]),
+        ("ec_state_try_finalize_nz", vec![EcState(BigInt::from(0), BigInt::from(0))]),
+    ]);
+    assert_eq!(results[0], (vec![gen()], 0));
+    assert_eq!(results[1], (vec![], 1));
+    // The smaller root is chosen, which is the generator's y coordinate.
+    assert_eq!(results[2], (vec![RangeCheck, gen()], 0));
+    assert_eq!(results[3], (vec![EcPoint(gen_x.clone(), neg_gen_y)], 0));
+    assert_eq!(results[4], (vec![], 0));
+    // Adding the generator to itself once, or twice using a multiplication, gives consistent
+    // results.
+    let EcState(double_x, double_y) = &results[5].0[0] else { panic!("Expected an EC state.") };
+    let results_triple = simulate_ec_libfuncs(vec![(
+        "ec_state_add",
+        vec![EcState(double_x.clone(), double_y.clone()), gen()],
+    )]);
+    assert_eq!(results[6].0[1], results_triple[0].0[0]);
+    assert_eq!(results[7], (vec![EcState(BigInt::from(0), BigInt::from(0))], 0));
+    assert_eq!(results[8], (vec![], 1));
+}
+
+/// Simulates a felt252_dict libfunc on a dict of felt252s.
+fn simulate_felt252_dict_libfunc(
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_declared(
+        &format!(
+            "{}libfunc tested = {libfunc}<felt252>;",
+            indoc! {"
+                type felt252 = felt252;
+                type RangeCheck = RangeCheck;
+                type GasBuiltin = GasBuiltin;
+                type SegmentArena = SegmentArena;
+                type Felt252DictFelt252 = Felt252Dict<felt252>;
+                type SquashedFelt252DictFelt252 = SquashedFelt252Dict<felt252>;
+            "}
+        ),
+        inputs,
+        &mut StarknetState::default(),
+    )
+}
+
+#[test]
+fn simulate_felt252_dict() {
+    let (outputs, _) =
+        simulate_felt252_dict_libfunc("felt252_dict_new", vec![SegmentArena]).unwrap();
+    assert_eq!(outputs, vec![SegmentArena, Felt252Dict { entries: HashMap::new(), n_accesses: 0 }]);
+    let (outputs, _) = simulate_felt252_dict_libfunc(
+        "felt252_dict_write",
+        vec![outputs[1].clone(), felt252(1), felt252(5)],
+    )
+    .unwrap();
+    let (outputs, _) =
+        simulate_felt252_dict_libfunc("felt252_dict_read", vec![outputs[0].clone(), felt252(1)])
+            .unwrap();
+    assert_eq!(outputs[1], felt252(5));
+    // Reading a missing key gives the default value.
+    let (outputs, _) =
+        simulate_felt252_dict_libfunc("felt252_dict_read", vec![outputs[0].clone(), felt252(2)])
+            .unwrap();
+    assert_eq!(outputs[1], felt252(0));
+    // 3 accesses to 2 keys - a single access is refunded.
+    let (outputs, _) = simulate_felt252_dict_libfunc(
+        "felt252_dict_squash",
+        vec![RangeCheck, GasBuiltin(0), SegmentArena, outputs[0].clone()],
+    )
+    .unwrap();
+    assert_eq!(
+        outputs,
+        vec![
+            RangeCheck,
+            GasBuiltin(5620),
+            SegmentArena,
+            SquashedFelt252Dict(HashMap::from([
+                (BigInt::from(1), felt252(5)),
+                (BigInt::from(2), felt252(0))
+            ])),
+        ]
+    );
+}
+
+/// Simulates a Starknet libfunc, with the given Starknet state.
+fn simulate_starknet_libfunc(
+    libfunc: &str,
+    inputs: Vec<CoreValue>,
+    starknet_state: &mut StarknetState,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_declared(
+        &format!(
+            "{}libfunc tested = {libfunc};",
+            indoc! {"
+                type felt252 = felt252;
+                type u32 = u32;
+                type u64 = u64;
+                type GasBuiltin = GasBuiltin;
+                type System = System;
+                type StorageAddress = StorageAddress;
+                type ContractAddress = ContractAddress;
+                type ArrayFelt252 = Array<felt252>;
+            "}
+        ),
+        inputs,
+        starknet_state,
+    )
+}
+
+#[test]
+fn simulate_starknet_storage() {
+    let mut state = StarknetState::default();
+    simulate_starknet_libfunc("set_contract_address", vec![felt252(7)], &mut state).unwrap();
+    simulate_starknet_libfunc("set_block_number", vec![Uint64(3)], &mut state).unwrap();
+    assert_eq!(state.exec_info.contract_address, BigInt::from(7));
+    assert_eq!(state.exec_info.block_info.block_number, 3);
+    assert_eq!(
+        simulate_starknet_libfunc(
+            "storage_write_syscall",
+            vec![GasBuiltin(1000), System, Uint32(0), felt252(1), felt252(5)],
+            &mut state,
+        ),
+        Ok((vec![GasBuiltin(0), System], 0))
+    );
+    assert_eq!(
+        simulate_starknet_libfunc(
+            "storage_read_syscall",
+            vec![GasBuiltin(1000), System, Uint32(0), felt252(1)],
+            &mut state,
+        ),
+        Ok((vec![GasBuiltin(900), System, felt252(5)], 0))
+    );
+    // Storage is kept per contract.
+    assert_eq!(
+        simulate_starknet_libfunc("get_storage_at", vec![felt252(0), felt252(1)], &mut state),
+        Ok((vec![felt252(0)], 0))
+    );
+    assert_eq!(
+        simulate_starknet_libfunc("get_storage_at", vec![felt252(7), felt252(1)], &mut state),
+        Ok((vec![felt252(5)], 0))
+    );
+    // Failures keep the gas and provide a revert reason.
+    assert_eq!(
+        simulate_starknet_libfunc(
+            "storage_read_syscall",
+            vec![GasBuiltin(99), System, Uint32(0), felt252(1)],
+            &mut state,
+        ),
+        Ok((
+            vec![
+                GasBuiltin(99),
+                System,
+                Array(vec![Felt252(BigInt::from_bytes_be(Sign::Plus, b"Syscall out of gas"))])
+            ],
+            1
+        ))
+    );
+    assert_eq!(
+        simulate_starknet_libfunc(
+            "storage_read_syscall",
+            vec![GasBuiltin(1000), System, Uint32(1), felt252(1)],
+            &mut state,
+        ),
+        Ok((
+            vec![
+                GasBuiltin(1000),
+                System,
+                Array(vec![Felt252(BigInt::from_bytes_be(
+                    Sign::Plus,
+                    b"Unsupported address domain"
+                ))])
+            ],
+            1
+        ))
+    );
+    simulate_starknet_libfunc("reset_state", vec![], &mut state).unwrap();
+    assert_eq!(state, StarknetState::default());
+}
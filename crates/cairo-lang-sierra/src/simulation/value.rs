@@ -2,23 +2,47 @@ use std::collections::HashMap;
 
 use num_bigint::BigInt;
 
+use crate::extensions::gas::CostTokenType;
+
 /// The logical value of a variable for Sierra simulation.
+///
+/// Values of types that are represented by a single felt252 in memory, such as `ContractAddress`
+/// or `StorageAddress`, are represented as [CoreValue::Felt252], and `Box` and `Snapshot` values
+/// are represented by the wrapped value itself.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CoreValue {
+    /// A point on the curve, where `(0, 0)` represents the zero point.
     EcPoint(BigInt, BigInt),
+    /// The accumulated point of an EC state, where `(0, 0)` represents the zero point.
+    EcState(BigInt, BigInt),
     // TODO(orizi): Use actual felt252 object.
+    /// A felt252 value, in the range `[0, PRIME)`.
     Felt252(BigInt),
     GasBuiltin(i64),
     RangeCheck,
+    Bitwise,
+    EcOp,
+    Pedersen,
+    Poseidon,
+    SegmentArena,
+    System,
+    /// The cost of a single usage of every builtin.
+    BuiltinCosts(HashMap<CostTokenType, i64>),
     Uint8(u8),
     Uint16(u16),
     Uint32(u32),
     Uint64(u64),
     Uint128(u128),
     NonZero(Box<CoreValue>),
+    Nullable(Option<Box<CoreValue>>),
     Ref(Box<CoreValue>),
     Array(Vec<CoreValue>),
-    Dict(HashMap<BigInt, CoreValue>),
+    Felt252Dict {
+        entries: HashMap<BigInt, CoreValue>,
+        /// The number of reads and writes done on the dict so far.
+        n_accesses: usize,
+    },
+    SquashedFelt252Dict(HashMap<BigInt, CoreValue>),
     Enum {
         value: Box<CoreValue>,
         /// The index of the relevant variant.
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use cairo_lang_sierra::extensions::core::{CoreLibfunc, CoreType};
 use cairo_lang_sierra::program::{Program, StatementIdx};
 use cairo_lang_sierra::program_registry::ProgramRegistry;
 use cairo_lang_sierra::simulation::value::CoreValue;
 use cairo_lang_sierra::simulation::{self};
-use num_bigint::ToBigInt;
+use num_bigint::{BigInt, ToBigInt};
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
@@ -41,6 +42,11 @@ fn create_registry(name: &str) {
 #[test_case((1000, 8), (912, 34); "8 => 34")]
 #[test_case((100, 80), (1, -1); "Out of gas.")]
 fn simulate_fib_jumps((gb, n): (i64, i128), (new_gb, fib): (i64, i128)) {
+    // Felts are simulated as their representatives in the range `[0, PRIME)`.
+    let prime = BigInt::from_str(
+        "3618502788666131213697322783095070105623107215331596699973092056135872020481",
+    )
+    .unwrap();
     assert_eq!(
         simulation::run(
             &get_example_program("fib_jumps"),
@@ -66,7 +72,7 @@ fn simulate_fib_jumps((gb, n): (i64, i128), (new_gb, fib): (i64, i128)) {
         Ok(vec![
             CoreValue::RangeCheck,
             CoreValue::GasBuiltin(new_gb),
-            CoreValue::Felt252(fib.to_bigint().unwrap())
+            CoreValue::Felt252((fib.to_bigint().unwrap() + &prime) % &prime)
         ])
     );
 }
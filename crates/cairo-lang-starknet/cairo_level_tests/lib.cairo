@@ -5,17 +5,50 @@ use option::OptionTrait;
 use traits::Into;
 use zeroable::Zeroable;
 
+#[derive(Copy, Drop, PartialEq, StorageAccess)]
+struct Position {
+    x: u128,
+    y: u256,
+    visible: bool,
+}
+
+#[derive(Copy, Drop, StorageAccess)]
+enum Shape {
+    Point: (),
+    Circle: u128,
+    Rectangle: Position,
+}
+
 #[contract]
 mod TestContract {
     use array::ArrayTrait;
     use option::OptionTrait;
     use traits::Into;
     use starknet::StorageAddress;
+    use super::Position;
+    use super::Shape;
 
     struct Storage {
         value: felt252,
         mapping: LegacyMap::<u128, bool>,
         large_mapping: LegacyMap::<u256, u256>,
+        nested_mapping: LegacyMap::<u128, LegacyMap<u128, felt252>>,
+        tuple_mapping: Map::<(u128, u128), felt252>,
+        position: Position,
+        shapes: LegacyMap::<felt252, Shape>,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct ValueSet {
+        #[key]
+        previous: felt252,
+        value: felt252,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        ValueSet: ValueSet, 
     }
 
     #[view]
@@ -38,7 +71,9 @@ mod TestContract {
 
     #[external]
     fn set_value(a: felt252) {
+        let previous = value::read();
         value::write(a);
+        emit(Event::ValueSet(ValueSet { previous, value: a }));
     }
 
     #[view]
@@ -71,6 +106,16 @@ mod TestContract {
         large_mapping::read(key)
     }
 
+    #[external]
+    fn set_nested(key0: u128, key1: u128, value: felt252) {
+        nested_mapping::write(key0, key1, value)
+    }
+
+    #[view]
+    fn get_nested(key0: u128, key1: u128) -> felt252 {
+        nested_mapping::read(key0, key1)
+    }
+
     #[view]
     fn test_storage_address(storage_address: StorageAddress) -> StorageAddress {
         storage_address
@@ -204,6 +249,83 @@ fn write_read_large_value() {
     assert(retdata.is_empty(), 'Array not empty');
 }
 
+#[test]
+#[available_gas(300000)]
+fn write_read_nested_value() {
+    TestContract::set_nested(1_u128, 2_u128, 3);
+    assert(TestContract::get_nested(1_u128, 2_u128) == 3, 'Wrong result');
+    assert(TestContract::get_nested(2_u128, 1_u128) == 0, 'Wrong result');
+    assert(TestContract::get_nested(1_u128, 3_u128) == 0, 'Wrong result');
+}
+
+#[test]
+#[available_gas(300000)]
+fn nested_and_tuple_mapping_addresses() {
+    let nested_address = starknet::storage_address_from_base(
+        TestContract::nested_mapping::address(1_u128, 2_u128)
+    );
+    let nested_base = 0x236d5e1cc43c2b83c8ee1a4e54991369f8671c5b7e2fc65528adbfc6f6c170c;
+    let tuple_key_address = hash::LegacyHash::hash(nested_base, (1_u128, 2_u128));
+    assert(nested_address.into() == tuple_key_address, 'Wrong nested address');
+    let tuple_address = starknet::storage_address_from_base(
+        TestContract::tuple_mapping::address((1_u128, 2_u128))
+    );
+    let tuple_base = 0x31828dd6a9c8abb871a7c5bed59fe62767da360dd0a0a9600cd98b65649b724;
+    assert(
+        tuple_address.into() == hash::LegacyHash::<u128>::hash(
+            hash::LegacyHash::<u128>::hash(tuple_base, 1_u128), 2_u128
+        ),
+        'Wrong tuple address'
+    );
+}
+
+#[test]
+#[available_gas(300000)]
+fn write_read_tuple_key_value() {
+    TestContract::tuple_mapping::write((1_u128, 2_u128), 3);
+    assert(TestContract::tuple_mapping::read((1_u128, 2_u128)) == 3, 'Wrong result');
+    assert(TestContract::tuple_mapping::read((2_u128, 1_u128)) == 0, 'Wrong result');
+}
+
+#[test]
+#[available_gas(300000)]
+fn write_read_struct_value() {
+    assert(starknet::StorageAccessInternal::<Position>::size_internal() == 4_u8, 'Wrong size');
+    let position = Position { x: 1_u128, y: u256 { low: 2_u128, high: 3_u128 }, visible: true };
+    TestContract::position::write(position);
+    assert(TestContract::position::read() == position, 'Wrong result');
+    let base = TestContract::position::address();
+    let contract_address = starknet::get_contract_address();
+    let address = starknet::storage_address_from_base_and_offset(base, 2_u8);
+    assert(starknet::testing::get_storage_at(contract_address, address) == 3, 'Wrong layout');
+    let address = starknet::storage_address_from_base_and_offset(base, 3_u8);
+    assert(starknet::testing::get_storage_at(contract_address, address) == 1, 'Wrong layout');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn write_read_enum_value() {
+    assert(starknet::StorageAccessInternal::<Shape>::size_internal() == 5_u8, 'Wrong size');
+    match TestContract::shapes::read(0) {
+        Shape::Point(_) => {},
+        Shape::Circle(_) => panic_with_felt252('Wrong default variant'),
+        Shape::Rectangle(_) => panic_with_felt252('Wrong default variant'),
+    }
+    TestContract::shapes::write(1, Shape::Circle(7_u128));
+    match TestContract::shapes::read(1) {
+        Shape::Point(_) => panic_with_felt252('Wrong variant'),
+        Shape::Circle(radius) => assert(radius == 7_u128, 'Wrong radius'),
+        Shape::Rectangle(_) => panic_with_felt252('Wrong variant'),
+    }
+    let corner = Position { x: 4_u128, y: u256 { low: 5_u128, high: 6_u128 }, visible: false };
+    TestContract::shapes::write(2, Shape::Rectangle(corner));
+    match TestContract::shapes::read(2) {
+        Shape::Point(_) => panic_with_felt252('Wrong variant'),
+        Shape::Circle(_) => panic_with_felt252('Wrong variant'),
+        Shape::Rectangle(position) => assert(position == corner, 'Wrong position'),
+    }
+}
+
 #[test]
 #[available_gas(300000)]
 fn test_get_block_info() {
@@ -236,6 +358,73 @@ fn test_get_contract_address() {
     assert(starknet::get_contract_address().into() == 1, 'not set value');
 }
 
+#[test]
+#[available_gas(300000)]
+fn test_get_tx_info() {
+    let mut signature = ArrayTrait::new();
+    signature.append(4);
+    signature.append(5);
+    starknet::testing::set_version(1);
+    starknet::testing::set_account_contract_address(starknet::contract_address_const::<2>());
+    starknet::testing::set_max_fee(3_u128);
+    starknet::testing::set_signature(signature.span());
+    starknet::testing::set_transaction_hash(6);
+    starknet::testing::set_chain_id(7);
+    starknet::testing::set_nonce(8);
+    let info = starknet::get_tx_info().unbox();
+    assert(info.version == 1, 'version not set');
+    assert(info.account_contract_address.into() == 2, 'account_address not set');
+    assert(info.max_fee == 3_u128, 'max_fee not set');
+    assert(info.transaction_hash == 6, 'transaction_hash not set');
+    assert(info.chain_id == 7, 'chain_id not set');
+    assert(info.nonce == 8, 'nonce not set');
+    let signature = info.signature;
+    assert(signature.len() == 2_u32, 'signature not set');
+    assert(*signature[0_u32] == 4, 'signature not set');
+    assert(*signature[1_u32] == 5, 'signature not set');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_storage_at() {
+    let contract_address = starknet::contract_address_const::<1>();
+    let address = starknet::storage_address_try_from_felt252(0x17).unwrap();
+    assert(starknet::testing::get_storage_at(contract_address, address) == 0, 'non default value');
+    starknet::testing::set_storage_at(contract_address, address, 5);
+    assert(starknet::testing::get_storage_at(contract_address, address) == 5, 'value not set');
+    let other_contract_address = starknet::contract_address_const::<2>();
+    assert(
+        starknet::testing::get_storage_at(other_contract_address, address) == 0,
+        'value set for other contract'
+    );
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_storage_at_of_contract() {
+    let contract_address = starknet::contract_address_const::<1>();
+    let address = starknet::storage_address_from_base(TestContract::value::address());
+    starknet::testing::set_contract_address(contract_address);
+    TestContract::set_value(5);
+    assert(starknet::testing::get_storage_at(contract_address, address) == 5, 'value not read');
+    starknet::testing::set_storage_at(contract_address, address, 6);
+    assert(TestContract::get_value() == 6, 'value not written');
+}
+
+#[test]
+#[available_gas(300000)]
+fn test_reset_state() {
+    let contract_address = starknet::contract_address_const::<1>();
+    let address = starknet::storage_address_try_from_felt252(0x17).unwrap();
+    starknet::testing::set_contract_address(contract_address);
+    starknet::testing::set_nonce(1);
+    starknet::testing::set_storage_at(contract_address, address, 5);
+    starknet::testing::reset_state();
+    assert(starknet::get_contract_address().is_zero(), 'contract_address not reset');
+    assert(starknet::get_tx_info().unbox().nonce == 0, 'nonce not reset');
+    assert(starknet::testing::get_storage_at(contract_address, address) == 0, 'storage not reset');
+}
+
 #[test]
 #[should_panic]
 fn test_out_of_range_storage_address_from_felt252() -> starknet::StorageAddress {
@@ -252,3 +441,24 @@ fn test_storage_address() {
 
     assert(*args[0_u32] == *ret_data[0_u32], 'Unexpected ret_data.');
 }
+
+#[test]
+#[available_gas(300000)]
+fn test_event_keys_and_data() {
+    let mut keys = ArrayTrait::new();
+    let mut data = ArrayTrait::new();
+    starknet::Event::append_keys_and_data(
+        TestContract::Event::ValueSet(TestContract::ValueSet { previous: 1, value: 2 }),
+        ref keys,
+        ref data
+    );
+    assert(keys.len() == 2_u32, 'Unexpected keys length');
+    // The selector of the variant, `starknet_keccak('ValueSet')`.
+    assert(
+        *keys[0_u32] == 0x3bcfdff2200d93292dd3eacdedd5d180b03c32e026410b89b302a5b2a3141f3,
+        'Unexpected variant selector'
+    );
+    assert(*keys[1_u32] == 1, 'Unexpected key');
+    assert(data.len() == 1_u32, 'Unexpected data length');
+    assert(*data[0_u32] == 2, 'Unexpected data');
+}
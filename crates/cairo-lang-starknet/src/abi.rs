@@ -1,15 +1,23 @@
 use std::collections::HashSet;
 
-use cairo_lang_defs::ids::{LanguageElementId, TraitFunctionId, TraitId};
-use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
+use cairo_lang_defs::ids::{
+    ImplDefId, LanguageElementId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+};
+use cairo_lang_diagnostics::{DiagnosticAdded, Maybe, ToMaybe};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::items::structure::SemanticStructEx;
 use cairo_lang_semantic::{ConcreteTypeId, GenericArgumentId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::Terminal;
 use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 use thiserror::Error;
 
-use crate::plugin::consts::{EVENT_ATTR, VIEW_ATTR};
+use crate::contract::{starknet_keccak, storage_var_path};
+use crate::plugin::consts::{
+    EMIT_FUNCTION_NAME, EVENT_ATTR, KEY_ATTR, STORAGE_VAR_ATTR, VIEW_ATTR,
+};
 
 #[cfg(test)]
 #[path = "abi_test.rs"]
@@ -19,7 +27,6 @@ mod test;
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Contract {
-    // TODO(spapini): Add storage variables.
     pub items: Vec<Item>,
 }
 impl Contract {
@@ -35,26 +42,75 @@ pub struct AbiBuilder {
     /// List of type that were included abi.
     /// Used to avoid redendency.
     types: HashSet<TypeId>,
+
+    /// List of types that were included in the abi as events.
+    event_types: HashSet<TypeId>,
+
+    /// List of traits that were included in the abi as interfaces.
+    interfaces: HashSet<TraitId>,
 }
 
 impl AbiBuilder {
     /// Creates a Starknet contract ABI from a TraitId.
     pub fn from_trait(db: &dyn SemanticGroup, trait_id: TraitId) -> Result<Contract, ABIError> {
+        Self::from_contract(db, trait_id, &[], &[])
+    }
+
+    /// Creates a Starknet contract ABI from the TraitId of the ABI trait of a contract, the
+    /// ImplDefIds of its external impls, whose functions are grouped by the interface they
+    /// implement, and the names and ABI traits of its embedded components, whose items are merged
+    /// into the ABI of the contract.
+    pub fn from_contract(
+        db: &dyn SemanticGroup,
+        trait_id: TraitId,
+        external_impls: &[ImplDefId],
+        components: &[(SmolStr, TraitId)],
+    ) -> Result<Contract, ABIError> {
+        let mut builder = Self {
+            abi: Contract::default(),
+            types: HashSet::new(),
+            event_types: HashSet::new(),
+            interfaces: HashSet::new(),
+        };
+
+        builder.add_abi_trait(db, trait_id, None)?;
+        for (component_name, component_trait_id) in components {
+            builder.add_abi_trait(db, *component_trait_id, Some(component_name))?;
+        }
+        for impl_def_id in external_impls {
+            builder.add_impl(db, *impl_def_id)?;
+        }
+
+        Ok(builder.abi)
+    }
+
+    /// Adds the functions, events and storage variables of an ABI trait to the ABI. The storage
+    /// variables of a component are namespaced by the name of the component.
+    fn add_abi_trait(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_id: TraitId,
+        storage_namespace: Option<&str>,
+    ) -> Result<(), ABIError> {
         if !db.trait_generic_params(trait_id).map_err(|_| ABIError::CompilationError)?.is_empty() {
             return Err(ABIError::GenericTraitsUnsupported);
         }
 
-        let mut builder = Self { abi: Contract::default(), types: HashSet::new() };
-
         for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
             if trait_function_has_attr(db, *trait_function_id, EVENT_ATTR)? {
-                builder.add_event(db, *trait_function_id)?;
+                if trait_function_id.name(db.upcast()) == EMIT_FUNCTION_NAME {
+                    self.add_emit_function(db, *trait_function_id)?;
+                } else {
+                    self.add_event_function(db, *trait_function_id)?;
+                }
+            } else if trait_function_has_attr(db, *trait_function_id, STORAGE_VAR_ATTR)? {
+                self.add_storage_variable(db, *trait_function_id, storage_namespace)?;
             } else {
-                builder.add_function(db, *trait_function_id)?;
+                self.add_function(db, *trait_function_id)?;
             }
         }
 
-        Ok(builder.abi)
+        Ok(())
     }
 
     /// Adds a function to the ABI from a TraitFunctionId.
@@ -63,6 +119,45 @@ impl AbiBuilder {
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
     ) -> Result<(), ABIError> {
+        let function = self.function_abi(db, trait_function_id)?;
+        self.abi.items.push(Item::Function(function));
+        Ok(())
+    }
+
+    /// Adds an external impl of a contract to the ABI, along with the interface it implements if
+    /// it was not added previously.
+    fn add_impl(&mut self, db: &dyn SemanticGroup, impl_def_id: ImplDefId) -> Result<(), ABIError> {
+        let concrete_trait_id =
+            db.impl_def_concrete_trait(impl_def_id).map_err(|_| ABIError::CompilationError)?;
+        if !concrete_trait_id.generic_args(db).is_empty() {
+            return Err(ABIError::GenericTraitsUnsupported);
+        }
+        let trait_id = concrete_trait_id.trait_id(db);
+        let interface_name = trait_id.full_path(db.upcast());
+
+        if self.interfaces.insert(trait_id) {
+            let mut items = vec![];
+            for trait_function_id in
+                db.trait_functions(trait_id).map_err(|_| ABIError::CompilationError)?.values()
+            {
+                items.push(Item::Function(self.function_abi(db, *trait_function_id)?));
+            }
+            self.abi.items.push(Item::Interface(Interface { name: interface_name.clone(), items }));
+        }
+        self.abi
+            .items
+            .push(Item::Impl(Impl { name: impl_def_id.name(db.upcast()).into(), interface_name }));
+
+        Ok(())
+    }
+
+    /// Returns the ABI of a function from a TraitFunctionId, and adds the types it uses to the
+    /// ABI.
+    fn function_abi(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<Function, ABIError> {
         let state_mutability = if trait_function_has_attr(db, trait_function_id, VIEW_ATTR)? {
             StateMutability::View
         } else {
@@ -88,13 +183,11 @@ impl AbiBuilder {
             vec![Output { ty: signature.return_type.format(db) }]
         };
 
-        self.abi.items.push(Item::Function(Function { name, inputs, outputs, state_mutability }));
-
-        Ok(())
+        Ok(Function { name, inputs, outputs, state_mutability })
     }
 
-    /// Adds an event to the ABI from a TraitFunctionId.
-    fn add_event(
+    /// Adds an event to the ABI from a TraitFunctionId of an `#[event]` function.
+    fn add_event_function(
         &mut self,
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
@@ -104,16 +197,117 @@ impl AbiBuilder {
         let signature = db
             .trait_function_signature(trait_function_id)
             .map_err(|_| ABIError::CompilationError)?;
-        self.abi.items.push(Item::Event(Event {
+        let inputs = signature
+            .params
+            .into_iter()
+            .map(|param| Input { name: param.id.name(db.upcast()).into(), ty: param.ty.format(db) })
+            .collect();
+        self.abi.items.push(Item::Event(Event { name, kind: EventKind::Function { inputs } }));
+
+        Ok(())
+    }
+
+    /// Adds the events of a contract to the ABI from a TraitFunctionId of its emit function, whose
+    /// single parameter is the event enum of the contract.
+    fn add_emit_function(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        let [param] = &signature.params[..] else {
+            return Err(ABIError::EventFunctionSignature);
+        };
+        self.add_event(db, param.ty)
+    }
+
+    /// Adds an event to the ABI from the TypeId of a type deriving `starknet::Event`, along with
+    /// the events nested in its variants if it is an enum.
+    fn add_event(&mut self, db: &dyn SemanticGroup, type_id: TypeId) -> Result<(), ABIError> {
+        if !self.event_types.insert(type_id) {
+            // The event was handled previously.
+            return Ok(());
+        }
+
+        let TypeLongId::Concrete(concrete) = db.lookup_intern_type(type_id) else {
+            return Err(ABIError::EventNotStructOrEnum);
+        };
+        let kind = match concrete {
+            ConcreteTypeId::Struct(id) => {
+                let key_members =
+                    get_struct_key_members(db, id).map_err(|_| ABIError::CompilationError)?;
+                let mut members = vec![];
+                for (name, member) in
+                    db.concrete_struct_members(id).map_err(|_| ABIError::CompilationError)?
+                {
+                    self.add_type(db, member.ty)?;
+                    members.push(EventField {
+                        kind: if key_members.contains(&name) {
+                            EventFieldKind::Key
+                        } else {
+                            EventFieldKind::Data
+                        },
+                        name: name.into(),
+                        ty: member.ty.format(db),
+                    });
+                }
+                EventKind::Type(EventTypeKind::Struct { members })
+            }
+            ConcreteTypeId::Enum(id) => {
+                let generic_id = id.enum_id(db);
+                let mut variants = vec![];
+                for (name, variant_id) in
+                    db.enum_variants(generic_id).map_err(|_| ABIError::CompilationError)?
+                {
+                    let ty = db
+                        .variant_semantic(generic_id, variant_id)
+                        .and_then(|variant| db.concrete_enum_variant(id, &variant))
+                        .map_err(|_| ABIError::CompilationError)?
+                        .ty;
+                    self.add_event(db, ty)?;
+                    variants.push(EventField {
+                        name: name.into(),
+                        ty: ty.format(db),
+                        kind: EventFieldKind::Nested,
+                    });
+                }
+                EventKind::Type(EventTypeKind::Enum { variants })
+            }
+            ConcreteTypeId::Extern(_) => return Err(ABIError::EventNotStructOrEnum),
+        };
+        self.abi.items.push(Item::Event(Event { name: concrete.format(db), kind }));
+
+        Ok(())
+    }
+
+    /// Adds a storage variable to the ABI from a TraitFunctionId, where the parameters of the
+    /// function are the keys of the variable and its return type is the type of the stored value.
+    /// The variable is named by its path, prefixed by the namespace of a component if given.
+    fn add_storage_variable(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+        namespace: Option<&str>,
+    ) -> Result<(), ABIError> {
+        let name = storage_var_path(namespace, &trait_function_id.name(db.upcast()));
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+
+        let mut keys = vec![];
+        for param in signature.params.into_iter() {
+            self.add_type(db, param.ty)?;
+            keys.push(param.ty.format(db));
+        }
+        self.add_type(db, signature.return_type)?;
+
+        self.abi.items.push(Item::Storage(StorageVariable {
+            address: format!("0x{:x}", starknet_keccak(name.as_bytes())),
             name,
-            inputs: signature
-                .params
-                .into_iter()
-                .map(|param| Input {
-                    name: param.id.name(db.upcast()).into(),
-                    ty: param.ty.format(db),
-                })
-                .collect(),
+            keys,
+            value: signature.return_type.format(db),
         }));
 
         Ok(())
@@ -142,7 +336,7 @@ impl AbiBuilder {
     }
 
     /// Adds a concrete type and all inner types that it depends on to ABI.
-    /// native types are skipped.
+    /// Extern types are skipped, as they have no inner structure to describe.
     fn add_concrete_type(
         &mut self,
         db: &dyn SemanticGroup,
@@ -155,62 +349,86 @@ impl AbiBuilder {
             }
         }
 
-        if is_native_type(db, &concrete) {
-            return Ok(());
-        }
-
         match concrete {
-            ConcreteTypeId::Struct(id) => self.abi.items.push(Item::Struct(Struct {
-                name: concrete.format(db),
-                members: get_struct_members(db, id).map_err(|_| ABIError::UnexpectedType)?,
-            })),
-            ConcreteTypeId::Enum(id) => self.abi.items.push(Item::Enum(Enum {
-                name: concrete.format(db),
-                variants: get_enum_variants(db, id).map_err(|_| ABIError::UnexpectedType)?,
-            })),
+            ConcreteTypeId::Struct(id) => {
+                let members = get_struct_members(db, id).map_err(|_| ABIError::UnexpectedType)?;
+                for (_, ty) in &members {
+                    self.add_type(db, *ty)?;
+                }
+                self.abi.items.push(Item::Struct(Struct {
+                    name: concrete.format(db),
+                    members: members
+                        .into_iter()
+                        .map(|(name, ty)| StructMember { name, ty: ty.format(db) })
+                        .collect(),
+                }))
+            }
+            ConcreteTypeId::Enum(id) => {
+                let variants = get_enum_variants(db, id).map_err(|_| ABIError::UnexpectedType)?;
+                for (_, ty) in &variants {
+                    self.add_type(db, *ty)?;
+                }
+                self.abi.items.push(Item::Enum(Enum {
+                    name: concrete.format(db),
+                    variants: variants
+                        .into_iter()
+                        .map(|(name, ty)| EnumVariant { name, ty: ty.format(db) })
+                        .collect(),
+                }))
+            }
             ConcreteTypeId::Extern(_) => {}
         }
         Ok(())
     }
 }
 
+/// Returns the names and types of the members of a concrete struct.
 fn get_struct_members(
     db: &dyn SemanticGroup,
     id: cairo_lang_semantic::ConcreteStructId,
-) -> Maybe<Vec<StructMember>> {
+) -> Maybe<Vec<(String, TypeId)>> {
     Ok(db
         .concrete_struct_members(id)?
         .iter()
-        .map(|(name, member)| StructMember { name: name.to_string(), ty: member.ty.format(db) })
+        .map(|(name, member)| (name.to_string(), member.ty))
         .collect())
 }
 
+/// Returns the names and types of the variants of a concrete enum.
 fn get_enum_variants(
     db: &dyn SemanticGroup,
     id: cairo_lang_semantic::ConcreteEnumId,
-) -> Maybe<Vec<EnumVariant>> {
+) -> Maybe<Vec<(String, TypeId)>> {
     let generic_id = id.enum_id(db);
 
     db.enum_variants(generic_id)?
         .iter()
         .map(|(name, variant_id)| {
-            Ok(EnumVariant {
-                name: name.to_string(),
-                ty: db
-                    .concrete_enum_variant(id, &db.variant_semantic(generic_id, *variant_id)?)?
-                    .ty
-                    .format(db),
-            })
+            Ok((
+                name.to_string(),
+                db.concrete_enum_variant(id, &db.variant_semantic(generic_id, *variant_id)?)?.ty,
+            ))
         })
         .collect::<Result<Vec<_>, DiagnosticAdded>>()
 }
 
-/// Returns true if concrete is a native type.
-///
-/// native types are not added to the ABI.
-fn is_native_type(db: &dyn SemanticGroup, concrete: &ConcreteTypeId) -> bool {
-    let def_db = db.upcast();
-    concrete.generic_type(db).parent_module(def_db).owning_crate(def_db) == db.core_crate()
+/// Returns the names of the members of a struct marked with `#[key]`, which are emitted as the keys
+/// of an event.
+fn get_struct_key_members(
+    db: &dyn SemanticGroup,
+    id: cairo_lang_semantic::ConcreteStructId,
+) -> Maybe<HashSet<SmolStr>> {
+    let struct_id = id.struct_id(db);
+    let module_structs = db.module_structs(struct_id.module_file_id(db.upcast()).0)?;
+    let struct_ast = module_structs.get(&struct_id).to_maybe()?;
+    let syntax_db = db.upcast();
+    Ok(struct_ast
+        .members(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .filter(|member| member.has_attr(syntax_db, KEY_ATTR))
+        .map(|member| member.name(syntax_db).text(syntax_db))
+        .collect())
 }
 
 /// Checks whether the trait function has the given attribute.
@@ -234,6 +452,10 @@ pub enum ABIError {
     CompilationError,
     #[error("Got unexpected type.")]
     UnexpectedType,
+    #[error("Event functions must have a single parameter, the event enum.")]
+    EventFunctionSignature,
+    #[error("Events must be structs or enums.")]
+    EventNotStructOrEnum,
 }
 
 /// Enum of contract item ABIs.
@@ -248,6 +470,12 @@ pub enum Item {
     Struct(Struct),
     #[serde(rename = "enum")]
     Enum(Enum),
+    #[serde(rename = "storage")]
+    Storage(StorageVariable),
+    #[serde(rename = "interface")]
+    Interface(Interface),
+    #[serde(rename = "impl")]
+    Impl(Impl),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -269,11 +497,73 @@ pub struct Function {
     pub state_mutability: StateMutability,
 }
 
+/// Interface ABI, the functions of a trait implemented by external impls of the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interface {
+    pub name: String,
+    pub items: Vec<Item>,
+}
+
+/// External impl ABI, whose functions are entry points of the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impl {
+    pub name: String,
+    pub interface_name: String,
+}
+
 /// Contract event.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
     pub name: String,
-    pub inputs: Vec<Input>,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// The kind of an event: an `#[event]` function or a type deriving `starknet::Event`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EventKind {
+    /// An `#[event]` function, emitted with the selector of its name as its single key, and its
+    /// parameters as data.
+    Function {
+        inputs: Vec<Input>,
+    },
+    Type(EventTypeKind),
+}
+
+/// The kind of an event type: a struct whose members are its keys and data, or an enum whose
+/// variants are nested events, emitted with the selector of the variant as an additional first
+/// key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum EventTypeKind {
+    #[serde(rename = "struct")]
+    Struct { members: Vec<EventField> },
+    #[serde(rename = "enum")]
+    Enum { variants: Vec<EventField> },
+}
+
+/// A member of a struct event or a variant of an enum event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub kind: EventFieldKind,
+}
+
+/// Where the value of an event field is emitted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventFieldKind {
+    /// A member emitted as keys of the event, by which events may be filtered.
+    #[serde(rename = "key")]
+    Key,
+    /// A member emitted as data of the event.
+    #[serde(rename = "data")]
+    Data,
+    /// A variant whose value is an event, emitted following the selector of the variant.
+    #[serde(rename = "nested")]
+    Nested,
 }
 
 /// Function input ABI.
@@ -320,3 +610,16 @@ pub struct EnumVariant {
     #[serde(rename = "type")]
     pub ty: String,
 }
+
+/// Storage variable ABI.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageVariable {
+    pub name: String,
+    /// The base address of the variable. The address of a value of a mapping is derived from it by
+    /// hashing the keys, one after the other, using `LegacyHash`.
+    pub address: String,
+    /// The types of the keys of the variable, empty if it is not a mapping.
+    pub keys: Vec<String>,
+    /// The type of the stored values.
+    pub value: String,
+}
@@ -5,7 +5,7 @@ use cairo_lang_utils::extract_matches;
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 
-use crate::abi::AbiBuilder;
+use crate::abi::{AbiBuilder, Contract};
 
 #[test]
 fn test_abi() {
@@ -23,6 +23,18 @@ fn test_abi() {
               b: MyStruct::<S>
             }
 
+            #[derive(Drop, starknet::Event)]
+            struct MyEventStruct {
+              #[key]
+              a: felt252,
+              b: MyStruct::<u128>
+            }
+
+            #[derive(Drop, starknet::Event)]
+            enum MyEventEnum {
+              a: MyEventStruct
+            }
+
             trait MyAbi {
                 fn foo(a: felt252, b: u128) -> Option::<()>;
 
@@ -37,6 +49,12 @@ fn test_abi() {
 
                 #[event]
                 fn foo_event(a: felt252, b: u128);
+
+                #[event]
+                fn emit(event: MyEventEnum);
+
+                #[storage_var]
+                fn allowances(key0: felt252, key1: felt252) -> u256;
             }
         "},
     )
@@ -54,6 +72,20 @@ fn test_abi() {
         actual_serialization,
         indoc! {
         r#"[
+            {
+              "type": "enum",
+              "name": "core::option::Option::<()>",
+              "variants": [
+                {
+                  "name": "Some",
+                  "type": "()"
+                },
+                {
+                  "name": "None",
+                  "type": "()"
+                }
+              ]
+            },
             {
               "type": "function",
               "name": "foo",
@@ -74,6 +106,20 @@ fn test_abi() {
               ],
               "state_mutability": "external"
             },
+            {
+              "type": "struct",
+              "name": "core::integer::u256",
+              "members": [
+                {
+                  "name": "low",
+                  "type": "core::integer::u128"
+                },
+                {
+                  "name": "high",
+                  "type": "core::integer::u128"
+                }
+              ]
+            },
             {
               "type": "struct",
               "name": "test::MyStruct::<core::integer::u256>",
@@ -108,6 +154,20 @@ fn test_abi() {
               ],
               "state_mutability": "external"
             },
+            {
+              "type": "struct",
+              "name": "test::MyStruct::<core::integer::u128>",
+              "members": [
+                {
+                  "name": "a",
+                  "type": "core::integer::u128"
+                },
+                {
+                  "name": "b",
+                  "type": "core::felt252"
+                }
+              ]
+            },
             {
               "type": "enum",
               "name": "test::MyEnum::<core::integer::u128>",
@@ -162,6 +222,440 @@ fn test_abi() {
                   "type": "core::integer::u128"
                 }
               ]
+            },
+            {
+              "type": "event",
+              "name": "test::MyEventStruct",
+              "kind": "struct",
+              "members": [
+                {
+                  "name": "a",
+                  "type": "core::felt252",
+                  "kind": "key"
+                },
+                {
+                  "name": "b",
+                  "type": "test::MyStruct::<core::integer::u128>",
+                  "kind": "data"
+                }
+              ]
+            },
+            {
+              "type": "event",
+              "name": "test::MyEventEnum",
+              "kind": "enum",
+              "variants": [
+                {
+                  "name": "a",
+                  "type": "test::MyEventStruct",
+                  "kind": "nested"
+                }
+              ]
+            },
+            {
+              "type": "storage",
+              "name": "allowances",
+              "address": "0xbf4c436d6f8521e5c6189511c75075de702ad597ce22c1786275e8e5167ec7",
+              "keys": [
+                "core::felt252",
+                "core::felt252"
+              ],
+              "value": "core::integer::u256"
+            }
+          ]"#}
+    );
+    // The events of `#[event]` functions and of event types are told apart when deserialized.
+    assert_eq!(serde_json::from_str::<Contract>(&actual_serialization).unwrap(), abi);
+}
+
+#[test]
+fn test_abi_with_external_impls() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct MyStruct {
+              a: felt252
+            }
+
+            trait MyInterface {
+                #[view]
+                fn get(a: felt252) -> MyStruct;
+
+                fn set(s: MyStruct);
+            }
+
+            impl MyImpl of MyInterface {
+                fn get(a: felt252) -> MyStruct {
+                    MyStruct { a }
+                }
+
+                fn set(s: MyStruct) {}
+            }
+
+            impl MyOtherImpl of MyInterface {
+                fn get(a: felt252) -> MyStruct {
+                    MyStruct { a: a + 1 }
+                }
+
+                fn set(s: MyStruct) {}
+            }
+
+            trait MyAbi {
+                fn foo();
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let external_impls = ["MyImpl", "MyOtherImpl"].map(|name| {
+        extract_matches!(
+            db.module_item_by_name(module_id, name.into()).unwrap().unwrap(),
+            ModuleItemId::Impl
+        )
+    });
+    let abi = AbiBuilder::from_contract(db, trait_id, &external_impls, &[]).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+              {
+                "type": "function",
+                "name": "foo",
+                "inputs": [],
+                "outputs": [],
+                "state_mutability": "external"
+              },
+              {
+                "type": "struct",
+                "name": "test::MyStruct",
+                "members": [
+                  {
+                    "name": "a",
+                    "type": "core::felt252"
+                  }
+                ]
+              },
+              {
+                "type": "interface",
+                "name": "test::MyInterface",
+                "items": [
+                  {
+                    "type": "function",
+                    "name": "get",
+                    "inputs": [
+                      {
+                        "name": "a",
+                        "type": "core::felt252"
+                      }
+                    ],
+                    "outputs": [
+                      {
+                        "type": "test::MyStruct"
+                      }
+                    ],
+                    "state_mutability": "view"
+                  },
+                  {
+                    "type": "function",
+                    "name": "set",
+                    "inputs": [
+                      {
+                        "name": "s",
+                        "type": "test::MyStruct"
+                      }
+                    ],
+                    "outputs": [],
+                    "state_mutability": "external"
+                  }
+                ]
+              },
+              {
+                "type": "impl",
+                "name": "MyImpl",
+                "interface_name": "test::MyInterface"
+              },
+              {
+                "type": "impl",
+                "name": "MyOtherImpl",
+                "interface_name": "test::MyInterface"
+              }
+            ]"#}
+    );
+}
+
+#[test]
+fn test_abi_types() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct MyStruct<T> {
+              a: T,
+              b: felt252
+            }
+
+            struct Nested {
+              inner: MyStruct::<u128>,
+              values: Span::<felt252>
+            }
+
+            enum MyEnum<S> {
+              a: MyStruct::<S>,
+              b: ()
+            }
+
+            trait MyAbi {
+                fn tuples(a: (felt252, MyStruct::<u128>), b: ()) -> (u256, (bool, u8));
+
+                fn arrays(a: Array::<Array::<felt252>>, b: Span::<Nested>) -> Span::<u256>;
+
+                fn generics(
+                    a: Option::<MyStruct::<u128>>, b: MyEnum::<u128>, c: MyEnum::<felt252>
+                ) -> Result::<u256, felt252>;
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+    let actual_serialization = serde_json::to_string_pretty(&abi).unwrap();
+    assert_eq!(
+        actual_serialization,
+        indoc! {
+        r#"[
+            {
+              "type": "struct",
+              "name": "test::MyStruct::<core::integer::u128>",
+              "members": [
+                {
+                  "name": "a",
+                  "type": "core::integer::u128"
+                },
+                {
+                  "name": "b",
+                  "type": "core::felt252"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "core::integer::u256",
+              "members": [
+                {
+                  "name": "low",
+                  "type": "core::integer::u128"
+                },
+                {
+                  "name": "high",
+                  "type": "core::integer::u128"
+                }
+              ]
+            },
+            {
+              "type": "enum",
+              "name": "core::bool",
+              "variants": [
+                {
+                  "name": "False",
+                  "type": "()"
+                },
+                {
+                  "name": "True",
+                  "type": "()"
+                }
+              ]
+            },
+            {
+              "type": "function",
+              "name": "tuples",
+              "inputs": [
+                {
+                  "name": "a",
+                  "type": "(core::felt252, test::MyStruct::<core::integer::u128>)"
+                },
+                {
+                  "name": "b",
+                  "type": "()"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "(core::integer::u256, (core::bool, core::integer::u8))"
+                }
+              ],
+              "state_mutability": "external"
+            },
+            {
+              "type": "struct",
+              "name": "core::array::Span::<core::felt252>",
+              "members": [
+                {
+                  "name": "snapshot",
+                  "type": "@core::array::Array::<core::felt252>"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "test::Nested",
+              "members": [
+                {
+                  "name": "inner",
+                  "type": "test::MyStruct::<core::integer::u128>"
+                },
+                {
+                  "name": "values",
+                  "type": "core::array::Span::<core::felt252>"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "core::array::Span::<test::Nested>",
+              "members": [
+                {
+                  "name": "snapshot",
+                  "type": "@core::array::Array::<test::Nested>"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "core::array::Span::<core::integer::u256>",
+              "members": [
+                {
+                  "name": "snapshot",
+                  "type": "@core::array::Array::<core::integer::u256>"
+                }
+              ]
+            },
+            {
+              "type": "function",
+              "name": "arrays",
+              "inputs": [
+                {
+                  "name": "a",
+                  "type": "core::array::Array::<core::array::Array::<core::felt252>>"
+                },
+                {
+                  "name": "b",
+                  "type": "core::array::Span::<test::Nested>"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "core::array::Span::<core::integer::u256>"
+                }
+              ],
+              "state_mutability": "external"
+            },
+            {
+              "type": "enum",
+              "name": "core::option::Option::<test::MyStruct::<core::integer::u128>>",
+              "variants": [
+                {
+                  "name": "Some",
+                  "type": "test::MyStruct::<core::integer::u128>"
+                },
+                {
+                  "name": "None",
+                  "type": "()"
+                }
+              ]
+            },
+            {
+              "type": "enum",
+              "name": "test::MyEnum::<core::integer::u128>",
+              "variants": [
+                {
+                  "name": "a",
+                  "type": "test::MyStruct::<core::integer::u128>"
+                },
+                {
+                  "name": "b",
+                  "type": "()"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "test::MyStruct::<core::felt252>",
+              "members": [
+                {
+                  "name": "a",
+                  "type": "core::felt252"
+                },
+                {
+                  "name": "b",
+                  "type": "core::felt252"
+                }
+              ]
+            },
+            {
+              "type": "enum",
+              "name": "test::MyEnum::<core::felt252>",
+              "variants": [
+                {
+                  "name": "a",
+                  "type": "test::MyStruct::<core::felt252>"
+                },
+                {
+                  "name": "b",
+                  "type": "()"
+                }
+              ]
+            },
+            {
+              "type": "enum",
+              "name": "core::result::Result::<core::integer::u256, core::felt252>",
+              "variants": [
+                {
+                  "name": "Ok",
+                  "type": "core::integer::u256"
+                },
+                {
+                  "name": "Err",
+                  "type": "core::felt252"
+                }
+              ]
+            },
+            {
+              "type": "function",
+              "name": "generics",
+              "inputs": [
+                {
+                  "name": "a",
+                  "type": "core::option::Option::<test::MyStruct::<core::integer::u128>>"
+                },
+                {
+                  "name": "b",
+                  "type": "test::MyEnum::<core::integer::u128>"
+                },
+                {
+                  "name": "c",
+                  "type": "test::MyEnum::<core::felt252>"
+                }
+              ],
+              "outputs": [
+                {
+                  "type": "core::result::Result::<core::integer::u256, core::felt252>"
+                }
+              ],
+              "state_mutability": "external"
             }
           ]"#}
     );
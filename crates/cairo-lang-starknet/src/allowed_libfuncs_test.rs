@@ -14,6 +14,16 @@ fn experimental_list_includes_all() {
         "set_caller_address",
         "set_contract_address",
         "set_sequencer_address",
+        "set_version",
+        "set_account_contract_address",
+        "set_max_fee",
+        "set_transaction_hash",
+        "set_chain_id",
+        "set_nonce",
+        "set_signature",
+        "set_storage_at",
+        "get_storage_at",
+        "reset_state",
         "get_available_gas",
     ];
     pretty_assertions::assert_eq!(
@@ -0,0 +1,160 @@
+// The runtime of the Rust bindings of Starknet contracts, generated by `starknet-abi-bindings`.
+// The bindings expect it as the module `cairo_serde` next to them.
+
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+/// A field element.
+pub type Felt252 = BigUint;
+
+/// An error in decoding a value from felts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodeError;
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Failed to decode a value from felts.")
+    }
+}
+impl std::error::Error for DecodeError {}
+
+/// A value that is encoded to felts following the layout of `Serde` in Cairo.
+pub trait CairoSerde: Sized {
+    /// Appends the encoding of the value to `output`.
+    fn serialize(&self, output: &mut Vec<Felt252>);
+    /// Decodes a value from the start of `input`, and advances `input` past it.
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError>;
+}
+
+/// Decodes a value from felts, failing if any of them is left over.
+pub fn decode<T: CairoSerde>(mut data: &[Felt252]) -> Result<T, DecodeError> {
+    let value = T::deserialize(&mut data)?;
+    if data.is_empty() { Ok(value) } else { Err(DecodeError) }
+}
+
+/// Appends the encoding of a variant of an enum, with the given index and value, to `output`.
+pub fn serialize_variant<T: CairoSerde>(index: usize, value: &T, output: &mut Vec<Felt252>) {
+    Felt252::from(index).serialize(output);
+    value.serialize(output);
+}
+
+/// Decodes the index of a variant of an enum.
+pub fn deserialize_variant_index(input: &mut &[Felt252]) -> Result<usize, DecodeError> {
+    Felt252::deserialize(input)?.to_usize().ok_or(DecodeError)
+}
+
+impl CairoSerde for Felt252 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        output.push(self.clone());
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        let (value, rest) = input.split_first().ok_or(DecodeError)?;
+        *input = rest;
+        Ok(value.clone())
+    }
+}
+
+impl CairoSerde for bool {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        Felt252::from(u8::from(*self)).serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Felt252::deserialize(input)? != Felt252::from(0_u8))
+    }
+}
+
+macro_rules! impl_uint_serde {
+    ($ty:ty, $to_primitive:ident) => {
+        impl CairoSerde for $ty {
+            fn serialize(&self, output: &mut Vec<Felt252>) {
+                Felt252::from(*self).serialize(output);
+            }
+            fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+                Felt252::deserialize(input)?.$to_primitive().ok_or(DecodeError)
+            }
+        }
+    };
+}
+impl_uint_serde!(u8, to_u8);
+impl_uint_serde!(u16, to_u16);
+impl_uint_serde!(u32, to_u32);
+impl_uint_serde!(u64, to_u64);
+impl_uint_serde!(u128, to_u128);
+
+impl<T: CairoSerde> CairoSerde for Vec<T> {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        Felt252::from(self.len()).serialize(output);
+        for value in self {
+            value.serialize(output);
+        }
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        let len = Felt252::deserialize(input)?.to_usize().ok_or(DecodeError)?;
+        (0..len).map(|_| T::deserialize(input)).collect()
+    }
+}
+
+impl CairoSerde for () {
+    fn serialize(&self, _output: &mut Vec<Felt252>) {}
+    fn deserialize(_input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(())
+    }
+}
+
+impl<T: CairoSerde> CairoSerde for Option<T> {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        match self {
+            Some(value) => serialize_variant(0, value, output),
+            None => serialize_variant(1, &(), output),
+        }
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        match deserialize_variant_index(input)? {
+            0 => Ok(Some(T::deserialize(input)?)),
+            1 => Ok(None),
+            _ => Err(DecodeError),
+        }
+    }
+}
+
+impl<T: CairoSerde, E: CairoSerde> CairoSerde for Result<T, E> {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        match self {
+            Ok(value) => serialize_variant(0, value, output),
+            Err(error) => serialize_variant(1, error, output),
+        }
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        match deserialize_variant_index(input)? {
+            0 => Ok(Ok(T::deserialize(input)?)),
+            1 => Ok(Err(E::deserialize(input)?)),
+            _ => Err(DecodeError),
+        }
+    }
+}
+
+macro_rules! impl_tuple_serde {
+    ($($ty:ident: $index:tt),+) => {
+        impl<$($ty: CairoSerde),+> CairoSerde for ($($ty,)+) {
+            fn serialize(&self, output: &mut Vec<Felt252>) {
+                $(self.$index.serialize(output);)+
+            }
+            fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+                Ok(($($ty::deserialize(input)?,)+))
+            }
+        }
+    };
+}
+impl_tuple_serde!(T0: 0);
+impl_tuple_serde!(T0: 0, T1: 1);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9);
+impl_tuple_serde!(T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10);
+impl_tuple_serde!(
+    T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9, T10: 10, T11: 11
+);
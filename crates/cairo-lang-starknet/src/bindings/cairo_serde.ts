@@ -0,0 +1,196 @@
+// The runtime of the TypeScript bindings of Starknet contracts, generated by
+// `starknet-abi-bindings`. The bindings import it from `./cairo_serde`.
+
+/** An error in decoding a value from felts. */
+export class DecodeError extends Error {}
+
+/** Encodes a value to felts following the layout of `Serde` in Cairo. */
+export type Serializer<T> = (value: T, output: bigint[]) => void;
+
+/** Decodes a value from felts following the layout of `Serde` in Cairo. */
+export type Deserializer<T> = (input: Reader) => T;
+
+/** A reader of felts, from which values are decoded one after the other. */
+export class Reader {
+  private position = 0;
+
+  constructor(private readonly data: bigint[]) {}
+
+  /** Returns the next felt. */
+  next(): bigint {
+    if (this.position >= this.data.length) {
+      throw new DecodeError("Unexpected end of input.");
+    }
+    return this.data[this.position++];
+  }
+
+  /** Returns whether all the felts were read. */
+  isDone(): boolean {
+    return this.position === this.data.length;
+  }
+}
+
+/** Decodes a value from felts, failing if any of them is left over. */
+export function decode<T>(data: bigint[], deserialize: Deserializer<T>): T {
+  const input = new Reader(data);
+  const value = deserialize(input);
+  if (!input.isDone()) {
+    throw new DecodeError("Unexpected felts after the end of the value.");
+  }
+  return value;
+}
+
+export type Option<T> = { variant: "Some"; value: T } | { variant: "None" };
+
+export type Result<T, E> = { variant: "Ok"; value: T } | { variant: "Err"; value: E };
+
+/** The prime of the field of felts. */
+export const FELT252_PRIME = 2n ** 251n + 17n * 2n ** 192n + 1n;
+
+export function serializeFelt252(value: bigint, output: bigint[]): void {
+  if (value < 0n || value >= FELT252_PRIME) {
+    throw new RangeError(`${value} is not a felt252.`);
+  }
+  output.push(value);
+}
+
+export function deserializeFelt252(input: Reader): bigint {
+  return input.next();
+}
+
+function serializeUint(bits: bigint): Serializer<bigint> {
+  return (value, output) => {
+    if (value < 0n || value >= 1n << bits) {
+      throw new RangeError(`${value} is not a u${bits}.`);
+    }
+    output.push(value);
+  };
+}
+
+function deserializeUint(bits: bigint): Deserializer<bigint> {
+  return (input) => {
+    const value = input.next();
+    if (value >= 1n << bits) {
+      throw new DecodeError(`${value} is not a u${bits}.`);
+    }
+    return value;
+  };
+}
+
+export const serializeU8 = serializeUint(8n);
+export const serializeU16 = serializeUint(16n);
+export const serializeU32 = serializeUint(32n);
+export const serializeU64 = serializeUint(64n);
+export const serializeU128 = serializeUint(128n);
+export const deserializeU8 = deserializeUint(8n);
+export const deserializeU16 = deserializeUint(16n);
+export const deserializeU32 = deserializeUint(32n);
+export const deserializeU64 = deserializeUint(64n);
+export const deserializeU128 = deserializeUint(128n);
+
+export function serializeBool(value: boolean, output: bigint[]): void {
+  output.push(value ? 1n : 0n);
+}
+
+export function deserializeBool(input: Reader): boolean {
+  return input.next() !== 0n;
+}
+
+export function serializeUnit(_value: [], _output: bigint[]): void {}
+
+export function deserializeUnit(_input: Reader): [] {
+  return [];
+}
+
+export function serializeArray<T>(serialize: Serializer<T>): Serializer<T[]> {
+  return (value, output) => {
+    output.push(BigInt(value.length));
+    for (const element of value) {
+      serialize(element, output);
+    }
+  };
+}
+
+export function deserializeArray<T>(deserialize: Deserializer<T>): Deserializer<T[]> {
+  return (input) => {
+    const length = input.next();
+    const value: T[] = [];
+    for (let i = 0n; i < length; i++) {
+      value.push(deserialize(input));
+    }
+    return value;
+  };
+}
+
+export function serializeTuple<T extends unknown[]>(
+  ...serializers: { [K in keyof T]: Serializer<T[K]> }
+): Serializer<T> {
+  return (value, output) => {
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    (serializers as Serializer<any>[]).forEach((serialize, i) => serialize(value[i], output));
+  };
+}
+
+export function deserializeTuple<T extends unknown[]>(
+  ...deserializers: { [K in keyof T]: Deserializer<T[K]> }
+): Deserializer<T> {
+  return (input) =>
+    (deserializers as Deserializer<unknown>[]).map((deserialize) => deserialize(input)) as T;
+}
+
+export function serializeOption<T>(serialize: Serializer<T>): Serializer<Option<T>> {
+  return (value, output) => {
+    if (value.variant === "Some") {
+      output.push(0n);
+      serialize(value.value, output);
+    } else {
+      output.push(1n);
+    }
+  };
+}
+
+export function deserializeOption<T>(deserialize: Deserializer<T>): Deserializer<Option<T>> {
+  return (input) => {
+    const index = input.next();
+    switch (index) {
+      case 0n:
+        return { variant: "Some", value: deserialize(input) };
+      case 1n:
+        return { variant: "None" };
+      default:
+        throw new DecodeError(`Invalid variant index ${index} of Option.`);
+    }
+  };
+}
+
+export function serializeResult<T, E>(
+  serializeOk: Serializer<T>,
+  serializeErr: Serializer<E>,
+): Serializer<Result<T, E>> {
+  return (value, output) => {
+    if (value.variant === "Ok") {
+      output.push(0n);
+      serializeOk(value.value, output);
+    } else {
+      output.push(1n);
+      serializeErr(value.value, output);
+    }
+  };
+}
+
+export function deserializeResult<T, E>(
+  deserializeOk: Deserializer<T>,
+  deserializeErr: Deserializer<E>,
+): Deserializer<Result<T, E>> {
+  return (input) => {
+    const index = input.next();
+    switch (index) {
+      case 0n:
+        return { variant: "Ok", value: deserializeOk(input) };
+      case 1n:
+        return { variant: "Err", value: deserializeErr(input) };
+      default:
+        throw new DecodeError(`Invalid variant index ${index} of Result.`);
+    }
+  };
+}
@@ -0,0 +1,417 @@
+//! Generation of Rust and TypeScript bindings from the ABI of a contract.
+//!
+//! The bindings contain a type for each struct and enum of the ABI, and for each function of the
+//! contract its selector, an encoder of its calldata and a decoder of its result. Values are
+//! encoded to felts following the layout of `Serde` in Cairo: the members of a struct one after
+//! the other, the index of the variant of an enum followed by its value, and the length of an array
+//! followed by its elements.
+
+use std::collections::HashMap;
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use thiserror::Error;
+
+use crate::abi::{Contract, Enum, Function, Item, Struct};
+use crate::contract::starknet_keccak;
+
+mod rust;
+mod typescript;
+
+pub use rust::{generate_rust_bindings, RUST_BINDINGS_RUNTIME};
+pub use typescript::{generate_typescript_bindings, TYPESCRIPT_BINDINGS_RUNTIME};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum BindingsError {
+    #[error("Invalid type `{0}`.")]
+    InvalidType(String),
+    #[error("Type `{0}` is not supported in bindings.")]
+    UnsupportedType(String),
+    #[error("Types `{0}` and `{1}` have the same name `{2}` in bindings.")]
+    NameCollision(String, String, String),
+}
+
+/// Names used by the runtime part of the bindings, which may not be used by generated types.
+const RESERVED_TYPE_NAMES: &[&str] =
+    &["Felt252", "DecodeError", "CairoSerde", "Reader", "Option", "Result", "Vec"];
+
+/// The maximal size of the tuples supported by the runtimes of the bindings.
+const MAX_TUPLE_SIZE: usize = 12;
+
+/// A type of the ABI, as it is encoded in bindings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BindingType {
+    /// A field element, or an extern type represented by one (e.g. `ContractAddress`).
+    Felt252,
+    Bool,
+    /// An unsigned integer with the given number of bits.
+    Uint(usize),
+    /// An `Array` or a `Span`, encoded as its length followed by its elements.
+    Array(Box<BindingType>),
+    Tuple(Vec<BindingType>),
+    Option(Box<BindingType>),
+    Result(Box<BindingType>, Box<BindingType>),
+    /// A struct or an enum of the ABI, by its name in the bindings.
+    Named(String),
+}
+/// A type name of the ABI, parsed into its parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TypeExpr {
+    /// A path, with its generic arguments, e.g. `core::array::Array::<core::felt252>`.
+    Path {
+        path: String,
+        args: Vec<TypeExpr>,
+    },
+    Tuple(Vec<TypeExpr>),
+    Snapshot(Box<TypeExpr>),
+}
+impl TypeExpr {
+    /// Parses a type name of the ABI.
+    pub(crate) fn parse(ty: &str) -> Result<Self, BindingsError> {
+        let mut parser = TypeParser { input: ty, position: 0 };
+        let expr = parser.parse_type();
+        match expr {
+            Some(expr) if parser.position == ty.len() => Ok(expr),
+            _ => Err(BindingsError::InvalidType(ty.to_string())),
+        }
+    }
+
+    /// Returns the name of the type in the ABI.
+    pub(crate) fn format(&self) -> String {
+        match self {
+            TypeExpr::Path { path, args } if args.is_empty() => path.clone(),
+            TypeExpr::Path { path, args } => format!(
+                "{path}::<{}>",
+                args.iter().map(|arg| arg.format()).collect::<Vec<_>>().join(", ")
+            ),
+            TypeExpr::Tuple(types) if types.len() == 1 => format!("({},)", types[0].format()),
+            TypeExpr::Tuple(types) => {
+                format!("({})", types.iter().map(|ty| ty.format()).collect::<Vec<_>>().join(", "))
+            }
+            TypeExpr::Snapshot(ty) => format!("@{}", ty.format()),
+        }
+    }
+
+    /// Returns the name of the type in the bindings, used for the generated types: the last
+    /// segment of its path followed by the names of its generic arguments, e.g.
+    /// `MyStructU128` for `test::MyStruct::<core::integer::u128>`.
+    fn binding_name(&self) -> String {
+        match self {
+            TypeExpr::Path { path, args } => {
+                let name = path.rsplit("::").next().unwrap_or(path);
+                let mut result = to_pascal_case(name);
+                for arg in args {
+                    result.push_str(&arg.binding_name());
+                }
+                result
+            }
+            TypeExpr::Tuple(types) if types.is_empty() => "Unit".into(),
+            TypeExpr::Tuple(types) => {
+                let mut result = "Tuple".to_string();
+                for ty in types {
+                    result.push_str(&ty.binding_name());
+                }
+                result
+            }
+            TypeExpr::Snapshot(ty) => ty.binding_name(),
+        }
+    }
+}
+
+/// A parser of type names of the ABI.
+struct TypeParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+impl<'a> TypeParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Consumes `token` if the remaining input starts with it.
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_type(&mut self) -> Option<TypeExpr> {
+        if self.eat("@") {
+            return Some(TypeExpr::Snapshot(Box::new(self.parse_type()?)));
+        }
+        if self.eat("(") {
+            return Some(TypeExpr::Tuple(self.parse_list(")")?));
+        }
+        let mut segments = vec![];
+        let mut args = vec![];
+        loop {
+            let segment_len = self
+                .rest()
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(self.rest().len());
+            if segment_len == 0 {
+                return None;
+            }
+            segments.push(&self.rest()[..segment_len]);
+            self.position += segment_len;
+            if !self.eat("::") {
+                break;
+            }
+            if self.eat("<") {
+                args = self.parse_list(">")?;
+                break;
+            }
+        }
+        Some(TypeExpr::Path { path: segments.join("::"), args })
+    }
+
+    /// Parses a list of types separated by `, `, with an optional trailing comma, and terminated by
+    /// `end`.
+    fn parse_list(&mut self, end: &str) -> Option<Vec<TypeExpr>> {
+        let mut types = vec![];
+        if self.eat(end) {
+            return Some(types);
+        }
+        loop {
+            types.push(self.parse_type()?);
+            if self.eat(end) {
+                return Some(types);
+            }
+            if !self.eat(",") {
+                return None;
+            }
+            if self.eat(end) {
+                return Some(types);
+            }
+            if !self.eat(" ") {
+                return None;
+            }
+        }
+    }
+}
+
+/// Converts an identifier to PascalCase, keeping the case of letters that are not at the start
+/// of a word.
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// A struct or an enum of the ABI for which a type is generated.
+struct TypeDef<'a> {
+    /// The name of the type in the ABI.
+    abi_name: &'a str,
+    /// The name of the type in the bindings.
+    name: String,
+    kind: TypeDefKind,
+    /// The members of a struct or the variants of an enum.
+    fields: Vec<(&'a str, BindingType)>,
+}
+
+enum TypeDefKind {
+    Struct,
+    Enum,
+}
+
+/// An entry point of the contract, with the types of its inputs and its result.
+struct EntryPoint<'a> {
+    name: &'a str,
+    /// The selector of the entry point, as a hex string.
+    selector: String,
+    inputs: Vec<(&'a str, BindingType)>,
+    output: BindingType,
+}
+impl EntryPoint<'_> {
+    /// Returns the name of the entry point without leading and trailing underscores, used in the
+    /// names of the generated items, e.g. `execute` for `__execute__`.
+    fn base_name(&self) -> &str {
+        match self.name.trim_matches('_') {
+            "" => self.name,
+            base_name => base_name,
+        }
+    }
+}
+
+/// The contents of the ABI of a contract, resolved into the types of the bindings.
+struct Bindings<'a> {
+    type_defs: Vec<TypeDef<'a>>,
+    entry_points: Vec<EntryPoint<'a>>,
+}
+impl<'a> Bindings<'a> {
+    fn new(abi: &'a Contract) -> Result<Self, BindingsError> {
+        let mut defined_types = OrderedHashMap::<&str, &Item>::default();
+        let mut functions = vec![];
+        for item in &abi.items {
+            match item {
+                Item::Struct(Struct { name, .. }) | Item::Enum(Enum { name, .. }) => {
+                    defined_types.insert(name, item);
+                }
+                Item::Function(function) => functions.push(function),
+                Item::Interface(interface) => {
+                    functions.extend(interface.items.iter().filter_map(|item| match item {
+                        Item::Function(function) => Some(function),
+                        _ => None,
+                    }));
+                }
+                Item::Event(_) | Item::Storage(_) | Item::Impl(_) => {}
+            }
+        }
+        let resolver = TypeResolver::new(&defined_types)?;
+
+        let mut type_defs = vec![];
+        for (name, item) in defined_types.iter() {
+            let BindingType::Named(binding_name) = resolver.resolve(name)? else { continue; };
+            let (kind, fields) = match item {
+                Item::Struct(Struct { members, .. }) => (
+                    TypeDefKind::Struct,
+                    resolver.resolve_fields(
+                        members.iter().map(|member| (member.name.as_str(), member.ty.as_str())),
+                    )?,
+                ),
+                Item::Enum(Enum { variants, .. }) => (
+                    TypeDefKind::Enum,
+                    resolver.resolve_fields(
+                        variants.iter().map(|variant| (variant.name.as_str(), variant.ty.as_str())),
+                    )?,
+                ),
+                _ => unreachable!("Only structs and enums are defined types."),
+            };
+            type_defs.push(TypeDef { abi_name: name, name: binding_name, kind, fields });
+        }
+        let entry_points = functions
+            .into_iter()
+            .map(|function| resolver.resolve_function(function))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { type_defs, entry_points })
+    }
+}
+
+/// Resolves the type names of the ABI into the types of the bindings.
+struct TypeResolver {
+    /// The names in the bindings of the structs and enums of the ABI, by their names in the ABI.
+    binding_names: HashMap<String, String>,
+}
+impl TypeResolver {
+    fn new(defined_types: &OrderedHashMap<&str, &Item>) -> Result<Self, BindingsError> {
+        let mut binding_names = HashMap::new();
+        let mut used_names: HashMap<String, String> =
+            RESERVED_TYPE_NAMES.iter().map(|name| (name.to_string(), name.to_string())).collect();
+        for name in defined_types.keys() {
+            let expr = TypeExpr::parse(name)?;
+            if Self::resolve_builtin(&expr).is_some() {
+                continue;
+            }
+            let binding_name = expr.binding_name();
+            if let Some(other) = used_names.insert(binding_name.clone(), name.to_string()) {
+                return Err(BindingsError::NameCollision(other, name.to_string(), binding_name));
+            }
+            binding_names.insert(name.to_string(), binding_name);
+        }
+        Ok(Self { binding_names })
+    }
+
+    fn resolve(&self, ty: &str) -> Result<BindingType, BindingsError> {
+        self.resolve_expr(&TypeExpr::parse(ty)?)
+    }
+
+    fn resolve_expr(&self, expr: &TypeExpr) -> Result<BindingType, BindingsError> {
+        Ok(match expr {
+            TypeExpr::Snapshot(ty) => self.resolve_expr(ty)?,
+            TypeExpr::Tuple(types) if types.len() > MAX_TUPLE_SIZE => {
+                return Err(BindingsError::UnsupportedType(expr.format()));
+            }
+            TypeExpr::Tuple(types) => BindingType::Tuple(
+                types.iter().map(|ty| self.resolve_expr(ty)).collect::<Result<_, _>>()?,
+            ),
+            TypeExpr::Path { args, .. } => match Self::resolve_builtin(expr) {
+                Some(BindingType::Array(_)) => {
+                    BindingType::Array(Box::new(self.resolve_expr(&args[0])?))
+                }
+                Some(BindingType::Option(_)) => {
+                    BindingType::Option(Box::new(self.resolve_expr(&args[0])?))
+                }
+                Some(BindingType::Result(_, _)) => BindingType::Result(
+                    Box::new(self.resolve_expr(&args[0])?),
+                    Box::new(self.resolve_expr(&args[1])?),
+                ),
+                Some(ty) => ty,
+                None => BindingType::Named(
+                    self.binding_names
+                        .get(&expr.format())
+                        .ok_or_else(|| BindingsError::UnsupportedType(expr.format()))?
+                        .clone(),
+                ),
+            },
+        })
+    }
+
+    /// Returns the type of the bindings for the types of the core library that are not generated,
+    /// with the types of their generic arguments left as the unit type. Returns None for other
+    /// types.
+    fn resolve_builtin(expr: &TypeExpr) -> Option<BindingType> {
+        let TypeExpr::Path { path, args } = expr else { return None; };
+        let placeholder = || Box::new(BindingType::Tuple(vec![]));
+        Some(match (path.as_str(), args.len()) {
+            (
+                "core::felt252"
+                | "core::starknet::contract_address::ContractAddress"
+                | "core::starknet::class_hash::ClassHash"
+                | "core::starknet::storage_access::StorageAddress",
+                0,
+            ) => BindingType::Felt252,
+            ("core::bool", 0) => BindingType::Bool,
+            ("core::integer::u8", 0) => BindingType::Uint(8),
+            ("core::integer::u16", 0) => BindingType::Uint(16),
+            ("core::integer::u32", 0) => BindingType::Uint(32),
+            ("core::integer::u64", 0) => BindingType::Uint(64),
+            ("core::integer::u128", 0) => BindingType::Uint(128),
+            ("core::array::Array" | "core::array::Span", 1) => BindingType::Array(placeholder()),
+            ("core::option::Option", 1) => BindingType::Option(placeholder()),
+            ("core::result::Result", 2) => BindingType::Result(placeholder(), placeholder()),
+            _ => return None,
+        })
+    }
+
+    /// Resolves the types of the members of a struct or the variants of an enum.
+    fn resolve_fields<'a>(
+        &self,
+        fields: impl Iterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Vec<(&'a str, BindingType)>, BindingsError> {
+        fields.map(|(name, ty)| Ok((name, self.resolve(ty)?))).collect()
+    }
+
+    fn resolve_function<'a>(
+        &self,
+        function: &'a Function,
+    ) -> Result<EntryPoint<'a>, BindingsError> {
+        let inputs = self.resolve_fields(
+            function.inputs.iter().map(|input| (input.name.as_str(), input.ty.as_str())),
+        )?;
+        let mut outputs = function
+            .outputs
+            .iter()
+            .map(|output| self.resolve(&output.ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        let output =
+            if outputs.len() == 1 { outputs.remove(0) } else { BindingType::Tuple(outputs) };
+        Ok(EntryPoint {
+            name: &function.name,
+            selector: format!("0x{:x}", starknet_keccak(function.name.as_bytes())),
+            inputs,
+            output,
+        })
+    }
+}
@@ -0,0 +1,202 @@
+//! Generation of Rust bindings.
+
+use indoc::{formatdoc, indoc};
+use itertools::Itertools;
+
+use super::{
+    to_pascal_case, BindingType, Bindings, BindingsError, EntryPoint, TypeDef, TypeDefKind,
+};
+use crate::abi::Contract;
+
+/// The runtime of the bindings, which does not depend on the ABI: the encoding of felts, integers,
+/// booleans, arrays, tuples, options and results.
+pub const RUST_BINDINGS_RUNTIME: &str = include_str!("cairo_serde.rs");
+
+/// The start of the bindings, importing the runtime.
+const HEADER: &str = indoc! {"
+    // Bindings of a Starknet contract, generated from its ABI.
+    // They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+    use super::cairo_serde::*;
+"};
+
+/// Keywords of Rust that may be used as identifiers in Cairo.
+const KEYWORDS: &[&str] = &[
+    "abstract", "async", "await", "become", "box", "break", "continue", "crate", "do", "dyn",
+    "final", "for", "in", "loop", "macro", "move", "override", "priv", "pub", "Self", "self",
+    "static", "super", "try", "typeof", "unsafe", "unsized", "virtual", "where", "while", "yield",
+];
+
+/// Names of local variables of the generated functions, which may not be used by their parameters.
+const LOCAL_NAMES: &[&str] = &["calldata"];
+
+/// Generates Rust bindings from the ABI of a contract.
+///
+/// The bindings use the runtime in [RUST_BINDINGS_RUNTIME], which depends on the `num-bigint` and
+/// `num-traits` crates, and represent felts as `BigUint`s.
+pub fn generate_rust_bindings(abi: &Contract) -> Result<String, BindingsError> {
+    let bindings = Bindings::new(abi)?;
+    let mut sections = vec![HEADER.to_string()];
+    sections.extend(bindings.type_defs.iter().map(type_def));
+    sections.extend(bindings.entry_points.iter().map(entry_point));
+    Ok(sections.join("\n"))
+}
+
+/// Returns the name of a type in Rust.
+fn rust_type(ty: &BindingType) -> String {
+    match ty {
+        BindingType::Felt252 => "Felt252".into(),
+        BindingType::Bool => "bool".into(),
+        BindingType::Uint(bits) => format!("u{bits}"),
+        BindingType::Array(ty) => format!("Vec<{}>", rust_type(ty)),
+        BindingType::Tuple(types) if types.len() == 1 => format!("({},)", rust_type(&types[0])),
+        BindingType::Tuple(types) => format!("({})", types.iter().map(rust_type).join(", ")),
+        BindingType::Option(ty) => format!("Option<{}>", rust_type(ty)),
+        BindingType::Result(ok_ty, err_ty) => {
+            format!("Result<{}, {}>", rust_type(ok_ty), rust_type(err_ty))
+        }
+        BindingType::Named(name) => name.clone(),
+    }
+}
+
+/// Returns a Rust identifier for a name of the ABI.
+fn rust_ident(name: &str) -> String {
+    match name {
+        "Self" | "self" | "super" | "crate" => format!("{name}_"),
+        _ if KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name.into(),
+    }
+}
+
+/// Returns the definition of a struct or an enum and its implementation of `CairoSerde`.
+fn type_def(type_def: &TypeDef<'_>) -> String {
+    let TypeDef { abi_name, name, kind, fields } = type_def;
+    let (output, input) =
+        if fields.is_empty() { ("_output", "_input") } else { ("output", "input") };
+    let (kind, definition, serialize, deserialize) = match kind {
+        TypeDefKind::Struct => {
+            let members = fields
+                .iter()
+                .map(|(member, ty)| format!("pub {}: {},", rust_ident(member), rust_type(ty)))
+                .join("\n    ");
+            let serialize = fields
+                .iter()
+                .map(|(member, _)| format!("self.{}.serialize(output);", rust_ident(member)))
+                .join("\n        ");
+            let deserialize = fields
+                .iter()
+                .map(|(member, _)| {
+                    format!("{}: CairoSerde::deserialize(input)?,", rust_ident(member))
+                })
+                .join("\n            ");
+            (
+                "struct",
+                format!("pub struct {name} {{\n    {members}\n}}"),
+                serialize,
+                format!("Ok(Self {{\n            {deserialize}\n        }})"),
+            )
+        }
+        TypeDefKind::Enum => {
+            let variant_name = |variant: &str| rust_ident(&to_pascal_case(variant));
+            let variants = fields
+                .iter()
+                .map(|(variant, ty)| match ty {
+                    BindingType::Tuple(types) if types.is_empty() => {
+                        format!("{},", variant_name(variant))
+                    }
+                    _ => format!("{}({}),", variant_name(variant), rust_type(ty)),
+                })
+                .join("\n    ");
+            let serialize = fields
+                .iter()
+                .enumerate()
+                .map(|(index, (variant, ty))| match ty {
+                    BindingType::Tuple(types) if types.is_empty() => format!(
+                        "Self::{} => serialize_variant({index}, &(), output),",
+                        variant_name(variant)
+                    ),
+                    _ => format!(
+                        "Self::{}(value) => serialize_variant({index}, value, output),",
+                        variant_name(variant)
+                    ),
+                })
+                .join("\n            ");
+            let deserialize = fields
+                .iter()
+                .enumerate()
+                .map(|(index, (variant, ty))| match ty {
+                    BindingType::Tuple(types) if types.is_empty() => {
+                        format!("{index} => Ok(Self::{}),", variant_name(variant))
+                    }
+                    _ => format!(
+                        "{index} => Ok(Self::{}(CairoSerde::deserialize(input)?)),",
+                        variant_name(variant)
+                    ),
+                })
+                .chain(["_ => Err(DecodeError),".to_string()])
+                .join("\n            ");
+            let deserialize = format!(
+                "match deserialize_variant_index(input)? {{\n            {deserialize}\n        }}"
+            );
+            (
+                "enum",
+                format!("pub enum {name} {{\n    {variants}\n}}"),
+                format!("match self {{\n            {serialize}\n        }}"),
+                deserialize,
+            )
+        }
+    };
+    formatdoc! {"
+        /// The `{abi_name}` {kind}.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        {definition}
+        impl CairoSerde for {name} {{
+            fn serialize(&self, {output}: &mut Vec<Felt252>) {{
+                {serialize}
+            }}
+            fn deserialize({input}: &mut &[Felt252]) -> Result<Self, DecodeError> {{
+                {deserialize}
+            }}
+        }}
+    "}
+}
+
+/// Returns the selector, the calldata encoder and the result decoder of an entry point.
+fn entry_point(entry_point: &EntryPoint<'_>) -> String {
+    let EntryPoint { name, selector, inputs, output } = entry_point;
+    let base_name = entry_point.base_name();
+    let selector_name = base_name.to_uppercase();
+    let params = inputs
+        .iter()
+        .map(|(param, ty)| {
+            let param =
+                if LOCAL_NAMES.contains(param) { format!("{param}_") } else { rust_ident(param) };
+            (param, rust_type(ty))
+        })
+        .collect_vec();
+    let signature = params.iter().map(|(param, ty)| format!("{param}: &{ty}")).join(", ");
+    let encode = if params.is_empty() {
+        "vec![]".to_string()
+    } else {
+        let serialize = params
+            .iter()
+            .map(|(param, _)| format!("{param}.serialize(&mut calldata);"))
+            .join("\n    ");
+        format!("let mut calldata = vec![];\n    {serialize}\n    calldata")
+    };
+    let output = rust_type(output);
+    formatdoc! {r#"
+        /// The selector of the `{name}` entry point.
+        pub const {selector_name}_SELECTOR: &str = "{selector}";
+
+        /// Encodes the calldata of a call to the `{name}` entry point.
+        pub fn encode_{base_name}_calldata({signature}) -> Vec<Felt252> {{
+            {encode}
+        }}
+
+        /// Decodes the result of a call to the `{name}` entry point.
+        pub fn decode_{base_name}_result(result: &[Felt252]) -> Result<{output}, DecodeError> {{
+            decode(result)
+        }}
+    "#}
+}
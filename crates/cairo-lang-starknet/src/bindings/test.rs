@@ -0,0 +1,271 @@
+use cairo_felt::Felt252 as VmFelt252;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::test_utils::{
+    setup_test_crate, setup_test_module, SemanticDatabaseForTesting,
+};
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use cairo_lang_utils::extract_matches;
+use indoc::indoc;
+use num_traits::ToPrimitive;
+use test_case::test_case;
+
+use self::cairo_serde::{decode, CairoSerde, DecodeError, Felt252};
+use super::{generate_rust_bindings, generate_typescript_bindings, BindingsError};
+use crate::abi::{AbiBuilder, Contract};
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_example_file_path;
+
+// The runtime of the Rust bindings and the generated bindings of the tests, compiled to check them.
+#[path = "cairo_serde.rs"]
+mod cairo_serde;
+// The generated bindings are golden files, which are not formatted.
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[path = "../../test_data"]
+mod bindings {
+    use super::cairo_serde;
+
+    #[path = "account.bindings.rs"]
+    mod account;
+    #[path = "contract_with_component.bindings.rs"]
+    mod contract_with_component;
+    #[path = "erc20.bindings.rs"]
+    mod erc20;
+    #[path = "event_emitter.bindings.rs"]
+    mod event_emitter;
+    #[path = "hello_starknet.bindings.rs"]
+    mod hello_starknet;
+    #[path = "round_trip.bindings.rs"]
+    pub mod round_trip;
+    #[path = "test_contract.bindings.rs"]
+    mod test_contract;
+    #[path = "token_bridge.bindings.rs"]
+    mod token_bridge;
+    #[path = "types.bindings.rs"]
+    mod types;
+}
+
+/// Tests that the bindings generated from the ABI in <test_case>.sierra.json are the same as in
+/// <test_case>.bindings.rs and <test_case>.bindings.ts.
+#[test_case("account")]
+#[test_case("test_contract")]
+#[test_case("hello_starknet")]
+#[test_case("erc20")]
+#[test_case("token_bridge")]
+#[test_case("contract_with_component")]
+#[test_case("event_emitter")]
+fn test_bindings(example_file_name: &str) {
+    let contract_class: ContractClass = serde_json::from_str(
+        &std::fs::read_to_string(get_example_file_path(&format!(
+            "{example_file_name}.sierra.json"
+        )))
+        .unwrap(),
+    )
+    .unwrap();
+    let abi = contract_class.abi.unwrap();
+
+    compare_contents_or_fix_with_path(
+        &get_example_file_path(&format!("{example_file_name}.bindings.rs")),
+        generate_rust_bindings(&abi).unwrap(),
+    );
+    compare_contents_or_fix_with_path(
+        &get_example_file_path(&format!("{example_file_name}.bindings.ts")),
+        generate_typescript_bindings(&abi).unwrap(),
+    );
+}
+
+/// Tests the bindings of generic structs and enums, tuples, spans, nested arrays, options and
+/// results.
+#[test]
+fn test_bindings_of_types() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let module_id = setup_test_module(
+        &mut db_val,
+        indoc! {"
+            struct MyStruct<T> {
+              a: T,
+              b: felt252
+            }
+
+            enum MyEnum<S> {
+              a: MyStruct::<S>,
+              b: ()
+            }
+
+            trait MyAbi {
+                fn tuples(a: (felt252, MyStruct::<u128>), b: (u8, )) -> (u256, (bool, u8));
+
+                fn arrays(a: Array::<Array::<felt252>>, b: Span::<u256>);
+
+                fn generics(
+                    a: Option::<MyStruct::<u128>>, b: MyEnum::<u128>, c: MyEnum::<felt252>
+                ) -> Result::<u256, felt252>;
+            }
+        "},
+    )
+    .unwrap()
+    .module_id;
+
+    let db = &db_val;
+    let trait_id = extract_matches!(
+        db.module_item_by_name(module_id, "MyAbi".into()).unwrap().unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(db, trait_id).unwrap();
+
+    compare_contents_or_fix_with_path(
+        &get_example_file_path("types.bindings.rs"),
+        generate_rust_bindings(&abi).unwrap(),
+    );
+    compare_contents_or_fix_with_path(
+        &get_example_file_path("types.bindings.ts"),
+        generate_typescript_bindings(&abi).unwrap(),
+    );
+}
+
+#[test]
+fn test_bindings_failures() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "foo",
+            "inputs": [{ "name": "a", "type": "test::Unknown" }],
+            "outputs": [],
+            "state_mutability": "external"
+          }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(
+        generate_rust_bindings(&abi),
+        Err(BindingsError::UnsupportedType("test::Unknown".into()))
+    );
+
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          { "type": "struct", "name": "a::Foo", "members": [] },
+          { "type": "struct", "name": "b::Foo", "members": [] }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(
+        generate_typescript_bindings(&abi),
+        Err(BindingsError::NameCollision("a::Foo".into(), "b::Foo".into(), "Foo".into()))
+    );
+
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [
+          { "type": "struct", "name": "a::Foo::<", "members": [] }
+        ]
+    "#})
+    .unwrap();
+    assert_eq!(generate_rust_bindings(&abi), Err(BindingsError::InvalidType("a::Foo::<".into())));
+}
+
+#[test]
+fn test_rust_bindings_runtime() {
+    let felts =
+        |values: &[u128]| values.iter().map(|value| Felt252::from(*value)).collect::<Vec<_>>();
+    let value: (Vec<u8>, Option<bool>, Result<(), u128>) = (vec![1, 2], Some(true), Err(7));
+    let mut encoded = vec![];
+    value.serialize(&mut encoded);
+    assert_eq!(encoded, felts(&[2, 1, 2, 0, 1, 1, 7]));
+    assert_eq!(decode(&encoded), Ok(value));
+
+    // Values out of the range of their type, unknown variants and missing or extra felts.
+    assert_eq!(decode::<u8>(&felts(&[256])), Err(DecodeError));
+    assert_eq!(decode::<Option<u8>>(&felts(&[2])), Err(DecodeError));
+    assert_eq!(decode::<Vec<u8>>(&felts(&[2, 1])), Err(DecodeError));
+    assert_eq!(decode::<(u8, u8)>(&felts(&[1, 2, 3])), Err(DecodeError));
+}
+
+/// A contract whose entry point is implemented over its calldata, for testing the bindings
+/// against `Serde`.
+const ROUND_TRIP_CONTRACT: &str = indoc! {"
+    use array::ArrayTrait;
+    use array::SpanTrait;
+    use option::OptionTrait;
+    use serde::Serde;
+    use traits::Into;
+    use traits::TryInto;
+
+    #[derive(Copy, Drop, Serde)]
+    struct Point {
+        x: u128,
+        y: felt252,
+    }
+
+    trait RoundTripAbi {
+        fn transform(p: Point, points: Array<Point>, flags: (bool, u8)) -> (Point, u64);
+    }
+
+    /// Doubles `x`, negates `y` if requested and shifts it, and counts the points.
+    fn transform(calldata: Array<felt252>) -> Array<felt252> {
+        let mut input = calldata.span();
+        let p: Point = Serde::deserialize(ref input).unwrap();
+        let points: Array<Point> = Serde::deserialize(ref input).unwrap();
+        let (negate, shift): (bool, u8) = Serde::deserialize(ref input).unwrap();
+        assert(input.is_empty(), 'Calldata left.');
+        let y = if negate { 0 - p.y } else { p.y } + shift.into();
+        let count: felt252 = points.len().into();
+        let count: u64 = count.try_into().unwrap();
+        let mut output = ArrayTrait::new();
+        Serde::serialize(ref output, (Point { x: p.x * 2, y }, count));
+        output
+    }
+"};
+
+/// Tests that calldata encoded by the Rust bindings is decoded by `Serde` in Cairo, and that
+/// results encoded by `Serde` are decoded by the bindings.
+#[test]
+fn test_rust_bindings_round_trip() {
+    use bindings::round_trip::{decode_transform_result, encode_transform_calldata, Point};
+
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(&mut db, ROUND_TRIP_CONTRACT);
+    let trait_id = extract_matches!(
+        db.module_item_by_name(ModuleId::CrateRoot(crate_id), "RoundTripAbi".into())
+            .unwrap()
+            .unwrap(),
+        ModuleItemId::Trait
+    );
+    let abi = AbiBuilder::from_trait(&db, trait_id).unwrap();
+    compare_contents_or_fix_with_path(
+        &get_example_file_path("round_trip.bindings.rs"),
+        generate_rust_bindings(&abi).unwrap(),
+    );
+
+    let program =
+        replace_sierra_ids_in_program(&db, &db.get_sierra_program(vec![crate_id]).unwrap());
+    let runner = SierraCasmRunner::new(program, Some(Default::default())).unwrap();
+    let point = |x: u128, y: u128| Point { x, y: Felt252::from(y) };
+    let calldata =
+        encode_transform_calldata(&point(1 << 100, 7), &vec![point(1, 2), point(3, 4)], &(true, 3));
+    let result = runner
+        .run_function(
+            "::transform",
+            &[Arg::Array(calldata.into_iter().map(VmFelt252::from).collect())],
+            Some(1000000),
+            None,
+        )
+        .unwrap();
+    let RunResultValue::Success(values) = result.value else {
+        panic!("Unexpected result: {:?}", result.value);
+    };
+    let [start, end] = &values[..] else {
+        panic!("Unexpected result: {values:?}");
+    };
+    let (start, end) = (start.to_usize().unwrap(), end.to_usize().unwrap());
+    let output = result.memory[start..end]
+        .iter()
+        .map(|cell| cell.as_ref().unwrap().to_biguint())
+        .collect::<Vec<_>>();
+    let y = (VmFelt252::from(3) - VmFelt252::from(7)).to_biguint();
+    assert_eq!(decode_transform_result(&output), Ok((Point { x: 1 << 101, y }, 2)));
+}
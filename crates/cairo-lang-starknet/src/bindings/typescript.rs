@@ -0,0 +1,275 @@
+//! Generation of TypeScript bindings.
+
+use std::collections::HashSet;
+
+use indoc::formatdoc;
+use itertools::{chain, Itertools};
+
+use super::{
+    to_pascal_case, BindingType, Bindings, BindingsError, EntryPoint, TypeDef, TypeDefKind,
+};
+use crate::abi::Contract;
+
+/// The runtime of the bindings, which does not depend on the ABI: the encoding of felts, integers,
+/// booleans, arrays, tuples, options and results.
+pub const TYPESCRIPT_BINDINGS_RUNTIME: &str = include_str!("cairo_serde.ts");
+
+/// Reserved words of TypeScript that may be used as identifiers in Cairo.
+const RESERVED_WORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "export",
+    "extends",
+    "finally",
+    "for",
+    "function",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Names of local variables of the generated functions, which may not be used by their parameters.
+const LOCAL_NAMES: &[&str] = &["calldata"];
+
+/// Generates TypeScript bindings from the ABI of a contract.
+///
+/// The bindings represent felts and integers as `bigint`s, structs as interfaces and enums as
+/// unions of objects tagged by the name of their variant.
+pub fn generate_typescript_bindings(abi: &Contract) -> Result<String, BindingsError> {
+    let bindings = Bindings::new(abi)?;
+    let sections = chain!(
+        bindings.type_defs.iter().map(type_def),
+        bindings.entry_points.iter().map(entry_point)
+    )
+    .join("\n");
+    Ok(format!("{}\n{sections}", header(&sections)))
+}
+
+/// Returns the start of the bindings, importing the names of the runtime used by their `code`.
+fn header(code: &str) -> String {
+    let used_names: HashSet<&str> =
+        code.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).collect();
+    let imports = TYPESCRIPT_BINDINGS_RUNTIME
+        .lines()
+        .filter_map(|line| {
+            // The name declared by an exported declaration, e.g. `export function decode<T>(`.
+            let (_, declaration) = line.strip_prefix("export ")?.split_once(' ')?;
+            declaration.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).next()
+        })
+        .filter(|name| used_names.contains(name))
+        .map(|name| format!("\n  {name},"))
+        .join("");
+    formatdoc! {r#"
+        // Bindings of a Starknet contract, generated from its ABI.
+        // They use the runtime generated along with them, as the module `./cairo_serde`.
+
+        import {{{imports}
+        }} from "./cairo_serde";
+    "#}
+}
+
+/// Returns the name of a type in TypeScript.
+fn ts_type(ty: &BindingType) -> String {
+    match ty {
+        BindingType::Felt252 | BindingType::Uint(_) => "bigint".into(),
+        BindingType::Bool => "boolean".into(),
+        BindingType::Array(ty) => format!("{}[]", ts_type(ty)),
+        BindingType::Tuple(types) => format!("[{}]", types.iter().map(ts_type).join(", ")),
+        BindingType::Option(ty) => format!("Option<{}>", ts_type(ty)),
+        BindingType::Result(ok_ty, err_ty) => {
+            format!("Result<{}, {}>", ts_type(ok_ty), ts_type(err_ty))
+        }
+        BindingType::Named(name) => name.clone(),
+    }
+}
+
+/// Returns an expression of the serializer (if `prefix` is `serialize`) or the deserializer (if
+/// `prefix` is `deserialize`) of a type.
+fn ts_serde(prefix: &str, ty: &BindingType) -> String {
+    match ty {
+        BindingType::Felt252 => format!("{prefix}Felt252"),
+        BindingType::Bool => format!("{prefix}Bool"),
+        BindingType::Uint(bits) => format!("{prefix}U{bits}"),
+        BindingType::Array(ty) => format!("{prefix}Array({})", ts_serde(prefix, ty)),
+        BindingType::Tuple(types) if types.is_empty() => format!("{prefix}Unit"),
+        BindingType::Tuple(types) => {
+            format!("{prefix}Tuple({})", types.iter().map(|ty| ts_serde(prefix, ty)).join(", "))
+        }
+        BindingType::Option(ty) => format!("{prefix}Option({})", ts_serde(prefix, ty)),
+        BindingType::Result(ok_ty, err_ty) => {
+            format!("{prefix}Result({}, {})", ts_serde(prefix, ok_ty), ts_serde(prefix, err_ty))
+        }
+        BindingType::Named(name) => format!("{prefix}{name}"),
+    }
+}
+
+/// Returns the definition of a struct or an enum and its serializer and deserializer.
+fn type_def(type_def: &TypeDef<'_>) -> String {
+    let TypeDef { abi_name, name, kind, fields } = type_def;
+    match kind {
+        TypeDefKind::Struct => {
+            let members = fields
+                .iter()
+                .map(|(member, ty)| format!("\n  {member}: {};", ts_type(ty)))
+                .join("");
+            let serialize = fields
+                .iter()
+                .map(|(member, ty)| {
+                    format!("\n  {}(value.{member}, output);", ts_serde("serialize", ty))
+                })
+                .join("");
+            let deserialize = fields
+                .iter()
+                .map(|(member, ty)| {
+                    format!("\n    {member}: {}(input),", ts_serde("deserialize", ty))
+                })
+                .join("");
+            let deserialize =
+                if fields.is_empty() { "{}".to_string() } else { format!("{{{deserialize}\n  }}") };
+            formatdoc! {"
+                /** The `{abi_name}` struct. */
+                export interface {name} {{{members}
+                }}
+
+                export function serialize{name}(value: {name}, output: bigint[]): void {{{serialize}
+                }}
+
+                export function deserialize{name}(input: Reader): {name} {{
+                  return {deserialize};
+                }}
+            "}
+        }
+        TypeDefKind::Enum => {
+            let variants = if fields.is_empty() {
+                " never".to_string()
+            } else {
+                fields
+                    .iter()
+                    .map(|(variant, ty)| match ty {
+                        BindingType::Tuple(types) if types.is_empty() => {
+                            format!("\n  | {{ variant: \"{variant}\" }}")
+                        }
+                        _ => format!("\n  | {{ variant: \"{variant}\"; value: {} }}", ts_type(ty)),
+                    })
+                    .join("")
+            };
+            let serialize = fields
+                .iter()
+                .enumerate()
+                .map(|(index, (variant, ty))| {
+                    let value = match ty {
+                        BindingType::Tuple(types) if types.is_empty() => String::new(),
+                        _ => {
+                            format!("\n      {}(value.value, output);", ts_serde("serialize", ty))
+                        }
+                    };
+                    let case = format!("\n    case \"{variant}\":\n      output.push({index}n);");
+                    format!("{case}{value}\n      break;")
+                })
+                .join("");
+            let deserialize = fields
+                .iter()
+                .enumerate()
+                .map(|(index, (variant, ty))| {
+                    let value = match ty {
+                        BindingType::Tuple(types) if types.is_empty() => String::new(),
+                        _ => format!(", value: {}(input)", ts_serde("deserialize", ty)),
+                    };
+                    format!(
+                        "\n    case {index}n:\n      return {{ variant: \"{variant}\"{value} }};"
+                    )
+                })
+                .join("");
+            formatdoc! {"
+                /** The `{abi_name}` enum. */
+                export type {name} ={variants};
+
+                export function serialize{name}(value: {name}, output: bigint[]): void {{
+                  switch (value.variant) {{{serialize}
+                  }}
+                }}
+
+                export function deserialize{name}(input: Reader): {name} {{
+                  const index = input.next();
+                  switch (index) {{{deserialize}
+                    default:
+                      throw new DecodeError(`Invalid variant index ${{index}} of {name}.`);
+                  }}
+                }}
+            "}
+        }
+    }
+}
+
+/// Returns the selector, the calldata encoder and the result decoder of an entry point.
+fn entry_point(entry_point: &EntryPoint<'_>) -> String {
+    let EntryPoint { name, selector, inputs, output } = entry_point;
+    let base_name = entry_point.base_name();
+    let selector_name = base_name.to_uppercase();
+    let function_name = to_pascal_case(base_name);
+    let params = inputs
+        .iter()
+        .map(|(param, ty)| {
+            let param = if RESERVED_WORDS.contains(param) || LOCAL_NAMES.contains(param) {
+                format!("{param}_")
+            } else {
+                param.to_string()
+            };
+            (param, ty)
+        })
+        .collect_vec();
+    let signature = params.iter().map(|(param, ty)| format!("{param}: {}", ts_type(ty))).join(", ");
+    let encode = if params.is_empty() {
+        "return [];".to_string()
+    } else {
+        let serialize = params
+            .iter()
+            .map(|(param, ty)| format!("\n  {}({param}, calldata);", ts_serde("serialize", ty)))
+            .join("");
+        format!("const calldata: bigint[] = [];{serialize}\n  return calldata;")
+    };
+    let output_type = ts_type(output);
+    let deserialize = ts_serde("deserialize", output);
+    formatdoc! {"
+        /** The selector of the `{name}` entry point. */
+        export const {selector_name}_SELECTOR = {selector}n;
+
+        /** Encodes the calldata of a call to the `{name}` entry point. */
+        export function encode{function_name}Calldata({signature}): bigint[] {{
+          {encode}
+        }}
+
+        /** Decodes the result of a call to the `{name}` entry point. */
+        export function decode{function_name}Result(result: bigint[]): {output_type} {{
+          return decode(result, {deserialize});
+        }}
+    "}
+}
@@ -2,7 +2,9 @@
 #[path = "casm_contract_class_test.rs"]
 mod test;
 
+use cairo_lang_casm::assembler::{disassemble, DisassemblyError};
 use cairo_lang_casm::hints::Hint;
+use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_sierra::extensions::array::ArrayType;
 use cairo_lang_sierra::extensions::ec::EcOpType;
 use cairo_lang_sierra::extensions::enm::EnumType;
@@ -21,12 +23,13 @@ use cairo_lang_sierra_to_casm::compiler::CompilationError;
 use cairo_lang_sierra_to_casm::metadata::{
     calc_metadata, MetadataComputationConfig, MetadataError,
 };
+use cairo_lang_sierra_to_casm::report::{CompilationReport, FunctionReport};
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use convert_case::{Case, Casing};
 use itertools::{chain, Itertools};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{Num, Signed};
 use serde::{Deserialize, Serialize};
@@ -65,6 +68,14 @@ pub enum StarknetSierraCompilationError {
     ValueOutOfRange,
 }
 
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum CasmDisassemblyError {
+    #[error(transparent)]
+    DisassemblyError(#[from] DisassemblyError),
+    #[error("Hints at offset {0}, which is not the start of an instruction.")]
+    InvalidHintOffset(usize),
+}
+
 fn skip_if_none<T>(opt_field: &Option<T>) -> bool {
     opt_field.is_none()
 }
@@ -165,6 +176,28 @@ impl CasmContractClass {
         contract_class: ContractClass,
         add_pythonic_hints: bool,
     ) -> Result<Self, StarknetSierraCompilationError> {
+        Self::compile_contract_class(contract_class, add_pythonic_hints, false)
+            .map(|(casm_contract, _)| casm_contract)
+    }
+
+    /// Compiles a contract class, together with a report of the code size and costs of its
+    /// functions and entry points.
+    #[allow(clippy::result_large_err)]
+    pub fn from_contract_class_with_report(
+        contract_class: ContractClass,
+        add_pythonic_hints: bool,
+    ) -> Result<(Self, ContractCompilationReport), StarknetSierraCompilationError> {
+        Self::compile_contract_class(contract_class, add_pythonic_hints, true)
+            .map(|(casm_contract, report)| (casm_contract, report.unwrap()))
+    }
+
+    /// Compiles a contract class, and creates its compilation report if `create_report` is true.
+    #[allow(clippy::result_large_err)]
+    fn compile_contract_class(
+        contract_class: ContractClass,
+        add_pythonic_hints: bool,
+        create_report: bool,
+    ) -> Result<(Self, Option<ContractCompilationReport>), StarknetSierraCompilationError> {
         let prime = BigUint::from_str_radix(
             "800000000000011000000000000000000000000000000000000000000000001",
             16,
@@ -177,7 +210,13 @@ impl CasmContractClass {
             }
         }
 
-        let (_, program) = sierra_from_felt252s(&contract_class.sierra_program)?;
+        let (_, mut program) = sierra_from_felt252s(&contract_class.sierra_program)?;
+        if create_report {
+            // Name the functions in the report.
+            if let Some(debug_info) = &contract_class.sierra_program_debug_info {
+                debug_info.populate(&mut program);
+            }
+        }
         for entry_points in [
             &contract_class.entry_points_by_type.constructor,
             &contract_class.entry_points_by_type.external,
@@ -210,7 +249,7 @@ impl CasmContractClass {
 
         let mut bytecode = vec![];
         let mut hints = vec![];
-        for instruction in cairo_program.instructions {
+        for instruction in &cairo_program.instructions {
             if !instruction.hints.is_empty() {
                 hints.push((bytecode.len(), instruction.hints.clone()))
             }
@@ -318,6 +357,38 @@ impl CasmContractClass {
             Ok::<Vec<CasmContractEntryPoint>, StarknetSierraCompilationError>(entry_points)
         };
 
+        let report = if create_report {
+            let compilation_report = CompilationReport::new(&program, &metadata, &cairo_program)?;
+            let as_entry_point_reports = |contract_entry_points: &[ContractEntryPoint]| {
+                contract_entry_points
+                    .iter()
+                    .map(|contract_entry_point| {
+                        let function = &program.funcs[contract_entry_point.function_idx];
+                        let name = function.id.to_string();
+                        EntryPointReport {
+                            selector: contract_entry_point.selector.clone(),
+                            offset: cairo_program.debug_info.sierra_statement_info
+                                [function.entry_point.0]
+                                .code_offset,
+                            bytecode_length: compilation_report.reachable_bytecode_length(&name),
+                            function: name,
+                        }
+                    })
+                    .collect()
+            };
+            let entry_points = &contract_class.entry_points_by_type;
+            Some(ContractCompilationReport {
+                entry_points_by_type: EntryPointsReport {
+                    external: as_entry_point_reports(&entry_points.external),
+                    l1_handler: as_entry_point_reports(&entry_points.l1_handler),
+                    constructor: as_entry_point_reports(&entry_points.constructor),
+                },
+                functions: compilation_report.functions,
+            })
+        } else {
+            None
+        };
+
         let pythonic_hints = if add_pythonic_hints {
             Some(
                 hints
@@ -331,7 +402,7 @@ impl CasmContractClass {
             None
         };
 
-        Ok(Self {
+        let casm_contract = Self {
             prime,
             compiler_version: "1.0.0".to_string(),
             bytecode,
@@ -342,7 +413,44 @@ impl CasmContractClass {
                 l1_handler: as_casm_entry_points(contract_class.entry_points_by_type.l1_handler)?,
                 constructor: as_casm_entry_points(contract_class.entry_points_by_type.constructor)?,
             },
-        })
+        };
+        Ok((casm_contract, report))
+    }
+}
+
+impl CasmContractClass {
+    /// Decodes the bytecode of the contract back into instructions, with the hints of the contract
+    /// attached to the instructions at their offsets.
+    pub fn disassemble(&self) -> Result<Vec<Instruction>, CasmDisassemblyError> {
+        // Values in the upper half of the field are read as negative numbers, as the compiler emits
+        // them, e.g. backward jumps and `-1` immediates. The encoded instructions are all small.
+        let half_prime = &self.prime / 2u32;
+        let bytecode = self
+            .bytecode
+            .iter()
+            .map(|value| {
+                if value.value > half_prime {
+                    BigInt::from(value.value.clone()) - BigInt::from(self.prime.clone())
+                } else {
+                    BigInt::from(value.value.clone())
+                }
+            })
+            .collect_vec();
+        let mut instructions = disassemble(&bytecode)?;
+        let mut hints = self.hints.iter().peekable();
+        let mut offset = 0;
+        for instruction in &mut instructions {
+            if let Some((_, instruction_hints)) =
+                hints.next_if(|(hints_offset, _)| *hints_offset == offset)
+            {
+                instruction.hints = instruction_hints.clone();
+            }
+            offset += instruction.body.op_size();
+        }
+        match hints.next() {
+            Some((hints_offset, _)) => Err(CasmDisassemblyError::InvalidHintOffset(*hints_offset)),
+            None => Ok(instructions),
+        }
     }
 }
 
@@ -366,3 +474,36 @@ pub struct CasmContractEntryPoints {
     #[serde(rename = "CONSTRUCTOR")]
     pub constructor: Vec<CasmContractEntryPoint>,
 }
+
+/// A report of the compilation of a contract class, for tracking the code size and costs of its
+/// functions and entry points.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ContractCompilationReport {
+    /// The reports of the Sierra functions of the contract.
+    pub functions: Vec<FunctionReport>,
+    pub entry_points_by_type: EntryPointsReport,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize)]
+pub struct EntryPointsReport {
+    #[serde(rename = "EXTERNAL")]
+    pub external: Vec<EntryPointReport>,
+    #[serde(rename = "L1_HANDLER")]
+    pub l1_handler: Vec<EntryPointReport>,
+    #[serde(rename = "CONSTRUCTOR")]
+    pub constructor: Vec<EntryPointReport>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize)]
+pub struct EntryPointReport {
+    /// A field element that encodes the signature of the called function.
+    #[serde(serialize_with = "serialize_big_uint")]
+    pub selector: BigUint,
+    /// The name of the Sierra function of the entry point.
+    pub function: String,
+    /// The offset of the entry point within the contract bytecode.
+    pub offset: usize,
+    /// The number of felt252s of the code of the entry point's function and all the functions it
+    /// may call.
+    pub bytecode_length: usize,
+}
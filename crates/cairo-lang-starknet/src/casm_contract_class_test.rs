@@ -1,11 +1,15 @@
 use std::io::BufReader;
 
+use cairo_lang_casm::casm;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use num_bigint::BigUint;
+use num_integer::Integer;
 use num_traits::Num;
 use test_case::test_case;
 
-use crate::casm_contract_class::{BigUintAsHex, CasmContractClass, StarknetSierraCompilationError};
+use crate::casm_contract_class::{
+    BigUintAsHex, CasmContractClass, CasmDisassemblyError, StarknetSierraCompilationError,
+};
 use crate::contract_class::ContractClass;
 use crate::test_utils::{get_example_file_path, get_test_contract};
 
@@ -16,6 +20,8 @@ use crate::test_utils::{get_example_file_path, get_test_contract};
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
 #[test_case("token_bridge")]
+#[test_case("contract_with_component")]
+#[test_case("event_emitter")]
 fn test_casm_contract_from_contract_class(example_file_name: &str) {
     let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
     let add_pythonic_hints = true;
@@ -48,3 +54,115 @@ fn test_casm_contract_from_contract_class_failure(example_file_name: &str) {
         Err(StarknetSierraCompilationError::ValueOutOfRange)
     );
 }
+
+/// Tests that the disassembled instructions of <test_case>.casm.json encode back to its bytecode
+/// and hints.
+#[test_case("account")]
+#[test_case("test_contract")]
+#[test_case("minimal_contract")]
+#[test_case("hello_starknet")]
+#[test_case("erc20")]
+#[test_case("token_bridge")]
+#[test_case("contract_with_component")]
+#[test_case("event_emitter")]
+fn test_casm_contract_disassemble(example_file_name: &str) {
+    let f = std::fs::File::open(get_example_file_path(&format!("{example_file_name}.casm.json")))
+        .unwrap();
+    let casm_contract: CasmContractClass = serde_json::from_reader(BufReader::new(f)).unwrap();
+    let instructions = casm_contract.disassemble().unwrap();
+
+    let mut bytecode = vec![];
+    let mut hints = vec![];
+    for instruction in instructions {
+        if !instruction.hints.is_empty() {
+            hints.push((bytecode.len(), instruction.hints.clone()));
+        }
+        bytecode.extend(instruction.assemble().encode().into_iter().map(|value| BigUintAsHex {
+            value: value.mod_floor(&casm_contract.prime.clone().into()).to_biguint().unwrap(),
+        }));
+    }
+    assert_eq!(bytecode, casm_contract.bytecode);
+    assert_eq!(hints, casm_contract.hints);
+}
+
+#[test]
+fn test_casm_contract_disassemble_invalid_hint_offset() {
+    let f = std::fs::File::open(get_example_file_path("test_contract.casm.json")).unwrap();
+    let mut casm_contract: CasmContractClass = serde_json::from_reader(BufReader::new(f)).unwrap();
+    // The first instruction has an immediate, so offset 1 is within it.
+    casm_contract.hints.insert(0, (1, vec![]));
+    assert_eq!(casm_contract.disassemble(), Err(CasmDisassemblyError::InvalidHintOffset(1)));
+}
+
+/// Tests that immediates in the upper half of the field, both jump offsets and other values, are
+/// disassembled as negative numbers.
+#[test]
+fn test_casm_contract_disassemble_signed_immediates() {
+    let prime = BigUint::from_str_radix(
+        "800000000000011000000000000000000000000000000000000000000000001",
+        16,
+    )
+    .unwrap();
+    let ctx = casm! {
+        [ap + 0] = (-1), ap++;
+        jmp rel (-2);
+        call rel (-4);
+    };
+    let bytecode = ctx
+        .instructions
+        .iter()
+        .flat_map(|instruction| instruction.assemble().encode())
+        .map(|value| BigUintAsHex {
+            value: value.mod_floor(&prime.clone().into()).to_biguint().unwrap(),
+        })
+        .collect();
+    let casm_contract = CasmContractClass { prime, bytecode, ..Default::default() };
+    assert_eq!(casm_contract.disassemble(), Ok(ctx.instructions));
+}
+
+/// Tests that the compilation report of <test_case>.cairo matches its compiled contract.
+#[test_case("hello_starknet")]
+#[test_case("erc20")]
+fn test_casm_contract_compilation_report(example_file_name: &str) {
+    let contract_class = get_test_contract(format!("{example_file_name}.cairo").as_str());
+    let add_pythonic_hints = false;
+    let (casm_contract, report) = CasmContractClass::from_contract_class_with_report(
+        contract_class.clone(),
+        add_pythonic_hints,
+    )
+    .unwrap();
+    assert_eq!(
+        casm_contract,
+        CasmContractClass::from_contract_class(contract_class, add_pythonic_hints).unwrap()
+    );
+
+    // The code of the functions covers the whole bytecode.
+    let mut offset = 0;
+    for function in &report.functions {
+        assert_eq!(function.code_offset, offset, "{}", function.name);
+        offset += function.bytecode_length;
+    }
+    assert_eq!(offset, casm_contract.bytecode.len());
+
+    let casm_entry_points = &casm_contract.entry_points_by_type;
+    let entry_points = &report.entry_points_by_type;
+    for (casm_entry_points, entry_points) in [
+        (&casm_entry_points.external, &entry_points.external),
+        (&casm_entry_points.l1_handler, &entry_points.l1_handler),
+        (&casm_entry_points.constructor, &entry_points.constructor),
+    ] {
+        assert_eq!(casm_entry_points.len(), entry_points.len());
+        for (casm_entry_point, entry_point) in casm_entry_points.iter().zip(entry_points) {
+            assert_eq!(casm_entry_point.selector, entry_point.selector);
+            assert_eq!(casm_entry_point.offset, entry_point.offset);
+            // Functions are named by their debug names.
+            assert!(entry_point.function.contains("::"), "{}", entry_point.function);
+            let function = report
+                .functions
+                .iter()
+                .find(|function| function.name == entry_point.function)
+                .unwrap();
+            assert!(entry_point.bytecode_length >= function.bytecode_length);
+        }
+    }
+}
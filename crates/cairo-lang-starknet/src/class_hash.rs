@@ -0,0 +1,177 @@
+//! Computation of the hashes by which contract classes are identified in Starknet: the class hash
+//! of a Sierra contract class and the compiled class hash of a CASM contract class.
+
+use std::io;
+
+use num_bigint::BigUint;
+use serde::Serialize;
+use starknet_crypto::FieldElement;
+use thiserror::Error;
+
+use crate::casm_contract_class::{CasmContractClass, CasmContractEntryPoint};
+use crate::contract::starknet_keccak;
+use crate::contract_class::{ContractClass, ContractEntryPoint};
+
+#[cfg(test)]
+#[path = "class_hash_test.rs"]
+mod test;
+
+/// The prefix of the version of a contract class in its class hash, followed by the version.
+const CONTRACT_CLASS_VERSION_PREFIX: &str = "CONTRACT_CLASS_V";
+
+/// The version of a compiled contract class in its compiled class hash.
+const COMPILED_CLASS_VERSION: &str = "COMPILED_CLASS_V1";
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum ClassHashError {
+    #[error("{0} is not a field element.")]
+    InvalidFelt252(BigUint),
+    #[error("`{0}` cannot be encoded as a short string.")]
+    InvalidShortString(String),
+}
+
+/// Computes the class hash of a contract class:
+/// `poseidon(api_version, external_hash, l1_handler_hash, constructor_hash, abi_hash,
+/// program_hash)`, where `api_version` is `CONTRACT_CLASS_V<version>` as a short string, each
+/// entry points hash is the poseidon hash of the selectors and function indices of the entry
+/// points, `abi_hash` is the `starknet_keccak` of the ABI and `program_hash` is the poseidon hash
+/// of the Sierra program.
+///
+/// The ABI is hashed in the form in which it is declared: a JSON string formatted as by Python's
+/// `json.dumps`, or an empty string if the contract class has no ABI.
+pub fn compute_class_hash(contract_class: &ContractClass) -> Result<BigUint, ClassHashError> {
+    let entry_points_hash = |entry_points: &[ContractEntryPoint]| {
+        let mut elements = vec![];
+        for entry_point in entry_points {
+            elements.push(to_felt252(&entry_point.selector)?);
+            elements.push(FieldElement::from(entry_point.function_idx));
+        }
+        Ok(starknet_crypto::poseidon_hash_many(&elements))
+    };
+    let abi = match &contract_class.abi {
+        Some(abi) => to_pythonic_json(abi),
+        None => String::new(),
+    };
+    let entry_points = &contract_class.entry_points_by_type;
+    let hash = starknet_crypto::poseidon_hash_many(&[
+        short_string(&format!(
+            "{CONTRACT_CLASS_VERSION_PREFIX}{}",
+            contract_class.contract_class_version
+        ))?,
+        entry_points_hash(&entry_points.external)?,
+        entry_points_hash(&entry_points.l1_handler)?,
+        entry_points_hash(&entry_points.constructor)?,
+        to_felt252(&starknet_keccak(abi.as_bytes()))?,
+        poseidon_hash_many(contract_class.sierra_program.iter().map(|felt| &felt.value))?,
+    ]);
+    Ok(from_felt252(hash))
+}
+
+/// Computes the compiled class hash of a CASM contract class:
+/// `poseidon(COMPILED_CLASS_V1, external_hash, l1_handler_hash, constructor_hash,
+/// bytecode_hash)`, where `COMPILED_CLASS_V1` is a short string, each entry points hash is the
+/// poseidon hash of the selectors, offsets and builtins hashes of the entry points (a builtins
+/// hash being the poseidon hash of the names of the builtins as short strings), and
+/// `bytecode_hash` is the poseidon hash of the bytecode.
+pub fn compute_compiled_class_hash(
+    casm_contract_class: &CasmContractClass,
+) -> Result<BigUint, ClassHashError> {
+    let entry_points_hash = |entry_points: &[CasmContractEntryPoint]| {
+        let mut elements = vec![];
+        for entry_point in entry_points {
+            let builtins = entry_point
+                .builtins
+                .iter()
+                .map(|builtin| short_string(builtin))
+                .collect::<Result<Vec<_>, _>>()?;
+            elements.push(to_felt252(&entry_point.selector)?);
+            elements.push(FieldElement::from(entry_point.offset));
+            elements.push(starknet_crypto::poseidon_hash_many(&builtins));
+        }
+        Ok(starknet_crypto::poseidon_hash_many(&elements))
+    };
+    let entry_points = &casm_contract_class.entry_points_by_type;
+    let hash = starknet_crypto::poseidon_hash_many(&[
+        short_string(COMPILED_CLASS_VERSION)?,
+        entry_points_hash(&entry_points.external)?,
+        entry_points_hash(&entry_points.l1_handler)?,
+        entry_points_hash(&entry_points.constructor)?,
+        poseidon_hash_many(casm_contract_class.bytecode.iter().map(|felt| &felt.value))?,
+    ]);
+    Ok(from_felt252(hash))
+}
+
+/// Computes the poseidon hash of a sequence of field elements.
+fn poseidon_hash_many<'a>(
+    values: impl Iterator<Item = &'a BigUint>,
+) -> Result<FieldElement, ClassHashError> {
+    let felts = values.map(to_felt252).collect::<Result<Vec<_>, _>>()?;
+    Ok(starknet_crypto::poseidon_hash_many(&felts))
+}
+
+fn to_felt252(value: &BigUint) -> Result<FieldElement, ClassHashError> {
+    FieldElement::from_byte_slice_be(&value.to_bytes_be())
+        .map_err(|_| ClassHashError::InvalidFelt252(value.clone()))
+}
+
+fn from_felt252(value: FieldElement) -> BigUint {
+    BigUint::from_bytes_be(&value.to_bytes_be())
+}
+
+/// Encodes an ASCII string of at most 31 characters as a field element, by its bytes.
+fn short_string(value: &str) -> Result<FieldElement, ClassHashError> {
+    if !value.is_ascii() || value.len() > 31 {
+        return Err(ClassHashError::InvalidShortString(value.to_string()));
+    }
+    Ok(FieldElement::from_byte_slice_be(value.as_bytes()).unwrap())
+}
+
+/// Serializes a value to JSON formatted as by Python's `json.dumps` with its default arguments:
+/// with `, ` and `: ` separators, and with non-ASCII characters escaped.
+fn to_pythonic_json(value: &impl Serialize) -> String {
+    let mut output = vec![];
+    let mut serializer = serde_json::Serializer::with_formatter(&mut output, PythonicFormatter);
+    value.serialize(&mut serializer).expect("Serialization to JSON should not fail.");
+    String::from_utf8(output).expect("Serialized JSON should be valid UTF-8.")
+}
+
+/// A JSON formatter that formats as Python's `json.dumps`.
+struct PythonicFormatter;
+impl serde_json::ser::Formatter for PythonicFormatter {
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+
+    fn write_string_fragment<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        for c in fragment.chars() {
+            if c.is_ascii() {
+                writer.write_all(&[c as u8])?;
+            } else {
+                for code_unit in c.encode_utf16(&mut [0; 2]) {
+                    write!(writer, "\\u{code_unit:04x}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
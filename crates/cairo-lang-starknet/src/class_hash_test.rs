@@ -0,0 +1,90 @@
+use std::fs;
+
+use indoc::indoc;
+use num_bigint::BigUint;
+use test_case::test_case;
+
+use super::{
+    compute_class_hash, compute_compiled_class_hash, short_string, to_pythonic_json, ClassHashError,
+};
+use crate::abi::Contract;
+use crate::casm_contract_class::CasmContractClass;
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_example_file_path;
+
+/// Tests the class hash of the contract class in <test_case>.sierra.json and the compiled class
+/// hash of the CASM contract class in <test_case>.casm.json.
+#[test_case(
+    "minimal_contract",
+    "0xa4991f661ab0137cd6f56bb69bd3480abe7e9e1d876b24ca1db28ba2ab07b6",
+    "0x46f2882281342dea7694207216f95d925ba08ef4be0cff5e81e9057f49ef3c2"
+)]
+#[test_case(
+    "hello_starknet",
+    "0x107dd78ff6a72e1526e0d3e9ae6c476d4f1906cfcbb832e7f90889d3f0d1412",
+    "0x3ed389c91c1e6d532ac767b00e236a3b209c853eca7f250a5399d65f39ab86f"
+)]
+#[test_case(
+    "erc20",
+    "0x59567b79dd53a7a7f5475311dd7a36adf6d17b5cf1a4749804ed59f888adf07",
+    "0x6cd0956406e7d3accb97c743b777f6f0bc50a6297817cdfa34363ad6f50e17"
+)]
+#[test_case(
+    "account",
+    "0x45fd62dd8116bb8b92c2376bf520db965992d07ef2003c87584d72ecce8106c",
+    "0x3d07f444cd6e25a6edde9e1ed4b7f271617495b896bba764cba5eee8018e333"
+)]
+fn test_class_hashes(example_file_name: &str, class_hash: &str, compiled_class_hash: &str) {
+    let read_example_file = |extension: &str| {
+        fs::read_to_string(get_example_file_path(&format!("{example_file_name}.{extension}")))
+            .unwrap()
+    };
+    let contract_class: ContractClass =
+        serde_json::from_str(&read_example_file("sierra.json")).unwrap();
+    let casm_contract_class: CasmContractClass =
+        serde_json::from_str(&read_example_file("casm.json")).unwrap();
+
+    assert_eq!(
+        format!("{:#x}", compute_class_hash(&contract_class).unwrap()),
+        class_hash,
+        "class hash"
+    );
+    assert_eq!(
+        format!("{:#x}", compute_compiled_class_hash(&casm_contract_class).unwrap()),
+        compiled_class_hash,
+        "compiled class hash"
+    );
+}
+
+#[test]
+fn test_class_hash_of_invalid_felt252() {
+    let mut contract_class: ContractClass = serde_json::from_str(
+        &fs::read_to_string(get_example_file_path("minimal_contract.sierra.json")).unwrap(),
+    )
+    .unwrap();
+    let value = BigUint::from(1u32) << 252_usize;
+    contract_class.sierra_program[0].value = value.clone();
+    assert_eq!(compute_class_hash(&contract_class), Err(ClassHashError::InvalidFelt252(value)));
+}
+
+#[test]
+fn test_short_string() {
+    assert_eq!(short_string("ABC").unwrap(), 0x414243_u32.into());
+    assert_eq!(
+        short_string("a string that is longer than 31 chars"),
+        Err(ClassHashError::InvalidShortString("a string that is longer than 31 chars".into()))
+    );
+}
+
+#[test]
+fn test_pythonic_json() {
+    let abi: Contract = serde_json::from_str(indoc! {r#"
+        [{"type":"function","name":"f\u00e9","inputs":[{"name":"a","type":"core::felt252"}],
+          "outputs":[],"state_mutability":"view"}]
+    "#})
+    .unwrap();
+    assert_eq!(
+        to_pythonic_json(&abi),
+        r#"[{"type": "function", "name": "f\u00e9", "inputs": [{"name": "a", "type": "core::felt252"}], "outputs": [], "state_mutability": "view"}]"#
+    );
+}
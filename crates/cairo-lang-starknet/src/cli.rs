@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use anyhow::Context;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
+use cairo_lang_starknet::class_hash::compute_class_hash;
 use cairo_lang_starknet::contract_class::compile_path;
 use clap::Parser;
 
@@ -25,6 +26,9 @@ struct Args {
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// A file to write the class hash of the compiled contract class to, as a hex string.
+    #[arg(long)]
+    class_hash_output: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -37,6 +41,12 @@ fn main() -> anyhow::Result<()> {
         CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
     )?;
     validate_compatible_sierra_version(&contract, list_selector)?;
+    if let Some(path) = args.class_hash_output {
+        let class_hash =
+            compute_class_hash(&contract).with_context(|| "Class hash computation failed.")?;
+        fs::write(path, format!("{class_hash:#x}"))
+            .with_context(|| "Failed to write class hash.")?;
+    }
     let res = serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?;
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
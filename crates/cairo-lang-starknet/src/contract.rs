@@ -1,16 +1,21 @@
 use anyhow::Context;
 use cairo_lang_defs::ids::{
-    FreeFunctionId, LanguageElementId, ModuleId, ModuleItemId, SubmoduleId, TraitId,
+    FreeFunctionId, ImplDefId, LanguageElementId, ModuleId, ModuleItemId, SubmoduleId, TraitId,
 };
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::us::SemanticUseEx;
 use cairo_lang_semantic::plugin::DynPluginAuxData;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::TypedSyntaxNode;
 use num_bigint::BigUint;
 use sha3::{Digest, Keccak256};
+use smol_str::SmolStr;
 
 use crate::plugin::aux_data::StarkNetContractAuxData;
-use crate::plugin::consts::ABI_TRAIT;
+use crate::plugin::consts::{ABI_TRAIT, COMPONENT_ATTR, EXTERNAL_ATTR};
 
 #[cfg(test)]
 #[path = "contract_test.rs"]
@@ -39,6 +44,15 @@ pub fn starknet_keccak(data: &[u8]) -> BigUint {
     BigUint::from_bytes_be(&result)
 }
 
+/// Returns the path of a storage variable, from which its address is derived. This is its name,
+/// prefixed by `<namespace>::` for the variables of a component, namespaced by the component name.
+pub fn storage_var_path(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{namespace}::{name}"),
+        None => name.to_string(),
+    }
+}
+
 /// Finds the inline modules annotated as contracts in the given crate_ids and
 /// returns the corresponding ContractDeclarations.
 pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<ContractDeclaration> {
@@ -85,15 +99,29 @@ pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<Cont
     contracts
 }
 
-/// Returns the list of functions in a given module.
+/// Returns the list of functions in a given module of the contract, followed by the functions in
+/// the same module of each of the components embedded in the contract.
 pub fn get_module_functions(
     db: &(dyn SemanticGroup + 'static),
     contract: &ContractDeclaration,
     module_name: &str,
 ) -> anyhow::Result<Vec<FreeFunctionId>> {
     let generated_module_id = get_generated_contract_module(db, contract)?;
+    let mut functions = get_submodule_functions(db, generated_module_id, module_name)?;
+    for component_module_id in get_components(db, contract)? {
+        functions.extend(get_submodule_functions(db, component_module_id, module_name)?);
+    }
+    Ok(functions)
+}
+
+/// Returns the list of functions in the submodule with the given name of the given module.
+fn get_submodule_functions(
+    db: &(dyn SemanticGroup + 'static),
+    module_id: ModuleId,
+    module_name: &str,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
     match db
-        .module_item_by_name(generated_module_id, module_name.into())
+        .module_item_by_name(module_id, module_name.into())
         .to_option()
         .with_context(|| "Failed to initiate a lookup in the {module_name} module.")?
     {
@@ -110,7 +138,14 @@ pub fn get_abi(
     db: &(dyn SemanticGroup + 'static),
     contract: &ContractDeclaration,
 ) -> anyhow::Result<TraitId> {
-    let generated_module_id = get_generated_contract_module(db, contract)?;
+    get_module_abi(db, get_generated_contract_module(db, contract)?)
+}
+
+/// Returns the ABI trait of a module generated for a contract or a component.
+fn get_module_abi(
+    db: &(dyn SemanticGroup + 'static),
+    generated_module_id: ModuleId,
+) -> anyhow::Result<TraitId> {
     match db
         .module_item_by_name(generated_module_id, ABI_TRAIT.into())
         .to_option()
@@ -121,6 +156,75 @@ pub fn get_abi(
     }
 }
 
+/// Returns the modules of the components embedded in the given contract, by `use` items annotated
+/// with `#[component]`.
+pub fn get_components(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<ModuleId>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    let syntax_db = db.upcast();
+    let mut components = vec![];
+    for (use_id, use_ast) in db
+        .module_uses(generated_module_id)
+        .to_option()
+        .with_context(|| "Failed to get the uses of the generated module.")?
+        .iter()
+    {
+        if !use_ast.has_attr(syntax_db, COMPONENT_ATTR) {
+            continue;
+        }
+        let use_path = use_ast.name(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db);
+        match db.use_resolved_item(*use_id).to_option() {
+            Some(ResolvedGenericItem::Module(module_id))
+                if get_module_abi(db, module_id).is_ok() =>
+            {
+                components.push(module_id)
+            }
+            _ => anyhow::bail!("`{use_path}` is not a component."),
+        }
+    }
+    Ok(components)
+}
+
+/// Returns the names and the ABI traits of the components embedded in the given contract.
+pub fn get_component_abis(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<(SmolStr, TraitId)>> {
+    get_components(db, contract)?
+        .into_iter()
+        .map(|module_id| {
+            let ModuleId::Submodule(submodule_id) = module_id else {
+                anyhow::bail!("Failed to get the name of a component.");
+            };
+            Ok((submodule_id.name(db.upcast()), get_module_abi(db, module_id)?))
+        })
+        .collect()
+}
+
+/// Returns the impls of the given contract and of its components marked as external, whose
+/// functions are entry points of the contract.
+pub fn get_external_impls(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<ImplDefId>> {
+    let syntax_db = db.upcast();
+    let mut external_impls = vec![];
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    for module_id in [generated_module_id].into_iter().chain(get_components(db, contract)?) {
+        external_impls.extend(
+            db.module_impls(module_id)
+                .to_option()
+                .with_context(|| "Failed to get the impls of the generated module.")?
+                .iter()
+                .filter(|(_, impl_ast)| impl_ast.has_attr(syntax_db, EXTERNAL_ATTR))
+                .map(|(impl_def_id, _)| *impl_def_id),
+        );
+    }
+    Ok(external_impls)
+}
+
 /// Returns the generated contract module.
 fn get_generated_contract_module(
     db: &(dyn SemanticGroup + 'static),
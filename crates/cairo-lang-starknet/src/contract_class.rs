@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -9,6 +10,8 @@ use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyLongId};
+use cairo_lang_sierra::dead_code_elimination::eliminate_dead_code;
+use cairo_lang_sierra::debug_info::DebugInfo;
 use cairo_lang_sierra_generator::canonical_id_replacer::CanonicalReplacer;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::{replace_sierra_ids_in_program, SierraIdReplacer};
@@ -22,7 +25,8 @@ use thiserror::Error;
 use crate::abi::{AbiBuilder, Contract};
 use crate::allowed_libfuncs::AllowedLibfuncsError;
 use crate::contract::{
-    find_contracts, get_abi, get_module_functions, starknet_keccak, ContractDeclaration,
+    find_contracts, get_abi, get_component_abis, get_external_impls, get_module_functions,
+    starknet_keccak, ContractDeclaration,
 };
 use crate::db::StarknetRootDatabaseBuilderEx;
 use crate::felt252_serde::sierra_to_felt252s;
@@ -149,7 +153,7 @@ fn compile_contract_with_prepared_and_checked_db(
         .into_iter()
         .flat_map(|f| ConcreteFunctionWithBodyId::from_no_generics_free(db, f))
         .collect();
-    let mut sierra_program = db
+    let sierra_program = db
         .get_sierra_program_for_functions(
             chain!(&external_functions, &l1_handler_functions, &constructor_functions)
                 .cloned()
@@ -157,12 +161,27 @@ fn compile_contract_with_prepared_and_checked_db(
         )
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
-
-    if compiler_config.replace_ids {
-        sierra_program = Arc::new(replace_sierra_ids_in_program(db, &sierra_program));
-    }
-    let replacer = CanonicalReplacer::from_program(&sierra_program);
-    let sierra_program = replacer.apply(&sierra_program);
+    let entry_point_ids =
+        chain!(&external_functions, &l1_handler_functions, &constructor_functions)
+            .map(|function_with_body_id| {
+                Ok(db.intern_sierra_function(
+                    function_with_body_id
+                        .function_id(db)
+                        .to_option()
+                        .with_context(|| "Function error.")?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+    let sierra_program = if compiler_config.replace_ids {
+        Arc::new(replace_sierra_ids_in_program(db, &sierra_program))
+    } else {
+        sierra_program
+    };
+    let dead_code_elimination = eliminate_dead_code(&sierra_program, &entry_point_ids)
+        .map_err(|err| anyhow::anyhow!("Dead code elimination failed: {err}"))?;
+    let debug_info = dead_code_elimination.map_debug_info(&DebugInfo::extract(&sierra_program));
+    let replacer = CanonicalReplacer::from_program(&dead_code_elimination.program);
+    let sierra_program = replacer.apply(&dead_code_elimination.program);
 
     let entry_points_by_type = ContractEntryPoints {
         external: get_entry_points(db, &external_functions, &replacer)?,
@@ -175,12 +194,18 @@ fn compile_contract_with_prepared_and_checked_db(
             sierra_version::VersionId::current_version_id(),
             &sierra_program,
         )?,
-        sierra_program_debug_info: Some(cairo_lang_sierra::debug_info::DebugInfo::extract(
-            &sierra_program,
-        )),
+        sierra_program_debug_info: Some(replacer.apply_debug_info(&debug_info)),
         contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
         entry_points_by_type,
-        abi: Some(AbiBuilder::from_trait(db, get_abi(db, contract)?).with_context(|| "ABI error")?),
+        abi: Some(
+            AbiBuilder::from_contract(
+                db,
+                get_abi(db, contract)?,
+                &get_external_impls(db, contract)?,
+                &get_component_abis(db, contract)?,
+            )
+            .with_context(|| "ABI error")?,
+        ),
     };
     Ok(contract_class)
 }
@@ -192,6 +217,7 @@ fn get_entry_points(
     replacer: &CanonicalReplacer,
 ) -> Result<Vec<ContractEntryPoint>> {
     let mut entry_points = vec![];
+    let mut names = HashSet::new();
     for function_with_body_id in entry_point_functions {
         let function_id =
             function_with_body_id.function_id(db).to_option().with_context(|| "Function error.")?;
@@ -205,8 +231,11 @@ fn get_entry_points(
         )
         .expect("Entrypoint cannot be a generated function.");
 
+        // Entry points of embedded components may collide with those of the contract.
+        let name = semantic.name(db);
+        ensure!(names.insert(name.clone()), "Entry point `{name}` is defined more than once.");
         entry_points.push(ContractEntryPoint {
-            selector: starknet_keccak(semantic.name(db).as_bytes()),
+            selector: starknet_keccak(name.as_bytes()),
             function_idx: replacer.replace_function_id(&sierra_id).id as usize,
         });
     }
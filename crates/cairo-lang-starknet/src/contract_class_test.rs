@@ -1,3 +1,4 @@
+use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use indoc::indoc;
 use num_bigint::BigUint;
@@ -6,7 +7,8 @@ use test_case::test_case;
 
 use crate::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
 use crate::contract_class::{
-    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+    compile_path, ContractClass, ContractEntryPoint, ContractEntryPoints,
+    DEFAULT_CONTRACT_CLASS_VERSION,
 };
 use crate::felt252_serde::sierra_from_felt252s;
 use crate::sierra_version;
@@ -58,6 +60,7 @@ fn test_serialization() {
 #[test_case("test_contract")]
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
+#[test_case("event_emitter")]
 fn test_full_contract_deserialization(example_file_name: &str) {
     let contract = get_test_contract(format!("{example_file_name}.cairo").as_str());
     let serialized = serde_json::to_string_pretty(&contract).unwrap();
@@ -72,6 +75,8 @@ fn test_full_contract_deserialization(example_file_name: &str) {
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
 #[test_case("token_bridge")]
+#[test_case("contract_with_component")]
+#[test_case("event_emitter")]
 fn test_compile_path(example_file_name: &str) {
     let contract = get_test_contract(format!("{example_file_name}.cairo").as_str());
 
@@ -97,3 +102,14 @@ fn test_compile_path(example_file_name: &str) {
         sierra_program.to_string(),
     );
 }
+
+/// Tests that an entry point defined both by the contract and by one of its components is rejected.
+#[test]
+fn test_duplicate_entry_point() {
+    let error = compile_path(
+        &get_example_file_path("duplicate_entry_point.cairo"),
+        CompilerConfig { replace_ids: true, ..CompilerConfig::default() },
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "Entry point `get_value` is defined more than once.");
+}
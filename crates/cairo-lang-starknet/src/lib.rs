@@ -6,13 +6,16 @@
 //! Learn more at [starkware.io](http://starknet.io/).
 pub mod abi;
 pub mod allowed_libfuncs;
+pub mod bindings;
 pub mod casm_contract_class;
+pub mod class_hash;
 pub mod contract;
 pub mod contract_class;
 pub mod db;
-mod felt252_serde;
+pub mod felt252_serde;
 pub mod plugin;
 mod sierra_version;
+pub mod upgrade_check;
 
 #[cfg(test)]
 mod test_utils;
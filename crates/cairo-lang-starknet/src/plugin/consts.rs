@@ -3,13 +3,20 @@ pub const EXTERNAL_MODULE: &str = "__external";
 pub const L1_HANDLER_MODULE: &str = "__l1_handler";
 pub const CONSTRUCTOR_MODULE: &str = "__constructor";
 pub const STORAGE_STRUCT_NAME: &str = "Storage";
+pub const EVENT_ENUM_NAME: &str = "Event";
+pub const EMIT_FUNCTION_NAME: &str = "emit";
+/// The path of the derivable trait of types emitted as events.
+pub const EVENT_TRAIT: &str = "starknet::Event";
 
 pub const VIEW_ATTR: &str = "view";
 pub const EVENT_ATTR: &str = "event";
+pub const KEY_ATTR: &str = "key";
+pub const STORAGE_VAR_ATTR: &str = "storage_var";
 pub(super) const ABI_ATTR: &str = "abi";
 pub(super) const ACCOUNT_CONTRACT_ATTR: &str = "account_contract";
 pub(super) const CONTRACT_ATTR: &str = "contract";
-pub(super) const EXTERNAL_ATTR: &str = "external";
+pub const COMPONENT_ATTR: &str = "component";
+pub const EXTERNAL_ATTR: &str = "external";
 pub(super) const L1_HANDLER_ATTR: &str = "l1_handler";
 pub(super) const CONSTRUCTOR_ATTR: &str = "constructor";
 pub(super) const RAW_OUTPUT_ATTR: &str = "raw_output";
@@ -26,4 +33,8 @@ pub(super) const ACCOUNT_CONTRACT_ENTRY_POINTS: [&str; 4] = [
     VALIDATE_DEPLOY_ENTRY_POINT_NAME,
 ];
 
+/// The names of the items generated in the module of a contract.
+pub(super) const GENERATED_ITEM_NAMES: [&str; 4] =
+    [ABI_TRAIT, EXTERNAL_MODULE, L1_HANDLER_MODULE, CONSTRUCTOR_MODULE];
+
 pub(super) const L1_HANDLER_FIRST_PARAM_NAME: &str = "from_address";
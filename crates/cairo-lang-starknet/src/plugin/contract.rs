@@ -14,31 +14,36 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use indoc::formatdoc;
 
 use super::consts::{
-    ABI_TRAIT, ACCOUNT_CONTRACT_ATTR, ACCOUNT_CONTRACT_ENTRY_POINTS, CONSTRUCTOR_MODULE,
-    CONTRACT_ATTR, EVENT_ATTR, EXTERNAL_MODULE, L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE,
-    STORAGE_STRUCT_NAME,
+    ABI_TRAIT, ACCOUNT_CONTRACT_ATTR, ACCOUNT_CONTRACT_ENTRY_POINTS, COMPONENT_ATTR,
+    CONSTRUCTOR_MODULE, CONTRACT_ATTR, EVENT_ATTR, EXTERNAL_ATTR, EXTERNAL_MODULE,
+    GENERATED_ITEM_NAMES, L1_HANDLER_FIRST_PARAM_NAME, L1_HANDLER_MODULE, STORAGE_STRUCT_NAME,
 };
+use super::derive::handle_event_derive;
 use super::entry_point::{generate_entry_point_wrapper, EntryPointKind};
-use super::events::handle_event;
+use super::events::{handle_event, handle_event_enum};
 use super::storage::handle_storage_struct;
 use super::utils::{is_felt252, is_mut_param, maybe_strip_underscore};
 use crate::plugin::aux_data::StarkNetContractAuxData;
 
 /// If the module is annotated with CONTRACT_ATTR, generate the relevant contract logic.
+/// If it is annotated with COMPONENT_ATTR, generate the same logic for a component, to be embedded
+/// in contracts, whose storage variables are namespaced by the name of the component.
 pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult {
     let is_account_contract = module_ast.has_attr(db, ACCOUNT_CONTRACT_ATTR);
+    let is_component = module_ast.has_attr(db, COMPONENT_ATTR);
 
-    if !is_account_contract && !module_ast.has_attr(db, CONTRACT_ATTR) {
+    if !is_account_contract && !is_component && !module_ast.has_attr(db, CONTRACT_ATTR) {
         return PluginResult::default();
     }
 
     let body = match module_ast.body(db) {
         MaybeModuleBody::Some(body) => body,
         MaybeModuleBody::None(empty_body) => {
+            let kind = if is_component { "Components" } else { "Contracts" };
             return PluginResult {
                 code: None,
                 diagnostics: vec![PluginDiagnostic {
-                    message: "Contracts without body are not supported.".to_string(),
+                    message: format!("{kind} without body are not supported."),
                     stable_ptr: empty_body.stable_ptr().untyped(),
                 }],
                 remove_original_item: false,
@@ -57,8 +62,7 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
         {
             continue;
         }
-        kept_original_items.push(RewriteNode::Copied(item.as_syntax_node()));
-        if let Some(ident) = match item {
+        let ident = match &item {
             ast::Item::Constant(item) => Some(item.name(db)),
             ast::Item::Module(item) => Some(item.name(db)),
             ast::Item::Use(item) => {
@@ -70,18 +74,40 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                     None
                 }
             }
+            ast::Item::FreeFunction(item) => Some(item.declaration(db).name(db)),
+            ast::Item::ExternFunction(item) => Some(item.declaration(db).name(db)),
+            ast::Item::ExternType(item) => Some(item.name(db)),
+            ast::Item::Trait(item) => Some(item.name(db)),
             ast::Item::Impl(item) => Some(item.name(db)),
+            ast::Item::ImplAlias(item) => Some(item.name(db)),
             ast::Item::Struct(item) => Some(item.name(db)),
             ast::Item::Enum(item) => Some(item.name(db)),
             ast::Item::TypeAlias(item) => Some(item.name(db)),
-            // Externs, trait declarations and free functions are not directly required in generated
-            // inner modules.
-            ast::Item::ExternFunction(_)
-            | ast::Item::ExternType(_)
-            | ast::Item::Trait(_)
-            | ast::Item::FreeFunction(_) => None,
-            ast::Item::ImplAlias(_) => todo!(),
-        } {
+        };
+        if let Some(ident) = &ident {
+            let name = ident.text(db);
+            if GENERATED_ITEM_NAMES.contains(&name.as_str()) {
+                diagnostics.push(PluginDiagnostic {
+                    message: format!(
+                        "The name `{name}` is reserved for an item generated in contracts."
+                    ),
+                    stable_ptr: ident.stable_ptr().untyped(),
+                });
+                continue;
+            }
+        }
+        kept_original_items.push(RewriteNode::Copied(item.as_syntax_node()));
+        let (event_impl, event_diagnostics) = handle_event_derive(db, &item);
+        kept_original_items.extend(event_impl);
+        diagnostics.extend(event_diagnostics);
+        // Externs and free functions are not directly required in generated inner modules.
+        if matches!(
+            item,
+            ast::Item::ExternFunction(_) | ast::Item::ExternType(_) | ast::Item::FreeFunction(_)
+        ) {
+            continue;
+        }
+        if let Some(ident) = ident {
             extra_uses
                 .entry(ident.text(db))
                 .or_insert_with_key(|ident| format!("super::{}", ident));
@@ -107,11 +133,11 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
             .collect(),
     );
 
-    let mut generated_external_functions = Vec::new();
-    let mut generated_constructor_functions = Vec::new();
-    let mut generated_l1_handler_functions = Vec::new();
+    let mut generated_entry_points = GeneratedEntryPoints::default();
+    let storage_namespace = is_component.then(|| module_ast.name(db).text(db));
 
     let mut storage_code = RewriteNode::Text("".to_string());
+    let mut abi_storage_vars = RewriteNode::Text("".to_string());
     let mut abi_functions = Vec::new();
     let mut event_functions = Vec::new();
     let mut abi_events = Vec::new();
@@ -125,41 +151,38 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                 }
                 diagnostics.extend(event_diagnostics);
             }
+            ast::Item::Enum(item_enum) if item_enum.has_attr(db, EVENT_ATTR) => {
+                let (rewrite_nodes, event_diagnostics) = handle_event_enum(db, item_enum.clone());
+                if let Some((emit_function_rewrite, abi_event_rewrite)) = rewrite_nodes {
+                    event_functions.push(emit_function_rewrite);
+                    abi_events.push(abi_event_rewrite);
+                }
+                diagnostics.extend(event_diagnostics);
+            }
             ast::Item::FreeFunction(item_function) => {
                 if let Some(entry_point_kind) =
                     EntryPointKind::try_from_function_with_body(db, item_function)
                 {
                     let attr = entry_point_kind.get_attr();
 
-                    let declaration = item_function.declaration(db);
-                    if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
-                        declaration.generic_params(db)
+                    if is_component
+                        && matches!(
+                            entry_point_kind,
+                            EntryPointKind::Constructor | EntryPointKind::L1Handler
+                        )
                     {
                         diagnostics.push(PluginDiagnostic {
-                            message: "Contract entry points cannot have generic arguments"
-                                .to_string(),
-                            stable_ptr: generic_params.stable_ptr().untyped(),
-                        })
-                    }
-
-                    let name = declaration.name(db);
-                    let name_str = name.text(db);
-
-                    if !is_account_contract {
-                        for account_contract_entry_point in ACCOUNT_CONTRACT_ENTRY_POINTS {
-                            if name_str == account_contract_entry_point {
-                                diagnostics.push(PluginDiagnostic {
-                                    message: format!(
-                                        "Only an account contract may implement `{name_str}`."
-                                    ),
-
-                                    stable_ptr: name.stable_ptr().untyped(),
-                                })
-                            }
-                        }
+                            message: format!("Components cannot have `#[{attr}]` functions."),
+                            stable_ptr: item_function
+                                .declaration(db)
+                                .name(db)
+                                .stable_ptr()
+                                .untyped(),
+                        });
+                        continue;
                     }
-                    // TODO(ilya): Validate that an account contract has all the required functions.
 
+                    let declaration = item_function.declaration(db);
                     let mut declaration_node =
                         RewriteNode::new_trimmed(declaration.as_syntax_node());
                     let original_parameters = declaration_node
@@ -181,37 +204,81 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                         RewriteNode::Text(";\n        ".to_string()),
                     ]));
 
-                    match generate_entry_point_wrapper(db, item_function) {
-                        Ok(generated_function) => {
-                            let generated = match entry_point_kind {
-                                EntryPointKind::Constructor => &mut generated_constructor_functions,
-                                EntryPointKind::L1Handler => {
-                                    validate_l1_handler_first_parameter(
-                                        db,
-                                        &params,
-                                        &mut diagnostics,
-                                    );
-                                    &mut generated_l1_handler_functions
-                                }
-                                EntryPointKind::External | EntryPointKind::View => {
-                                    &mut generated_external_functions
-                                }
-                            };
-                            generated.push(generated_function);
-                            generated.push(RewriteNode::Text("\n        ".to_string()));
-                        }
-                        Err(entry_point_diagnostics) => {
-                            diagnostics.extend(entry_point_diagnostics);
-                        }
-                    }
+                    let wrapped_function_path = RewriteNode::interpolate_patched(
+                        "super::$function_name$",
+                        HashMap::from([(
+                            "function_name".to_string(),
+                            RewriteNode::new_trimmed(declaration.name(db).as_syntax_node()),
+                        )]),
+                    );
+                    handle_entry_point(
+                        db,
+                        item_function,
+                        entry_point_kind,
+                        wrapped_function_path,
+                        is_account_contract,
+                        &mut diagnostics,
+                        &mut generated_entry_points,
+                    );
+                }
+            }
+            ast::Item::Impl(item_impl) if item_impl.has_attr(db, EXTERNAL_ATTR) => {
+                if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
+                    item_impl.generic_params(db)
+                {
+                    diagnostics.push(PluginDiagnostic {
+                        message: "External impls cannot have generic arguments".to_string(),
+                        stable_ptr: generic_params.stable_ptr().untyped(),
+                    });
+                    continue;
+                }
+                let ast::MaybeImplBody::Some(impl_body) = item_impl.body(db) else { continue; };
+                let impl_name = RewriteNode::new_trimmed(item_impl.name(db).as_syntax_node());
+                // The functions of an external impl are external entry points. Whether they are
+                // views is declared by the implemented interface.
+                for impl_item in impl_body.items(db).elements(db) {
+                    let ast::Item::FreeFunction(item_function) = impl_item else { continue; };
+                    let wrapped_function_path = RewriteNode::interpolate_patched(
+                        "super::$impl_name$::$function_name$",
+                        HashMap::from([
+                            ("impl_name".to_string(), impl_name.clone()),
+                            (
+                                "function_name".to_string(),
+                                RewriteNode::new_trimmed(
+                                    item_function.declaration(db).name(db).as_syntax_node(),
+                                ),
+                            ),
+                        ]),
+                    );
+                    handle_entry_point(
+                        db,
+                        &item_function,
+                        EntryPointKind::External,
+                        wrapped_function_path,
+                        is_account_contract,
+                        &mut diagnostics,
+                        &mut generated_entry_points,
+                    );
                 }
             }
+            ast::Item::Use(item_use) if is_component && item_use.has_attr(db, COMPONENT_ATTR) => {
+                diagnostics.push(PluginDiagnostic {
+                    message: "Components cannot embed other components.".to_string(),
+                    stable_ptr: item_use.name(db).stable_ptr().untyped(),
+                });
+            }
             ast::Item::Struct(item_struct)
                 if item_struct.name(db).text(db) == STORAGE_STRUCT_NAME =>
             {
-                let (storage_rewrite_node, storage_diagnostics) =
-                    handle_storage_struct(db, item_struct.clone(), &extra_uses_node);
+                let (storage_rewrite_node, abi_storage_rewrite_node, storage_diagnostics) =
+                    handle_storage_struct(
+                        db,
+                        item_struct.clone(),
+                        &extra_uses_node,
+                        storage_namespace.as_deref(),
+                    );
                 storage_code = storage_rewrite_node;
+                abi_storage_vars = abi_storage_rewrite_node;
                 diagnostics.extend(storage_diagnostics);
             }
             _ => {}
@@ -234,6 +301,7 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
                 trait {ABI_TRAIT} {{
                     $abi_functions$
                     $abi_events$
+                    $abi_storage_vars$
                 }}
 
                 mod {EXTERNAL_MODULE} {{$extra_uses$
@@ -264,18 +332,19 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
             ("event_functions".to_string(), RewriteNode::new_modified(event_functions)),
             ("abi_functions".to_string(), RewriteNode::new_modified(abi_functions)),
             ("abi_events".to_string(), RewriteNode::new_modified(abi_events)),
+            ("abi_storage_vars".to_string(), abi_storage_vars),
             ("extra_uses".to_string(), extra_uses_node),
             (
                 "generated_external_functions".to_string(),
-                RewriteNode::new_modified(generated_external_functions),
+                RewriteNode::new_modified(generated_entry_points.external_functions),
             ),
             (
                 "generated_l1_handler_functions".to_string(),
-                RewriteNode::new_modified(generated_l1_handler_functions),
+                RewriteNode::new_modified(generated_entry_points.l1_handler_functions),
             ),
             (
                 "generated_constructor_functions".to_string(),
-                RewriteNode::new_modified(generated_constructor_functions),
+                RewriteNode::new_modified(generated_entry_points.constructor_functions),
             ),
         ]),
     );
@@ -289,7 +358,7 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
             aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(
                 StarkNetContractAuxData {
                     patches: builder.patches,
-                    contracts: vec![module_name_ast.text(db)],
+                    contracts: if is_component { vec![] } else { vec![module_name_ast.text(db)] },
                 },
             )),
         }),
@@ -298,6 +367,76 @@ pub fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginRe
     }
 }
 
+/// The wrappers of the entry points of a contract, by the module they are generated in.
+#[derive(Default)]
+struct GeneratedEntryPoints {
+    external_functions: Vec<RewriteNode>,
+    l1_handler_functions: Vec<RewriteNode>,
+    constructor_functions: Vec<RewriteNode>,
+}
+
+/// Validates an entry point of a contract and generates its wrapper, calling the function at
+/// `wrapped_function_path`.
+fn handle_entry_point(
+    db: &dyn SyntaxGroup,
+    item_function: &ast::FunctionWithBody,
+    entry_point_kind: EntryPointKind,
+    wrapped_function_path: RewriteNode,
+    is_account_contract: bool,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    generated_entry_points: &mut GeneratedEntryPoints,
+) {
+    let declaration = item_function.declaration(db);
+    if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
+        declaration.generic_params(db)
+    {
+        diagnostics.push(PluginDiagnostic {
+            message: "Contract entry points cannot have generic arguments".to_string(),
+            stable_ptr: generic_params.stable_ptr().untyped(),
+        })
+    }
+
+    let name = declaration.name(db);
+    let name_str = name.text(db);
+
+    if !is_account_contract {
+        for account_contract_entry_point in ACCOUNT_CONTRACT_ENTRY_POINTS {
+            if name_str == account_contract_entry_point {
+                diagnostics.push(PluginDiagnostic {
+                    message: format!("Only an account contract may implement `{name_str}`."),
+
+                    stable_ptr: name.stable_ptr().untyped(),
+                })
+            }
+        }
+    }
+    // TODO(ilya): Validate that an account contract has all the required functions.
+
+    match generate_entry_point_wrapper(db, item_function, wrapped_function_path) {
+        Ok(generated_function) => {
+            let generated = match entry_point_kind {
+                EntryPointKind::Constructor => &mut generated_entry_points.constructor_functions,
+                EntryPointKind::L1Handler => {
+                    validate_l1_handler_first_parameter(
+                        db,
+                        &declaration.signature(db).parameters(db),
+                        diagnostics,
+                    );
+                    &mut generated_entry_points.l1_handler_functions
+                }
+                EntryPointKind::External | EntryPointKind::View => {
+                    &mut generated_entry_points.external_functions
+                }
+            };
+            generated.push(generated_function);
+            generated.push(RewriteNode::Text("\n        ".to_string()));
+        }
+        Err(entry_point_diagnostics) => {
+            diagnostics.extend(entry_point_diagnostics);
+        }
+    }
+}
+
 /// Validates the first parameter of an L1 handler is `from_address: felt252` or `_from_address:
 /// felt252`.
 fn validate_l1_handler_first_parameter(
@@ -0,0 +1,178 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_semantic::patcher::RewriteNode;
+use cairo_lang_syntax::attribute::structured::{
+    AttributeArg, AttributeArgVariant, AttributeStructurize,
+};
+use cairo_lang_syntax::node::ast::{AttributeList, MemberList};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::formatdoc;
+use itertools::Itertools;
+use smol_str::SmolStr;
+
+use super::consts::{EVENT_TRAIT, KEY_ATTR};
+use crate::contract::starknet_keccak;
+
+/// The name and type of a struct member or an enum variant.
+struct MemberInfo {
+    name: SmolStr,
+    ty: String,
+    /// Whether the member is marked with `#[key]`, making it a key of the event.
+    is_key: bool,
+}
+
+enum EventInfo {
+    Struct(Vec<MemberInfo>),
+    Enum(Vec<MemberInfo>),
+}
+
+/// Derives `starknet::Event` for an item of a contract module marked with
+/// `#[derive(starknet::Event)]`.
+/// Returns the impl of `starknet::Event` for the item if it derives it, and diagnostics.
+pub fn handle_event_derive(
+    db: &dyn SyntaxGroup,
+    item_ast: &ast::Item,
+) -> (Option<RewriteNode>, Vec<PluginDiagnostic>) {
+    let mut diagnostics = vec![];
+    let (ident, event_info) = match item_ast {
+        ast::Item::Struct(struct_ast) => {
+            if derives_event(db, struct_ast.attributes(db), &mut diagnostics).is_none() {
+                return (None, diagnostics);
+            }
+            (struct_ast.name(db), EventInfo::Struct(members_info(db, struct_ast.members(db))))
+        }
+        ast::Item::Enum(enum_ast) => {
+            let variants = enum_ast.variants(db);
+            for variant in variants.elements(db) {
+                if let Some(attr) = variant.find_attr(db, KEY_ATTR) {
+                    diagnostics.push(PluginDiagnostic {
+                        stable_ptr: attr.stable_ptr().untyped(),
+                        message: format!(
+                            "`#[{KEY_ATTR}]` is only supported on the members of a struct, not on \
+                             enum variants."
+                        ),
+                    });
+                }
+            }
+            if derives_event(db, enum_ast.attributes(db), &mut diagnostics).is_none() {
+                return (None, diagnostics);
+            }
+            (enum_ast.name(db), EventInfo::Enum(members_info(db, variants)))
+        }
+        ast::Item::ExternType(extern_type_ast) => {
+            if let Some(stable_ptr) =
+                derives_event(db, extern_type_ast.attributes(db), &mut diagnostics)
+            {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr,
+                    message: "Unsupported trait for derive for extern types.".into(),
+                });
+            }
+            return (None, diagnostics);
+        }
+        _ => return (None, diagnostics),
+    };
+    // The impl follows the item in the generated contract module, indented as its other items.
+    let event_impl = get_event_impl(&ident.text(db), &event_info)
+        .lines()
+        .map(|line| if line.is_empty() { "\n".to_string() } else { format!("\n    {line}") })
+        .join("");
+    (Some(RewriteNode::Text(format!("{event_impl}\n"))), diagnostics)
+}
+
+fn members_info(db: &dyn SyntaxGroup, members: MemberList) -> Vec<MemberInfo> {
+    members
+        .elements(db)
+        .into_iter()
+        .map(|member| MemberInfo {
+            name: member.name(db).text(db),
+            ty: member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db),
+            is_key: member.has_attr(db, KEY_ATTR),
+        })
+        .collect()
+}
+
+/// Returns the pointer to the `starknet::Event` argument of the `#[derive]` attributes of an item,
+/// if it derives `starknet::Event`.
+fn derives_event(
+    db: &dyn SyntaxGroup,
+    attributes: AttributeList,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<SyntaxStablePtrId> {
+    let mut event_derive = None;
+    for attr in attributes.query_attr(db, "derive") {
+        for arg in attr.structurize(db).args {
+            let AttributeArg {
+                variant: AttributeArgVariant::Unnamed {
+                    value: ast::Expr::Path(path),
+                    value_stable_ptr,
+                    ..
+                },
+                ..
+            } = arg else {
+                // Reported by the derive plugin.
+                continue;
+            };
+            match path.as_syntax_node().get_text_without_trivia(db).as_str() {
+                // The derive plugin ignores unknown single segment derives, so an `Event` derive
+                // of the imported trait would silently generate nothing.
+                "Event" => diagnostics.push(PluginDiagnostic {
+                    stable_ptr: value_stable_ptr.untyped(),
+                    message: format!("`Event` must be derived by its full path `{EVENT_TRAIT}`."),
+                }),
+                EVENT_TRAIT => event_derive = Some(value_stable_ptr.untyped()),
+                _ => {}
+            }
+        }
+    }
+    event_derive
+}
+
+/// Returns an impl of `starknet::Event`, which appends the members of a struct marked with
+/// `#[key]` to the keys of the event and the other members to its data, and for an enum, appends
+/// the selector of the variant to the keys, followed by the keys and data of its value.
+fn get_event_impl(name: &str, event_info: &EventInfo) -> String {
+    match event_info {
+        EventInfo::Enum(variants) => {
+            formatdoc! {"
+                    impl {name}StarknetEvent of starknet::Event::<{name}> {{
+                        fn append_keys_and_data(
+                            self: {name}, ref keys: array::Array<felt252>, ref data: array::Array<felt252>
+                        ) {{
+                            match self {{
+                                {}
+                            }}
+                        }}
+                    }}
+                ",
+                variants.iter().map(|MemberInfo { name: variant, ty, .. }| {
+                    format!(
+                        "{name}::{variant}(x) => {{\n                \
+                         array::ArrayTrait::append(ref keys, {:#x});\n                \
+                         starknet::Event::<{ty}>::append_keys_and_data(x, ref keys, ref data);\n            \
+                         }},",
+                        starknet_keccak(variant.as_bytes()),
+                    )
+                }).join("\n            "),
+            }
+        }
+        EventInfo::Struct(members) => {
+            formatdoc! {"
+                    impl {name}StarknetEvent of starknet::Event::<{name}> {{
+                        fn append_keys_and_data(
+                            self: {name}, ref keys: array::Array<felt252>, ref data: array::Array<felt252>
+                        ) {{
+                            {}
+                        }}
+                    }}
+                ",
+                members.iter().map(|MemberInfo { name: member, ty, is_key }| {
+                    let target = if *is_key { "keys" } else { "data" };
+                    format!("serde::Serde::<{ty}>::serialize(ref {target}, self.{member});")
+                }).join("\n        "),
+            }
+        }
+    }
+}
@@ -47,10 +47,12 @@ impl EntryPointKind {
     }
 }
 
-/// Generates Cairo code for an entry point wrapper.
+/// Generates Cairo code for an entry point wrapper, which calls the function at
+/// `wrapped_function_path`.
 pub fn generate_entry_point_wrapper(
     db: &dyn SyntaxGroup,
     function: &FunctionWithBody,
+    wrapped_function_path: RewriteNode,
 ) -> Result<RewriteNode, Vec<PluginDiagnostic>> {
     let declaration = function.declaration(db);
     let sig = declaration.signature(db);
@@ -96,10 +98,6 @@ pub fn generate_entry_point_wrapper(
     let arg_names_str = arg_names.join(", ");
 
     let function_name = RewriteNode::new_trimmed(declaration.name(db).as_syntax_node());
-    let wrapped_name = RewriteNode::interpolate_patched(
-        "super::$function_name$",
-        HashMap::from([("function_name".to_string(), function_name.clone())]),
-    );
 
     let ret_ty = sig.ret_ty(db);
     let (let_res, append_res, return_ty_is_felt252_span, ret_type_ptr) = match &ret_ty {
@@ -149,7 +147,7 @@ pub fn generate_entry_point_wrapper(
     let output_handling = RewriteNode::interpolate_patched(
         &output_handling_string,
         HashMap::from([
-            ("wrapped_name".to_string(), wrapped_name),
+            ("wrapped_name".to_string(), wrapped_function_path),
             ("ref_appends".to_string(), RewriteNode::new_modified(ref_appends)),
         ]),
     );
@@ -5,7 +5,9 @@ use cairo_lang_semantic::patcher::RewriteNode;
 use cairo_lang_syntax::node::ast::{self, OptionReturnTypeClause, OptionWrappedGenericParamList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+use indoc::formatdoc;
 
+use super::consts::{EMIT_FUNCTION_NAME, EVENT_ATTR, EVENT_ENUM_NAME};
 use super::utils::is_ref_param;
 use crate::contract::starknet_keccak;
 
@@ -28,6 +30,17 @@ pub fn handle_event(
         })
     }
 
+    let name = declaration.name(db);
+    if name.text(db) == EMIT_FUNCTION_NAME {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: name.stable_ptr().untyped(),
+            message: format!(
+                "The name `{EMIT_FUNCTION_NAME}` is reserved for the function emitting the events \
+                 of the `#[{EVENT_ATTR}]` enum `{EVENT_ENUM_NAME}`."
+            ),
+        });
+    }
+
     let signature = declaration.signature(db);
     let ret_ty = declaration.signature(db).ret_ty(db);
     if matches!(ret_ty, OptionReturnTypeClause::ReturnTypeClause(_)) {
@@ -79,7 +92,7 @@ pub fn handle_event(
         return (None, diagnostics);
     }
 
-    let name = declaration.name(db).text(db);
+    let name = name.text(db);
     let event_key = format!("0x{:x}", starknet_keccak(name.as_bytes()));
 
     (
@@ -128,3 +141,64 @@ pub fn handle_event(
         diagnostics,
     )
 }
+
+/// Generates the function emitting the events of a contract from its `#[event]` enum, and the
+/// corresponding ABI item.
+/// On success, returns a RewriteNode for the emit function and a RewriteNode for the ABI
+/// declaration. On failure returns None. In addition, returns diagnostics.
+pub fn handle_event_enum(
+    db: &dyn SyntaxGroup,
+    enum_ast: ast::ItemEnum,
+) -> (Option<(RewriteNode, RewriteNode)>, Vec<PluginDiagnostic>) {
+    let mut diagnostics = vec![];
+
+    let name = enum_ast.name(db);
+    if name.text(db) != EVENT_ENUM_NAME {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: name.stable_ptr().untyped(),
+            message: format!(
+                "The `#[{EVENT_ATTR}]` enum of a contract must be named `{EVENT_ENUM_NAME}`."
+            ),
+        });
+    }
+
+    if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
+        enum_ast.generic_params(db)
+    {
+        diagnostics.push(PluginDiagnostic {
+            message: "Event enums cannot have generic arguments".to_string(),
+            stable_ptr: generic_params.stable_ptr().untyped(),
+        })
+    }
+
+    if !diagnostics.is_empty() {
+        return (None, diagnostics);
+    }
+
+    (
+        Some((
+            // Emit function
+            RewriteNode::Text(formatdoc!(
+                "
+                fn {EMIT_FUNCTION_NAME}(event: {EVENT_ENUM_NAME}) {{
+                        let mut keys = array::ArrayTrait::new();
+                        let mut data = array::ArrayTrait::new();
+                        starknet::Event::append_keys_and_data(event, ref keys, ref data);
+                        starknet::syscalls::emit_event_syscall(
+                            array::ArrayTrait::span(@keys),
+                            array::ArrayTrait::span(@data),
+                        ).unwrap_syscall()
+                    }}
+                "
+            )),
+            // ABI event
+            RewriteNode::new_modified(vec![
+                RewriteNode::Text(format!("#[{EVENT_ATTR}]\n        ")),
+                RewriteNode::Text(format!(
+                    "fn {EMIT_FUNCTION_NAME}(event: {EVENT_ENUM_NAME});\n        "
+                )),
+            ]),
+        )),
+        diagnostics,
+    )
+}
@@ -12,6 +12,7 @@ use consts::*;
 
 pub mod aux_data;
 mod contract;
+mod derive;
 mod dispatcher;
 mod entry_point;
 mod events;
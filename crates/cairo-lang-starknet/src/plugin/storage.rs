@@ -3,104 +3,122 @@ use std::collections::HashMap;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_semantic::patcher::RewriteNode;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::try_extract_matches;
 
-use crate::contract::starknet_keccak;
+use super::consts::STORAGE_VAR_ATTR;
+use crate::contract::{starknet_keccak, storage_var_path};
 
-/// Generate getters and setters for the variables in the storage struct.
+/// Generate getters and setters for the variables in the storage struct, and the declarations of
+/// the variables for the ABI trait.
+/// The address of a variable is derived from its name, prefixed by `<namespace>::` if a namespace
+/// is given.
 pub fn handle_storage_struct(
     db: &dyn SyntaxGroup,
     struct_ast: ast::ItemStruct,
     extra_uses_node: &RewriteNode,
-) -> (RewriteNode, Vec<PluginDiagnostic>) {
+    namespace: Option<&str>,
+) -> (RewriteNode, RewriteNode, Vec<PluginDiagnostic>) {
     let mut members_code = Vec::new();
+    let mut abi_declarations = Vec::new();
     let mut diagnostics = vec![];
 
     for member in struct_ast.members(db).elements(db) {
         let name = member.name(db).text(db);
-        let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+        let address =
+            format!("0x{:x}", starknet_keccak(storage_var_path(namespace, &name).as_bytes()));
         let type_ast = member.type_clause(db).ty(db);
-        match try_extract_mapping_types(db, &type_ast) {
-            Some((key_type_ast, value_type_ast, MappingType::Legacy)) => {
-                members_code.push(RewriteNode::interpolate_patched(
-                    handle_legacy_mapping_storage_var(&address).as_str(),
-                    HashMap::from([
-                        (
-                            "storage_var_name".to_string(),
-                            RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
-                        ),
-                        ("extra_uses".to_string(), extra_uses_node.clone()),
-                        (
-                            "key_type".to_string(),
-                            RewriteNode::new_trimmed(key_type_ast.as_syntax_node()),
-                        ),
-                        (
-                            "value_type".to_string(),
-                            RewriteNode::new_trimmed(value_type_ast.as_syntax_node()),
-                        ),
-                    ]),
-                ));
-            }
-            Some((_, _, MappingType::NonLegacy)) => {
-                diagnostics.push(PluginDiagnostic {
-                    message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
-                    stable_ptr: type_ast.stable_ptr().untyped(),
-                });
-            }
-            None => {
-                members_code.push(RewriteNode::interpolate_patched(
-                    handle_simple_storage_var(&address).as_str(),
-                    HashMap::from([
-                        (
-                            "storage_var_name".to_string(),
-                            RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
-                        ),
-                        ("extra_uses".to_string(), extra_uses_node.clone()),
-                        (
-                            "type_name".to_string(),
-                            RewriteNode::new_trimmed(type_ast.as_syntax_node()),
-                        ),
-                    ]),
-                ));
+        let (key_types, value_type) = match try_extract_mapping_types(db, &type_ast) {
+            Ok(Some((key_types, value_type))) => (key_types, value_type),
+            Ok(None) => (vec![], type_ast.as_syntax_node()),
+            Err(diagnostic) => {
+                diagnostics.push(diagnostic);
+                continue;
             }
+        };
+        let mut patches = HashMap::from([
+            (
+                "storage_var_name".to_string(),
+                RewriteNode::new_trimmed(member.name(db).as_syntax_node()),
+            ),
+            ("extra_uses".to_string(), extra_uses_node.clone()),
+            ("value_type".to_string(), RewriteNode::new_trimmed(value_type)),
+        ]);
+        let key_names = match key_types.len() {
+            1 => vec!["key".to_string()],
+            n_keys => (0..n_keys).map(|i| format!("key{i}")).collect(),
+        };
+        for (i, key_type) in key_types.into_iter().enumerate() {
+            patches.insert(format!("key_type_{i}"), RewriteNode::new_trimmed(key_type));
         }
+        let code = if key_names.is_empty() {
+            handle_simple_storage_var(&address)
+        } else {
+            handle_mapping_storage_var(&address, &key_names)
+        };
+        members_code.push(RewriteNode::interpolate_patched(code.as_str(), patches.clone()));
+        let abi_declaration = format!(
+            "#[{STORAGE_VAR_ATTR}]\n        fn $storage_var_name$({}) -> $value_type$;\n        ",
+            key_params(&key_names)
+        );
+        abi_declarations.push(RewriteNode::interpolate_patched(&abi_declaration, patches));
     }
-    (RewriteNode::new_modified(members_code), diagnostics)
-}
-
-/// The type of the mapping storage variable.
-enum MappingType {
-    /// Pedersen based.
-    Legacy,
-    /// Poseidon based.
-    NonLegacy,
+    (
+        RewriteNode::new_modified(members_code),
+        RewriteNode::new_modified(abi_declarations),
+        diagnostics,
+    )
 }
 
-/// Given a type, if it is of form `Map{Legacy,}::<K, V>`, returns `K` and `V` and the mapping type.
-/// Otherwise, returns None.
+/// Given a type, if it is of form `{Legacy,}Map::<K, V>`, returns the types of the keys and the
+/// value, where nested mappings are flattened, e.g. `LegacyMap::<K0, LegacyMap::<K1, V>>` returns
+/// `[K0, K1]` and `V`. Otherwise, returns None.
 fn try_extract_mapping_types(
     db: &dyn SyntaxGroup,
     type_ast: &ast::Expr,
-) -> Option<(ast::GenericArg, ast::GenericArg, MappingType)> {
-    let as_path = try_extract_matches!(type_ast, ast::Expr::Path)?;
+) -> Result<Option<(Vec<SyntaxNode>, SyntaxNode)>, PluginDiagnostic> {
+    let Some(as_path) = try_extract_matches!(type_ast, ast::Expr::Path) else {
+        return Ok(None);
+    };
     let [ast::PathSegment::WithGenericArgs(segment)] = &as_path.elements(db)[..] else {
-        return None;
+        return Ok(None);
     };
     let ty = segment.ident(db).text(db);
-    if ty == "LegacyMap" || ty == "Map" {
-        let [key_ty, value_ty] = <[ast::GenericArg; 2]>::try_from(
-            segment.generic_args(db).generic_args(db).elements(db),
-        )
-        .ok()?;
-        Some((
-            key_ty,
-            value_ty,
-            if ty == "LegacyMap" { MappingType::Legacy } else { MappingType::NonLegacy },
-        ))
-    } else {
-        None
+    if ty != "LegacyMap" && ty != "Map" {
+        return Ok(None);
     }
+    let generic_args = segment.generic_args(db).generic_args(db);
+    let Ok([key_ty, value_ty]) = <[ast::GenericArg; 2]>::try_from(generic_args.elements(db)) else {
+        return Err(PluginDiagnostic {
+            message: format!("`{ty}` must have exactly 2 generic arguments."),
+            stable_ptr: generic_args.stable_ptr().untyped(),
+        });
+    };
+    let (ast::GenericArg::Expr(key_ty), ast::GenericArg::Expr(value_ty)) = (key_ty, value_ty) else {
+        return Err(PluginDiagnostic {
+            message: format!("The generic arguments of `{ty}` must be explicit types."),
+            stable_ptr: generic_args.stable_ptr().untyped(),
+        });
+    };
+    let value_ty = value_ty.value(db);
+    Ok(Some(match try_extract_mapping_types(db, &value_ty)? {
+        Some((mut key_types, value_type)) => {
+            key_types.insert(0, key_ty.as_syntax_node());
+            (key_types, value_type)
+        }
+        None => (vec![key_ty.as_syntax_node()], value_ty.as_syntax_node()),
+    }))
+}
+
+/// Returns the parameters list of the keys with the given names, for a template in which the type
+/// of the i-th key is `$key_type_i$`.
+fn key_params(key_names: &[String]) -> String {
+    key_names
+        .iter()
+        .enumerate()
+        .map(|(i, key_name)| format!("{key_name}: $key_type_{i}$"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Generate getters and setters skeleton for a non-mapping member in the storage struct.
@@ -114,18 +132,18 @@ fn handle_simple_storage_var(address: &str) -> String {
         fn address() -> starknet::StorageBaseAddress {{
             starknet::storage_base_address_const::<{address}>()
         }}
-        fn read() -> $type_name$ {{
+        fn read() -> $value_type$ {{
             // Only address_domain 0 is currently supported.
             let address_domain = 0_u32;
-            starknet::StorageAccess::<$type_name$>::read(
+            starknet::StorageAccess::<$value_type$>::read(
                 address_domain,
                 address(),
             ).unwrap_syscall()
         }}
-        fn write(value: $type_name$) {{
+        fn write(value: $value_type$) {{
             // Only address_domain 0 is currently supported.
             let address_domain = 0_u32;
-            starknet::StorageAccess::<$type_name$>::write(
+            starknet::StorageAccess::<$value_type$>::write(
                 address_domain,
                 address(),
                 value,
@@ -135,32 +153,44 @@ fn handle_simple_storage_var(address: &str) -> String {
     )
 }
 
-/// Generate getters and setters skeleton for a non-mapping member in the storage struct.
-fn handle_legacy_mapping_storage_var(address: &str) -> String {
+/// Generate getters and setters skeleton for a mapping member in the storage struct, with keys of
+/// the given names.
+///
+/// The address of a value is derived by hashing the keys one after the other into the base address
+/// of the variable using `LegacyHash`. Hence, a value of `LegacyMap::<K0, LegacyMap::<K1, V>>` is
+/// stored at the same address as the value of `LegacyMap::<(K0, K1), V>` keyed by the tuple of the
+/// same keys.
+fn handle_mapping_storage_var(address: &str, key_names: &[String]) -> String {
+    let params = key_params(key_names);
+    let args = key_names.join(", ");
+    let hashed_address =
+        key_names.iter().enumerate().fold(address.to_string(), |state, (i, key_name)| {
+            format!("hash::LegacyHash::<$key_type_{i}$>::hash({state}, {key_name})")
+        });
     format!(
         "
     mod $storage_var_name$ {{$extra_uses$
         use starknet::SyscallResultTrait;
         use starknet::SyscallResultTraitImpl;
 
-        fn address(key: $key_type$) -> starknet::StorageBaseAddress {{
+        fn address({params}) -> starknet::StorageBaseAddress {{
             starknet::storage_base_address_from_felt252(
-                hash::LegacyHash::<$key_type$>::hash({address}, key))
+                {hashed_address})
         }}
-        fn read(key: $key_type$) -> $value_type$ {{
+        fn read({params}) -> $value_type$ {{
             // Only address_domain 0 is currently supported.
             let address_domain = 0_u32;
             starknet::StorageAccess::<$value_type$>::read(
                 address_domain,
-                address(key),
+                address({args}),
             ).unwrap_syscall()
         }}
-        fn write(key: $key_type$, value: $value_type$) {{
+        fn write({params}, value: $value_type$) {{
             // Only address_domain 0 is currently supported.
             let address_domain = 0_u32;
             starknet::StorageAccess::<$value_type$>::write(
                 address_domain,
-                address(key),
+                address({args}),
                 value,
             ).unwrap_syscall()
         }}
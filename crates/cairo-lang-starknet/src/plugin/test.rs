@@ -63,6 +63,9 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         dispatcher: "dispatcher",
         user_defined_types: "user_defined_types",
         l1_handler: "l1_handler",
+        external_impl: "external_impl",
+        component: "component",
+        events: "events",
     },
     ExpandContractTestRunner
 );
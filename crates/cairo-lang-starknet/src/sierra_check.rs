@@ -0,0 +1,66 @@
+use std::fs;
+use std::process::ExitCode;
+
+use anyhow::Context;
+use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra_to_casm::program_checks::check_program;
+use cairo_lang_starknet::allowed_libfuncs::{lookup_allowed_libfuncs_list, ListSelector};
+use cairo_lang_starknet::contract_class::ContractClass;
+use cairo_lang_starknet::felt252_serde::sierra_from_felt252s;
+use clap::Parser;
+
+/// Command line args parser.
+/// Reports all the issues found in a Sierra program.
+/// Exits with 0/1 if the program has no errors/has errors.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The file to check - either a textual Sierra program or a contract class.
+    file: String,
+    /// The allowed libfuncs list to check against (default: no check).
+    #[arg(long, conflicts_with = "allowed_libfuncs_list_file")]
+    allowed_libfuncs_list_name: Option<String>,
+    /// A file of the allowed libfuncs list to check against.
+    #[arg(long)]
+    allowed_libfuncs_list_file: Option<String>,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let args = Args::parse();
+    let allowed_libfuncs =
+        match (&args.allowed_libfuncs_list_name, &args.allowed_libfuncs_list_file) {
+            (None, None) => None,
+            _ => {
+                let list_selector = ListSelector::new(
+                    args.allowed_libfuncs_list_name,
+                    args.allowed_libfuncs_list_file,
+                )
+                .with_context(|| "Both allowed libfunc list name and file were supplied.")?;
+                Some(lookup_allowed_libfuncs_list(list_selector)?.allowed_libfuncs)
+            }
+        };
+    let content = fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read {}.", &args.file))?;
+    let program = match ProgramParser::new().parse(&content) {
+        Ok(program) => program,
+        Err(parse_error) => {
+            let contract_class: ContractClass = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse the Sierra program: {parse_error}"))?;
+            sierra_from_felt252s(&contract_class.sierra_program)
+                .with_context(|| "Failed to decode the contract class Sierra program.")?
+                .1
+        }
+    };
+
+    let issues = check_program(&program, allowed_libfuncs.as_ref());
+    let mut has_errors = false;
+    for issue in &issues {
+        if issue.is_warning() {
+            println!("warning: {issue}");
+        } else {
+            has_errors = true;
+            println!("error: {issue}");
+        }
+    }
+    Ok(if has_errors { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
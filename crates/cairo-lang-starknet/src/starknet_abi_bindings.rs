@@ -0,0 +1,60 @@
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet::bindings::{
+    generate_rust_bindings, generate_typescript_bindings, RUST_BINDINGS_RUNTIME,
+    TYPESCRIPT_BINDINGS_RUNTIME,
+};
+use cairo_lang_starknet::contract_class::ContractClass;
+use clap::{Parser, ValueEnum};
+
+/// The language of the generated bindings.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Language {
+    Rust,
+    #[value(name = "typescript")]
+    TypeScript,
+}
+
+/// Command line args parser.
+/// Generates bindings for calling a contract from the ABI in its contract class.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The contract class file, as generated by `starknet-compile`.
+    file: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+    /// The language of the generated bindings.
+    #[arg(long, value_enum)]
+    lang: Language,
+    /// A file to write the runtime used by the bindings to, as the module `cairo_serde` next to
+    /// them.
+    #[arg(long)]
+    runtime: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let contract_class: ContractClass = serde_json::from_str(
+        &fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}.", &args.file))?,
+    )
+    .with_context(|| "deserialization Failed.")?;
+    let abi = contract_class.abi.with_context(|| "The contract class has no ABI.")?;
+    let (res, runtime) = match args.lang {
+        Language::Rust => (generate_rust_bindings(&abi), RUST_BINDINGS_RUNTIME),
+        Language::TypeScript => (generate_typescript_bindings(&abi), TYPESCRIPT_BINDINGS_RUNTIME),
+    };
+    let res = res.with_context(|| "Bindings generation failed.")?;
+
+    if let Some(path) = args.runtime {
+        fs::write(path, runtime).with_context(|| "Failed to write the runtime.")?;
+    }
+
+    match args.output {
+        Some(path) => fs::write(path, res).with_context(|| "Failed to write bindings.")?,
+        None => print!("{res}"),
+    }
+    Ok(())
+}
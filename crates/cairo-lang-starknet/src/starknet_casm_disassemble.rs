@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use clap::Parser;
+use itertools::Itertools;
+
+/// Command line args parser.
+/// Disassembles the bytecode of a compiled contract class, marking the offsets of its entry points.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The compiled contract class file to disassemble.
+    file: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let casm_contract: CasmContractClass = serde_json::from_str(
+        &fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}.", &args.file))?,
+    )
+    .with_context(|| "deserialization Failed.")?;
+    let instructions = casm_contract.disassemble().with_context(|| "Disassembly failed.")?;
+
+    let entry_points = &casm_contract.entry_points_by_type;
+    let mut entry_points_by_offset = HashMap::<usize, Vec<String>>::new();
+    for (entry_point_type, entry_points) in [
+        ("EXTERNAL", &entry_points.external),
+        ("L1_HANDLER", &entry_points.l1_handler),
+        ("CONSTRUCTOR", &entry_points.constructor),
+    ] {
+        for entry_point in entry_points {
+            entry_points_by_offset.entry(entry_point.offset).or_default().push(format!(
+                "// {entry_point_type} entry point, selector: {:#x}.",
+                entry_point.selector
+            ));
+        }
+    }
+    let mut res = String::new();
+    let mut offset = 0;
+    for instruction in instructions {
+        for entry_point in entry_points_by_offset.remove(&offset).unwrap_or_default() {
+            writeln!(res, "{entry_point}")?;
+        }
+        writeln!(res, "{instruction};")?;
+        offset += instruction.body.op_size();
+    }
+    if !entry_points_by_offset.is_empty() {
+        let offsets = entry_points_by_offset.keys().sorted().join(", ");
+        anyhow::bail!("Entry points at offsets {offsets} are not at the start of an instruction.");
+    }
+
+    match args.output {
+        Some(path) => fs::write(path, res).with_context(|| "Failed to write casm.")?,
+        None => print!("{res}"),
+    }
+    Ok(())
+}
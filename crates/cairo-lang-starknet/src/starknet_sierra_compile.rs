@@ -3,6 +3,7 @@ use std::fs;
 use anyhow::Context;
 use cairo_lang_starknet::allowed_libfuncs::{validate_compatible_sierra_version, ListSelector};
 use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::class_hash::{compute_class_hash, compute_compiled_class_hash};
 use cairo_lang_starknet::contract_class::ContractClass;
 use clap::Parser;
 
@@ -24,6 +25,17 @@ struct Args {
     /// Add pythonic hints.
     #[arg(long, default_value_t = false)]
     add_pythonic_hints: bool,
+    /// A file to write a JSON report of the compilation to, with the code size and costs of each
+    /// function and entry point.
+    #[arg(long)]
+    report: Option<String>,
+    /// A file to write the class hash of the input contract class to, as a hex string.
+    #[arg(long)]
+    class_hash_output: Option<String>,
+    /// A file to write the compiled class hash of the output CASM contract class to, as a hex
+    /// string.
+    #[arg(long)]
+    compiled_class_hash_output: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -37,9 +49,34 @@ fn main() -> anyhow::Result<()> {
     )
     .with_context(|| "deserialization Failed.")?;
     validate_compatible_sierra_version(&contract_class, list_selector)?;
-    let casm_contract =
-        CasmContractClass::from_contract_class(contract_class, args.add_pythonic_hints)
+    if let Some(path) = &args.class_hash_output {
+        let class_hash = compute_class_hash(&contract_class)
+            .with_context(|| "Class hash computation failed.")?;
+        fs::write(path, format!("{class_hash:#x}"))
+            .with_context(|| "Failed to write class hash.")?;
+    }
+    let casm_contract = match &args.report {
+        Some(report_path) => {
+            let (casm_contract, report) = CasmContractClass::from_contract_class_with_report(
+                contract_class,
+                args.add_pythonic_hints,
+            )
             .with_context(|| "Compilation failed.")?;
+            let report = serde_json::to_string_pretty(&report)
+                .with_context(|| "Report serialization failed.")?;
+            fs::write(report_path, report).with_context(|| "Failed to write report.")?;
+            casm_contract
+        }
+        None => CasmContractClass::from_contract_class(contract_class, args.add_pythonic_hints)
+            .with_context(|| "Compilation failed.")?,
+    };
+
+    if let Some(path) = &args.compiled_class_hash_output {
+        let compiled_class_hash = compute_compiled_class_hash(&casm_contract)
+            .with_context(|| "Compiled class hash computation failed.")?;
+        fs::write(path, format!("{compiled_class_hash:#x}"))
+            .with_context(|| "Failed to write compiled class hash.")?;
+    }
 
     let res = serde_json::to_string_pretty(&casm_contract)
         .with_context(|| "Casm contract Serialization failed.")?;
@@ -0,0 +1,47 @@
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::Context;
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_starknet::contract_class::{compile_path, ContractClass};
+use cairo_lang_starknet::upgrade_check::check_upgrade;
+use clap::Parser;
+
+/// Command line args parser.
+/// Reports the incompatibilities of a new contract class with the old contract class it replaces:
+/// storage variable collisions, overlaps and type changes, removed or changed external entry
+/// points, and removed or changed events.
+/// Exits with 0/1 if the new class is compatible/incompatible with the old class.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The old contract class - either a contract class file or a Cairo file of the contract.
+    old: String,
+    /// The new contract class - either a contract class file or a Cairo file of the contract.
+    new: String,
+}
+
+/// Reads a contract class file, or compiles the contract if the file is a Cairo file.
+fn load_contract_class(path: &str) -> anyhow::Result<ContractClass> {
+    if path.ends_with(".cairo") {
+        return compile_path(Path::new(path), CompilerConfig::default())
+            .with_context(|| format!("Failed to compile {path}."));
+    }
+    serde_json::from_str(
+        &fs::read_to_string(path).with_context(|| format!("Failed to read {path}."))?,
+    )
+    .with_context(|| format!("Failed to deserialize {path}."))
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let args = Args::parse();
+    let old = load_contract_class(&args.old)?;
+    let new = load_contract_class(&args.new)?;
+
+    let issues = check_upgrade(&old, &new)?;
+    for issue in &issues {
+        println!("error: {issue}");
+    }
+    Ok(if issues.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
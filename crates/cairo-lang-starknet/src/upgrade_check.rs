@@ -0,0 +1,400 @@
+//! Checks of the compatibility of a contract class with the class it replaces in a deployed
+//! contract, e.g. by `replace_class_syscall`: the new class must keep the storage of the contract
+//! readable, keep serving the entry points called by other contracts and users, and keep emitting
+//! the events observed by indexers.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+use thiserror::Error;
+
+use crate::abi::{
+    Contract, Event, EventField, EventKind, EventTypeKind, Function, Item, StorageVariable,
+};
+use crate::bindings::TypeExpr;
+use crate::contract::starknet_keccak;
+use crate::contract_class::ContractClass;
+
+#[cfg(test)]
+#[path = "upgrade_check_test.rs"]
+mod test;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum UpgradeCheckError {
+    #[error("The {0} contract class has no ABI.")]
+    MissingAbi(&'static str),
+}
+
+/// An incompatibility of a new contract class with the old contract class it replaces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpgradeIssue {
+    /// A storage variable of the new class is at the address of a different storage variable of
+    /// the old class.
+    StorageAddressCollision { address: String, old_name: String, new_name: String },
+    /// A storage variable of the new class occupies some of the addresses of a different storage
+    /// variable of the old class, as one of them spans over a few consecutive addresses.
+    StorageAddressOverlap { old_name: String, new_name: String },
+    /// A storage variable has different key types or a different value type, or one of its types
+    /// has a different layout.
+    StorageTypeChanged { name: String, old_type: String, new_type: String },
+    /// An external entry point of the old class is not an entry point of the new class.
+    EntryPointRemoved { selector: BigUint, name: Option<String> },
+    /// An external entry point has different input types or a different output type, or one of
+    /// its types has a different layout.
+    EntryPointSignatureChanged { name: String, old_signature: String, new_signature: String },
+    /// An event of the old class is not an event of the new class.
+    EventRemoved { name: String },
+    /// An event has different keys, data or nested events.
+    EventChanged { name: String },
+}
+impl fmt::Display for UpgradeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeIssue::StorageAddressCollision { address, old_name, new_name } => write!(
+                f,
+                "Storage variable `{new_name}` is at address {address} of the previous storage \
+                 variable `{old_name}`."
+            ),
+            UpgradeIssue::StorageAddressOverlap { old_name, new_name } => write!(
+                f,
+                "Storage variable `{new_name}` overlaps the addresses of the previous storage \
+                 variable `{old_name}`."
+            ),
+            UpgradeIssue::StorageTypeChanged { name, old_type, new_type }
+                if old_type == new_type =>
+            {
+                write!(f, "Storage variable `{name}` changed the layout of its type `{old_type}`.")
+            }
+            UpgradeIssue::StorageTypeChanged { name, old_type, new_type } => write!(
+                f,
+                "Storage variable `{name}` changed its type from `{old_type}` to `{new_type}`."
+            ),
+            UpgradeIssue::EntryPointRemoved { selector, name: Some(name) } => {
+                write!(f, "External entry point `{name}` (selector {selector:#x}) was removed.")
+            }
+            UpgradeIssue::EntryPointRemoved { selector, name: None } => {
+                write!(f, "External entry point with selector {selector:#x} was removed.")
+            }
+            UpgradeIssue::EntryPointSignatureChanged { name, old_signature, new_signature }
+                if old_signature == new_signature =>
+            {
+                write!(f, "External entry point `{name}` changed the layout of its types.")
+            }
+            UpgradeIssue::EntryPointSignatureChanged { name, old_signature, new_signature } => {
+                write!(
+                    f,
+                    "External entry point `{name}` changed its signature from `{old_signature}` \
+                     to `{new_signature}`."
+                )
+            }
+            UpgradeIssue::EventRemoved { name } => write!(f, "Event `{name}` was removed."),
+            UpgradeIssue::EventChanged { name } => {
+                write!(f, "Event `{name}` changed its keys, data or nested events.")
+            }
+        }
+    }
+}
+
+/// Checks that the new contract class can replace the old contract class in a deployed contract,
+/// and returns the found incompatibilities.
+///
+/// The storage variables are matched by their addresses, as derived by the storage plugin from
+/// their names, and the entry points by their selectors. A value that is not in a mapping occupies
+/// consecutive addresses starting at the address of its variable, as laid out by `StorageAccess`,
+/// and must not occupy the addresses of a different variable. Types are compared by their layouts:
+/// the layout of a struct or an enum of the ABI consists of the names and layouts of its members
+/// or variants, in order.
+pub fn check_upgrade(
+    old: &ContractClass,
+    new: &ContractClass,
+) -> Result<Vec<UpgradeIssue>, UpgradeCheckError> {
+    let old = ClassInfo::new(old).ok_or(UpgradeCheckError::MissingAbi("old"))?;
+    let new = ClassInfo::new(new).ok_or(UpgradeCheckError::MissingAbi("new"))?;
+
+    let mut issues = vec![];
+    for old_var in old.storage_variables() {
+        for new_var in new.storage_variables() {
+            if new_var.address != old_var.address {
+                if new_var.name != old_var.name
+                    && ranges_overlap(old.storage_range(old_var), new.storage_range(new_var))
+                {
+                    issues.push(UpgradeIssue::StorageAddressOverlap {
+                        old_name: old_var.name.clone(),
+                        new_name: new_var.name.clone(),
+                    });
+                }
+            } else if new_var.name != old_var.name {
+                issues.push(UpgradeIssue::StorageAddressCollision {
+                    address: old_var.address.clone(),
+                    old_name: old_var.name.clone(),
+                    new_name: new_var.name.clone(),
+                });
+            } else if old.storage_layout(old_var) != new.storage_layout(new_var) {
+                issues.push(UpgradeIssue::StorageTypeChanged {
+                    name: old_var.name.clone(),
+                    old_type: storage_type(old_var),
+                    new_type: storage_type(new_var),
+                });
+            }
+        }
+    }
+
+    let new_selectors: HashSet<_> =
+        new.contract_class.entry_points_by_type.external.iter().map(|e| &e.selector).collect();
+    for entry_point in &old.contract_class.entry_points_by_type.external {
+        let selector = &entry_point.selector;
+        let old_function = old.functions.get(selector);
+        if !new_selectors.contains(selector) {
+            issues.push(UpgradeIssue::EntryPointRemoved {
+                selector: selector.clone(),
+                name: old_function.map(|function| function.name.clone()),
+            });
+            continue;
+        }
+        let (Some(old_function), Some(new_function)) = (old_function, new.functions.get(selector))
+        else {
+            continue;
+        };
+        if old.signature_layout(old_function) != new.signature_layout(new_function) {
+            issues.push(UpgradeIssue::EntryPointSignatureChanged {
+                name: old_function.name.clone(),
+                old_signature: signature(old_function),
+                new_signature: signature(new_function),
+            });
+        }
+    }
+
+    for old_event in old.events() {
+        match new.events().find(|event| event.name == old_event.name) {
+            None => issues.push(UpgradeIssue::EventRemoved { name: old_event.name.clone() }),
+            Some(new_event) if old.event_layout(old_event) != new.event_layout(new_event) => {
+                issues.push(UpgradeIssue::EventChanged { name: old_event.name.clone() })
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(issues)
+}
+
+/// A contract class along with the items of its ABI, by which it is compared to another class.
+struct ClassInfo<'a> {
+    contract_class: &'a ContractClass,
+    abi: &'a Contract,
+    /// The structs and enums of the ABI, by their names.
+    types: HashMap<&'a str, &'a Item>,
+    /// The functions of the ABI, including those of interfaces, by their selectors.
+    functions: HashMap<BigUint, &'a Function>,
+}
+impl<'a> ClassInfo<'a> {
+    fn new(contract_class: &'a ContractClass) -> Option<Self> {
+        let abi = contract_class.abi.as_ref()?;
+        let mut types = HashMap::new();
+        let mut functions = HashMap::new();
+        for item in &abi.items {
+            match item {
+                Item::Struct(s) => {
+                    types.insert(s.name.as_str(), item);
+                }
+                Item::Enum(e) => {
+                    types.insert(e.name.as_str(), item);
+                }
+                Item::Function(function) => {
+                    functions.insert(starknet_keccak(function.name.as_bytes()), function);
+                }
+                Item::Interface(interface) => {
+                    for item in &interface.items {
+                        let Item::Function(function) = item else { continue; };
+                        functions.insert(starknet_keccak(function.name.as_bytes()), function);
+                    }
+                }
+                Item::Event(_) | Item::Storage(_) | Item::Impl(_) => {}
+            }
+        }
+        Some(Self { contract_class, abi, types, functions })
+    }
+
+    fn storage_variables(&self) -> impl Iterator<Item = &'a StorageVariable> {
+        self.abi.items.iter().filter_map(|item| match item {
+            Item::Storage(var) => Some(var),
+            _ => None,
+        })
+    }
+
+    fn events(&self) -> impl Iterator<Item = &'a Event> {
+        self.abi.items.iter().filter_map(|item| match item {
+            Item::Event(event) => Some(event),
+            _ => None,
+        })
+    }
+
+    /// Returns the layouts of the key types and the value type of a storage variable.
+    fn storage_layout(&self, var: &StorageVariable) -> Vec<String> {
+        var.keys.iter().chain([&var.value]).map(|ty| self.layout(ty, &mut vec![])).collect()
+    }
+
+    /// Returns the range of addresses occupied by the value of a storage variable, or None if the
+    /// variable is a mapping, whose values are at addresses hashed from its keys.
+    fn storage_range(&self, var: &StorageVariable) -> Option<(BigUint, BigUint)> {
+        if !var.keys.is_empty() {
+            return None;
+        }
+        let start = BigUint::from_str_radix(var.address.strip_prefix("0x")?, 16).ok()?;
+        let end = &start + self.storage_size(&var.value, &mut vec![]);
+        Some((start, end))
+    }
+
+    /// Returns the number of consecutive storage addresses occupied by a value of a type, as laid
+    /// out by `StorageAccess`: the members of a struct one after the other, and the index of the
+    /// variant of an enum followed by its largest variant. Other types occupy a single address.
+    fn storage_size(&self, ty: &str, visited: &mut Vec<String>) -> usize {
+        match TypeExpr::parse(ty) {
+            Ok(expr) => self.expr_storage_size(&expr, visited),
+            Err(_) => 1,
+        }
+    }
+
+    fn expr_storage_size(&self, expr: &TypeExpr, visited: &mut Vec<String>) -> usize {
+        let name = expr.format();
+        let item = self.types.get(name.as_str());
+        if item.is_some() && visited.contains(&name) {
+            // A recursive type has no `StorageAccess`.
+            return 1;
+        }
+        visited.push(name);
+        let size = match (item, expr) {
+            (Some(Item::Struct(s)), _) => {
+                s.members.iter().map(|m| self.storage_size(&m.ty, visited)).sum()
+            }
+            (Some(Item::Enum(e)), _) => {
+                1 + e.variants.iter().map(|v| self.storage_size(&v.ty, visited)).max().unwrap_or(0)
+            }
+            (_, TypeExpr::Tuple(types)) => {
+                types.iter().map(|ty| self.expr_storage_size(ty, visited)).sum()
+            }
+            _ => 1,
+        };
+        visited.pop();
+        size
+    }
+
+    /// Returns the layouts of the input types and the output types of a function.
+    fn signature_layout(&self, function: &Function) -> Vec<String> {
+        let inputs = function.inputs.iter().map(|input| &input.ty);
+        let outputs = function.outputs.iter().map(|output| &output.ty);
+        let mut layout: Vec<_> = inputs.map(|ty| self.layout(ty, &mut vec![])).collect();
+        layout.push("->".into());
+        layout.extend(outputs.map(|ty| self.layout(ty, &mut vec![])));
+        layout
+    }
+
+    /// Returns the kind of an event and the names, kinds and layouts of its fields.
+    fn event_layout(&self, event: &Event) -> String {
+        let (kind, fields) = match &event.kind {
+            EventKind::Function { inputs } => (
+                "function",
+                inputs
+                    .iter()
+                    .map(|input| format!("{}: {}", input.name, self.layout(&input.ty, &mut vec![])))
+                    .collect::<Vec<_>>(),
+            ),
+            EventKind::Type(EventTypeKind::Struct { members }) => {
+                ("struct", self.fields_layout(members))
+            }
+            EventKind::Type(EventTypeKind::Enum { variants }) => {
+                ("enum", self.fields_layout(variants))
+            }
+        };
+        let fields = fields.join(", ");
+        format!("{kind} {{ {fields} }}")
+    }
+
+    /// Returns the names, layouts and kinds of the fields of an event type.
+    fn fields_layout(&self, fields: &[EventField]) -> Vec<String> {
+        fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}: {} ({:?})",
+                    field.name,
+                    self.layout(&field.ty, &mut vec![]),
+                    field.kind
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the layout of a type of the ABI, in which the structs and enums of the ABI are
+    /// replaced by their members or variants.
+    /// `visited` holds the structs and enums being expanded, which are left as names when they
+    /// recursively contain themselves.
+    fn layout(&self, ty: &str, visited: &mut Vec<String>) -> String {
+        match TypeExpr::parse(ty) {
+            Ok(expr) => self.expr_layout(&expr, visited),
+            Err(_) => ty.to_string(),
+        }
+    }
+
+    fn expr_layout(&self, expr: &TypeExpr, visited: &mut Vec<String>) -> String {
+        let name = expr.format();
+        let fields: Vec<_> = match self.types.get(name.as_str()) {
+            Some(_) if visited.contains(&name) => return name,
+            Some(Item::Struct(s)) => s.members.iter().map(|m| (&m.name, &m.ty)).collect(),
+            Some(Item::Enum(e)) => e.variants.iter().map(|v| (&v.name, &v.ty)).collect(),
+            _ => {
+                return match expr {
+                    TypeExpr::Path { args, .. } if args.is_empty() => name,
+                    TypeExpr::Path { path, args } => {
+                        format!("{path}::<{}>", self.list_layout(args, visited))
+                    }
+                    TypeExpr::Tuple(types) => format!("({})", self.list_layout(types, visited)),
+                    TypeExpr::Snapshot(ty) => format!("@{}", self.expr_layout(ty, visited)),
+                };
+            }
+        };
+        visited.push(name.clone());
+        let fields = fields
+            .into_iter()
+            .map(|(field, ty)| format!("{field}: {}", self.layout(ty, visited)))
+            .collect::<Vec<_>>();
+        visited.pop();
+        format!("{name} {{ {} }}", fields.join(", "))
+    }
+
+    fn list_layout(&self, types: &[TypeExpr], visited: &mut Vec<String>) -> String {
+        types.iter().map(|ty| self.expr_layout(ty, visited)).collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// Returns whether two ranges of storage addresses have a common address.
+fn ranges_overlap(
+    range: Option<(BigUint, BigUint)>,
+    other_range: Option<(BigUint, BigUint)>,
+) -> bool {
+    let (Some((start, end)), Some((other_start, other_end))) = (range, other_range) else {
+        return false;
+    };
+    start < other_end && other_start < end
+}
+
+/// Returns the type of a storage variable: its value type, preceded by its key types if it is a
+/// mapping.
+fn storage_type(var: &StorageVariable) -> String {
+    if var.keys.is_empty() {
+        var.value.clone()
+    } else {
+        format!("({}) -> {}", var.keys.join(", "), var.value)
+    }
+}
+
+/// Returns the signature of a function: its input types followed by its output types.
+fn signature(function: &Function) -> String {
+    let inputs = function.inputs.iter().map(|input| input.ty.as_str()).collect::<Vec<_>>();
+    let outputs = function.outputs.iter().map(|output| output.ty.as_str()).collect::<Vec<_>>();
+    match &outputs[..] {
+        [] => format!("({})", inputs.join(", ")),
+        _ => format!("({}) -> {}", inputs.join(", "), outputs.join(", ")),
+    }
+}
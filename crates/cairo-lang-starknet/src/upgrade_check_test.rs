@@ -0,0 +1,152 @@
+use indoc::indoc;
+use num_bigint::BigUint;
+use num_traits::Num;
+use pretty_assertions::assert_eq;
+
+use super::{check_upgrade, UpgradeCheckError, UpgradeIssue};
+use crate::abi::{Item, StorageVariable, Struct, StructMember};
+use crate::contract_class::ContractClass;
+use crate::test_utils::get_test_contract;
+
+#[test]
+fn test_check_upgrade_of_same_class() {
+    let contract_class = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    assert_eq!(check_upgrade(&contract_class, &contract_class), Ok(vec![]));
+}
+
+#[test]
+fn test_check_upgrade() {
+    let old = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    let new = get_test_contract("upgrade_check/new/upgradable_contract.cairo");
+    let issues = check_upgrade(&old, &new).unwrap();
+    assert_eq!(
+        issues.iter().map(|issue| format!("{issue}\n")).collect::<String>(),
+        indoc! {"
+            Storage variable `balances` changed its type from \
+             `(core::starknet::contract_address::ContractAddress) -> core::integer::u128` to \
+             `(core::starknet::contract_address::ContractAddress) -> core::integer::u256`.
+            Storage variable `positions` changed the layout of its type \
+             `(core::starknet::contract_address::ContractAddress) -> \
+             upgradable_contract::upgradable_contract::UpgradableContract::Position`.
+            External entry point `increase_counter` (selector \
+             0x245f9bea6574169db91599999bf914dd43aebc1e0544bdc96c9f401a52b8768) was removed.
+            External entry point `move_to` changed its signature from \
+             `(core::starknet::contract_address::ContractAddress, \
+             upgradable_contract::upgradable_contract::UpgradableContract::Position)` to \
+             `(upgradable_contract::upgradable_contract::UpgradableContract::Position)`.
+            External entry point `get_balance` changed its signature from \
+             `(core::starknet::contract_address::ContractAddress) -> core::integer::u128` to \
+             `(core::starknet::contract_address::ContractAddress) -> core::integer::u256`.
+            External entry point `get_position` changed the layout of its types.
+            Event `upgradable_contract::upgradable_contract::UpgradableContract::Moved` changed \
+             its keys, data or nested events.
+            Event `upgradable_contract::upgradable_contract::UpgradableContract::Counted` was \
+             removed.
+            Event `upgradable_contract::upgradable_contract::UpgradableContract::Event` changed \
+             its keys, data or nested events.
+        "}
+    );
+}
+
+#[test]
+fn test_check_upgrade_storage_address_collision() {
+    let old = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    let mut new = old.clone();
+    for item in &mut new.abi.as_mut().unwrap().items {
+        let Item::Storage(var) = item else { continue; };
+        if var.name == "counter" {
+            var.name = "renamed_counter".into();
+        }
+    }
+    let issues = check_upgrade(&old, &new).unwrap();
+    let [UpgradeIssue::StorageAddressCollision { old_name, new_name, .. }] = &issues[..] else {
+        panic!("Unexpected issues: {issues:?}");
+    };
+    assert_eq!((old_name.as_str(), new_name.as_str()), ("counter", "renamed_counter"));
+}
+
+#[test]
+fn test_check_upgrade_storage_address_overlap() {
+    let mut old = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    let mut new = old.clone();
+    // Places `owner` right after `counter`, into which a `Position` of the new class spills.
+    for (contract_class, counter_type) in [
+        (&mut old, "core::felt252"),
+        (&mut new, "upgradable_contract::upgradable_contract::UpgradableContract::Position"),
+    ] {
+        let items = &mut contract_class.abi.as_mut().unwrap().items;
+        let counter_address = items
+            .iter()
+            .find_map(|item| match item {
+                Item::Storage(var) if var.name == "counter" => Some(var.address.clone()),
+                _ => None,
+            })
+            .unwrap();
+        let counter_address =
+            BigUint::from_str_radix(counter_address.strip_prefix("0x").unwrap(), 16).unwrap();
+        for item in items {
+            let Item::Storage(var) = item else { continue; };
+            match var.name.as_str() {
+                "owner" => var.address = format!("{:#x}", &counter_address + 1u32),
+                "counter" => var.value = counter_type.into(),
+                _ => {}
+            }
+        }
+    }
+    let issues = check_upgrade(&old, &new).unwrap();
+    assert_eq!(
+        issues.iter().map(|issue| format!("{issue}\n")).collect::<String>(),
+        indoc! {"
+            Storage variable `counter` overlaps the addresses of the previous storage variable \
+             `owner`.
+            Storage variable `counter` changed its type from `core::felt252` to \
+             `upgradable_contract::upgradable_contract::UpgradableContract::Position`.
+        "}
+    );
+}
+
+/// Adds a storage variable `nodes` of the recursive type `Node` to a contract class, and returns
+/// the members of `Node`.
+fn add_recursive_storage_variable(contract_class: &mut ContractClass) -> &mut Vec<StructMember> {
+    let items = &mut contract_class.abi.as_mut().unwrap().items;
+    items.push(Item::Storage(StorageVariable {
+        name: "nodes".into(),
+        address: "0x1234".into(),
+        keys: vec![],
+        value: "test::Node".into(),
+    }));
+    items.push(Item::Struct(Struct {
+        name: "test::Node".into(),
+        members: vec![
+            StructMember { name: "value".into(), ty: "core::felt252".into() },
+            StructMember { name: "children".into(), ty: "core::array::Array::<test::Node>".into() },
+        ],
+    }));
+    let Some(Item::Struct(node)) = items.last_mut() else { unreachable!() };
+    &mut node.members
+}
+
+#[test]
+fn test_check_upgrade_of_recursive_type() {
+    let contract_class = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    let mut old = contract_class.clone();
+    add_recursive_storage_variable(&mut old);
+    assert_eq!(check_upgrade(&old, &old), Ok(vec![]));
+
+    let mut new = contract_class;
+    let node_members = add_recursive_storage_variable(&mut new);
+    node_members.push(StructMember { name: "parent".into(), ty: "test::Node".into() });
+    let issues = check_upgrade(&old, &new).unwrap();
+    assert_eq!(
+        issues.iter().map(|issue| issue.to_string()).collect::<Vec<_>>(),
+        vec!["Storage variable `nodes` changed the layout of its type `test::Node`."]
+    );
+}
+
+#[test]
+fn test_check_upgrade_without_abi() {
+    let old = get_test_contract("upgrade_check/old/upgradable_contract.cairo");
+    let mut new = old.clone();
+    new.abi = None;
+    assert_eq!(check_upgrade(&old, &new), Err(UpgradeCheckError::MissingAbi("new")));
+}
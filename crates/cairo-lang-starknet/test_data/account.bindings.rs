@@ -0,0 +1,105 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The `account::account::Call` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    pub to: Felt252,
+    pub selector: Felt252,
+    pub calldata: Vec<Felt252>,
+}
+impl CairoSerde for Call {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.to.serialize(output);
+        self.selector.serialize(output);
+        self.calldata.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            to: CairoSerde::deserialize(input)?,
+            selector: CairoSerde::deserialize(input)?,
+            calldata: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The selector of the `constructor` entry point.
+pub const CONSTRUCTOR_SELECTOR: &str = "0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194";
+
+/// Encodes the calldata of a call to the `constructor` entry point.
+pub fn encode_constructor_calldata(public_key_: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    public_key_.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `constructor` entry point.
+pub fn decode_constructor_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `__validate_deploy__` entry point.
+pub const VALIDATE_DEPLOY_SELECTOR: &str = "0x36fcbf06cd96843058359e1a75928beacfac10727dab22a3972f0af8aa92895";
+
+/// Encodes the calldata of a call to the `__validate_deploy__` entry point.
+pub fn encode_validate_deploy_calldata(class_hash: &Felt252, contract_address_salt: &Felt252, public_key_: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    class_hash.serialize(&mut calldata);
+    contract_address_salt.serialize(&mut calldata);
+    public_key_.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `__validate_deploy__` entry point.
+pub fn decode_validate_deploy_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `__validate_declare__` entry point.
+pub const VALIDATE_DECLARE_SELECTOR: &str = "0x289da278a8dc833409cabfdad1581e8e7d40e42dcaed693fa4008dcdb4963b3";
+
+/// Encodes the calldata of a call to the `__validate_declare__` entry point.
+pub fn encode_validate_declare_calldata(class_hash: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    class_hash.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `__validate_declare__` entry point.
+pub fn decode_validate_declare_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `__validate__` entry point.
+pub const VALIDATE_SELECTOR: &str = "0x162da33a4585851fe8d3af3c2a9c60b557814e221e0d4f30ff0b2189d9c7775";
+
+/// Encodes the calldata of a call to the `__validate__` entry point.
+pub fn encode_validate_calldata(contract_address: &Felt252, entry_point_selector: &Felt252, calldata_: &Vec<Felt252>) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    contract_address.serialize(&mut calldata);
+    entry_point_selector.serialize(&mut calldata);
+    calldata_.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `__validate__` entry point.
+pub fn decode_validate_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `__execute__` entry point.
+pub const EXECUTE_SELECTOR: &str = "0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad";
+
+/// Encodes the calldata of a call to the `__execute__` entry point.
+pub fn encode_execute_calldata(calls: &Vec<Call>) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    calls.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `__execute__` entry point.
+pub fn decode_execute_result(result: &[Felt252]) -> Result<Vec<Felt252>, DecodeError> {
+    decode(result)
+}
@@ -0,0 +1,112 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  Reader,
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  deserializeUnit,
+  serializeArray,
+  deserializeArray,
+} from "./cairo_serde";
+
+/** The `account::account::Call` struct. */
+export interface Call {
+  to: bigint;
+  selector: bigint;
+  calldata: bigint[];
+}
+
+export function serializeCall(value: Call, output: bigint[]): void {
+  serializeFelt252(value.to, output);
+  serializeFelt252(value.selector, output);
+  serializeArray(serializeFelt252)(value.calldata, output);
+}
+
+export function deserializeCall(input: Reader): Call {
+  return {
+    to: deserializeFelt252(input),
+    selector: deserializeFelt252(input),
+    calldata: deserializeArray(deserializeFelt252)(input),
+  };
+}
+
+/** The selector of the `constructor` entry point. */
+export const CONSTRUCTOR_SELECTOR = 0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194n;
+
+/** Encodes the calldata of a call to the `constructor` entry point. */
+export function encodeConstructorCalldata(public_key_: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(public_key_, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `constructor` entry point. */
+export function decodeConstructorResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `__validate_deploy__` entry point. */
+export const VALIDATE_DEPLOY_SELECTOR = 0x36fcbf06cd96843058359e1a75928beacfac10727dab22a3972f0af8aa92895n;
+
+/** Encodes the calldata of a call to the `__validate_deploy__` entry point. */
+export function encodeValidateDeployCalldata(class_hash: bigint, contract_address_salt: bigint, public_key_: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(class_hash, calldata);
+  serializeFelt252(contract_address_salt, calldata);
+  serializeFelt252(public_key_, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `__validate_deploy__` entry point. */
+export function decodeValidateDeployResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `__validate_declare__` entry point. */
+export const VALIDATE_DECLARE_SELECTOR = 0x289da278a8dc833409cabfdad1581e8e7d40e42dcaed693fa4008dcdb4963b3n;
+
+/** Encodes the calldata of a call to the `__validate_declare__` entry point. */
+export function encodeValidateDeclareCalldata(class_hash: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(class_hash, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `__validate_declare__` entry point. */
+export function decodeValidateDeclareResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `__validate__` entry point. */
+export const VALIDATE_SELECTOR = 0x162da33a4585851fe8d3af3c2a9c60b557814e221e0d4f30ff0b2189d9c7775n;
+
+/** Encodes the calldata of a call to the `__validate__` entry point. */
+export function encodeValidateCalldata(contract_address: bigint, entry_point_selector: bigint, calldata_: bigint[]): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(contract_address, calldata);
+  serializeFelt252(entry_point_selector, calldata);
+  serializeArray(serializeFelt252)(calldata_, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `__validate__` entry point. */
+export function decodeValidateResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `__execute__` entry point. */
+export const EXECUTE_SELECTOR = 0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5adn;
+
+/** Encodes the calldata of a call to the `__execute__` entry point. */
+export function encodeExecuteCalldata(calls: Call[]): bigint[] {
+  const calldata: bigint[] = [];
+  serializeArray(serializeCall)(calls, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `__execute__` entry point. */
+export function decodeExecuteResult(result: bigint[]): bigint[] {
+  return decode(result, deserializeArray(deserializeFelt252));
+}
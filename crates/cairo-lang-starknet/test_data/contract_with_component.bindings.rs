@@ -0,0 +1,73 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The selector of the `constructor` entry point.
+pub const CONSTRUCTOR_SELECTOR: &str = "0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194";
+
+/// Encodes the calldata of a call to the `constructor` entry point.
+pub fn encode_constructor_calldata(owner: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    owner.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `constructor` entry point.
+pub fn decode_constructor_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `increase_counter` entry point.
+pub const INCREASE_COUNTER_SELECTOR: &str = "0x245f9bea6574169db91599999bf914dd43aebc1e0544bdc96c9f401a52b8768";
+
+/// Encodes the calldata of a call to the `increase_counter` entry point.
+pub fn encode_increase_counter_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `increase_counter` entry point.
+pub fn decode_increase_counter_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_counter` entry point.
+pub const GET_COUNTER_SELECTOR: &str = "0x3370263ab53343580e77063a719a5865004caff7f367ec136a6cdd34b6786ca";
+
+/// Encodes the calldata of a call to the `get_counter` entry point.
+pub fn encode_get_counter_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_counter` entry point.
+pub fn decode_get_counter_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_owner` entry point.
+pub const GET_OWNER_SELECTOR: &str = "0x3ee0bfaf5b124501fef19bbd1312e71f6966d186c42eeb91d1bff729b91d1d4";
+
+/// Encodes the calldata of a call to the `get_owner` entry point.
+pub fn encode_get_owner_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_owner` entry point.
+pub fn decode_get_owner_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `transfer_ownership` entry point.
+pub const TRANSFER_OWNERSHIP_SELECTOR: &str = "0x2a3bb1eaa05b77c4b0eeee0116a3177c6d62319dd7149ae148185d9e09de74a";
+
+/// Encodes the calldata of a call to the `transfer_ownership` entry point.
+pub fn encode_transfer_ownership_calldata(new_owner: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    new_owner.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `transfer_ownership` entry point.
+pub fn decode_transfer_ownership_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
@@ -0,0 +1,78 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  deserializeUnit,
+} from "./cairo_serde";
+
+/** The selector of the `constructor` entry point. */
+export const CONSTRUCTOR_SELECTOR = 0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194n;
+
+/** Encodes the calldata of a call to the `constructor` entry point. */
+export function encodeConstructorCalldata(owner: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(owner, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `constructor` entry point. */
+export function decodeConstructorResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `increase_counter` entry point. */
+export const INCREASE_COUNTER_SELECTOR = 0x245f9bea6574169db91599999bf914dd43aebc1e0544bdc96c9f401a52b8768n;
+
+/** Encodes the calldata of a call to the `increase_counter` entry point. */
+export function encodeIncreaseCounterCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `increase_counter` entry point. */
+export function decodeIncreaseCounterResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `get_counter` entry point. */
+export const GET_COUNTER_SELECTOR = 0x3370263ab53343580e77063a719a5865004caff7f367ec136a6cdd34b6786can;
+
+/** Encodes the calldata of a call to the `get_counter` entry point. */
+export function encodeGetCounterCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_counter` entry point. */
+export function decodeGetCounterResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `get_owner` entry point. */
+export const GET_OWNER_SELECTOR = 0x3ee0bfaf5b124501fef19bbd1312e71f6966d186c42eeb91d1bff729b91d1d4n;
+
+/** Encodes the calldata of a call to the `get_owner` entry point. */
+export function encodeGetOwnerCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_owner` entry point. */
+export function decodeGetOwnerResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `transfer_ownership` entry point. */
+export const TRANSFER_OWNERSHIP_SELECTOR = 0x2a3bb1eaa05b77c4b0eeee0116a3177c6d62319dd7149ae148185d9e09de74an;
+
+/** Encodes the calldata of a call to the `transfer_ownership` entry point. */
+export function encodeTransferOwnershipCalldata(new_owner: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(new_owner, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `transfer_ownership` entry point. */
+export function decodeTransferOwnershipResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
@@ -0,0 +1,69 @@
+#[component]
+mod ownable {
+    use starknet::ContractAddress;
+    use starknet::get_caller_address;
+
+    struct Storage {
+        owner: ContractAddress, 
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        OwnershipTransferred: OwnershipTransferred, 
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct OwnershipTransferred {
+        previous_owner: ContractAddress,
+        new_owner: ContractAddress,
+    }
+
+    #[view]
+    fn get_owner() -> ContractAddress {
+        owner::read()
+    }
+
+    #[external]
+    fn transfer_ownership(new_owner: ContractAddress) {
+        assert_only_owner();
+        initialize(new_owner);
+    }
+
+    fn initialize(new_owner: ContractAddress) {
+        let previous_owner = owner::read();
+        owner::write(new_owner);
+        emit(Event::OwnershipTransferred(OwnershipTransferred { previous_owner, new_owner }));
+    }
+
+    fn assert_only_owner() {
+        assert(get_caller_address() == owner::read(), 'Caller is not the owner');
+    }
+}
+
+#[contract]
+mod OwnedCounter {
+    use starknet::ContractAddress;
+    #[component]
+    use super::ownable;
+
+    struct Storage {
+        counter: felt252, 
+    }
+
+    #[constructor]
+    fn constructor(owner: ContractAddress) {
+        ownable::initialize(owner);
+    }
+
+    #[external]
+    fn increase_counter() {
+        ownable::assert_only_owner();
+        counter::write(counter::read() + 1);
+    }
+
+    #[view]
+    fn get_counter() -> felt252 {
+        counter::read()
+    }
+}
@@ -0,0 +1,24 @@
+#[component]
+mod counter {
+    struct Storage {
+        value: felt252,
+    }
+
+    #[view]
+    fn get_value() -> felt252 {
+        value::read()
+    }
+}
+
+#[contract]
+mod CounterWrapper {
+    #[component]
+    use super::counter;
+
+    struct Storage {}
+
+    #[view]
+    fn get_value() -> felt252 {
+        0
+    }
+}
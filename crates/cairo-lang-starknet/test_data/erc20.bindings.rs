@@ -0,0 +1,206 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The `core::integer::u256` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct U256 {
+    pub low: u128,
+    pub high: u128,
+}
+impl CairoSerde for U256 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.low.serialize(output);
+        self.high.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            low: CairoSerde::deserialize(input)?,
+            high: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The selector of the `constructor` entry point.
+pub const CONSTRUCTOR_SELECTOR: &str = "0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194";
+
+/// Encodes the calldata of a call to the `constructor` entry point.
+pub fn encode_constructor_calldata(name_: &Felt252, symbol_: &Felt252, decimals_: &u8, initial_supply: &U256, recipient: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    name_.serialize(&mut calldata);
+    symbol_.serialize(&mut calldata);
+    decimals_.serialize(&mut calldata);
+    initial_supply.serialize(&mut calldata);
+    recipient.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `constructor` entry point.
+pub fn decode_constructor_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_name` entry point.
+pub const GET_NAME_SELECTOR: &str = "0x31341177714d81ad9ccd0c903211bc056a60e8af988d0fd918cc43874549653";
+
+/// Encodes the calldata of a call to the `get_name` entry point.
+pub fn encode_get_name_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_name` entry point.
+pub fn decode_get_name_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_symbol` entry point.
+pub const GET_SYMBOL_SELECTOR: &str = "0x351ccc9e7b13b17e701a7d4f5f85b525bac37b7648419fe194e6c15bc73da47";
+
+/// Encodes the calldata of a call to the `get_symbol` entry point.
+pub fn encode_get_symbol_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_symbol` entry point.
+pub fn decode_get_symbol_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_decimals` entry point.
+pub const GET_DECIMALS_SELECTOR: &str = "0x16d9d5d83f8eecc5d7450519aad7e6e649be1a6c9d6df85bd0b177cc59a926a";
+
+/// Encodes the calldata of a call to the `get_decimals` entry point.
+pub fn encode_get_decimals_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_decimals` entry point.
+pub fn decode_get_decimals_result(result: &[Felt252]) -> Result<u8, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_total_supply` entry point.
+pub const GET_TOTAL_SUPPLY_SELECTOR: &str = "0x2819e8b2b82ee4c56798709651ab9e8537f644c0823e42ba017efce4f2077e4";
+
+/// Encodes the calldata of a call to the `get_total_supply` entry point.
+pub fn encode_get_total_supply_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_total_supply` entry point.
+pub fn decode_get_total_supply_result(result: &[Felt252]) -> Result<U256, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `balance_of` entry point.
+pub const BALANCE_OF_SELECTOR: &str = "0x35a73cd311a05d46deda634c5ee045db92f811b4e74bca4437fcb5302b7af33";
+
+/// Encodes the calldata of a call to the `balance_of` entry point.
+pub fn encode_balance_of_calldata(account: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    account.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `balance_of` entry point.
+pub fn decode_balance_of_result(result: &[Felt252]) -> Result<U256, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `allowance` entry point.
+pub const ALLOWANCE_SELECTOR: &str = "0x1e888a1026b19c8c0b57c72d63ed1737106aa10034105b980ba117bd0c29fe1";
+
+/// Encodes the calldata of a call to the `allowance` entry point.
+pub fn encode_allowance_calldata(owner: &Felt252, spender: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    owner.serialize(&mut calldata);
+    spender.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `allowance` entry point.
+pub fn decode_allowance_result(result: &[Felt252]) -> Result<U256, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `transfer` entry point.
+pub const TRANSFER_SELECTOR: &str = "0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e";
+
+/// Encodes the calldata of a call to the `transfer` entry point.
+pub fn encode_transfer_calldata(recipient: &Felt252, amount: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    recipient.serialize(&mut calldata);
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `transfer` entry point.
+pub fn decode_transfer_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `transfer_from` entry point.
+pub const TRANSFER_FROM_SELECTOR: &str = "0x3704ffe8fba161be0e994951751a5033b1462b918ff785c0a636be718dfdb68";
+
+/// Encodes the calldata of a call to the `transfer_from` entry point.
+pub fn encode_transfer_from_calldata(sender: &Felt252, recipient: &Felt252, amount: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    sender.serialize(&mut calldata);
+    recipient.serialize(&mut calldata);
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `transfer_from` entry point.
+pub fn decode_transfer_from_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `approve` entry point.
+pub const APPROVE_SELECTOR: &str = "0x219209e083275171774dab1df80982e9df2096516f06319c5c6d71ae0a8480c";
+
+/// Encodes the calldata of a call to the `approve` entry point.
+pub fn encode_approve_calldata(spender: &Felt252, amount: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    spender.serialize(&mut calldata);
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `approve` entry point.
+pub fn decode_approve_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `increase_allowance` entry point.
+pub const INCREASE_ALLOWANCE_SELECTOR: &str = "0x1d13ab0a76d7407b1d5faccd4b3d8a9efe42f3d3c21766431d4fafb30f45bd4";
+
+/// Encodes the calldata of a call to the `increase_allowance` entry point.
+pub fn encode_increase_allowance_calldata(spender: &Felt252, added_value: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    spender.serialize(&mut calldata);
+    added_value.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `increase_allowance` entry point.
+pub fn decode_increase_allowance_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `decrease_allowance` entry point.
+pub const DECREASE_ALLOWANCE_SELECTOR: &str = "0x3b076186c19fe96221e4dfacd40c519f612eae02e0555e4e115a2a6cf2f1c1f";
+
+/// Encodes the calldata of a call to the `decrease_allowance` entry point.
+pub fn encode_decrease_allowance_calldata(spender: &Felt252, subtracted_value: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    spender.serialize(&mut calldata);
+    subtracted_value.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `decrease_allowance` entry point.
+pub fn decode_decrease_allowance_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
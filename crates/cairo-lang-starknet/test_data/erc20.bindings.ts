@@ -0,0 +1,215 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  Reader,
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  serializeU8,
+  serializeU128,
+  deserializeU8,
+  deserializeU128,
+  deserializeUnit,
+} from "./cairo_serde";
+
+/** The `core::integer::u256` struct. */
+export interface U256 {
+  low: bigint;
+  high: bigint;
+}
+
+export function serializeU256(value: U256, output: bigint[]): void {
+  serializeU128(value.low, output);
+  serializeU128(value.high, output);
+}
+
+export function deserializeU256(input: Reader): U256 {
+  return {
+    low: deserializeU128(input),
+    high: deserializeU128(input),
+  };
+}
+
+/** The selector of the `constructor` entry point. */
+export const CONSTRUCTOR_SELECTOR = 0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194n;
+
+/** Encodes the calldata of a call to the `constructor` entry point. */
+export function encodeConstructorCalldata(name_: bigint, symbol_: bigint, decimals_: bigint, initial_supply: U256, recipient: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(name_, calldata);
+  serializeFelt252(symbol_, calldata);
+  serializeU8(decimals_, calldata);
+  serializeU256(initial_supply, calldata);
+  serializeFelt252(recipient, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `constructor` entry point. */
+export function decodeConstructorResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `get_name` entry point. */
+export const GET_NAME_SELECTOR = 0x31341177714d81ad9ccd0c903211bc056a60e8af988d0fd918cc43874549653n;
+
+/** Encodes the calldata of a call to the `get_name` entry point. */
+export function encodeGetNameCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_name` entry point. */
+export function decodeGetNameResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `get_symbol` entry point. */
+export const GET_SYMBOL_SELECTOR = 0x351ccc9e7b13b17e701a7d4f5f85b525bac37b7648419fe194e6c15bc73da47n;
+
+/** Encodes the calldata of a call to the `get_symbol` entry point. */
+export function encodeGetSymbolCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_symbol` entry point. */
+export function decodeGetSymbolResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `get_decimals` entry point. */
+export const GET_DECIMALS_SELECTOR = 0x16d9d5d83f8eecc5d7450519aad7e6e649be1a6c9d6df85bd0b177cc59a926an;
+
+/** Encodes the calldata of a call to the `get_decimals` entry point. */
+export function encodeGetDecimalsCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_decimals` entry point. */
+export function decodeGetDecimalsResult(result: bigint[]): bigint {
+  return decode(result, deserializeU8);
+}
+
+/** The selector of the `get_total_supply` entry point. */
+export const GET_TOTAL_SUPPLY_SELECTOR = 0x2819e8b2b82ee4c56798709651ab9e8537f644c0823e42ba017efce4f2077e4n;
+
+/** Encodes the calldata of a call to the `get_total_supply` entry point. */
+export function encodeGetTotalSupplyCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_total_supply` entry point. */
+export function decodeGetTotalSupplyResult(result: bigint[]): U256 {
+  return decode(result, deserializeU256);
+}
+
+/** The selector of the `balance_of` entry point. */
+export const BALANCE_OF_SELECTOR = 0x35a73cd311a05d46deda634c5ee045db92f811b4e74bca4437fcb5302b7af33n;
+
+/** Encodes the calldata of a call to the `balance_of` entry point. */
+export function encodeBalanceOfCalldata(account: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(account, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `balance_of` entry point. */
+export function decodeBalanceOfResult(result: bigint[]): U256 {
+  return decode(result, deserializeU256);
+}
+
+/** The selector of the `allowance` entry point. */
+export const ALLOWANCE_SELECTOR = 0x1e888a1026b19c8c0b57c72d63ed1737106aa10034105b980ba117bd0c29fe1n;
+
+/** Encodes the calldata of a call to the `allowance` entry point. */
+export function encodeAllowanceCalldata(owner: bigint, spender: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(owner, calldata);
+  serializeFelt252(spender, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `allowance` entry point. */
+export function decodeAllowanceResult(result: bigint[]): U256 {
+  return decode(result, deserializeU256);
+}
+
+/** The selector of the `transfer` entry point. */
+export const TRANSFER_SELECTOR = 0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12en;
+
+/** Encodes the calldata of a call to the `transfer` entry point. */
+export function encodeTransferCalldata(recipient: bigint, amount: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(recipient, calldata);
+  serializeU256(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `transfer` entry point. */
+export function decodeTransferResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `transfer_from` entry point. */
+export const TRANSFER_FROM_SELECTOR = 0x3704ffe8fba161be0e994951751a5033b1462b918ff785c0a636be718dfdb68n;
+
+/** Encodes the calldata of a call to the `transfer_from` entry point. */
+export function encodeTransferFromCalldata(sender: bigint, recipient: bigint, amount: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(sender, calldata);
+  serializeFelt252(recipient, calldata);
+  serializeU256(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `transfer_from` entry point. */
+export function decodeTransferFromResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `approve` entry point. */
+export const APPROVE_SELECTOR = 0x219209e083275171774dab1df80982e9df2096516f06319c5c6d71ae0a8480cn;
+
+/** Encodes the calldata of a call to the `approve` entry point. */
+export function encodeApproveCalldata(spender: bigint, amount: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(spender, calldata);
+  serializeU256(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `approve` entry point. */
+export function decodeApproveResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `increase_allowance` entry point. */
+export const INCREASE_ALLOWANCE_SELECTOR = 0x1d13ab0a76d7407b1d5faccd4b3d8a9efe42f3d3c21766431d4fafb30f45bd4n;
+
+/** Encodes the calldata of a call to the `increase_allowance` entry point. */
+export function encodeIncreaseAllowanceCalldata(spender: bigint, added_value: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(spender, calldata);
+  serializeU256(added_value, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `increase_allowance` entry point. */
+export function decodeIncreaseAllowanceResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `decrease_allowance` entry point. */
+export const DECREASE_ALLOWANCE_SELECTOR = 0x3b076186c19fe96221e4dfacd40c519f612eae02e0555e4e115a2a6cf2f1c1fn;
+
+/** Encodes the calldata of a call to the `decrease_allowance` entry point. */
+export function encodeDecreaseAllowanceCalldata(spender: bigint, subtracted_value: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(spender, calldata);
+  serializeU256(subtracted_value, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `decrease_allowance` entry point. */
+export function decodeDecreaseAllowanceResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
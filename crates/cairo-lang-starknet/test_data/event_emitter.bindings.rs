@@ -0,0 +1,60 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The selector of the `get_value` entry point.
+pub const GET_VALUE_SELECTOR: &str = "0x26813d396fdb198e9ead934e4f7a592a8b88a059e45ab0eb6ee53494e8d45b0";
+
+/// Encodes the calldata of a call to the `get_value` entry point.
+pub fn encode_get_value_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_value` entry point.
+pub fn decode_get_value_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `set_value` entry point.
+pub const SET_VALUE_SELECTOR: &str = "0x3d7905601c217734671143d457f0db37f7f8883112abd34b92c4abfeafde0c3";
+
+/// Encodes the calldata of a call to the `set_value` entry point.
+pub fn encode_set_value_calldata(value: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    value.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `set_value` entry point.
+pub fn decode_set_value_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `reset` entry point.
+pub const RESET_SELECTOR: &str = "0x2eb92e3029c3933e5350d8418880dd9fa7c329cb0cbf40a904129df80f1a668";
+
+/// Encodes the calldata of a call to the `reset` entry point.
+pub fn encode_reset_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `reset` entry point.
+pub fn decode_reset_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `log` entry point.
+pub const LOG_SELECTOR: &str = "0x1e837825143d8db1af63ebd5afd9feb6080222abcbda49b2018d11215a33241";
+
+/// Encodes the calldata of a call to the `log` entry point.
+pub fn encode_log_calldata(data: &Vec<Felt252>) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    data.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `log` entry point.
+pub fn decode_log_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
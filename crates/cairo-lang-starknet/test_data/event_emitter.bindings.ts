@@ -0,0 +1,66 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  deserializeUnit,
+  serializeArray,
+} from "./cairo_serde";
+
+/** The selector of the `get_value` entry point. */
+export const GET_VALUE_SELECTOR = 0x26813d396fdb198e9ead934e4f7a592a8b88a059e45ab0eb6ee53494e8d45b0n;
+
+/** Encodes the calldata of a call to the `get_value` entry point. */
+export function encodeGetValueCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_value` entry point. */
+export function decodeGetValueResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `set_value` entry point. */
+export const SET_VALUE_SELECTOR = 0x3d7905601c217734671143d457f0db37f7f8883112abd34b92c4abfeafde0c3n;
+
+/** Encodes the calldata of a call to the `set_value` entry point. */
+export function encodeSetValueCalldata(value: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(value, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `set_value` entry point. */
+export function decodeSetValueResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `reset` entry point. */
+export const RESET_SELECTOR = 0x2eb92e3029c3933e5350d8418880dd9fa7c329cb0cbf40a904129df80f1a668n;
+
+/** Encodes the calldata of a call to the `reset` entry point. */
+export function encodeResetCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `reset` entry point. */
+export function decodeResetResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `log` entry point. */
+export const LOG_SELECTOR = 0x1e837825143d8db1af63ebd5afd9feb6080222abcbda49b2018d11215a33241n;
+
+/** Encodes the calldata of a call to the `log` entry point. */
+export function encodeLogCalldata(data: bigint[]): bigint[] {
+  const calldata: bigint[] = [];
+  serializeArray(serializeFelt252)(data, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `log` entry point. */
+export function decodeLogResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
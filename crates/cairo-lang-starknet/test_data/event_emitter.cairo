@@ -0,0 +1,73 @@
+#[contract]
+mod EventEmitter {
+    use starknet::ContractAddress;
+    use starknet::get_caller_address;
+
+    struct Storage {
+        value: felt252, 
+    }
+
+    // An event that is emitted when set_value is called.
+    // * caller is the address from which the call was made, by which the events may be filtered.
+    // * previous is the previous value.
+    // * value is the new value.
+    #[derive(Drop, starknet::Event)]
+    struct ValueSet {
+        #[key]
+        caller: ContractAddress,
+        previous: felt252,
+        value: felt252,
+    }
+
+    // An event that is emitted when reset is called.
+    #[derive(Drop, starknet::Event)]
+    struct ValueReset {}
+
+    // The events that are emitted when the value changes.
+    #[derive(Drop, starknet::Event)]
+    enum ValueChanged {
+        ValueSet: ValueSet,
+        ValueReset: ValueReset,
+    }
+
+    // An event that is emitted when log is called.
+    // * data is the logged data.
+    #[derive(Drop, starknet::Event)]
+    struct Logged {
+        data: Array<felt252>, 
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        ValueChanged: ValueChanged,
+        Logged: Logged,
+    }
+
+    #[view]
+    fn get_value() -> felt252 {
+        value::read()
+    }
+
+    #[external]
+    fn set_value(value: felt252) {
+        let previous = value::read();
+        value::write(value);
+        emit(
+            Event::ValueChanged(
+                ValueChanged::ValueSet(ValueSet { caller: get_caller_address(), previous, value })
+            )
+        );
+    }
+
+    #[external]
+    fn reset() {
+        value::write(0);
+        emit(Event::ValueChanged(ValueChanged::ValueReset(ValueReset {})));
+    }
+
+    #[external]
+    fn log(data: Array<felt252>) {
+        emit(Event::Logged(Logged { data }));
+    }
+}
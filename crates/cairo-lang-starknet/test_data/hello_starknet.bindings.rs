@@ -0,0 +1,32 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The selector of the `increase_balance` entry point.
+pub const INCREASE_BALANCE_SELECTOR: &str = "0x362398bec32bc0ebb411203221a35a0301193a96f317ebe5e40be9f60d15320";
+
+/// Encodes the calldata of a call to the `increase_balance` entry point.
+pub fn encode_increase_balance_calldata(amount: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `increase_balance` entry point.
+pub fn decode_increase_balance_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_balance` entry point.
+pub const GET_BALANCE_SELECTOR: &str = "0x39e11d48192e4333233c7eb19d10ad67c362bb28580c604d67884c85da39695";
+
+/// Encodes the calldata of a call to the `get_balance` entry point.
+pub fn encode_get_balance_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_balance` entry point.
+pub fn decode_get_balance_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
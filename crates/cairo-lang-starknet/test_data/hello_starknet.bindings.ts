@@ -0,0 +1,37 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  deserializeUnit,
+} from "./cairo_serde";
+
+/** The selector of the `increase_balance` entry point. */
+export const INCREASE_BALANCE_SELECTOR = 0x362398bec32bc0ebb411203221a35a0301193a96f317ebe5e40be9f60d15320n;
+
+/** Encodes the calldata of a call to the `increase_balance` entry point. */
+export function encodeIncreaseBalanceCalldata(amount: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `increase_balance` entry point. */
+export function decodeIncreaseBalanceResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `get_balance` entry point. */
+export const GET_BALANCE_SELECTOR = 0x39e11d48192e4333233c7eb19d10ad67c362bb28580c604d67884c85da39695n;
+
+/** Encodes the calldata of a call to the `get_balance` entry point. */
+export function encodeGetBalanceCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_balance` entry point. */
+export function decodeGetBalanceResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
@@ -0,0 +1,40 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The `test::Point` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: u128,
+    pub y: Felt252,
+}
+impl CairoSerde for Point {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.x.serialize(output);
+        self.y.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            x: CairoSerde::deserialize(input)?,
+            y: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The selector of the `transform` entry point.
+pub const TRANSFORM_SELECTOR: &str = "0x399f8aadbfd8586c6741de4bf523d2b2f3a7ab05d45322919c95ce4f7e7038";
+
+/// Encodes the calldata of a call to the `transform` entry point.
+pub fn encode_transform_calldata(p: &Point, points: &Vec<Point>, flags: &(bool, u8)) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    p.serialize(&mut calldata);
+    points.serialize(&mut calldata);
+    flags.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `transform` entry point.
+pub fn decode_transform_result(result: &[Felt252]) -> Result<(Point, u64), DecodeError> {
+    decode(result)
+}
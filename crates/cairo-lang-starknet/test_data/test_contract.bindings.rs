@@ -0,0 +1,109 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The selector of the `test` entry point.
+pub const TEST_SELECTOR: &str = "0x22ff5f21f0b81b113e63f7db6da94fedef11b2119b4088b89664fb9a3cb658";
+
+/// Encodes the calldata of a call to the `test` entry point.
+pub fn encode_test_calldata(arg: &Felt252, arg1: &Felt252, arg2: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    arg.serialize(&mut calldata);
+    arg1.serialize(&mut calldata);
+    arg2.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `test` entry point.
+pub fn decode_test_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `call_foo` entry point.
+pub const CALL_FOO_SELECTOR: &str = "0x3d778356014c91effae9863ee4a8c2663d8fa2e9f0c4145c1e01f5435ced0be";
+
+/// Encodes the calldata of a call to the `call_foo` entry point.
+pub fn encode_call_foo_calldata(another_contract_address: &Felt252, a: &u128) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    another_contract_address.serialize(&mut calldata);
+    a.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `call_foo` entry point.
+pub fn decode_call_foo_result(result: &[Felt252]) -> Result<u128, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `libcall_foo` entry point.
+pub const LIBCALL_FOO_SELECTOR: &str = "0x24102d0bc2635dfdb19daf0fad1912bfce02dfe86e62d1185444f3e1dd5e37b";
+
+/// Encodes the calldata of a call to the `libcall_foo` entry point.
+pub fn encode_libcall_foo_calldata(a: &u128) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    a.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `libcall_foo` entry point.
+pub fn decode_libcall_foo_result(result: &[Felt252]) -> Result<u128, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `segment_arena_builtin` entry point.
+pub const SEGMENT_ARENA_BUILTIN_SELECTOR: &str = "0x10d2fede95e3ec06a875a67219425c27c5bd734d57f1b221d729a2337b6b556";
+
+/// Encodes the calldata of a call to the `segment_arena_builtin` entry point.
+pub fn encode_segment_arena_builtin_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `segment_arena_builtin` entry point.
+pub fn decode_segment_arena_builtin_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `l1_handle` entry point.
+pub const L1_HANDLE_SELECTOR: &str = "0x39edbbb129ad752107a94d40c3873cae369a46fd2fc578d075679aa67e85d12";
+
+/// Encodes the calldata of a call to the `l1_handle` entry point.
+pub fn encode_l1_handle_calldata(from_address: &Felt252, arg: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    from_address.serialize(&mut calldata);
+    arg.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `l1_handle` entry point.
+pub fn decode_l1_handle_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_value` entry point.
+pub const GET_VALUE_SELECTOR: &str = "0x26813d396fdb198e9ead934e4f7a592a8b88a059e45ab0eb6ee53494e8d45b0";
+
+/// Encodes the calldata of a call to the `get_value` entry point.
+pub fn encode_get_value_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_value` entry point.
+pub fn decode_get_value_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `set_value` entry point.
+pub const SET_VALUE_SELECTOR: &str = "0x3d7905601c217734671143d457f0db37f7f8883112abd34b92c4abfeafde0c3";
+
+/// Encodes the calldata of a call to the `set_value` entry point.
+pub fn encode_set_value_calldata(value: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    value.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `set_value` entry point.
+pub fn decode_set_value_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
@@ -0,0 +1,116 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  serializeU128,
+  deserializeU128,
+  deserializeUnit,
+} from "./cairo_serde";
+
+/** The selector of the `test` entry point. */
+export const TEST_SELECTOR = 0x22ff5f21f0b81b113e63f7db6da94fedef11b2119b4088b89664fb9a3cb658n;
+
+/** Encodes the calldata of a call to the `test` entry point. */
+export function encodeTestCalldata(arg: bigint, arg1: bigint, arg2: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(arg, calldata);
+  serializeFelt252(arg1, calldata);
+  serializeFelt252(arg2, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `test` entry point. */
+export function decodeTestResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `call_foo` entry point. */
+export const CALL_FOO_SELECTOR = 0x3d778356014c91effae9863ee4a8c2663d8fa2e9f0c4145c1e01f5435ced0ben;
+
+/** Encodes the calldata of a call to the `call_foo` entry point. */
+export function encodeCallFooCalldata(another_contract_address: bigint, a: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(another_contract_address, calldata);
+  serializeU128(a, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `call_foo` entry point. */
+export function decodeCallFooResult(result: bigint[]): bigint {
+  return decode(result, deserializeU128);
+}
+
+/** The selector of the `libcall_foo` entry point. */
+export const LIBCALL_FOO_SELECTOR = 0x24102d0bc2635dfdb19daf0fad1912bfce02dfe86e62d1185444f3e1dd5e37bn;
+
+/** Encodes the calldata of a call to the `libcall_foo` entry point. */
+export function encodeLibcallFooCalldata(a: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeU128(a, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `libcall_foo` entry point. */
+export function decodeLibcallFooResult(result: bigint[]): bigint {
+  return decode(result, deserializeU128);
+}
+
+/** The selector of the `segment_arena_builtin` entry point. */
+export const SEGMENT_ARENA_BUILTIN_SELECTOR = 0x10d2fede95e3ec06a875a67219425c27c5bd734d57f1b221d729a2337b6b556n;
+
+/** Encodes the calldata of a call to the `segment_arena_builtin` entry point. */
+export function encodeSegmentArenaBuiltinCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `segment_arena_builtin` entry point. */
+export function decodeSegmentArenaBuiltinResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `l1_handle` entry point. */
+export const L1_HANDLE_SELECTOR = 0x39edbbb129ad752107a94d40c3873cae369a46fd2fc578d075679aa67e85d12n;
+
+/** Encodes the calldata of a call to the `l1_handle` entry point. */
+export function encodeL1HandleCalldata(from_address: bigint, arg: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(from_address, calldata);
+  serializeFelt252(arg, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `l1_handle` entry point. */
+export function decodeL1HandleResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `get_value` entry point. */
+export const GET_VALUE_SELECTOR = 0x26813d396fdb198e9ead934e4f7a592a8b88a059e45ab0eb6ee53494e8d45b0n;
+
+/** Encodes the calldata of a call to the `get_value` entry point. */
+export function encodeGetValueCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_value` entry point. */
+export function decodeGetValueResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `set_value` entry point. */
+export const SET_VALUE_SELECTOR = 0x3d7905601c217734671143d457f0db37f7f8883112abd34b92c4abfeafde0c3n;
+
+/** Encodes the calldata of a call to the `set_value` entry point. */
+export function encodeSetValueCalldata(value: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(value, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `set_value` entry point. */
+export function decodeSetValueResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
@@ -3,6 +3,13 @@ trait IAnotherContract {
     fn foo(a: u128) -> u128;
 }
 
+#[abi]
+trait ITestContract {
+    #[view]
+    fn get_value() -> felt252;
+    fn set_value(value: felt252);
+}
+
 
 #[contract]
 mod TestContract {
@@ -44,6 +51,17 @@ mod TestContract {
         x.squash();
     }
 
+    #[external]
+    impl TestContractImpl of super::ITestContract {
+        fn get_value() -> felt252 {
+            my_storage_var::read()
+        }
+
+        fn set_value(value: felt252) {
+            my_storage_var::write(value);
+        }
+    }
+
     #[l1_handler]
     fn l1_handle(from_address: felt252, arg: felt252) -> felt252 {
         arg
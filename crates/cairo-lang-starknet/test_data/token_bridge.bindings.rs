@@ -0,0 +1,143 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The `token_bridge::token_bridge::EthAddress` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EthAddress {
+    pub address: Felt252,
+}
+impl CairoSerde for EthAddress {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.address.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            address: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The `core::integer::u256` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct U256 {
+    pub low: u128,
+    pub high: u128,
+}
+impl CairoSerde for U256 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.low.serialize(output);
+        self.high.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            low: CairoSerde::deserialize(input)?,
+            high: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The selector of the `get_version` entry point.
+pub const GET_VERSION_SELECTOR: &str = "0x2a4bb4205277617b698a9a2950b938d0a236dd4619f82f05bec02bdbd245fab";
+
+/// Encodes the calldata of a call to the `get_version` entry point.
+pub fn encode_get_version_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_version` entry point.
+pub fn decode_get_version_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `get_identity` entry point.
+pub const GET_IDENTITY_SELECTOR: &str = "0x2c4943a27e820803a6ef49bb04b629950e2de615ab9ac0fb8baef037b168782";
+
+/// Encodes the calldata of a call to the `get_identity` entry point.
+pub fn encode_get_identity_calldata() -> Vec<Felt252> {
+    vec![]
+}
+
+/// Decodes the result of a call to the `get_identity` entry point.
+pub fn decode_get_identity_result(result: &[Felt252]) -> Result<Felt252, DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `constructor` entry point.
+pub const CONSTRUCTOR_SELECTOR: &str = "0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194";
+
+/// Encodes the calldata of a call to the `constructor` entry point.
+pub fn encode_constructor_calldata(governor_address: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    governor_address.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `constructor` entry point.
+pub fn decode_constructor_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `set_l1_bridge` entry point.
+pub const SET_L1_BRIDGE_SELECTOR: &str = "0x2991bb19305a4d6507010cd65c3b5ae1573665f684593f23c839f2011e46bf1";
+
+/// Encodes the calldata of a call to the `set_l1_bridge` entry point.
+pub fn encode_set_l1_bridge_calldata(l1_bridge_address: &EthAddress) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    l1_bridge_address.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `set_l1_bridge` entry point.
+pub fn decode_set_l1_bridge_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `set_l2_token` entry point.
+pub const SET_L2_TOKEN_SELECTOR: &str = "0x2dc43b5b06e7678e8776ee682b94dd95787b1157b364f83f82cf83b12cb9cf8";
+
+/// Encodes the calldata of a call to the `set_l2_token` entry point.
+pub fn encode_set_l2_token_calldata(l2_token_address: &Felt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    l2_token_address.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `set_l2_token` entry point.
+pub fn decode_set_l2_token_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `initiate_withdraw` entry point.
+pub const INITIATE_WITHDRAW_SELECTOR: &str = "0xe48e45e0642d5f170bb832c637926f4c85b77d555848b693304600c4275f26";
+
+/// Encodes the calldata of a call to the `initiate_withdraw` entry point.
+pub fn encode_initiate_withdraw_calldata(l1_recipient: &EthAddress, amount: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    l1_recipient.serialize(&mut calldata);
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `initiate_withdraw` entry point.
+pub fn decode_initiate_withdraw_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `handle_deposit` entry point.
+pub const HANDLE_DEPOSIT_SELECTOR: &str = "0x2d757788a8d8d6f21d1cd40bce38a8222d70654214e96ff95d8086e684fbee5";
+
+/// Encodes the calldata of a call to the `handle_deposit` entry point.
+pub fn encode_handle_deposit_calldata(from_address: &Felt252, account: &Felt252, amount: &U256) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    from_address.serialize(&mut calldata);
+    account.serialize(&mut calldata);
+    amount.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `handle_deposit` entry point.
+pub fn decode_handle_deposit_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
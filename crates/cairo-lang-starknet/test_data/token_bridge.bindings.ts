@@ -0,0 +1,149 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  Reader,
+  decode,
+  serializeFelt252,
+  deserializeFelt252,
+  serializeU128,
+  deserializeU128,
+  deserializeUnit,
+} from "./cairo_serde";
+
+/** The `token_bridge::token_bridge::EthAddress` struct. */
+export interface EthAddress {
+  address: bigint;
+}
+
+export function serializeEthAddress(value: EthAddress, output: bigint[]): void {
+  serializeFelt252(value.address, output);
+}
+
+export function deserializeEthAddress(input: Reader): EthAddress {
+  return {
+    address: deserializeFelt252(input),
+  };
+}
+
+/** The `core::integer::u256` struct. */
+export interface U256 {
+  low: bigint;
+  high: bigint;
+}
+
+export function serializeU256(value: U256, output: bigint[]): void {
+  serializeU128(value.low, output);
+  serializeU128(value.high, output);
+}
+
+export function deserializeU256(input: Reader): U256 {
+  return {
+    low: deserializeU128(input),
+    high: deserializeU128(input),
+  };
+}
+
+/** The selector of the `get_version` entry point. */
+export const GET_VERSION_SELECTOR = 0x2a4bb4205277617b698a9a2950b938d0a236dd4619f82f05bec02bdbd245fabn;
+
+/** Encodes the calldata of a call to the `get_version` entry point. */
+export function encodeGetVersionCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_version` entry point. */
+export function decodeGetVersionResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `get_identity` entry point. */
+export const GET_IDENTITY_SELECTOR = 0x2c4943a27e820803a6ef49bb04b629950e2de615ab9ac0fb8baef037b168782n;
+
+/** Encodes the calldata of a call to the `get_identity` entry point. */
+export function encodeGetIdentityCalldata(): bigint[] {
+  return [];
+}
+
+/** Decodes the result of a call to the `get_identity` entry point. */
+export function decodeGetIdentityResult(result: bigint[]): bigint {
+  return decode(result, deserializeFelt252);
+}
+
+/** The selector of the `constructor` entry point. */
+export const CONSTRUCTOR_SELECTOR = 0x28ffe4ff0f226a9107253e17a904099aa4f63a02a5621de0576e5aa71bc5194n;
+
+/** Encodes the calldata of a call to the `constructor` entry point. */
+export function encodeConstructorCalldata(governor_address: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(governor_address, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `constructor` entry point. */
+export function decodeConstructorResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `set_l1_bridge` entry point. */
+export const SET_L1_BRIDGE_SELECTOR = 0x2991bb19305a4d6507010cd65c3b5ae1573665f684593f23c839f2011e46bf1n;
+
+/** Encodes the calldata of a call to the `set_l1_bridge` entry point. */
+export function encodeSetL1BridgeCalldata(l1_bridge_address: EthAddress): bigint[] {
+  const calldata: bigint[] = [];
+  serializeEthAddress(l1_bridge_address, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `set_l1_bridge` entry point. */
+export function decodeSetL1BridgeResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `set_l2_token` entry point. */
+export const SET_L2_TOKEN_SELECTOR = 0x2dc43b5b06e7678e8776ee682b94dd95787b1157b364f83f82cf83b12cb9cf8n;
+
+/** Encodes the calldata of a call to the `set_l2_token` entry point. */
+export function encodeSetL2TokenCalldata(l2_token_address: bigint): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(l2_token_address, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `set_l2_token` entry point. */
+export function decodeSetL2TokenResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `initiate_withdraw` entry point. */
+export const INITIATE_WITHDRAW_SELECTOR = 0xe48e45e0642d5f170bb832c637926f4c85b77d555848b693304600c4275f26n;
+
+/** Encodes the calldata of a call to the `initiate_withdraw` entry point. */
+export function encodeInitiateWithdrawCalldata(l1_recipient: EthAddress, amount: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeEthAddress(l1_recipient, calldata);
+  serializeU256(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `initiate_withdraw` entry point. */
+export function decodeInitiateWithdrawResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `handle_deposit` entry point. */
+export const HANDLE_DEPOSIT_SELECTOR = 0x2d757788a8d8d6f21d1cd40bce38a8222d70654214e96ff95d8086e684fbee5n;
+
+/** Encodes the calldata of a call to the `handle_deposit` entry point. */
+export function encodeHandleDepositCalldata(from_address: bigint, account: bigint, amount: U256): bigint[] {
+  const calldata: bigint[] = [];
+  serializeFelt252(from_address, calldata);
+  serializeFelt252(account, calldata);
+  serializeU256(amount, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `handle_deposit` entry point. */
+export function decodeHandleDepositResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
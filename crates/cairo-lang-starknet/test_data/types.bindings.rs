@@ -0,0 +1,154 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `cairo_serde` next to them.
+
+use super::cairo_serde::*;
+
+/// The `test::MyStruct::<core::integer::u128>` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MyStructU128 {
+    pub a: u128,
+    pub b: Felt252,
+}
+impl CairoSerde for MyStructU128 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.a.serialize(output);
+        self.b.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            a: CairoSerde::deserialize(input)?,
+            b: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The `core::integer::u256` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct U256 {
+    pub low: u128,
+    pub high: u128,
+}
+impl CairoSerde for U256 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.low.serialize(output);
+        self.high.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            low: CairoSerde::deserialize(input)?,
+            high: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The `test::MyEnum::<core::integer::u128>` enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MyEnumU128 {
+    A(MyStructU128),
+    B,
+}
+impl CairoSerde for MyEnumU128 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        match self {
+            Self::A(value) => serialize_variant(0, value, output),
+            Self::B => serialize_variant(1, &(), output),
+        }
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        match deserialize_variant_index(input)? {
+            0 => Ok(Self::A(CairoSerde::deserialize(input)?)),
+            1 => Ok(Self::B),
+            _ => Err(DecodeError),
+        }
+    }
+}
+
+/// The `test::MyStruct::<core::felt252>` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MyStructFelt252 {
+    pub a: Felt252,
+    pub b: Felt252,
+}
+impl CairoSerde for MyStructFelt252 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        self.a.serialize(output);
+        self.b.serialize(output);
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        Ok(Self {
+            a: CairoSerde::deserialize(input)?,
+            b: CairoSerde::deserialize(input)?,
+        })
+    }
+}
+
+/// The `test::MyEnum::<core::felt252>` enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MyEnumFelt252 {
+    A(MyStructFelt252),
+    B,
+}
+impl CairoSerde for MyEnumFelt252 {
+    fn serialize(&self, output: &mut Vec<Felt252>) {
+        match self {
+            Self::A(value) => serialize_variant(0, value, output),
+            Self::B => serialize_variant(1, &(), output),
+        }
+    }
+    fn deserialize(input: &mut &[Felt252]) -> Result<Self, DecodeError> {
+        match deserialize_variant_index(input)? {
+            0 => Ok(Self::A(CairoSerde::deserialize(input)?)),
+            1 => Ok(Self::B),
+            _ => Err(DecodeError),
+        }
+    }
+}
+
+/// The selector of the `tuples` entry point.
+pub const TUPLES_SELECTOR: &str = "0x2afbbcfa4ab2a84c1942bf5b6311d873cf0b9229efbf477e6e39880ef15f7cc";
+
+/// Encodes the calldata of a call to the `tuples` entry point.
+pub fn encode_tuples_calldata(a: &(Felt252, MyStructU128), b: &(u8,)) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    a.serialize(&mut calldata);
+    b.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `tuples` entry point.
+pub fn decode_tuples_result(result: &[Felt252]) -> Result<(U256, (bool, u8)), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `arrays` entry point.
+pub const ARRAYS_SELECTOR: &str = "0x204d7fbadd52bb6eaec8e1c96ec2cfc6f3a9e78d21e5826ceac5973553f1fa1";
+
+/// Encodes the calldata of a call to the `arrays` entry point.
+pub fn encode_arrays_calldata(a: &Vec<Vec<Felt252>>, b: &Vec<U256>) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    a.serialize(&mut calldata);
+    b.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `arrays` entry point.
+pub fn decode_arrays_result(result: &[Felt252]) -> Result<(), DecodeError> {
+    decode(result)
+}
+
+/// The selector of the `generics` entry point.
+pub const GENERICS_SELECTOR: &str = "0x1d6c74a91f3c4438e1ed9477358b69ac1361e6ebf1fbdca6f3c1443889b5461";
+
+/// Encodes the calldata of a call to the `generics` entry point.
+pub fn encode_generics_calldata(a: &Option<MyStructU128>, b: &MyEnumU128, c: &MyEnumFelt252) -> Vec<Felt252> {
+    let mut calldata = vec![];
+    a.serialize(&mut calldata);
+    b.serialize(&mut calldata);
+    c.serialize(&mut calldata);
+    calldata
+}
+
+/// Decodes the result of a call to the `generics` entry point.
+pub fn decode_generics_result(result: &[Felt252]) -> Result<Result<U256, Felt252>, DecodeError> {
+    decode(result)
+}
@@ -0,0 +1,184 @@
+// Bindings of a Starknet contract, generated from its ABI.
+// They use the runtime generated along with them, as the module `./cairo_serde`.
+
+import {
+  DecodeError,
+  Reader,
+  decode,
+  Option,
+  Result,
+  serializeFelt252,
+  deserializeFelt252,
+  serializeU8,
+  serializeU128,
+  deserializeU8,
+  deserializeU128,
+  deserializeBool,
+  deserializeUnit,
+  serializeArray,
+  serializeTuple,
+  deserializeTuple,
+  serializeOption,
+  deserializeResult,
+} from "./cairo_serde";
+
+/** The `test::MyStruct::<core::integer::u128>` struct. */
+export interface MyStructU128 {
+  a: bigint;
+  b: bigint;
+}
+
+export function serializeMyStructU128(value: MyStructU128, output: bigint[]): void {
+  serializeU128(value.a, output);
+  serializeFelt252(value.b, output);
+}
+
+export function deserializeMyStructU128(input: Reader): MyStructU128 {
+  return {
+    a: deserializeU128(input),
+    b: deserializeFelt252(input),
+  };
+}
+
+/** The `core::integer::u256` struct. */
+export interface U256 {
+  low: bigint;
+  high: bigint;
+}
+
+export function serializeU256(value: U256, output: bigint[]): void {
+  serializeU128(value.low, output);
+  serializeU128(value.high, output);
+}
+
+export function deserializeU256(input: Reader): U256 {
+  return {
+    low: deserializeU128(input),
+    high: deserializeU128(input),
+  };
+}
+
+/** The `test::MyEnum::<core::integer::u128>` enum. */
+export type MyEnumU128 =
+  | { variant: "a"; value: MyStructU128 }
+  | { variant: "b" };
+
+export function serializeMyEnumU128(value: MyEnumU128, output: bigint[]): void {
+  switch (value.variant) {
+    case "a":
+      output.push(0n);
+      serializeMyStructU128(value.value, output);
+      break;
+    case "b":
+      output.push(1n);
+      break;
+  }
+}
+
+export function deserializeMyEnumU128(input: Reader): MyEnumU128 {
+  const index = input.next();
+  switch (index) {
+    case 0n:
+      return { variant: "a", value: deserializeMyStructU128(input) };
+    case 1n:
+      return { variant: "b" };
+    default:
+      throw new DecodeError(`Invalid variant index ${index} of MyEnumU128.`);
+  }
+}
+
+/** The `test::MyStruct::<core::felt252>` struct. */
+export interface MyStructFelt252 {
+  a: bigint;
+  b: bigint;
+}
+
+export function serializeMyStructFelt252(value: MyStructFelt252, output: bigint[]): void {
+  serializeFelt252(value.a, output);
+  serializeFelt252(value.b, output);
+}
+
+export function deserializeMyStructFelt252(input: Reader): MyStructFelt252 {
+  return {
+    a: deserializeFelt252(input),
+    b: deserializeFelt252(input),
+  };
+}
+
+/** The `test::MyEnum::<core::felt252>` enum. */
+export type MyEnumFelt252 =
+  | { variant: "a"; value: MyStructFelt252 }
+  | { variant: "b" };
+
+export function serializeMyEnumFelt252(value: MyEnumFelt252, output: bigint[]): void {
+  switch (value.variant) {
+    case "a":
+      output.push(0n);
+      serializeMyStructFelt252(value.value, output);
+      break;
+    case "b":
+      output.push(1n);
+      break;
+  }
+}
+
+export function deserializeMyEnumFelt252(input: Reader): MyEnumFelt252 {
+  const index = input.next();
+  switch (index) {
+    case 0n:
+      return { variant: "a", value: deserializeMyStructFelt252(input) };
+    case 1n:
+      return { variant: "b" };
+    default:
+      throw new DecodeError(`Invalid variant index ${index} of MyEnumFelt252.`);
+  }
+}
+
+/** The selector of the `tuples` entry point. */
+export const TUPLES_SELECTOR = 0x2afbbcfa4ab2a84c1942bf5b6311d873cf0b9229efbf477e6e39880ef15f7ccn;
+
+/** Encodes the calldata of a call to the `tuples` entry point. */
+export function encodeTuplesCalldata(a: [bigint, MyStructU128], b: [bigint]): bigint[] {
+  const calldata: bigint[] = [];
+  serializeTuple(serializeFelt252, serializeMyStructU128)(a, calldata);
+  serializeTuple(serializeU8)(b, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `tuples` entry point. */
+export function decodeTuplesResult(result: bigint[]): [U256, [boolean, bigint]] {
+  return decode(result, deserializeTuple(deserializeU256, deserializeTuple(deserializeBool, deserializeU8)));
+}
+
+/** The selector of the `arrays` entry point. */
+export const ARRAYS_SELECTOR = 0x204d7fbadd52bb6eaec8e1c96ec2cfc6f3a9e78d21e5826ceac5973553f1fa1n;
+
+/** Encodes the calldata of a call to the `arrays` entry point. */
+export function encodeArraysCalldata(a: bigint[][], b: U256[]): bigint[] {
+  const calldata: bigint[] = [];
+  serializeArray(serializeArray(serializeFelt252))(a, calldata);
+  serializeArray(serializeU256)(b, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `arrays` entry point. */
+export function decodeArraysResult(result: bigint[]): [] {
+  return decode(result, deserializeUnit);
+}
+
+/** The selector of the `generics` entry point. */
+export const GENERICS_SELECTOR = 0x1d6c74a91f3c4438e1ed9477358b69ac1361e6ebf1fbdca6f3c1443889b5461n;
+
+/** Encodes the calldata of a call to the `generics` entry point. */
+export function encodeGenericsCalldata(a: Option<MyStructU128>, b: MyEnumU128, c: MyEnumFelt252): bigint[] {
+  const calldata: bigint[] = [];
+  serializeOption(serializeMyStructU128)(a, calldata);
+  serializeMyEnumU128(b, calldata);
+  serializeMyEnumFelt252(c, calldata);
+  return calldata;
+}
+
+/** Decodes the result of a call to the `generics` entry point. */
+export function decodeGenericsResult(result: bigint[]): Result<U256, bigint> {
+  return decode(result, deserializeResult(deserializeU256, deserializeFelt252));
+}
@@ -0,0 +1,53 @@
+#[contract]
+mod UpgradableContract {
+    use starknet::ContractAddress;
+    use starknet::get_caller_address;
+
+    #[derive(Copy, Drop, Serde, StorageAccess)]
+    struct Position {
+        x: u128,
+        y: u128,
+        z: u128,
+    }
+
+    struct Storage {
+        owner: ContractAddress,
+        balances: LegacyMap::<ContractAddress, u256>,
+        positions: LegacyMap::<ContractAddress, Position>,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Moved {
+        #[key]
+        user: ContractAddress,
+        position: Position,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Moved: Moved,
+    }
+
+    #[external]
+    fn move_to(position: Position) {
+        let user = get_caller_address();
+        positions::write(user, position);
+        emit(Event::Moved(Moved { user, position }));
+    }
+
+    #[view]
+    fn get_balance(user: ContractAddress) -> u256 {
+        balances::read(user)
+    }
+
+    #[view]
+    fn get_position(user: ContractAddress) -> Position {
+        positions::read(user)
+    }
+
+    #[view]
+    fn get_owner() -> ContractAddress {
+        owner::read()
+    }
+}
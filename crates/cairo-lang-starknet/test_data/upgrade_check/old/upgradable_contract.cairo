@@ -0,0 +1,59 @@
+#[contract]
+mod UpgradableContract {
+    use starknet::ContractAddress;
+
+    #[derive(Copy, Drop, Serde, StorageAccess)]
+    struct Position {
+        x: u128,
+        y: u128,
+    }
+
+    struct Storage {
+        owner: ContractAddress,
+        balances: LegacyMap::<ContractAddress, u128>,
+        positions: LegacyMap::<ContractAddress, Position>,
+        counter: felt252,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Moved {
+        #[key]
+        user: ContractAddress,
+        position: Position,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Counted {
+        value: felt252,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Moved: Moved,
+        Counted: Counted,
+    }
+
+    #[external]
+    fn move_to(user: ContractAddress, position: Position) {
+        positions::write(user, position);
+        emit(Event::Moved(Moved { user, position }));
+    }
+
+    #[external]
+    fn increase_counter() {
+        let value = counter::read() + 1;
+        counter::write(value);
+        emit(Event::Counted(Counted { value }));
+    }
+
+    #[view]
+    fn get_balance(user: ContractAddress) -> u128 {
+        balances::read(user)
+    }
+
+    #[view]
+    fn get_position(user: ContractAddress) -> Position {
+        positions::read(user)
+    }
+}
@@ -6949,14 +6949,16 @@ pub struct Member {
     children: Vec<SyntaxNode>,
 }
 impl Member {
-    pub const INDEX_NAME: usize = 0;
-    pub const INDEX_TYPE_CLAUSE: usize = 1;
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
         name: TerminalIdentifierGreen,
         type_clause: TypeClauseGreen,
     ) -> MemberGreen {
-        let children: Vec<GreenId> = vec![name.0, type_clause.0];
+        let children: Vec<GreenId> = vec![attributes.0, name.0, type_clause.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         MemberGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::Member,
@@ -6965,11 +6967,14 @@ impl Member {
     }
 }
 impl Member {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
     }
     pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[1].clone())
+        TypeClause::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -6997,7 +7002,11 @@ impl TypedSyntaxNode for Member {
         MemberGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::Member,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalIdentifier::missing(db).0, TypeClause::missing(db).0],
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                ],
                 width: TextWidth::default(),
             },
         }))
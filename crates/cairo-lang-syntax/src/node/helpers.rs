@@ -3,8 +3,9 @@ use smol_str::SmolStr;
 use super::ast::{
     self, FunctionDeclaration, FunctionDeclarationGreen, FunctionWithBody, FunctionWithBodyPtr,
     Item, ItemConstant, ItemEnum, ItemExternFunction, ItemExternFunctionPtr, ItemExternType,
-    ItemImpl, ItemImplAlias, ItemModule, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse, Modifier,
-    TerminalIdentifierGreen, TokenIdentifierGreen, TraitItemFunction, TraitItemFunctionPtr,
+    ItemImpl, ItemImplAlias, ItemModule, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse, Member,
+    Modifier, TerminalIdentifierGreen, TokenIdentifierGreen, TraitItemFunction,
+    TraitItemFunctionPtr,
 };
 use super::db::SyntaxGroup;
 use super::Terminal;
@@ -209,6 +210,11 @@ impl QueryAttrs for TraitItemFunction {
         self.attributes(db).elements(db)
     }
 }
+impl QueryAttrs for Member {
+    fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
+        self.attributes(db).elements(db)
+    }
+}
 
 impl QueryAttrs for Item {
     fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
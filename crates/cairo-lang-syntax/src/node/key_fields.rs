@@ -70,7 +70,7 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::OptionImplicitsClauseEmpty => vec![],
         SyntaxKind::OptionTerminalNoPanicEmpty => vec![],
         SyntaxKind::FunctionSignature => vec![],
-        SyntaxKind::Member => vec![/* name */ children[0]],
+        SyntaxKind::Member => vec![/* name */ children[1]],
         SyntaxKind::MemberList => vec![],
         SyntaxKind::ItemList => vec![],
         SyntaxKind::Attribute => vec![],
@@ -1,6 +1,6 @@
 //! Compiles and runs a Cairo program.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{bail, Context};
@@ -22,6 +22,7 @@ use cairo_lang_semantic::{ConcreteFunction, FunctionLongId};
 use cairo_lang_sierra::extensions::gas::CostTokenType;
 use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::find_free_functions;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_starknet::casm_contract_class::ENTRY_POINT_COST;
@@ -31,6 +32,9 @@ use cairo_lang_starknet::plugin::StarkNetPlugin;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use clap::Parser;
 use colored::Colorize;
+use coverage::{statements_source_lines, LineCoverage};
+use filter::TestFilter;
+use fuzzing::{fuzz, FuzzResult, FuzzType, FuzzedParam, FuzzerConfig};
 use itertools::{chain, Itertools};
 use plugin::TestPlugin;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
@@ -38,6 +42,9 @@ use test_config::{try_extract_test_config, TestConfig};
 
 use crate::test_config::{PanicExpectation, TestExpectation};
 
+mod coverage;
+mod filter;
+mod fuzzing;
 mod plugin;
 mod test_config;
 
@@ -48,9 +55,25 @@ mod test_config;
 struct Args {
     /// The path to compile and run its tests.
     path: String,
-    /// The filter for the tests, running only tests containing the filter string.
+    /// The filter for the tests, running only tests whose full path contains the filter string.
     #[arg(short, long, default_value_t = String::default())]
     filter: String,
+    /// Runs only the test whose full path is exactly the filter.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["glob", "regex"])]
+    exact: bool,
+    /// Treats the filter as a glob pattern matched against the full path of the tests.
+    /// `*` and `?` match within a single path segment, `**` matches across segments.
+    #[arg(long, default_value_t = false, conflicts_with = "regex")]
+    glob: bool,
+    /// Treats the filter as a regular expression searched in the full path of the tests.
+    #[arg(long, default_value_t = false)]
+    regex: bool,
+    /// Runs only the tests of the given crates of the project. May be given multiple times.
+    #[arg(long = "crate")]
+    crates: Vec<String>,
+    /// Lists the selected tests without running them.
+    #[arg(long, default_value_t = false)]
+    list: bool,
     /// Should we run ignored tests as well.
     #[arg(long, default_value_t = false)]
     include_ignored: bool,
@@ -60,15 +83,35 @@ struct Args {
     /// Should we add the starknet plugin to run the tests.
     #[arg(long, default_value_t = false)]
     starknet: bool,
+    /// The number of random inputs each test with parameters is run with.
+    #[arg(long, default_value_t = 256)]
+    fuzzer_runs: usize,
+    /// The seed for generating the inputs of tests with parameters. Random if not provided.
+    #[arg(long)]
+    fuzzer_seed: Option<u64>,
+    /// Writes the line coverage of the tests to the given path, in the lcov format.
+    #[arg(long)]
+    coverage: Option<PathBuf>,
+    /// Fails if the line coverage of the tests is below the given percentage.
+    #[arg(long, requires = "coverage")]
+    min_coverage: Option<f64>,
 }
 
 /// The status of a ran test.
 enum TestStatus {
     Success,
-    Fail(RunResultValue),
+    Fail(TestFailure),
     Ignore,
 }
 
+/// The data of a failed test.
+struct TestFailure {
+    /// The result of the failing run.
+    run_result: RunResultValue,
+    /// The minimal input found failing the test, for tests with parameters.
+    counterexample: Option<String>,
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
@@ -82,8 +125,19 @@ fn main() -> anyhow::Result<()> {
         .with_plugins(plugins)
         .detect_corelib()
         .build()?;
+    db.set_add_statements_locations(args.coverage.is_some());
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+    let test_crate_ids = select_crates(db, &main_crate_ids, &args.crates)?;
+    let filter = if args.exact {
+        TestFilter::Exact(args.filter.clone())
+    } else if args.glob {
+        TestFilter::glob(&args.filter)?
+    } else if args.regex {
+        TestFilter::regex(&args.filter)?
+    } else {
+        TestFilter::Substring(args.filter.clone())
+    };
 
     if DiagnosticsReporter::stderr().check(db) {
         bail!("failed to compile: {}", args.path);
@@ -113,49 +167,109 @@ fn main() -> anyhow::Result<()> {
                 )
             })
             .collect();
-    let all_tests = find_all_tests(db, main_crate_ids);
-    let sierra_program = db
-        .get_sierra_program_for_functions(
-            chain!(
-                all_entry_points.into_iter(),
-                all_tests.iter().flat_map(|(func_id, _cfg)| {
-                    ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
-                })
-            )
-            .collect(),
-        )
-        .to_option()
-        .with_context(|| "Compilation failed without any diagnostics.")?;
-    let sierra_program = replace_sierra_ids_in_program(db, &sierra_program);
+    let all_tests = find_all_tests(db, test_crate_ids.clone());
     let total_tests_count = all_tests.len();
-    let named_tests = all_tests
+    let selected_tests = all_tests
         .into_iter()
         .map(|(func_id, mut test)| {
             // Un-ignoring all the tests in `include-ignored` mode.
             if args.include_ignored {
                 test.ignored = false;
             }
-            (
-                format!(
-                    "{:?}",
-                    FunctionLongId {
-                        function: ConcreteFunction {
-                            generic_function: GenericFunctionId::Free(func_id),
-                            generic_args: vec![]
-                        }
+            let name = format!(
+                "{:?}",
+                FunctionLongId {
+                    function: ConcreteFunction {
+                        generic_function: GenericFunctionId::Free(func_id),
+                        generic_args: vec![]
                     }
-                    .debug(db)
-                ),
-                test,
-            )
+                }
+                .debug(db)
+            );
+            (name, func_id, test)
         })
-        .filter(|(name, _)| name.contains(&args.filter))
+        .filter(|(name, _, _)| filter.matches(name))
         // Filtering unignored tests in `ignored` mode.
-        .filter(|(_, test)| !args.ignored || test.ignored)
+        .filter(|(_, _, test)| !args.ignored || test.ignored)
         .collect_vec();
+    if args.list {
+        for (name, _, _) in &selected_tests {
+            println!("{name}: test");
+        }
+        println!();
+        println!("{} tests", selected_tests.len());
+        return Ok(());
+    }
+    // When collecting coverage, all the functions are compiled, so that lines of functions not
+    // called by any test are reported as not covered.
+    let coverage_function_ids = if args.coverage.is_some() {
+        find_free_functions(db, &test_crate_ids)
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?
+    } else {
+        vec![]
+    };
+    let requested_function_ids = chain!(
+        all_entry_points.into_iter(),
+        selected_tests.iter().flat_map(|(_, func_id, _)| {
+            ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
+        }),
+        coverage_function_ids.into_iter()
+    )
+    .collect_vec();
+    let sierra_program = db
+        .get_sierra_program_for_functions(requested_function_ids.clone())
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let statements_lines = if args.coverage.is_some() {
+        let statements_locations = db
+            .get_sierra_program_statements_locations(requested_function_ids)
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?;
+        Some(statements_source_lines(db, &statements_locations, &test_crate_ids))
+    } else {
+        None
+    };
+    let sierra_program = replace_sierra_ids_in_program(db, &sierra_program);
+    let named_tests = selected_tests
+        .into_iter()
+        .map(|(name, func_id, test)| -> anyhow::Result<(String, TestConfig, Vec<FuzzedParam>)> {
+            let params = get_fuzzed_params(db, func_id)
+                .with_context(|| format!("Failed to fuzz the test `{name}`."))?;
+            Ok((name, test, params))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
     let filtered_out = total_tests_count - named_tests.len();
-    let TestsSummary { passed, failed, ignored, failed_run_results } =
-        run_tests(named_tests, sierra_program, function_set_costs)?;
+    let fuzzer_config = FuzzerConfig {
+        runs: args.fuzzer_runs,
+        seed: args.fuzzer_seed.unwrap_or_else(rand::random),
+    };
+    let TestsSummary { passed, failed, ignored, failures, statements_hits } = run_tests(
+        named_tests,
+        sierra_program,
+        function_set_costs,
+        fuzzer_config,
+        statements_lines.is_some(),
+    )?;
+    if let (Some(path), Some(statements_lines)) = (&args.coverage, &statements_lines) {
+        let coverage = LineCoverage::new(statements_lines, &statements_hits);
+        std::fs::write(path, coverage.to_lcov())
+            .with_context(|| format!("Failed to write the coverage to `{}`.", path.display()))?;
+        println!(
+            "coverage: {:.2}% of lines covered ({}/{}).",
+            coverage.percentage(),
+            coverage.covered_lines_count(),
+            coverage.lines_count()
+        );
+        if let Some(min_coverage) = args.min_coverage {
+            if coverage.percentage() < min_coverage {
+                bail!(
+                    "coverage {:.2}% is below the required minimum of {min_coverage}%.",
+                    coverage.percentage()
+                );
+            }
+        }
+    }
     if failed.is_empty() {
         println!(
             "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -167,7 +281,8 @@ fn main() -> anyhow::Result<()> {
         Ok(())
     } else {
         println!("failures:");
-        for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
+        for (failure, TestFailure { run_result, counterexample }) in failed.iter().zip_eq(failures)
+        {
             print!("   {failure} - ");
             match run_result {
                 RunResultValue::Success(_) => {
@@ -184,6 +299,9 @@ fn main() -> anyhow::Result<()> {
                     println!("].")
                 }
             }
+            if let Some(counterexample) = counterexample {
+                println!("      minimal failing input: {counterexample}");
+            }
         }
         println!();
         bail!(
@@ -201,14 +319,19 @@ struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<RunResultValue>,
+    failures: Vec<TestFailure>,
+    /// The number of times each Sierra statement was executed by the tests (`None` for statements
+    /// that can't be tracked). Empty if coverage was not collected.
+    statements_hits: Vec<Option<usize>>,
 }
 
 /// Runs the tests and process the results for a summary.
 fn run_tests(
-    named_tests: Vec<(String, TestConfig)>,
+    named_tests: Vec<(String, TestConfig, Vec<FuzzedParam>)>,
     sierra_program: cairo_lang_sierra::program::Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
+    fuzzer_config: FuzzerConfig,
+    collect_coverage: bool,
 ) -> anyhow::Result<TestsSummary> {
     let runner = SierraCasmRunner::new(
         sierra_program,
@@ -216,39 +339,58 @@ fn run_tests(
     )
     .with_context(|| "Failed setting up runner.")?;
     println!("running {} tests", named_tests.len());
+    if named_tests.iter().any(|(_, test, params)| !test.ignored && !params.is_empty()) {
+        println!("fuzzing tests with parameters using seed {}", fuzzer_config.seed);
+    }
     let wrapped_summary = Mutex::new(Ok(TestsSummary {
         passed: vec![],
         failed: vec![],
         ignored: vec![],
-        failed_run_results: vec![],
+        failures: vec![],
+        statements_hits: vec![],
     }));
+    let statements_hits =
+        Mutex::new(if collect_coverage { runner.empty_sierra_statements_hits() } else { vec![] });
     named_tests
         .into_par_iter()
-        .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
+        .map(|(name, test, params)| -> anyhow::Result<(String, TestStatus)> {
             if test.ignored {
                 return Ok((name, TestStatus::Ignore));
             }
-            let result = runner
-                .run_function(name.as_str(), &[], test.available_gas)
-                .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-            Ok((
-                name,
-                match &result.value {
-                    RunResultValue::Success(_) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
-                    },
-                    RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
-                        TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                            PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
-                            }
-                            _ => TestStatus::Success,
-                        },
-                    },
-                },
-            ))
+            let run = |args: &[_]| -> anyhow::Result<Option<RunResultValue>> {
+                let result = runner
+                    .run_function(name.as_str(), args, test.available_gas, None)
+                    .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
+                if collect_coverage {
+                    let hits = runner.sierra_statements_hits(&result);
+                    let mut statements_hits = statements_hits.lock().unwrap();
+                    for (total, hits) in statements_hits.iter_mut().zip_eq(hits) {
+                        if let (Some(total), Some(hits)) = (total, hits) {
+                            *total += hits;
+                        }
+                    }
+                }
+                Ok(unexpected_run_result(result.value, &test.expectation))
+            };
+            let status = if params.is_empty() {
+                match run(&[])? {
+                    Some(run_result) => {
+                        TestStatus::Fail(TestFailure { run_result, counterexample: None })
+                    }
+                    None => TestStatus::Success,
+                }
+            } else {
+                match fuzz(&params, fuzzer_config, run)? {
+                    FuzzResult::Passed => TestStatus::Success,
+                    FuzzResult::Failed { counterexample, failure } => {
+                        TestStatus::Fail(TestFailure {
+                            run_result: failure,
+                            counterexample: Some(counterexample),
+                        })
+                    }
+                }
+            };
+            Ok((name, status))
         })
         .for_each(|r| {
             let mut wrapped_summary = wrapped_summary.lock().unwrap();
@@ -265,8 +407,8 @@ fn run_tests(
             let summary = wrapped_summary.as_mut().unwrap();
             let (res_type, status_str) = match status {
                 TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
-                TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(run_result);
+                TestStatus::Fail(failure) => {
+                    summary.failures.push(failure);
                     (&mut summary.failed, "fail".bright_red())
                 }
                 TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
@@ -274,7 +416,71 @@ fn run_tests(
             println!("test {name} ... {status_str}",);
             res_type.push(name);
         });
-    wrapped_summary.into_inner().unwrap()
+    let mut summary = wrapped_summary.into_inner().unwrap()?;
+    summary.statements_hits = statements_hits.into_inner().unwrap();
+    Ok(summary)
+}
+
+/// Returns the run result if it does not match the expectation of the test, and `None` otherwise.
+fn unexpected_run_result(
+    value: RunResultValue,
+    expectation: &TestExpectation,
+) -> Option<RunResultValue> {
+    let expected = match (&value, expectation) {
+        (RunResultValue::Success(_), TestExpectation::Success) => true,
+        (RunResultValue::Success(_), TestExpectation::Panics(_)) => false,
+        (RunResultValue::Panic(_), TestExpectation::Success) => false,
+        (RunResultValue::Panic(_), TestExpectation::Panics(PanicExpectation::Any)) => true,
+        (
+            RunResultValue::Panic(values),
+            TestExpectation::Panics(PanicExpectation::Exact(expected)),
+        ) => values == expected,
+    };
+    if expected { None } else { Some(value) }
+}
+
+/// Returns the parameters of a test, which are fuzzed when the test is run.
+fn get_fuzzed_params(
+    db: &dyn SemanticGroup,
+    func_id: FreeFunctionId,
+) -> anyhow::Result<Vec<FuzzedParam>> {
+    let signature = db
+        .free_function_signature(func_id)
+        .to_option()
+        .with_context(|| "Failed to get the signature of the test.")?;
+    signature
+        .params
+        .iter()
+        .map(|param| {
+            let ty_name = param.ty.format(db);
+            let ty = FuzzType::from_type_name(&ty_name).with_context(|| {
+                format!("Parameter `{}` is of unsupported type `{ty_name}`.", param.name)
+            })?;
+            Ok(FuzzedParam { name: param.name.to_string(), ty })
+        })
+        .collect()
+}
+
+/// Returns the crates with the given names out of the main crates, or all the main crates if no
+/// names are given.
+fn select_crates(
+    db: &dyn SemanticGroup,
+    main_crates: &[CrateId],
+    names: &[String],
+) -> anyhow::Result<Vec<CrateId>> {
+    if names.is_empty() {
+        return Ok(main_crates.to_vec());
+    }
+    names
+        .iter()
+        .map(|name| {
+            main_crates
+                .iter()
+                .copied()
+                .find(|crate_id| db.lookup_intern_crate(*crate_id).0 == name.as_str())
+                .with_context(|| format!("Crate `{name}` is not a crate of the project."))
+        })
+        .collect()
 }
 
 /// Finds the tests in the requested crates.
@@ -0,0 +1,106 @@
+//! Line coverage of the tests, reported in the lcov format.
+//!
+//! Every Sierra statement is mapped to the source line of the code it was generated from, and a
+//! line is considered covered if any of its statements was executed by any of the tests.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_diagnostics::DiagnosticLocation;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
+
+#[cfg(test)]
+#[path = "coverage_test.rs"]
+mod test;
+
+/// A line in a source file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLine {
+    /// The path of the source file.
+    pub path: PathBuf,
+    /// The line number, 1 based.
+    pub line: usize,
+}
+
+/// Returns the source lines of the Sierra statements with the given locations.
+/// Only statements generated from code on disk of one of `crate_ids` are mapped to a line.
+pub fn statements_source_lines(
+    db: &dyn DefsGroup,
+    statements_locations: &[Option<StableLocation>],
+    crate_ids: &[CrateId],
+) -> Vec<Option<SourceLine>> {
+    statements_locations
+        .iter()
+        .map(|location| {
+            let location = location.as_ref()?;
+            if !crate_ids.contains(&location.module_file_id.0.owning_crate(db)) {
+                return None;
+            }
+            let DiagnosticLocation { file_id, span } = location.diagnostic_location(db);
+            let FileLongId::OnDisk(path) = db.lookup_intern_file(file_id) else { return None; };
+            let position = span.start.position_in_file(db.upcast(), file_id)?;
+            Some(SourceLine { path, line: position.line + 1 })
+        })
+        .collect()
+}
+
+/// The number of executions of the lines of the source files.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LineCoverage {
+    /// A map from a file path to the number of executions of each of its instrumented lines.
+    files: BTreeMap<PathBuf, BTreeMap<usize, usize>>,
+}
+impl LineCoverage {
+    /// Creates the line coverage from the source lines of the Sierra statements and the number of
+    /// times each statement was executed (`None` for statements that can't be tracked). A line's
+    /// executions count is the maximal executions count of its tracked statements.
+    pub fn new(statements_lines: &[Option<SourceLine>], statements_hits: &[Option<usize>]) -> Self {
+        let mut files = BTreeMap::<PathBuf, BTreeMap<usize, usize>>::new();
+        for (source_line, hits) in statements_lines.iter().zip(statements_hits) {
+            let (Some(SourceLine { path, line }), Some(hits)) = (source_line, hits) else {
+                continue;
+            };
+            let line_hits = files.entry(path.clone()).or_default().entry(*line).or_default();
+            *line_hits = (*line_hits).max(*hits);
+        }
+        Self { files }
+    }
+
+    /// Returns the number of instrumented lines.
+    pub fn lines_count(&self) -> usize {
+        self.files.values().map(|lines| lines.len()).sum()
+    }
+
+    /// Returns the number of instrumented lines that were executed.
+    pub fn covered_lines_count(&self) -> usize {
+        self.files.values().map(|lines| lines.values().filter(|hits| **hits > 0).count()).sum()
+    }
+
+    /// Returns the percentage of executed lines out of the instrumented lines, or 0 if there are
+    /// no instrumented lines.
+    pub fn percentage(&self) -> f64 {
+        match self.lines_count() {
+            0 => 0.0,
+            lines_count => 100.0 * self.covered_lines_count() as f64 / lines_count as f64,
+        }
+    }
+
+    /// Formats the coverage as an lcov tracefile.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, lines) in &self.files {
+            writeln!(lcov, "TN:").unwrap();
+            writeln!(lcov, "SF:{}", path.display()).unwrap();
+            for (line, hits) in lines {
+                writeln!(lcov, "DA:{line},{hits}").unwrap();
+            }
+            writeln!(lcov, "LF:{}", lines.len()).unwrap();
+            writeln!(lcov, "LH:{}", lines.values().filter(|hits| **hits > 0).count()).unwrap();
+            writeln!(lcov, "end_of_record").unwrap();
+        }
+        lcov
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::find_free_functions;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use indoc::indoc;
+use itertools::chain;
+use pretty_assertions::assert_eq;
+
+use super::{statements_source_lines, LineCoverage, SourceLine};
+use crate::fuzzing::FuzzerConfig;
+use crate::plugin::TestPlugin;
+use crate::{find_all_tests, run_tests, TestsSummary};
+
+fn line(path: &str, line: usize) -> Option<SourceLine> {
+    Some(SourceLine { path: path.into(), line })
+}
+
+#[test]
+fn test_line_coverage() {
+    let statements_lines = [
+        line("src/lib.cairo", 3),
+        line("src/lib.cairo", 3),
+        None,
+        line("src/lib.cairo", 1),
+        line("src/utils.cairo", 7),
+        line("src/lib.cairo", 5),
+        line("src/lib.cairo", 9),
+    ];
+    let statements_hits = [Some(0), Some(2), Some(4), Some(1), Some(0), Some(0), None];
+    let coverage = LineCoverage::new(&statements_lines, &statements_hits);
+    assert_eq!(coverage.lines_count(), 4);
+    assert_eq!(coverage.covered_lines_count(), 2);
+    assert_eq!(coverage.percentage(), 50.0);
+    assert_eq!(
+        coverage.to_lcov(),
+        indoc! {"
+            TN:
+            SF:src/lib.cairo
+            DA:1,1
+            DA:3,2
+            DA:5,0
+            LF:3
+            LH:2
+            end_of_record
+            TN:
+            SF:src/utils.cairo
+            DA:7,0
+            LF:1
+            LH:0
+            end_of_record
+        "}
+    );
+}
+
+#[test]
+fn test_empty_line_coverage() {
+    let coverage = LineCoverage::new(&[None], &[Some(1)]);
+    assert_eq!(coverage.lines_count(), 0);
+    assert_eq!(coverage.percentage(), 0.0);
+    assert_eq!(coverage.to_lcov(), "");
+}
+
+/// Tests the coverage when no test ran, where all the tracked statements have no hits.
+#[test]
+fn test_line_coverage_without_runs() {
+    let statements_lines = [line("src/lib.cairo", 1), line("src/lib.cairo", 2), None];
+    let coverage = LineCoverage::new(&statements_lines, &[Some(0), Some(0), None]);
+    assert_eq!(coverage.lines_count(), 2);
+    assert_eq!(coverage.covered_lines_count(), 0);
+    assert_eq!(coverage.percentage(), 0.0);
+    assert_eq!(
+        coverage.to_lcov(),
+        indoc! {"
+            TN:
+            SF:src/lib.cairo
+            DA:1,0
+            DA:2,0
+            LF:2
+            LH:0
+            end_of_record
+        "}
+    );
+}
+
+/// Runs the tests of a small project and checks the lines reported as covered: the lines of the
+/// taken branch are covered, and the lines of the other branch and of the untested function are
+/// not.
+#[test]
+fn test_tests_coverage() {
+    let mut plugins = get_default_plugins();
+    plugins.push(Arc::new(TestPlugin::default()));
+    let db = &mut RootDatabase::builder()
+        .with_cfg(CfgSet::from_iter([Cfg::tag("test")]))
+        .with_plugins(plugins)
+        .detect_corelib()
+        .build()
+        .unwrap();
+    db.set_add_statements_locations(true);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/coverage.cairo");
+    let crate_ids = setup_project(db, &path).unwrap();
+
+    let tests = find_all_tests(db, crate_ids.clone());
+    let requested_function_ids: Vec<_> = chain!(
+        tests.iter().flat_map(|(func_id, _)| {
+            ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
+        }),
+        find_free_functions(db, &crate_ids).unwrap()
+    )
+    .collect();
+    let sierra_program =
+        db.get_sierra_program_for_functions(requested_function_ids.clone()).unwrap();
+    let statements_locations =
+        db.get_sierra_program_statements_locations(requested_function_ids).unwrap();
+    let statements_lines = statements_source_lines(db, &statements_locations, &crate_ids);
+
+    let named_tests =
+        tests.into_iter().map(|(_, test)| ("coverage::test_sign".into(), test, vec![])).collect();
+    let TestsSummary { passed, statements_hits, .. } = run_tests(
+        named_tests,
+        replace_sierra_ids_in_program(db, &sierra_program),
+        Default::default(),
+        FuzzerConfig { runs: 0, seed: 0 },
+        true,
+    )
+    .unwrap();
+    assert_eq!(passed, ["coverage::test_sign"]);
+
+    let coverage = LineCoverage::new(&statements_lines, &statements_hits);
+    assert_eq!(
+        coverage.to_lcov().replace(&path.display().to_string(), "coverage.cairo"),
+        indoc! {"
+            TN:
+            SF:coverage.cairo
+            DA:2,1
+            DA:3,0
+            DA:5,1
+            DA:10,0
+            DA:14,1
+            DA:15,1
+            LF:6
+            LH:4
+            end_of_record
+        "}
+    );
+}
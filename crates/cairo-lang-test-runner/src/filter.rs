@@ -0,0 +1,57 @@
+//! Selection of the tests to run by their full path.
+
+use anyhow::Context;
+use regex::Regex;
+
+#[cfg(test)]
+#[path = "filter_test.rs"]
+mod test;
+
+/// A filter selecting tests by their full path (e.g. `my_crate::tests::test_add`).
+#[derive(Debug)]
+pub enum TestFilter {
+    /// Selects the tests whose path contains the string. Selects all tests if empty.
+    Substring(String),
+    /// Selects only the test with exactly the given path.
+    Exact(String),
+    /// Selects the tests whose path matches the regular expression.
+    Pattern(Regex),
+}
+impl TestFilter {
+    /// Creates a filter selecting the tests whose whole path matches a glob pattern.
+    /// `*` matches any sequence of characters within a single path segment, `**` matches any
+    /// sequence of characters across segments, and `?` matches a single character of a segment.
+    pub fn glob(pattern: &str) -> anyhow::Result<Self> {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.next_if_eq(&'*').is_some() => regex.push_str(".*"),
+                '*' => regex.push_str("[^:]*"),
+                '?' => regex.push_str("[^:]"),
+                _ => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+        Ok(Self::Pattern(
+            Regex::new(&regex).with_context(|| format!("Invalid glob pattern `{pattern}`."))?,
+        ))
+    }
+
+    /// Creates a filter selecting the tests whose path matches a regular expression.
+    /// The expression is not anchored, so it may match any part of the path.
+    pub fn regex(pattern: &str) -> anyhow::Result<Self> {
+        Ok(Self::Pattern(
+            Regex::new(pattern).with_context(|| format!("Invalid regex pattern `{pattern}`."))?,
+        ))
+    }
+
+    /// Returns true if the test with the given full path is selected by the filter.
+    pub fn matches(&self, test_path: &str) -> bool {
+        match self {
+            TestFilter::Substring(filter) => test_path.contains(filter.as_str()),
+            TestFilter::Exact(filter) => test_path == filter,
+            TestFilter::Pattern(regex) => regex.is_match(test_path),
+        }
+    }
+}
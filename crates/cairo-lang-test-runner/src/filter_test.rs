@@ -0,0 +1,52 @@
+use test_case::test_case;
+
+use super::TestFilter;
+
+const TESTS: [&str; 5] = [
+    "my_crate::test_add",
+    "my_crate::test_add_overflow",
+    "my_crate::tests::test_add",
+    "my_crate::tests::inner::test_sub",
+    "other_crate::test_add",
+];
+
+fn selected(filter: &TestFilter) -> Vec<&'static str> {
+    TESTS.into_iter().filter(|test| filter.matches(test)).collect()
+}
+
+#[test_case(TestFilter::Substring("".into()), &TESTS; "empty substring")]
+#[test_case(
+    TestFilter::Substring("test_add".into()),
+    &["my_crate::test_add", "my_crate::test_add_overflow", "my_crate::tests::test_add", "other_crate::test_add"];
+    "substring"
+)]
+#[test_case(TestFilter::Exact("my_crate::test_add".into()), &["my_crate::test_add"]; "exact")]
+#[test_case(TestFilter::Exact("test_add".into()), &[]; "exact requires full path")]
+#[test_case(
+    TestFilter::glob("my_crate::*").unwrap(),
+    &["my_crate::test_add", "my_crate::test_add_overflow"];
+    "glob single segment"
+)]
+#[test_case(
+    TestFilter::glob("my_crate::**").unwrap(),
+    &["my_crate::test_add", "my_crate::test_add_overflow", "my_crate::tests::test_add", "my_crate::tests::inner::test_sub"];
+    "glob any segments"
+)]
+#[test_case(
+    TestFilter::glob("*::test_ad?").unwrap(),
+    &["my_crate::test_add", "other_crate::test_add"];
+    "glob single char"
+)]
+#[test_case(
+    TestFilter::regex("tests::.*sub$").unwrap(),
+    &["my_crate::tests::inner::test_sub"];
+    "regex"
+)]
+fn test_filter(filter: TestFilter, expected: &[&str]) {
+    assert_eq!(selected(&filter), expected);
+}
+
+#[test]
+fn test_invalid_regex() {
+    assert!(TestFilter::regex("test_(").is_err());
+}
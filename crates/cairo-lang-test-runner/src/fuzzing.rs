@@ -0,0 +1,316 @@
+//! Property based testing of tests with parameters.
+//!
+//! A test with parameters is run multiple times with randomly generated arguments. When a run
+//! fails, the failing arguments are shrunk to a minimal counterexample that still fails the test.
+
+use cairo_felt::Felt252;
+use cairo_lang_runner::Arg;
+use itertools::Itertools;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+#[path = "fuzzing_test.rs"]
+mod test;
+
+/// The maximal length of a generated array argument.
+const MAX_ARRAY_LEN: usize = 16;
+/// The maximal number of additional runs used for shrinking a failing input.
+const MAX_SHRINK_RUNS: usize = 1024;
+/// The probability of generating an edge case value instead of a uniformly distributed one.
+const EDGE_CASE_PROBABILITY: f64 = 0.2;
+
+/// The configuration of the fuzzer.
+#[derive(Clone, Copy, Debug)]
+pub struct FuzzerConfig {
+    /// The number of random inputs each test with parameters is run with.
+    pub runs: usize,
+    /// The seed of the random inputs generator.
+    pub seed: u64,
+}
+
+/// The type of a fuzzed parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzType {
+    Felt252,
+    /// An unsigned integer of the given number of bits.
+    Uint(usize),
+    Bool,
+    U256,
+    /// An `Array` or a `Span` of a type represented by a single felt252.
+    Array(Box<FuzzType>),
+}
+impl FuzzType {
+    /// Returns the fuzz type of a fully qualified Cairo type name, if the type is supported.
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "core::felt252" => FuzzType::Felt252,
+            "core::bool" => FuzzType::Bool,
+            "core::integer::u8" => FuzzType::Uint(8),
+            "core::integer::u16" => FuzzType::Uint(16),
+            "core::integer::u32" => FuzzType::Uint(32),
+            "core::integer::u64" => FuzzType::Uint(64),
+            "core::integer::u128" => FuzzType::Uint(128),
+            "core::integer::u256" => FuzzType::U256,
+            _ => {
+                let inner = name
+                    .strip_prefix("core::array::Array::<")
+                    .or_else(|| name.strip_prefix("core::array::Span::<"))?
+                    .strip_suffix('>')?;
+                let inner = Self::from_type_name(inner)?;
+                if !inner.is_single_felt252() {
+                    return None;
+                }
+                FuzzType::Array(Box::new(inner))
+            }
+        })
+    }
+
+    /// Returns true if values of the type are represented by a single felt252.
+    fn is_single_felt252(&self) -> bool {
+        matches!(self, FuzzType::Felt252 | FuzzType::Uint(_) | FuzzType::Bool)
+    }
+
+    /// Returns the exclusive upper bound of the values of a non-array type.
+    fn bound(&self) -> BigUint {
+        match self {
+            FuzzType::Felt252 => Felt252::prime(),
+            FuzzType::Uint(bits) => BigUint::one() << bits,
+            FuzzType::Bool => BigUint::from(2u32),
+            FuzzType::U256 => BigUint::one() << 256,
+            FuzzType::Array(_) => unreachable!("Array types have no value bound."),
+        }
+    }
+}
+
+/// A parameter of a fuzzed test.
+#[derive(Clone, Debug)]
+pub struct FuzzedParam {
+    /// The name of the parameter.
+    pub name: String,
+    /// The type of the parameter.
+    pub ty: FuzzType,
+}
+
+/// A value generated for a fuzzed parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzValue {
+    Scalar(BigUint),
+    Array(Vec<BigUint>),
+}
+
+/// The result of fuzzing a test.
+pub enum FuzzResult<F> {
+    /// All the runs passed.
+    Passed,
+    /// A run failed. Contains the formatted minimal failing input and the failure it caused.
+    Failed { counterexample: String, failure: F },
+}
+
+/// Runs a test with parameters on random inputs, according to `config`.
+/// `run` runs the test with the given arguments, and returns the failure if the test failed.
+pub fn fuzz<F>(
+    params: &[FuzzedParam],
+    config: FuzzerConfig,
+    mut run: impl FnMut(&[Arg]) -> anyhow::Result<Option<F>>,
+) -> anyhow::Result<FuzzResult<F>> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    for _ in 0..config.runs {
+        let inputs = params.iter().map(|param| random_value(&mut rng, &param.ty)).collect_vec();
+        if let Some(failure) = run(&to_args(params, &inputs))? {
+            let (inputs, failure) = shrink(params, inputs, failure, &mut run)?;
+            return Ok(FuzzResult::Failed {
+                counterexample: format_inputs(params, &inputs),
+                failure,
+            });
+        }
+    }
+    Ok(FuzzResult::Passed)
+}
+
+/// Shrinks a failing input to a smaller input failing the test, returning the smallest input found
+/// and its failure.
+fn shrink<F>(
+    params: &[FuzzedParam],
+    mut inputs: Vec<FuzzValue>,
+    mut failure: F,
+    run: &mut impl FnMut(&[Arg]) -> anyhow::Result<Option<F>>,
+) -> anyhow::Result<(Vec<FuzzValue>, F)> {
+    let mut remaining_runs = MAX_SHRINK_RUNS;
+    let mut try_candidate = |candidate: &[FuzzValue]| -> anyhow::Result<Option<F>> {
+        if remaining_runs == 0 {
+            return Ok(None);
+        }
+        remaining_runs -= 1;
+        run(&to_args(params, candidate))
+    };
+    loop {
+        let mut improved = false;
+        // Shortening the arrays.
+        for i in 0..inputs.len() {
+            let FuzzValue::Array(values) = &inputs[i] else { continue; };
+            for shorter in shorter_arrays(values) {
+                let mut candidate = inputs.clone();
+                candidate[i] = FuzzValue::Array(shorter);
+                if let Some(candidate_failure) = try_candidate(&candidate)? {
+                    inputs = candidate;
+                    failure = candidate_failure;
+                    improved = true;
+                    break;
+                }
+            }
+        }
+        // Minimizing the scalars, by a binary search between zero and the failing value.
+        for position in scalar_positions(&inputs) {
+            let mut high = get_scalar(&inputs, position).clone();
+            if high.is_zero() {
+                continue;
+            }
+            let zero_candidate = with_scalar(&inputs, position, BigUint::zero());
+            if let Some(candidate_failure) = try_candidate(&zero_candidate)? {
+                inputs = zero_candidate;
+                failure = candidate_failure;
+                improved = true;
+                continue;
+            }
+            let mut low = BigUint::zero();
+            while &high - &low > BigUint::one() {
+                let mid: BigUint = (&low + &high) >> 1;
+                let candidate = with_scalar(&inputs, position, mid.clone());
+                if let Some(candidate_failure) = try_candidate(&candidate)? {
+                    inputs = candidate;
+                    failure = candidate_failure;
+                    improved = true;
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+        }
+        if !improved {
+            return Ok((inputs, failure));
+        }
+    }
+}
+
+/// The position of a scalar in the inputs - the index of the parameter, and the index within the
+/// array for array parameters.
+type ScalarPosition = (usize, Option<usize>);
+
+/// Returns the positions of all the scalars in the inputs.
+fn scalar_positions(inputs: &[FuzzValue]) -> Vec<ScalarPosition> {
+    inputs
+        .iter()
+        .enumerate()
+        .flat_map(|(i, value)| match value {
+            FuzzValue::Scalar(_) => vec![(i, None)],
+            FuzzValue::Array(values) => (0..values.len()).map(|j| (i, Some(j))).collect(),
+        })
+        .collect()
+}
+
+/// Returns the scalar at the given position.
+fn get_scalar(inputs: &[FuzzValue], position: ScalarPosition) -> &BigUint {
+    match (&inputs[position.0], position.1) {
+        (FuzzValue::Scalar(value), None) => value,
+        (FuzzValue::Array(values), Some(j)) => &values[j],
+        _ => unreachable!("Invalid scalar position."),
+    }
+}
+
+/// Returns a copy of the inputs with the scalar at the given position replaced.
+fn with_scalar(inputs: &[FuzzValue], position: ScalarPosition, scalar: BigUint) -> Vec<FuzzValue> {
+    let mut inputs = inputs.to_vec();
+    match (&mut inputs[position.0], position.1) {
+        (FuzzValue::Scalar(value), None) => *value = scalar,
+        (FuzzValue::Array(values), Some(j)) => values[j] = scalar,
+        _ => unreachable!("Invalid scalar position."),
+    }
+    inputs
+}
+
+/// Returns strictly shorter versions of an array, from the shortest to the longest.
+fn shorter_arrays(values: &[BigUint]) -> Vec<Vec<BigUint>> {
+    if values.is_empty() {
+        return vec![];
+    }
+    let half = values.len() / 2;
+    let mut shorter = vec![vec![], values[..half].to_vec(), values[half..].to_vec()];
+    for i in 0..values.len() {
+        let mut removed = values.to_vec();
+        removed.remove(i);
+        shorter.push(removed);
+    }
+    shorter.into_iter().filter(|v| v.len() < values.len()).unique().collect()
+}
+
+/// Generates a random value of the given type.
+fn random_value(rng: &mut StdRng, ty: &FuzzType) -> FuzzValue {
+    match ty {
+        FuzzType::Array(inner) => {
+            let len = rng.gen_range(0..=MAX_ARRAY_LEN);
+            FuzzValue::Array((0..len).map(|_| random_scalar(rng, inner)).collect())
+        }
+        _ => FuzzValue::Scalar(random_scalar(rng, ty)),
+    }
+}
+
+/// Generates a random value of a non-array type, favoring edge cases.
+fn random_scalar(rng: &mut StdRng, ty: &FuzzType) -> BigUint {
+    let bound = ty.bound();
+    if rng.gen_bool(EDGE_CASE_PROBABILITY) {
+        let max = &bound - 1u32;
+        let mut edge_cases = vec![BigUint::zero(), BigUint::one(), &max - 1u32, max];
+        return edge_cases.swap_remove(rng.gen_range(0..edge_cases.len()));
+    }
+    let n_bytes = bound.to_bytes_le().len();
+    let bytes: Vec<u8> = (0..n_bytes).map(|_| rng.gen()).collect();
+    BigUint::from_bytes_le(&bytes) % bound
+}
+
+/// Converts the values of the parameters into the arguments of the test function.
+fn to_args(params: &[FuzzedParam], inputs: &[FuzzValue]) -> Vec<Arg> {
+    params
+        .iter()
+        .zip_eq(inputs)
+        .flat_map(|(param, value)| match (&param.ty, value) {
+            (FuzzType::U256, FuzzValue::Scalar(value)) => {
+                let low = value & ((BigUint::one() << 128) - 1u32);
+                let high = value >> 128;
+                vec![Arg::Value(Felt252::from(low)), Arg::Value(Felt252::from(high))]
+            }
+            (_, FuzzValue::Scalar(value)) => vec![Arg::Value(Felt252::from(value))],
+            (_, FuzzValue::Array(values)) => {
+                vec![Arg::Array(values.iter().map(Felt252::from).collect())]
+            }
+        })
+        .collect()
+}
+
+/// Formats the values of the parameters, for printing a counterexample.
+fn format_inputs(params: &[FuzzedParam], inputs: &[FuzzValue]) -> String {
+    params
+        .iter()
+        .zip_eq(inputs)
+        .map(|(param, value)| {
+            let formatted = match (&param.ty, value) {
+                (FuzzType::Array(inner), FuzzValue::Array(values)) => {
+                    format!("[{}]", values.iter().map(|v| format_scalar(inner, v)).join(", "))
+                }
+                (ty, FuzzValue::Scalar(value)) => format_scalar(ty, value),
+                (_, FuzzValue::Array(_)) => unreachable!("Array values are only of array types."),
+            };
+            format!("{} = {formatted}", param.name)
+        })
+        .join(", ")
+}
+
+/// Formats a value of a non-array type.
+fn format_scalar(ty: &FuzzType, value: &BigUint) -> String {
+    match ty {
+        FuzzType::Bool => (!value.is_zero()).to_string(),
+        _ => value.to_string(),
+    }
+}
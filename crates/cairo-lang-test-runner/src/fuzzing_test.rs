@@ -0,0 +1,175 @@
+use cairo_felt::Felt252;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_runner::{Arg, RunResultValue, RunnerError, SierraCasmRunner};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_utils::extract_matches;
+use indoc::indoc;
+use num_bigint::BigUint;
+use test_case::test_case;
+
+use super::{fuzz, FuzzResult, FuzzType, FuzzedParam, FuzzerConfig};
+use crate::get_fuzzed_params;
+
+#[test_case("core::felt252", Some(FuzzType::Felt252); "felt252")]
+#[test_case("core::integer::u64", Some(FuzzType::Uint(64)); "u64")]
+#[test_case("core::integer::u256", Some(FuzzType::U256); "u256")]
+#[test_case(
+    "core::array::Array::<core::bool>",
+    Some(FuzzType::Array(Box::new(FuzzType::Bool)));
+    "array of bool"
+)]
+#[test_case("core::array::Span::<core::integer::u256>", None; "span of u256")]
+#[test_case("core::option::Option::<core::felt252>", None; "option")]
+fn test_fuzz_type_from_name(name: &str, expected: Option<FuzzType>) {
+    assert_eq!(FuzzType::from_type_name(name), expected);
+}
+
+fn param(name: &str, ty: FuzzType) -> FuzzedParam {
+    FuzzedParam { name: name.into(), ty }
+}
+
+fn config() -> FuzzerConfig {
+    FuzzerConfig { runs: 256, seed: 3 }
+}
+
+/// Returns the value of a single valued argument.
+fn value(arg: &Arg) -> BigUint {
+    match arg {
+        Arg::Value(value) => value.to_biguint(),
+        Arg::Array(_) => panic!("Expected a single value."),
+    }
+}
+
+#[test]
+fn test_fuzz_passes() {
+    let result = fuzz(&[param("a", FuzzType::Uint(8))], config(), |args| {
+        Ok((value(&args[0]) >= BigUint::from(256u32)).then_some(()))
+    })
+    .unwrap();
+    assert!(matches!(result, FuzzResult::Passed));
+}
+
+#[test]
+fn test_fuzz_shrinks_scalars() {
+    let params = [param("a", FuzzType::Felt252), param("b", FuzzType::Bool)];
+    let result = fuzz(&params, config(), |args| {
+        Ok((value(&args[0]) > BigUint::from(1000u32)).then(|| value(&args[0])))
+    })
+    .unwrap();
+    let FuzzResult::Failed { counterexample, failure } = result else {
+        panic!("Expected the fuzzed test to fail.");
+    };
+    assert_eq!(counterexample, "a = 1001, b = false");
+    assert_eq!(failure, BigUint::from(1001u32));
+}
+
+#[test]
+fn test_fuzz_shrinks_arrays() {
+    let params = [param("values", FuzzType::Array(Box::new(FuzzType::Uint(32))))];
+    let result = fuzz(&params, config(), |args| {
+        let Arg::Array(values) = &args[0] else { panic!("Expected an array.") };
+        Ok(values.iter().any(|v| v > &Felt252::from(10)).then_some(()))
+    })
+    .unwrap();
+    let FuzzResult::Failed { counterexample, .. } = result else {
+        panic!("Expected the fuzzed test to fail.");
+    };
+    assert_eq!(counterexample, "values = [11]");
+}
+
+#[test]
+fn test_fuzz_u256_args() {
+    let result = fuzz(&[param("x", FuzzType::U256)], config(), |args| {
+        assert_eq!(args.len(), 2);
+        Ok((value(&args[1]) > BigUint::from(0u32)).then_some(()))
+    })
+    .unwrap();
+    let FuzzResult::Failed { counterexample, .. } = result else {
+        panic!("Expected the fuzzed test to fail.");
+    };
+    assert_eq!(counterexample, format!("x = {}", BigUint::from(1u32) << 128));
+}
+
+/// Tests with parameters, compiled and run by the runner as done by `cairo-test`.
+const CAIRO_TESTS: &str = indoc! {"
+    use array::ArrayTrait;
+
+    fn test_small_first_element(values: Array<u8>) {
+        if values.len() > 0_usize {
+            assert(*values.at(0_usize) < 100_u8, 'first element too large');
+        }
+    }
+
+    fn test_short_array(x: u32, values: Array<felt252>) {
+        assert(values.len() <= 16_u32, 'array too long');
+    }
+"};
+
+/// Compiles the tests of `CAIRO_TESTS`, and returns the database, the crate and the runner of the
+/// tests.
+fn setup_cairo_tests() -> (RootDatabase, CrateId, SierraCasmRunner) {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(&mut db, CAIRO_TESTS);
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let program = replace_sierra_ids_in_program(&db, &program);
+    let runner = SierraCasmRunner::new(program, None).unwrap();
+    (db, crate_id, runner)
+}
+
+/// Fuzzes the Cairo test with the given name through the runner.
+fn fuzz_cairo_test(
+    (db, crate_id, runner): &(RootDatabase, CrateId, SierraCasmRunner),
+    name: &str,
+) -> FuzzResult<RunResultValue> {
+    let func_id = extract_matches!(
+        db.module_item_by_name(ModuleId::CrateRoot(*crate_id), name.into()).unwrap().unwrap(),
+        ModuleItemId::FreeFunction
+    );
+    let params = get_fuzzed_params(db, func_id).unwrap();
+    fuzz(&params, config(), |args| {
+        let result = runner.run_function(name, args, None, None)?;
+        Ok(match result.value {
+            RunResultValue::Success(_) => None,
+            value @ RunResultValue::Panic(_) => Some(value),
+        })
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_fuzz_cairo_tests() {
+    let setup = setup_cairo_tests();
+
+    let FuzzResult::Failed { counterexample, failure } =
+        fuzz_cairo_test(&setup, "test_small_first_element")
+    else {
+        panic!("Expected the fuzzed test to fail.");
+    };
+    assert_eq!(counterexample, "values = [100]");
+    assert_eq!(
+        failure,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be(b"first element too large")])
+    );
+
+    assert!(matches!(fuzz_cairo_test(&setup, "test_short_array"), FuzzResult::Passed));
+}
+
+#[test]
+fn test_too_large_array_argument() {
+    let (_, _, runner) = setup_cairo_tests();
+    let values = vec![Felt252::from(0); 1 << 16];
+    assert!(matches!(
+        runner.run_function(
+            "test_short_array",
+            &[Arg::Value(0.into()), Arg::Array(values)],
+            None,
+            None
+        ),
+        Err(RunnerError::ArgumentsTooLarge)
+    ));
+}
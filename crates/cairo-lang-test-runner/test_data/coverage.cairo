@@ -0,0 +1,16 @@
+fn sign(x: felt252) -> felt252 {
+    if x == 0 {
+        0
+    } else {
+        1
+    }
+}
+
+fn untested() -> felt252 {
+    sign(0)
+}
+
+#[test]
+fn test_sign() {
+    assert(sign(5) == 1, 'wrong sign');
+}
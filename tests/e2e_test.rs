@@ -1,6 +1,7 @@
 use std::ops::DerefMut;
 use std::sync::Mutex;
 
+use cairo_lang_casm::parser::parse_casm;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_semantic::test_utils::setup_test_module;
@@ -85,9 +86,11 @@ impl TestFileRunner for SmallE2ETestRunner {
             .join("\n");
 
         // Compile to casm.
-        let casm = cairo_lang_sierra_to_casm::compiler::compile(&sierra_program, &metadata, true)
-            .unwrap()
-            .to_string();
+        let cairo_program =
+            cairo_lang_sierra_to_casm::compiler::compile(&sierra_program, &metadata, true).unwrap();
+        let casm = cairo_program.to_string();
+        // The printed code should be parsed back to the same instructions.
+        assert_eq!(parse_casm(&casm).unwrap(), cairo_program.instructions);
 
         OrderedHashMap::from([
             ("casm".into(), casm),
@@ -3,6 +3,7 @@ use std::sync::{Arc, Mutex};
 
 use assert_matches::assert_matches;
 use cairo_felt::{felt_str as felt252_str, Felt252};
+use cairo_lang_casm::parser::parse_casm;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
@@ -11,12 +12,13 @@ use cairo_lang_filesystem::db::FilesGroupEx;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
+use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use cairo_lang_utils::{extract_matches, Upcast};
+use itertools::Itertools;
 use rstest::{fixture, rstest};
 
 type ExampleDirData = (Mutex<RootDatabase>, Vec<CrateId>);
@@ -144,17 +146,16 @@ fn cairo_to_casm(
     example_dir_data: &ExampleDirData,
 ) {
     let program = checked_compile_to_sierra(name, example_dir_data, false);
-    compare_contents_or_fix(
-        name,
-        "casm",
-        cairo_lang_sierra_to_casm::compiler::compile(
-            &program,
-            &build_metadata(&program, enable_gas_checks),
-            enable_gas_checks,
-        )
-        .unwrap()
-        .to_string(),
-    );
+    let casm = cairo_lang_sierra_to_casm::compiler::compile(
+        &program,
+        &build_metadata(&program, enable_gas_checks),
+        enable_gas_checks,
+    )
+    .unwrap();
+    let casm_code = casm.to_string();
+    // The printed code should be parsed back to the same instructions.
+    assert_eq!(parse_casm(&casm_code).unwrap(), casm.instructions);
+    compare_contents_or_fix(name, "casm", casm_code);
 }
 
 /// Tests lowering from Cairo to casm, with automatic addition of `withdraw_gas` calls.
@@ -189,7 +190,13 @@ fn run_function(
     )
     .expect("Failed setting up runner.");
     let result = runner
-        .run_function(/* find first */ "", params, available_gas)
+        .run_function(
+            // find first
+            "",
+            &params.iter().cloned().map(Arg::Value).collect_vec(),
+            available_gas,
+            None,
+        )
         .expect("Failed running the function.");
     if let Some(expected_cost) = expected_cost {
         assert_eq!(